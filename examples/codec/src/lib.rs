@@ -96,24 +96,82 @@ mod tests {
         ABIDecoder::new(DecoderConfig {
             max_depth: 5,
             max_tokens: 100,
+            max_array_length: 100,
         });
         // ANCHOR_END: configuring_the_decoder
 
         Ok(())
     }
 
+    #[test]
+    fn tolerant_decoding() -> Result<()> {
+        // ANCHOR: tolerant_decoding
+        use fuels::{
+            core::{codec::ABIDecoder, traits::Parameterize},
+            macros::{Parameterize, Tokenizable},
+        };
+
+        #[derive(Parameterize, Tokenizable)]
+        struct MyStruct {
+            amount: u64,
+            recipient: [u8; 32],
+        }
+
+        // the recipient is cut short
+        let bytes = [[0, 0, 0, 0, 0, 0, 0, 100], [1; 8]].concat();
+
+        let partial = ABIDecoder::default().decode_tolerant(&MyStruct::param_type(), &bytes);
+
+        assert_eq!(partial.decoded[0].0, "value.0");
+        let failure = partial.failure.expect("the recipient cannot be decoded");
+        assert_eq!(failure.path, "value.1[8]");
+        assert_eq!(failure.byte_range, 16..17);
+        // ANCHOR_END: tolerant_decoding
+
+        Ok(())
+    }
+
     #[test]
     fn configuring_the_encoder() -> Result<()> {
         // ANCHOR: configuring_the_encoder
-        use fuels::core::codec::ABIEncoder;
+        use fuels::core::codec::{ABIEncoder, StringPolicy};
 
         ABIEncoder::new(EncoderConfig {
             max_depth: 5,
             max_tokens: 100,
             max_total_enum_width: 10_000,
+            string_policy: StringPolicy::Ascii,
         });
         // ANCHOR_END: configuring_the_encoder
 
         Ok(())
     }
+
+    #[test]
+    fn encoding_utf8_strings() -> Result<()> {
+        // ANCHOR: string_policy
+        use fuels::{
+            core::codec::{ABIEncoder, StringPolicy},
+            types::{SizedAsciiString, StaticStringToken, Token},
+        };
+
+        // `str[5]` holds five bytes, "café" is four characters but five bytes in UTF-8
+        let token = Token::StringArray(StaticStringToken::new("café".to_string(), Some(5)));
+
+        assert!(ABIEncoder::default().encode(&[token.clone()]).is_err());
+
+        let encoded = ABIEncoder::new(EncoderConfig {
+            string_policy: StringPolicy::Utf8,
+            ..Default::default()
+        })
+        .encode(&[token])?
+        .resolve(0);
+        assert_eq!(&encoded[..5], "café".as_bytes());
+        // ANCHOR_END: string_policy
+
+        // typed strings stay ASCII-only
+        assert!(SizedAsciiString::<5>::new("café".to_string()).is_err());
+
+        Ok(())
+    }
 }
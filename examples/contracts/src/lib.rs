@@ -96,7 +96,7 @@ mod tests {
         .await?;
 
         // ANCHOR: contract_call_cost_estimation
-        let contract_instance = MyContract::new(contract_id, wallet);
+        let contract_instance = MyContract::new(MyContractId::new(contract_id), wallet);
 
         let tolerance = 0.0;
         let transaction_cost = contract_instance
@@ -172,7 +172,7 @@ mod tests {
         // ANCHOR_END: abigen_example
 
         // This is an instance of your contract which you can use to make calls to your functions
-        let contract_instance = MyContract::new(contract_id_2, wallet);
+        let contract_instance = MyContract::new(MyContractId::new(contract_id_2), wallet);
 
         let response = contract_instance
             .methods()
@@ -226,7 +226,8 @@ mod tests {
         .await?;
 
         println!("Contract deployed @ {contract_id_1}");
-        let contract_instance_1 = MyContract::new(contract_id_1, wallets[0].clone());
+        let contract_instance_1 =
+            MyContract::new(MyContractId::new(contract_id_1), wallets[0].clone());
 
         let response = contract_instance_1
             .methods()
@@ -245,7 +246,8 @@ mod tests {
         .await?;
 
         println!("Contract deployed @ {contract_id_2}");
-        let contract_instance_2 = MyContract::new(contract_id_2, wallets[1].clone());
+        let contract_instance_2 =
+            MyContract::new(MyContractId::new(contract_id_2), wallets[1].clone());
 
         let response = contract_instance_2
             .methods()
@@ -278,7 +280,8 @@ mod tests {
 
         println!("Contract deployed @ {contract_id}");
         // ANCHOR: tx_policies
-        let contract_methods = MyContract::new(contract_id.clone(), wallet.clone()).methods();
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone()).methods();
 
         let tx_policies = TxPolicies::default()
             .with_gas_price(1)
@@ -301,7 +304,8 @@ mod tests {
         // ANCHOR_END: tx_policies_default
 
         // ANCHOR: call_parameters
-        let contract_methods = MyContract::new(contract_id, wallet.clone()).methods();
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
 
         let tx_policies = TxPolicies::default();
 
@@ -347,7 +351,8 @@ mod tests {
         .await?;
 
         println!("Contract deployed @ {contract_id}");
-        let contract_methods = MyContract::new(contract_id.clone(), wallet.clone()).methods();
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone()).methods();
         // ANCHOR: simulate
         // you would mint 100 coins if the transaction wasn't simulated
         let counter = contract_methods.mint_coins(100).simulate().await?;
@@ -391,8 +396,11 @@ mod tests {
             .deploy(&wallet, TxPolicies::default())
             .await?;
 
-        let contract_methods =
-            MyContract::new(caller_contract_id.clone(), wallet.clone()).methods();
+        let contract_methods = MyContract::new(
+            MyContractId::new(caller_contract_id.clone()),
+            wallet.clone(),
+        )
+        .methods();
 
         // ANCHOR: dependency_estimation_fail
         let address = wallet.address();
@@ -455,7 +463,7 @@ mod tests {
         let contract_id: Bech32ContractId =
             "fuel1vkm285ypjesypw7vhdlhnty3kjxxx4efckdycqh3ttna4xvmxtfs6murwy".parse()?;
 
-        let connected_contract_instance = MyContract::new(contract_id, wallet);
+        let connected_contract_instance = MyContract::new(MyContractId::new(contract_id), wallet);
         // You can now use the `connected_contract_instance` just as you did above!
         // ANCHOR_END: deployed_contracts
 
@@ -464,7 +472,7 @@ mod tests {
         let contract_id: ContractId =
             "0x65b6a3d081966040bbccbb7f79ac91b48c635729c59a4c02f15ae7da999b32d3".parse()?;
 
-        let connected_contract_instance = MyContract::new(contract_id, wallet);
+        let connected_contract_instance = MyContract::new(MyContractId::new(contract_id), wallet);
         // ANCHOR_END: deployed_contracts_hex
 
         Ok(())
@@ -488,7 +496,8 @@ mod tests {
         .deploy(&wallet, TxPolicies::default())
         .await?;
 
-        let contract_methods = MyContract::new(contract_id, wallet.clone()).methods();
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
 
         // ANCHOR: call_params_gas
         // Set the transaction `gas_limit` to 1_000_000 and `gas_forwarded` to 4300 to specify that
@@ -527,7 +536,8 @@ mod tests {
         .await?;
 
         // ANCHOR: multi_call_prepare
-        let contract_methods = MyContract::new(contract_id, wallet.clone()).methods();
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
 
         let call_handler_1 = contract_methods.initialize_counter(42);
         let call_handler_2 = contract_methods.get_array([42; 2]);
@@ -582,7 +592,8 @@ mod tests {
         .deploy(&wallet, TxPolicies::default())
         .await?;
 
-        let contract_methods = MyContract::new(contract_id, wallet.clone()).methods();
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
 
         // ANCHOR: multi_call_cost_estimation
         let mut multi_call_handler = MultiContractCallHandler::new(wallet.clone());
@@ -605,6 +616,92 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn multi_call_size_estimation() -> Result<()> {
+        use fuels::prelude::*;
+
+        abigen!(Contract(
+            name = "MyContract",
+            abi = "packages/fuels/tests/contracts/contract_test/out/debug/contract_test-abi.json"
+        ));
+
+        let wallet = launch_provider_and_get_wallet().await?;
+
+        let contract_id = Contract::load_from(
+            "../../packages/fuels/tests/contracts/contract_test/out/debug/contract_test.bin",
+            LoadConfiguration::default(),
+        )?
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
+
+        // ANCHOR: call_size_estimation
+        let mut multi_call_handler = MultiContractCallHandler::new(wallet.clone());
+        multi_call_handler.add_call(contract_methods.initialize_counter(42));
+        let size_estimate = multi_call_handler.estimate_size().await?;
+
+        multi_call_handler.add_call(contract_methods.get_array([42; 2]));
+        let bigger_estimate = multi_call_handler.estimate_size().await?;
+
+        if let Some(warning) = &bigger_estimate.warning {
+            println!("the multicall is getting too big: {warning}");
+        }
+        // ANCHOR_END: call_size_estimation
+
+        assert!(bigger_estimate.size > size_estimate.size);
+        assert!(bigger_estimate.bytes_gas > size_estimate.bytes_gas);
+        assert!(bigger_estimate.warning.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_aggregator_example() -> Result<()> {
+        use fuels::prelude::*;
+
+        abigen!(Contract(
+            name = "MyContract",
+            abi = "packages/fuels/tests/contracts/contract_test/out/debug/contract_test-abi.json"
+        ));
+
+        let wallet = launch_provider_and_get_wallet().await?;
+
+        let contract_id = Contract::load_from(
+            "../../packages/fuels/tests/contracts/contract_test/out/debug/contract_test.bin",
+            LoadConfiguration::default(),
+        )?
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
+
+        // ANCHOR: read_aggregator
+        let mut aggregator = ReadAggregator::new(wallet.clone()).with_max_calls_per_request(2);
+
+        let sums = (0..5)
+            .map(|x| aggregator.add(contract_methods.get(x, 10)))
+            .collect::<Vec<_>>();
+        let array = aggregator.add(contract_methods.get_array([7; 2]));
+
+        let reads = aggregator.run().await?;
+
+        let sums = sums
+            .into_iter()
+            .map(|sum| reads.get(sum))
+            .collect::<Result<Vec<u64>>>()?;
+        let array: [u64; 2] = reads.get(array)?;
+        // ANCHOR_END: read_aggregator
+
+        assert_eq!(sums, [10, 11, 12, 13, 14]);
+        assert_eq!(array, [7; 2]);
+        assert_eq!(reads.requests(), 3);
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[allow(unused_variables)]
     async fn connect_wallet() -> Result<()> {
@@ -628,7 +725,7 @@ mod tests {
 
         // ANCHOR: connect_wallet
         // Create contract instance with wallet_1
-        let contract_instance = MyContract::new(contract_id, wallet_1.clone());
+        let contract_instance = MyContract::new(MyContractId::new(contract_id), wallet_1.clone());
 
         // Perform contract call with wallet_2
         let response = contract_instance
@@ -786,6 +883,7 @@ mod tests {
             .with_decoder_config(DecoderConfig {
                 max_depth: 10,
                 max_tokens: 2_000,
+                max_array_length: 1_000,
             })
             .call()
             .await?;
@@ -889,6 +987,7 @@ mod tests {
                 max_depth: 10,
                 max_tokens: 2_000,
                 max_total_enum_width: 10_000,
+                ..Default::default()
             })
             .methods()
             .initialize_counter(42)
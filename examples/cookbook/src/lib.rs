@@ -59,7 +59,8 @@ mod tests {
         .deploy(wallet, TxPolicies::default())
         .await?;
 
-        let contract_methods = MyContract::new(contract_id.clone(), wallet.clone()).methods();
+        let contract_methods =
+            MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone()).methods();
         // ANCHOR_END: liquidity_deploy
 
         // ANCHOR: liquidity_deposit
@@ -13,9 +13,7 @@ mod tests {
         use fuels::{crypto::SecretKey, prelude::*};
 
         // Create a provider pointing to the testnet.
-        // This example will not work as the testnet does not support the new version of fuel-core
-        // yet
-        let provider = Provider::connect("beta-4.fuel.network").await.unwrap();
+        let provider = Provider::connect(Network::Testnet).await.unwrap();
 
         // Setup a private key
         let secret = SecretKey::from_str(
@@ -0,0 +1,509 @@
+//! A command line wallet built on the SDK, both a reference for applications managing keys and
+//! an end-to-end exercise of the public API:
+//!
+//! ```text
+//! fuels-wallet-cli [options] new <name>
+//! fuels-wallet-cli [options] import <name> <private key>
+//! fuels-wallet-cli [options] list
+//! fuels-wallet-cli [options] default <name>
+//! fuels-wallet-cli [options] balance
+//! fuels-wallet-cli [options] transfer <to> <amount> [asset id]
+//! fuels-wallet-cli [options] call <contract id> <abi file> <function> [args...]
+//!
+//! options:
+//!   --keystore <dir>    where the accounts are kept, `./keys` by default
+//!   --network <name>    `local`, `testnet` or the url of a node, `local` by default
+//!   --account <name>    the account to use instead of the default one
+//!   --dry-run           only simulate contract calls
+//!   --yes               send transactions without asking for confirmation
+//! ```
+//!
+//! The password of the accounts is read from the `FUEL_WALLET_PASSWORD` environment variable if
+//! set, from standard input otherwise. Arguments of contract calls are parsed as described in
+//! [`tokenize`].
+
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use fuels::{
+    accounts::{
+        keystore::Keystore, networks::Network, provider::Provider, wallet::WalletUnlocked, Account,
+        ViewOnlyAccount,
+    },
+    core::{
+        codec::{
+            resolve_fn_selector, token_to_json, tokenize, AbiCache, AbiRegistry, DecoderConfig,
+            EncoderConfig, LogDecoder,
+        },
+        constants::BASE_ASSET_ID,
+    },
+    crypto::SecretKey,
+    programs::contract::method_hash,
+    types::{
+        bech32::{Bech32Address, Bech32ContractId},
+        errors::{error, Result},
+        param_types::ParamType,
+        transaction::{Transaction, TxPolicies},
+        transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
+        AssetId, ContractId,
+    },
+};
+
+pub const PASSWORD_VAR: &str = "FUEL_WALLET_PASSWORD";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub keystore: PathBuf,
+    pub network: String,
+    pub account: Option<String>,
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            keystore: PathBuf::from("keys"),
+            network: Network::Local.to_string(),
+            account: None,
+            dry_run: false,
+            yes: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    New {
+        name: String,
+    },
+    Import {
+        name: String,
+        private_key: String,
+    },
+    List,
+    Default {
+        name: String,
+    },
+    Balance,
+    Transfer {
+        to: String,
+        amount: String,
+        asset_id: Option<String>,
+    },
+    Call {
+        contract_id: String,
+        abi_path: PathBuf,
+        function: String,
+        args: Vec<String>,
+    },
+}
+
+/// Parses the command line arguments, without the name of the binary.
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<(Options, Command)> {
+    let mut options = Options::default();
+    let mut positional = vec![];
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value_of = |option: &str| {
+            args.next()
+                .ok_or_else(|| error!(Other, "`{option}` expects a value"))
+        };
+        match arg.as_str() {
+            "--keystore" => options.keystore = value_of(arg.as_str())?.into(),
+            "--network" => options.network = value_of(arg.as_str())?,
+            "--account" => options.account = Some(value_of(arg.as_str())?),
+            "--dry-run" => options.dry_run = true,
+            "--yes" => options.yes = true,
+            _ if arg.starts_with("--") => return Err(error!(Other, "unknown option `{arg}`")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let command = positional
+        .next()
+        .ok_or_else(|| error!(Other, "missing command"))?;
+    let mut arg = |name: &str| {
+        positional
+            .next()
+            .ok_or_else(|| error!(Other, "`{command}` expects a {name}"))
+    };
+
+    let parsed = match command.as_str() {
+        "new" => Command::New { name: arg("name")? },
+        "import" => Command::Import {
+            name: arg("name")?,
+            private_key: arg("private key")?,
+        },
+        "list" => Command::List,
+        "default" => Command::Default { name: arg("name")? },
+        "balance" => Command::Balance,
+        "transfer" => Command::Transfer {
+            to: arg("recipient")?,
+            amount: arg("amount")?,
+            asset_id: positional.next(),
+        },
+        "call" => Command::Call {
+            contract_id: arg("contract id")?,
+            abi_path: arg("abi file")?.into(),
+            function: arg("function")?,
+            args: positional.by_ref().collect(),
+        },
+        _ => return Err(error!(Other, "unknown command `{command}`")),
+    };
+    if let Some(extra) = positional.next() {
+        return Err(error!(Other, "unexpected argument `{extra}`"));
+    }
+
+    Ok((options, parsed))
+}
+
+/// Runs `command`, writing what it does to `out`.
+pub async fn run(options: &Options, command: Command, out: &mut impl Write) -> Result<()> {
+    let mut keystore = Keystore::open(&options.keystore)?;
+
+    match command {
+        Command::New { name } => {
+            let account = keystore.create(&name, password(&name)?)?;
+            writeln!(out, "created `{name}`: {}", account.address)?;
+        }
+        Command::Import { name, private_key } => {
+            let private_key = SecretKey::from_str(&private_key)?;
+            let account = keystore.import(&name, private_key, password(&name)?)?;
+            writeln!(out, "imported `{name}`: {}", account.address)?;
+        }
+        Command::List => {
+            let default = keystore.default_account().map(|account| &account.name);
+            for account in keystore.list() {
+                let marker = if Some(&account.name) == default {
+                    "*"
+                } else {
+                    " "
+                };
+                writeln!(
+                    out,
+                    "{marker} {} {} (created {})",
+                    account.name,
+                    account.address,
+                    account.created_at.format("%Y-%m-%d")
+                )?;
+            }
+        }
+        Command::Default { name } => {
+            keystore.set_default(&name)?;
+            writeln!(out, "`{name}` is now the default account")?;
+        }
+        Command::Balance => {
+            let wallet = unlock(&keystore, options).await?;
+            let mut balances = wallet.get_balances().await?.into_iter().collect::<Vec<_>>();
+            balances.sort();
+            for (asset_id, amount) in balances {
+                writeln!(out, "{amount} of {asset_id}")?;
+            }
+        }
+        Command::Transfer {
+            to,
+            amount,
+            asset_id,
+        } => {
+            let wallet = unlock(&keystore, options).await?;
+            transfer(&wallet, options, &to, &amount, asset_id.as_deref(), out).await?;
+        }
+        Command::Call {
+            contract_id,
+            abi_path,
+            function,
+            args,
+        } => {
+            let wallet = unlock(&keystore, options).await?;
+            let abi_json = fs::read_to_string(&abi_path)?;
+            call(
+                &wallet,
+                options,
+                &contract_id,
+                &abi_json,
+                &function,
+                &args,
+                out,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn unlock(keystore: &Keystore, options: &Options) -> Result<WalletUnlocked> {
+    let name: &str = match &options.account {
+        Some(name) => name.as_str(),
+        None => {
+            &keystore
+                .default_account()
+                .ok_or_else(|| error!(Other, "no `--account` given and no default account"))?
+                .name
+        }
+    };
+    let provider = match options.network.parse::<Network>() {
+        Ok(network) => Provider::connect(network).await?,
+        Err(_) => Provider::connect(&options.network).await?,
+    };
+
+    keystore.unlock(name, password(name)?, Some(provider))
+}
+
+async fn transfer(
+    wallet: &WalletUnlocked,
+    options: &Options,
+    to: &str,
+    amount: &str,
+    asset_id: Option<&str>,
+    out: &mut impl Write,
+) -> Result<()> {
+    let to = Bech32Address::from_str(to)?;
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| error!(Other, "invalid amount `{amount}`"))?;
+    let asset_id = asset_id
+        .map(AssetId::from_str)
+        .transpose()?
+        .unwrap_or(BASE_ASSET_ID);
+    let provider = wallet.try_provider()?;
+
+    // ANCHOR: transfer_preview
+    // built step by step, rather than with `Account::transfer`, to preview it before sending
+    let inputs = wallet.get_asset_inputs_for_amount(asset_id, amount).await?;
+    let outputs = wallet.get_asset_outputs_for_amount(&to, asset_id, amount);
+    let mut tx_builder =
+        ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    wallet.add_witnesses(&mut tx_builder)?;
+    let used_base_amount = if asset_id == BASE_ASSET_ID { amount } else { 0 };
+    wallet
+        .adjust_for_fee(&mut tx_builder, used_base_amount)
+        .await?;
+    let tx = tx_builder.build(provider).await?;
+
+    writeln!(out, "{}", tx.explain(&AbiRegistry::new()))?;
+    // ANCHOR_END: transfer_preview
+    if !confirm(options, out)? {
+        return Ok(());
+    }
+
+    let tx_id = tx.id(provider.chain_id());
+    provider
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .take_receipts_checked(None)?;
+    writeln!(
+        out,
+        "sent {amount} of {asset_id} to {to} in transaction {tx_id}"
+    )?;
+
+    Ok(())
+}
+
+/// Calls `function` without generated bindings: the arguments are parsed according to the ABI
+/// and the returned value is decoded with it.
+async fn call(
+    wallet: &WalletUnlocked,
+    options: &Options,
+    contract_id: &str,
+    abi_json: &str,
+    function: &str,
+    args: &[String],
+    out: &mut impl Write,
+) -> Result<()> {
+    let contract_id = Bech32ContractId::from_str(contract_id)?;
+    let abi = AbiCache::new().get_or_insert_json(abi_json)?;
+    let abi_function = abi
+        .function(function)
+        .ok_or_else(|| error!(Other, "function `{function}` not found in the ABI"))?;
+
+    // ANCHOR: dynamic_call
+    let input_types = abi_function
+        .inputs
+        .iter()
+        .map(|input| ParamType::try_from_type_application(input, &abi.type_lookup))
+        .collect::<Result<Vec<_>>>()?;
+    let output_type = ParamType::try_from_type_application(&abi_function.output, &abi.type_lookup)?;
+    if args.len() != input_types.len() {
+        return Err(error!(
+            Other,
+            "`{function}` takes {} arguments, got {}",
+            input_types.len(),
+            args.len()
+        ));
+    }
+    let tokens = input_types
+        .iter()
+        .zip(args)
+        .map(|(param_type, value)| tokenize(param_type, value))
+        .collect::<Result<Vec<_>>>()?;
+
+    // the output is decoded below, the handler only builds the transaction
+    let mut call_handler = method_hash::<(), _>(
+        contract_id.clone(),
+        wallet.clone(),
+        resolve_fn_selector(function, &input_types),
+        &tokens,
+        LogDecoder::default(),
+        false,
+        EncoderConfig::default(),
+    );
+    // the call script needs the actual output type to return heap types
+    call_handler.contract_call.output_param = output_type;
+    let tx = call_handler.build_tx().await?;
+    // ANCHOR_END: dynamic_call
+    let provider = wallet.try_provider()?;
+
+    let registry = AbiRegistry::new().with_abi(ContractId::from(&contract_id), abi.clone());
+    writeln!(out, "{}", tx.explain(&registry))?;
+
+    let tx_status = if options.dry_run {
+        provider.checked_dry_run(tx).await?
+    } else {
+        if !confirm(options, out)? {
+            return Ok(());
+        }
+        provider.send_transaction_and_await_commit(tx).await?
+    };
+    let receipts = tx_status.take_receipts_checked(None)?;
+    let value = call_handler
+        .contract_call
+        .decode_output(&receipts, DecoderConfig::default())?;
+    writeln!(out, "returned {}", token_to_json(&value))?;
+
+    Ok(())
+}
+
+fn password(account: &str) -> Result<String> {
+    if let Ok(password) = std::env::var(PASSWORD_VAR) {
+        return Ok(password);
+    }
+
+    // echoed, a real wallet would read it from the terminal without echoing it
+    eprint!("password of `{account}`: ");
+    read_line()
+}
+
+fn confirm(options: &Options, out: &mut impl Write) -> Result<bool> {
+    if options.yes {
+        return Ok(true);
+    }
+
+    write!(out, "send the transaction? [y/N] ")?;
+    out.flush()?;
+    let confirmed = read_line()?.eq_ignore_ascii_case("y");
+    if !confirmed {
+        writeln!(out, "cancelled")?;
+    }
+
+    Ok(confirmed)
+}
+
+fn read_line() -> Result<String> {
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+
+    Ok(line.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels::prelude::*;
+
+    use super::*;
+
+    const CONTRACT_DIR: &str = "../../packages/fuels/tests/contracts/contract_test/out/debug";
+
+    async fn run_cli(options: &Options, args: &str) -> Result<String> {
+        let (_, command) = parse_args(args.split_whitespace().map(ToString::to_string))?;
+        let mut out = vec![];
+        run(options, command, &mut out).await?;
+
+        Ok(String::from_utf8(out).expect("output should be utf8"))
+    }
+
+    #[test]
+    fn arguments_are_parsed() -> Result<()> {
+        let args = "--network testnet call fuel1abc abi.json transfer 42 --yes 0x01";
+        let (options, command) = parse_args(args.split_whitespace().map(ToString::to_string))?;
+
+        assert_eq!(options.network, "testnet");
+        assert!(options.yes);
+        assert_eq!(
+            command,
+            Command::Call {
+                contract_id: "fuel1abc".to_string(),
+                abi_path: "abi.json".into(),
+                function: "transfer".to_string(),
+                args: vec!["42".to_string(), "0x01".to_string()],
+            }
+        );
+
+        let err = parse_args(["transfer".to_string()]).expect_err("should miss the recipient");
+        assert_eq!(err.to_string(), "`transfer` expects a recipient");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn accounts_transfer_coins_and_call_contracts() -> Result<()> {
+        let funder = launch_provider_and_get_wallet().await?;
+        let provider = funder.try_provider()?.clone();
+        let keystore_dir = tempfile::tempdir()?;
+        std::env::set_var(PASSWORD_VAR, "password");
+        let options = Options {
+            keystore: keystore_dir.path().to_path_buf(),
+            network: provider.url().to_string(),
+            yes: true,
+            ..Default::default()
+        };
+
+        run_cli(&options, "new alice").await?;
+        run_cli(&options, "new bob").await?;
+        let keystore = Keystore::open(keystore_dir.path())?;
+        let alice = keystore
+            .account("alice")
+            .expect("should exist")
+            .address
+            .clone();
+        let bob = keystore
+            .account("bob")
+            .expect("should exist")
+            .address
+            .clone();
+        assert!(run_cli(&options, "list").await?.contains("* alice"));
+
+        funder
+            .transfer(&alice, 1_000_000, BASE_ASSET_ID, TxPolicies::default())
+            .await?;
+        assert!(run_cli(&options, "balance").await?.contains("1000000 of"));
+
+        run_cli(&options, &format!("transfer {bob} 100")).await?;
+        assert_eq!(provider.get_asset_balance(&bob, BASE_ASSET_ID).await?, 100);
+
+        let contract_id = Contract::load_from(
+            format!("{CONTRACT_DIR}/contract_test.bin"),
+            LoadConfiguration::default(),
+        )?
+        .deploy(&funder, TxPolicies::default())
+        .await?;
+        let output = run_cli(
+            &options,
+            &format!(
+                "call {contract_id} {CONTRACT_DIR}/contract_test-abi.json initialize_counter 42"
+            ),
+        )
+        .await?;
+        assert!(output.contains("initialize_counter(42)"));
+        assert!(output.ends_with("returned 42\n"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use std::{io, process::ExitCode};
+
+use fuels_wallet_cli::{parse_args, run};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let result = match parse_args(std::env::args().skip(1)) {
+        Ok((options, command)) => run(&options, command, &mut io::stdout()).await,
+        Err(err) => Err(err),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address, errors::Result, input::Input,
+        transaction_builders::TransactionBuilder, unresolved_bytes::UnresolvedBytes, AssetId,
+    },
+};
+
+use crate::{
+    predicate::Predicate, provider::Provider, Account, SubmittingAccount, ViewOnlyAccount,
+};
+
+/// An account owned by a predicate rather than a key, e.g. one verifying a secp256r1 or
+/// ed25519 signature, or a multisig. It transfers, deploys and calls contracts like a wallet
+/// does: its coins are spent as predicate inputs and its signers sign every transaction it
+/// builds.
+///
+/// The signatures are added to the witnesses in the order the signers were given, after the
+/// witnesses already in the transaction. Transfers and contract calls have no other witnesses,
+/// so the signatures start at index 0, but a contract deployment carries the bytecode as its
+/// first witness, so they start at index 1. The predicate should take the index of the first
+/// signature as predicate data, see [`Self::with_predicate_data`].
+///
+/// ```ignore
+/// let account = AbstractAccount::new(multisig_predicate.with_provider(provider))
+///     .with_predicate_data(MultisigEncoder::default().encode_data(0)?)
+///     .with_signer(first_owner)
+///     .with_signer(second_owner);
+///
+/// account.transfer(recipient, 100, AssetId::BASE, TxPolicies::default()).await?;
+/// let contract = MyContract::new(MyContractId::new(contract_id), account.clone());
+///
+/// // The bytecode comes first in a deployment.
+/// let deployer = account.with_predicate_data(MultisigEncoder::default().encode_data(1)?);
+/// Contract::load_from(path, config)?.deploy(&deployer, TxPolicies::default()).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct AbstractAccount {
+    predicate: Predicate,
+    signers: Vec<SharedSigner>,
+}
+
+impl AbstractAccount {
+    pub fn new(predicate: Predicate) -> Self {
+        Self {
+            predicate,
+            signers: vec![],
+        }
+    }
+
+    /// Adds a signer whose signature the predicate expects. Transaction builders tell signers
+    /// apart by their address, so every signer needs a different one.
+    pub fn with_signer(mut self, signer: impl Signer + Send + Sync) -> Self {
+        self.signers.push(SharedSigner(Arc::new(signer)));
+        self
+    }
+
+    /// Replaces the data passed to the predicate, e.g. the index of the first signature.
+    pub fn with_predicate_data(self, data: UnresolvedBytes) -> Self {
+        Self {
+            predicate: self.predicate.with_data(data),
+            ..self
+        }
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.predicate.set_provider(provider);
+    }
+
+    pub fn with_provider(self, provider: Provider) -> Self {
+        Self {
+            predicate: self.predicate.with_provider(provider),
+            ..self
+        }
+    }
+}
+
+impl ViewOnlyAccount for AbstractAccount {
+    fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.predicate.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Account for AbstractAccount {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount(asset_id, amount)
+            .await
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        for signer in &self.signers {
+            tb.add_signer(signer.clone())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SubmittingAccount for AbstractAccount {}
+
+/// Lets the signers of an account be added to every transaction it builds.
+#[derive(Clone)]
+struct SharedSigner(Arc<dyn Signer + Send + Sync>);
+
+impl fmt::Debug for SharedSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signer")
+            .field("address", self.0.address())
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for SharedSigner {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        self.0.sign(message).await
+    }
+
+    fn address(&self) -> &Bech32Address {
+        self.0.address()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::{
+        transaction::TxPolicies, transaction_builders::ScriptTransactionBuilder,
+    };
+
+    use super::*;
+    use crate::wallet::WalletUnlocked;
+
+    #[test]
+    fn signers_are_added_to_transactions() -> Result<()> {
+        let first = WalletUnlocked::new_random(None);
+        let second = WalletUnlocked::new_random(None);
+        let account = AbstractAccount::new(Predicate::from_code(vec![0; 8]))
+            .with_signer(first.clone())
+            .with_signer(second.clone());
+
+        let mut tb =
+            ScriptTransactionBuilder::prepare_transfer(vec![], vec![], TxPolicies::default());
+        account.add_witnesses(&mut tb)?;
+
+        let err = tb.add_signer(second.clone()).unwrap_err();
+        assert!(err.to_string().contains(&format!(
+            "already added `Signer` with address: `{}`",
+            second.address()
+        )));
+
+        assert_eq!(
+            ViewOnlyAccount::address(&account),
+            account.predicate().address()
+        );
+
+        Ok(())
+    }
+}
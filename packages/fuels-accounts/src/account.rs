@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use fuel_core_client::client::pagination::{PaginatedResult, PaginationRequest};
-use fuel_tx::{Output, Receipt, TxId, TxPointer, UtxoId};
+use fuel_tx::{ConsensusParameters, Input as FuelInput, Output, Receipt, TxId, TxPointer, UtxoId};
 use fuel_types::{AssetId, Bytes32, ContractId, Nonce};
 use fuels_core::{
     constants::BASE_ASSET_ID,
@@ -10,10 +10,10 @@ use fuels_core::{
         bech32::{Bech32Address, Bech32ContractId},
         coin::Coin,
         coin_type::CoinType,
-        errors::Result,
+        errors::{error, Result},
         input::Input,
         message::Message,
-        transaction::{Transaction, TxPolicies},
+        transaction::{ScriptTransaction, Transaction, TxPolicies},
         transaction_builders::{
             BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
         },
@@ -23,7 +23,7 @@ use fuels_core::{
 
 use crate::{
     accounts_utils::{adjust_inputs_outputs, calculate_missing_base_amount, extract_message_nonce},
-    provider::{Provider, ResourceFilter},
+    provider::{CoinBalances, Provider, ResourceFilter},
 };
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -71,6 +71,12 @@ pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
         self.try_provider()?.get_balances(self.address()).await
     }
 
+    /// The balances of the account's coins, split into the ones it can spend now and the ones
+    /// still locked by their `maturity`. Resource selection only ever picks spendable coins.
+    async fn get_coin_balances(&self) -> Result<CoinBalances> {
+        self.try_provider()?.get_coin_balances(self.address()).await
+    }
+
     /// Get some spendable resources (coins and messages) of asset `asset_id` owned by the account
     /// that add up at least to amount `amount`. The returned coins (UTXOs) are actual coins that
     /// can be spent. The number of UXTOs is optimized to prevent dust accumulation.
@@ -90,6 +96,12 @@ pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
     }
 }
 
+/// Marks accounts able to send transactions. Contract and script handlers can only `call` and
+/// `submit`, and accounts can only transfer or withdraw, if the account implements it.
+/// [`ReadOnlyAccount`](crate::read_only::ReadOnlyAccount) does not, so its handlers can only
+/// `simulate`.
+pub trait SubmittingAccount: Account {}
+
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Account: ViewOnlyAccount {
     /// Returns a vector consisting of `Input::Coin`s and `Input::Message`s for the given
@@ -144,6 +156,46 @@ pub trait Account: ViewOnlyAccount {
         Ok(())
     }
 
+    /// Adds inputs covering `amount` of `asset_id` owned by this account to `tb`, along with
+    /// whatever is needed to unlock them. Called for several accounts, e.g. a couple of
+    /// predicates and a wallet, it assembles a transaction spending from all of them.
+    ///
+    /// A transaction can hold only one change output per asset. If `tb` has none for
+    /// `asset_id`, one returning the excess to this account is added, otherwise the excess
+    /// goes to the owner of the existing change output.
+    async fn add_spending_inputs<Tb: TransactionBuilder + Sync>(
+        &self,
+        tb: &mut Tb,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<()> {
+        let inputs = self.get_asset_inputs_for_amount(asset_id, amount).await?;
+
+        let is_already_spending = tb.inputs().iter().any(|input| {
+            matches!(
+                input,
+                Input::ResourceSigned { resource } | Input::ResourcePredicate { resource, .. }
+                    if resource.owner() == self.address()
+            )
+        });
+        tb.inputs_mut().extend(inputs);
+
+        let is_change_present = tb.outputs().iter().any(|output| {
+            matches!(output, Output::Change { asset_id: change_asset_id, .. }
+                                if *change_asset_id == asset_id)
+        });
+        if !is_change_present {
+            tb.outputs_mut()
+                .push(Output::change(self.address().into(), 0, asset_id));
+        }
+
+        if !is_already_spending {
+            self.add_witnesses(tb)?;
+        }
+
+        Ok(())
+    }
+
     /// Transfer funds from this account to another `Address`.
     /// Fails if amount for asset ID is larger than address's spendable coins.
     /// Returns the transaction ID that was sent and the list of receipts.
@@ -153,14 +205,55 @@ pub trait Account: ViewOnlyAccount {
         amount: u64,
         asset_id: AssetId,
         tx_policies: TxPolicies,
-    ) -> Result<(TxId, Vec<Receipt>)> {
+    ) -> Result<(TxId, Vec<Receipt>)>
+    where
+        Self: SubmittingAccount,
+    {
+        self.transfer_with_memo(to, amount, asset_id, &[], tx_policies)
+            .await
+    }
+
+    /// Same as [`transfer`](Self::transfer), attaching `memo` to the transaction, e.g. to tag
+    /// a deposit. Read it back from the transaction with `ScriptTransaction::transfer_memo`.
+    async fn transfer_with_memo(
+        &self,
+        to: &Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+        memo: &[u8],
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)>
+    where
+        Self: SubmittingAccount,
+    {
+        let response = self
+            .transfer_with_utxos(to, amount, asset_id, memo, tx_policies)
+            .await?;
+
+        Ok((response.tx_id, response.receipts))
+    }
+
+    /// Same as [`transfer_with_memo`](Self::transfer_with_memo), also returning the UTXOs created
+    /// by the transfer, see [`TransferResponse`]. Pass an empty `memo` for a plain transfer.
+    async fn transfer_with_utxos(
+        &self,
+        to: &Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+        memo: &[u8],
+        tx_policies: TxPolicies,
+    ) -> Result<TransferResponse>
+    where
+        Self: SubmittingAccount,
+    {
         let provider = self.try_provider()?;
 
         let inputs = self.get_asset_inputs_for_amount(asset_id, amount).await?;
         let outputs = self.get_asset_outputs_for_amount(to, asset_id, amount);
 
         let mut tx_builder =
-            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies)
+                .with_transfer_memo(memo);
 
         self.add_witnesses(&mut tx_builder)?;
 
@@ -171,11 +264,26 @@ pub trait Account: ViewOnlyAccount {
         let tx = tx_builder.build(provider).await?;
         let tx_id = tx.id(provider.chain_id());
 
-        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let tx_status = provider
+            .send_transaction_and_await_commit(tx.clone())
+            .await?;
 
         let receipts = tx_status.take_receipts_checked(None)?;
-
-        Ok((tx_id, receipts))
+        let fee =
+            charged_fee(&tx, provider.consensus_parameters(), &receipts).ok_or_else(|| {
+                error!(
+                    Other,
+                    "could not compute the fee charged for transfer `{tx_id}`"
+                )
+            })?;
+
+        Ok(TransferResponse::new(
+            tx_id,
+            receipts,
+            tx.inputs(),
+            tx.outputs(),
+            fee,
+        ))
     }
 
     /// Unconditionally transfers `balance` of type `asset_id` to
@@ -193,7 +301,10 @@ pub trait Account: ViewOnlyAccount {
         balance: u64,
         asset_id: AssetId,
         tx_policies: TxPolicies,
-    ) -> Result<(String, Vec<Receipt>)> {
+    ) -> Result<(String, Vec<Receipt>)>
+    where
+        Self: SubmittingAccount,
+    {
         let provider = self.try_provider()?;
 
         let zeroes = Bytes32::zeroed();
@@ -245,7 +356,10 @@ pub trait Account: ViewOnlyAccount {
         to: &Bech32Address,
         amount: u64,
         tx_policies: TxPolicies,
-    ) -> Result<(TxId, Nonce, Vec<Receipt>)> {
+    ) -> Result<(TxId, Nonce, Vec<Receipt>)>
+    where
+        Self: SubmittingAccount,
+    {
         let provider = self.try_provider()?;
 
         let inputs = self
@@ -276,6 +390,140 @@ pub trait Account: ViewOnlyAccount {
     }
 }
 
+/// The result of [`Account::transfer_with_utxos`]: the transaction, its receipts and the coins
+/// it created. Follow-up transactions can spend those coins right away, without querying the
+/// node for them and racing its indexing.
+#[derive(Debug, Clone)]
+pub struct TransferResponse {
+    pub tx_id: TxId,
+    pub receipts: Vec<Receipt>,
+    /// The UTXOs of the coin and change outputs, in the order of the outputs.
+    pub created_utxos: Vec<CreatedUtxo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedUtxo {
+    pub utxo_id: UtxoId,
+    pub owner: Bech32Address,
+    pub asset_id: AssetId,
+    /// For change outputs, what is left of the spent coins of the asset once the coin outputs
+    /// and, for the base asset, the fee are paid, as the node sets it on execution.
+    pub amount: u64,
+    pub is_change: bool,
+}
+
+impl TransferResponse {
+    pub(crate) fn new(
+        tx_id: TxId,
+        receipts: Vec<Receipt>,
+        inputs: &[FuelInput],
+        outputs: &[Output],
+        fee: u64,
+    ) -> Self {
+        let change = |asset_id: &AssetId| {
+            let spent: u64 = inputs
+                .iter()
+                // data messages only carry their amount to the contract they are meant for
+                .filter(|input| {
+                    !matches!(
+                        input,
+                        FuelInput::MessageDataSigned(_) | FuelInput::MessageDataPredicate(_)
+                    )
+                })
+                .filter(|input| input.asset_id(&BASE_ASSET_ID) == Some(asset_id))
+                .filter_map(FuelInput::amount)
+                .sum();
+            let sent: u64 = outputs
+                .iter()
+                .filter_map(|output| match output {
+                    Output::Coin {
+                        amount,
+                        asset_id: coin_asset_id,
+                        ..
+                    } if coin_asset_id == asset_id => Some(*amount),
+                    _ => None,
+                })
+                .sum();
+            let fee = if *asset_id == BASE_ASSET_ID { fee } else { 0 };
+
+            spent.saturating_sub(sent).saturating_sub(fee)
+        };
+
+        let created_utxos = outputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| {
+                let (owner, asset_id, amount, is_change) = match output {
+                    Output::Coin {
+                        to,
+                        amount,
+                        asset_id,
+                    } => (to, asset_id, *amount, false),
+                    Output::Change { to, asset_id, .. } => (to, asset_id, change(asset_id), true),
+                    _ => return None,
+                };
+                let output_index = index
+                    .try_into()
+                    .expect("transactions have fewer outputs than the index can hold");
+
+                Some(CreatedUtxo {
+                    utxo_id: UtxoId::new(tx_id, output_index),
+                    owner: (*owner).into(),
+                    asset_id: *asset_id,
+                    amount,
+                    is_change,
+                })
+            })
+            .collect();
+
+        Self {
+            tx_id,
+            receipts,
+            created_utxos,
+        }
+    }
+
+    /// The coins sent to `owner`, i.e. the coin outputs without the change.
+    pub fn coins_to<'a>(
+        &'a self,
+        owner: &'a Bech32Address,
+    ) -> impl Iterator<Item = &'a CreatedUtxo> + 'a {
+        self.created_utxos
+            .iter()
+            .filter(move |utxo| !utxo.is_change && utxo.owner == *owner)
+    }
+
+    /// The change returned to `owner`, one UTXO per asset.
+    pub fn change_of<'a>(
+        &'a self,
+        owner: &'a Bech32Address,
+    ) -> impl Iterator<Item = &'a CreatedUtxo> + 'a {
+        self.created_utxos
+            .iter()
+            .filter(move |utxo| utxo.is_change && utxo.owner == *owner)
+    }
+}
+
+/// The fee the node charged for `tx`: the most it could cost, minus the refund of the script gas
+/// it didn't use.
+fn charged_fee(
+    tx: &ScriptTransaction,
+    consensus_parameters: &ConsensusParameters,
+    receipts: &[Receipt],
+) -> Option<u64> {
+    let max_fee = tx.fee_checked_from_tx(consensus_parameters)?.max_fee();
+    let gas_used = receipts.iter().rev().find_map(|receipt| match receipt {
+        Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+        _ => None,
+    })?;
+
+    let unused_gas = u128::from(tx.gas_limit().saturating_sub(gas_used));
+    let refund = unused_gas * u128::from(tx.gas_price())
+        / u128::from(consensus_parameters.fee_params().gas_price_factor.max(1));
+
+    max_fee.checked_sub(refund.try_into().ok()?)
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -402,4 +650,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn transfer_responses_point_at_the_created_coins() {
+        let tx_id = TxId::from([1; 32]);
+        let sender = Bech32Address::new("fuel", [2; 32]);
+        let recipient = Bech32Address::new("fuel", [3; 32]);
+        let other_asset = AssetId::from([4; 32]);
+        let coin = |index: u8, amount: u64, asset_id: AssetId| {
+            FuelInput::coin_signed(
+                UtxoId::new(Bytes32::from([index; 32]), 0),
+                (&sender).into(),
+                amount,
+                asset_id,
+                Default::default(),
+                0,
+                Default::default(),
+            )
+        };
+        let inputs = [
+            coin(1, 15, BASE_ASSET_ID),
+            coin(2, 7, BASE_ASSET_ID),
+            coin(3, 5, other_asset),
+        ];
+        let outputs = [
+            Output::coin((&recipient).into(), 10, BASE_ASSET_ID),
+            Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()),
+            Output::change((&sender).into(), 0, BASE_ASSET_ID),
+            Output::change((&sender).into(), 0, other_asset),
+        ];
+
+        let response = TransferResponse::new(tx_id, vec![], &inputs, &outputs, 2);
+
+        let sent = CreatedUtxo {
+            utxo_id: UtxoId::new(tx_id, 0),
+            owner: recipient.clone(),
+            asset_id: BASE_ASSET_ID,
+            amount: 10,
+            is_change: false,
+        };
+        // 22 spent, 10 sent and 2 paid as fee
+        let base_change = CreatedUtxo {
+            utxo_id: UtxoId::new(tx_id, 2),
+            owner: sender.clone(),
+            asset_id: BASE_ASSET_ID,
+            amount: 10,
+            is_change: true,
+        };
+        let other_change = CreatedUtxo {
+            utxo_id: UtxoId::new(tx_id, 3),
+            owner: sender.clone(),
+            asset_id: other_asset,
+            amount: 5,
+            is_change: true,
+        };
+        assert_eq!(
+            response.created_utxos,
+            vec![sent.clone(), base_change.clone(), other_change.clone()]
+        );
+        assert_eq!(response.coins_to(&recipient).collect::<Vec<_>>(), [&sent]);
+        assert_eq!(
+            response.change_of(&sender).collect::<Vec<_>>(),
+            [&base_change, &other_change]
+        );
+        assert_eq!(response.coins_to(&sender).count(), 0);
+    }
 }
@@ -0,0 +1,284 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use chrono::{DateTime, Utc};
+use fuel_crypto::SecretKey;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+};
+use serde_json::{json, Value};
+
+use crate::{provider::Provider, wallet::WalletUnlocked};
+
+/// The file, next to the key files, holding the names of the accounts and the default one.
+const METADATA_FILE: &str = "accounts.json";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInfo {
+    pub name: String,
+    pub address: Bech32Address,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A directory of named accounts, each with its private key in an encrypted JSON keystore file,
+/// see [`WalletUnlocked::load_keystore`]. One of the accounts is the default one, the first
+/// one created unless another one is selected with [`Keystore::set_default`].
+///
+/// ```ignore
+/// let mut keystore = Keystore::open(home.join(".fuel/keys"))?;
+/// keystore.create("alice", "password")?;
+///
+/// let wallet = keystore.unlock("alice", "password", Some(provider))?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keystore {
+    dir: PathBuf,
+    accounts: BTreeMap<String, AccountInfo>,
+    default: Option<String>,
+}
+
+impl Keystore {
+    /// Opens the keystore in `dir`, creating the directory if it doesn't exist.
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        let metadata_path = dir.join(METADATA_FILE);
+        if !metadata_path.exists() {
+            return Ok(Self {
+                dir,
+                accounts: BTreeMap::new(),
+                default: None,
+            });
+        }
+
+        let metadata: Value = serde_json::from_slice(&fs::read(&metadata_path)?)?;
+        let invalid = |what: &str| {
+            error!(
+                Other,
+                "invalid keystore metadata in `{}`: {what}",
+                metadata_path.display()
+            )
+        };
+
+        let mut accounts = BTreeMap::new();
+        for account in metadata["accounts"]
+            .as_array()
+            .ok_or_else(|| invalid("`accounts` must be an array"))?
+        {
+            let field = |name: &str| {
+                account[name]
+                    .as_str()
+                    .ok_or_else(|| invalid(&format!("every account must have a `{name}`")))
+            };
+            let info = AccountInfo {
+                name: field("name")?.to_string(),
+                address: Bech32Address::from_str(field("address")?)?,
+                created_at: DateTime::parse_from_rfc3339(field("created_at")?)
+                    .map_err(|e| invalid(&e.to_string()))?
+                    .with_timezone(&Utc),
+            };
+            accounts.insert(info.name.clone(), info);
+        }
+        let default = metadata["default"].as_str().map(ToString::to_string);
+
+        Ok(Self {
+            dir,
+            accounts,
+            default,
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Creates an account with a new random key, encrypted with `password`.
+    pub fn create(&mut self, name: &str, password: impl AsRef<[u8]>) -> Result<AccountInfo> {
+        let private_key = SecretKey::random(&mut rand::thread_rng());
+
+        self.import(name, private_key, password)
+    }
+
+    /// Adds an account with an existing key, encrypted with `password`. A key can only be in
+    /// the keystore once.
+    pub fn import(
+        &mut self,
+        name: &str,
+        private_key: SecretKey,
+        password: impl AsRef<[u8]>,
+    ) -> Result<AccountInfo> {
+        validate_name(name)?;
+        if self.accounts.contains_key(name) {
+            return Err(error!(Other, "account `{name}` already exists"));
+        }
+        let address = WalletUnlocked::new_from_private_key(private_key, None)
+            .address()
+            .clone();
+        if let Some(existing) = self.accounts.values().find(|info| info.address == address) {
+            return Err(error!(
+                Other,
+                "the key is already in the keystore as account `{}`", existing.name
+            ));
+        }
+
+        eth_keystore::encrypt_key(
+            &self.dir,
+            &mut rand::thread_rng(),
+            *private_key,
+            password,
+            Some(name),
+        )
+        .map_err(|e| error!(Other, "{e}"))?;
+
+        let info = AccountInfo {
+            name: name.to_string(),
+            address,
+            created_at: Utc::now(),
+        };
+        self.accounts.insert(name.to_string(), info.clone());
+        self.default.get_or_insert_with(|| name.to_string());
+        self.save()?;
+
+        Ok(info)
+    }
+
+    /// The accounts, ordered by name.
+    pub fn list(&self) -> impl Iterator<Item = &AccountInfo> {
+        self.accounts.values()
+    }
+
+    pub fn account(&self, name: &str) -> Option<&AccountInfo> {
+        self.accounts.get(name)
+    }
+
+    pub fn default_account(&self) -> Option<&AccountInfo> {
+        self.default.as_deref().and_then(|name| self.account(name))
+    }
+
+    pub fn set_default(&mut self, name: &str) -> Result<()> {
+        self.try_account(name)?;
+        self.default = Some(name.to_string());
+
+        self.save()
+    }
+
+    /// Decrypts the key of the account with `password`.
+    pub fn unlock(
+        &self,
+        name: &str,
+        password: impl AsRef<[u8]>,
+        provider: Option<Provider>,
+    ) -> Result<WalletUnlocked> {
+        let info = self.try_account(name)?;
+        let wallet = WalletUnlocked::load_keystore(self.dir.join(name), password, provider)?;
+
+        if *wallet.address() != info.address {
+            return Err(error!(
+                Other,
+                "the key file of account `{name}` holds the key of `{}` instead of `{}`",
+                wallet.address(),
+                info.address
+            ));
+        }
+
+        Ok(wallet)
+    }
+
+    fn try_account(&self, name: &str) -> Result<&AccountInfo> {
+        self.account(name)
+            .ok_or_else(|| error!(Other, "no account named `{name}` in the keystore"))
+    }
+
+    fn save(&self) -> Result<()> {
+        let accounts = self
+            .accounts
+            .values()
+            .map(|info| {
+                json!({
+                    "name": info.name,
+                    "address": info.address.to_string(),
+                    "created_at": info.created_at.to_rfc3339(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let metadata = json!({
+            "default": self.default,
+            "accounts": accounts,
+        });
+
+        fs::write(
+            self.dir.join(METADATA_FILE),
+            serde_json::to_vec_pretty(&metadata)?,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Names are file names, so they are restricted to characters that are safe in paths.
+fn validate_name(name: &str) -> Result<()> {
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if !is_valid {
+        return Err(error!(
+            Other,
+            "invalid account name `{name}`: only ASCII letters, digits, `-` and `_` are allowed"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn accounts_are_persisted_and_unlocked_by_name() -> Result<()> {
+        let dir = tempdir()?;
+        let private_key = SecretKey::random(&mut rand::thread_rng());
+
+        let mut keystore = Keystore::open(dir.path())?;
+        let alice = keystore.create("alice", "password")?;
+        let bob = keystore.import("bob", private_key, "password")?;
+        keystore.set_default("bob")?;
+
+        let err = keystore
+            .import("carol", private_key, "password")
+            .expect_err("should already be in the keystore");
+        assert_eq!(
+            err.to_string(),
+            "the key is already in the keystore as account `bob`"
+        );
+        assert!(keystore.create("../alice", "password").is_err());
+
+        let keystore = Keystore::open(dir.path())?;
+        assert_eq!(
+            keystore.list().cloned().collect::<Vec<_>>(),
+            vec![alice.clone(), bob.clone()]
+        );
+        assert_eq!(keystore.default_account(), Some(&bob));
+
+        let wallet = keystore.unlock("alice", "password", None)?;
+        assert_eq!(*wallet.address(), alice.address);
+        assert!(keystore.unlock("alice", "wrong password", None).is_err());
+
+        let err = keystore
+            .unlock("dave", "password", None)
+            .expect_err("should not exist");
+        assert_eq!(err.to_string(), "no account named `dave` in the keystore");
+
+        Ok(())
+    }
+}
@@ -1,10 +1,20 @@
 #[cfg(feature = "std")]
+pub mod abstract_account;
+#[cfg(feature = "std")]
 mod account;
 #[cfg(feature = "std")]
 mod accounts_utils;
 #[cfg(feature = "std")]
+pub mod keystore;
+#[cfg(feature = "std")]
+pub mod network_registry;
+pub mod networks;
+#[cfg(feature = "std")]
 pub mod provider;
 #[cfg(feature = "std")]
+pub mod read_only;
+pub mod signers;
+#[cfg(feature = "std")]
 pub mod wallet;
 
 #[cfg(feature = "std")]
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use fuel_types::ContractId;
+use fuels_core::types::errors::{error, Error, Result};
+
+use crate::{provider::Provider, wallet::WalletUnlocked};
+
+/// Providers registered once under a network name, and the network every contract lives on.
+/// Lets applications work with contracts on several networks, e.g. testnet and mainnet, by
+/// passing the registry around instead of a provider per network:
+///
+/// ```ignore
+/// let mut networks = NetworkRegistry::new();
+/// networks.register("testnet", Provider::connect(Network::Testnet).await?);
+/// networks.register("mainnet", Provider::connect(MAINNET_URL).await?);
+/// networks.bind(token_id, "mainnet")?;
+///
+/// let token = MyToken::new(
+///     MyTokenId::new(token_id.clone()),
+///     networks.wallet_for(token_id, &wallet)?,
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NetworkRegistry {
+    providers: HashMap<String, Provider>,
+    bindings: HashMap<ContractId, String>,
+}
+
+impl NetworkRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `provider` as the one of the network `name`, replacing the previous one.
+    /// Contracts bound to the network use the new provider from now on.
+    pub fn register(&mut self, name: impl Into<String>, provider: Provider) {
+        self.providers.insert(name.into(), provider);
+    }
+
+    pub fn with_network(mut self, name: impl Into<String>, provider: Provider) -> Self {
+        self.register(name, provider);
+        self
+    }
+
+    /// Binds the contract to the network `name`, which must be registered.
+    pub fn bind(&mut self, contract_id: impl Into<ContractId>, network: &str) -> Result<()> {
+        if !self.providers.contains_key(network) {
+            return Err(unknown_network(network));
+        }
+        self.bindings
+            .insert(contract_id.into(), network.to_string());
+
+        Ok(())
+    }
+
+    pub fn networks(&self) -> impl Iterator<Item = &str> {
+        self.providers.keys().map(String::as_str)
+    }
+
+    pub fn provider(&self, network: &str) -> Result<&Provider> {
+        self.providers
+            .get(network)
+            .ok_or_else(|| unknown_network(network))
+    }
+
+    /// The network the contract is bound to, if any.
+    pub fn network_of(&self, contract_id: impl Into<ContractId>) -> Option<&str> {
+        self.bindings.get(&contract_id.into()).map(String::as_str)
+    }
+
+    /// The provider of the network the contract is bound to.
+    pub fn provider_for(&self, contract_id: impl Into<ContractId>) -> Result<&Provider> {
+        let contract_id = contract_id.into();
+        let network = self
+            .network_of(contract_id)
+            .ok_or_else(|| error!(Other, "contract `{contract_id}` is not bound to a network"))?;
+
+        self.provider(network)
+    }
+
+    /// A copy of `wallet` connected to the network the contract is bound to, to create the
+    /// contract instance with.
+    pub fn wallet_for(
+        &self,
+        contract_id: impl Into<ContractId>,
+        wallet: &WalletUnlocked,
+    ) -> Result<WalletUnlocked> {
+        let mut wallet = wallet.clone();
+        wallet.set_provider(self.provider_for(contract_id)?.clone());
+
+        Ok(wallet)
+    }
+}
+
+fn unknown_network(name: &str) -> Error {
+    error!(Other, "no provider registered for network `{name}`")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contracts_are_only_bound_to_registered_networks() {
+        let mut networks = NetworkRegistry::new();
+        let contract_id = ContractId::from([1; 32]);
+
+        let err = networks
+            .bind(contract_id, "mainnet")
+            .expect_err("should not be registered");
+        assert_eq!(
+            err.to_string(),
+            "no provider registered for network `mainnet`"
+        );
+        assert_eq!(networks.network_of(contract_id), None);
+
+        let err = networks
+            .provider_for(contract_id)
+            .expect_err("should not be bound");
+        assert_eq!(
+            err.to_string(),
+            format!("contract `{contract_id}` is not bound to a network")
+        );
+    }
+}
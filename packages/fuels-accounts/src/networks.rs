@@ -0,0 +1,99 @@
+use std::{fmt, str::FromStr};
+
+use fuel_types::{AssetId, ChainId};
+use fuels_core::{
+    constants::BASE_ASSET_ID,
+    types::errors::{error, Error, Result},
+};
+
+/// Known Fuel networks. Can be passed to `Provider::connect` in place of a url:
+///
+/// ```ignore
+/// let provider = Provider::connect(Network::Testnet).await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// A node running locally with its default configuration.
+    Local,
+    /// The public testnet, currently `beta-5`.
+    Testnet,
+}
+
+impl Network {
+    pub fn url(&self) -> &'static str {
+        match self {
+            Self::Local => "127.0.0.1:4000",
+            Self::Testnet => "beta-5.fuel.network",
+        }
+    }
+
+    pub fn chain_id(&self) -> ChainId {
+        match self {
+            Self::Local | Self::Testnet => ChainId::new(0),
+        }
+    }
+
+    pub fn base_asset_id(&self) -> AssetId {
+        BASE_ASSET_ID
+    }
+
+    /// Where to get coins for testing, if the network has a faucet.
+    pub fn faucet_url(&self) -> Option<&'static str> {
+        match self {
+            Self::Local => None,
+            Self::Testnet => Some("https://faucet-beta-5.fuel.network"),
+        }
+    }
+}
+
+impl AsRef<str> for Network {
+    fn as_ref(&self) -> &str {
+        self.url()
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Local => "local",
+            Self::Testnet => "testnet",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name {
+            "local" => Ok(Self::Local),
+            "testnet" => Ok(Self::Testnet),
+            _ => Err(error!(
+                Other,
+                "unknown network `{name}`, expected `local` or `testnet`"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn networks_are_parsed_from_their_names() -> Result<()> {
+        for network in [Network::Local, Network::Testnet] {
+            assert_eq!(network.to_string().parse::<Network>()?, network);
+        }
+
+        let err = "mainnet".parse::<Network>().expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "unknown network `mainnet`, expected `local` or `testnet`"
+        );
+
+        Ok(())
+    }
+}
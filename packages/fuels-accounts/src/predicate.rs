@@ -1,7 +1,7 @@
 use std::{fmt::Debug, fs};
 
 #[cfg(feature = "std")]
-use fuels_core::types::{input::Input, AssetId};
+use fuels_core::types::{input::Input, transaction::Transaction, AssetId};
 use fuels_core::{
     types::{bech32::Bech32Address, errors::Result, unresolved_bytes::UnresolvedBytes},
     Configurables,
@@ -10,7 +10,7 @@ use fuels_core::{
 #[cfg(feature = "std")]
 use crate::accounts_utils::try_provider_error;
 #[cfg(feature = "std")]
-use crate::{provider::Provider, Account, ViewOnlyAccount};
+use crate::{provider::Provider, Account, SubmittingAccount, ViewOnlyAccount};
 
 #[derive(Debug, Clone)]
 pub struct Predicate {
@@ -92,6 +92,12 @@ impl Predicate {
             ..self
         }
     }
+
+    /// Fills in the `predicate_gas_used` of the predicate inputs of `tx` with the gas the node
+    /// measured while verifying them.
+    pub async fn estimate_predicate_gas<T: Transaction>(&self, tx: T) -> Result<T> {
+        self.try_provider()?.estimate_predicates(tx).await
+    }
 }
 
 #[cfg(feature = "std")]
@@ -123,3 +129,6 @@ impl Account for Predicate {
             .collect::<Vec<Input>>())
     }
 }
+
+#[cfg(feature = "std")]
+impl SubmittingAccount for Predicate {}
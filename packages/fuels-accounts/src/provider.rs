@@ -1,25 +1,36 @@
-use std::{collections::HashMap, fmt::Debug, net::SocketAddr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    net::SocketAddr,
+    sync::{Arc, Mutex as StdMutex},
+    time::Duration,
+};
 
+mod gas_price;
+mod provider_config;
+mod query_cache;
+mod resource_cache;
 mod retry_util;
 mod retryable_client;
 mod supported_versions;
 
-#[cfg(feature = "coin-cache")]
-use std::sync::Arc;
-
 use chrono::{DateTime, Utc};
 use fuel_core_client::client::{
     pagination::{PageDirection, PaginatedResult, PaginationRequest},
     types::{balance::Balance, contract::ContractBalance},
 };
 use fuel_tx::{
-    AssetId, ConsensusParameters, Receipt, ScriptExecutionResult, Transaction as FuelTransaction,
-    TxId, UtxoId,
+    field::Inputs,
+    input::{
+        coin::CoinSigned,
+        message::{MessageCoinSigned, MessageDataSigned},
+    },
+    AssetId, ConsensusParameters, Input, Receipt, ScriptExecutionResult,
+    Transaction as FuelTransaction, TxId, UtxoId,
 };
 use fuel_types::{Address, Bytes32, ChainId, Nonce};
-#[cfg(feature = "coin-cache")]
-use fuels_core::types::coin_type_id::CoinTypeId;
 use fuels_core::{
+    codec::AbiRegistry,
     constants::{BASE_ASSET_ID, DEFAULT_GAS_ESTIMATION_TOLERANCE},
     types::{
         bech32::{Bech32Address, Bech32ContractId},
@@ -27,7 +38,8 @@ use fuels_core::{
         chain_info::ChainInfo,
         coin::Coin,
         coin_type::CoinType,
-        errors::{error, Result},
+        coin_type_id::CoinTypeId,
+        errors::{error, Error, Result},
         message::Message,
         message_proof::MessageProof,
         node_info::NodeInfo,
@@ -35,9 +47,17 @@ use fuels_core::{
         transaction_builders::DryRunner,
         transaction_response::TransactionResponse,
         tx_status::TxStatus,
+        wallet_activity::WalletActivity,
     },
 };
+pub use gas_price::{EmaGasPrice, GasPriceProvider, NodeGasPrice};
+pub use provider_config::ProviderConfig;
+use query_cache::QueryCache;
+pub use query_cache::QueryCacheConfig;
+use resource_cache::ResourceCache;
 pub use retry_util::{Backoff, RetryConfig};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 use supported_versions::{check_fuel_core_version_compatibility, VersionCompatibility};
 use tai64::Tai64;
 #[cfg(feature = "coin-cache")]
@@ -47,6 +67,9 @@ use tokio::sync::Mutex;
 use crate::coin_cache::CoinsCache;
 use crate::provider::retryable_client::RetryableClient;
 
+/// How often the node is polled by default, see [`ProviderConfig::poll_interval`].
+pub const CONFIRMATION_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
 #[derive(Debug)]
 pub struct TransactionCost {
     pub min_gas_price: u64,
@@ -128,6 +151,14 @@ impl Default for ResourceFilter {
     }
 }
 
+/// The balances of an owner's coins, split by whether they can be spent at the latest block.
+/// Coins with a `maturity` are locked until the chain reaches [`Coin::matures_at`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoinBalances {
+    pub spendable: HashMap<AssetId, u64>,
+    pub locked: HashMap<AssetId, u64>,
+}
+
 /// Encapsulates common client operations in the SDK.
 /// Note that you may also use `client`, which is an instance
 /// of `FuelClient`, directly, which provides a broader API.
@@ -137,6 +168,10 @@ pub struct Provider {
     consensus_parameters: ConsensusParameters,
     #[cfg(feature = "coin-cache")]
     cache: Arc<Mutex<CoinsCache>>,
+    query_cache: Option<Arc<StdMutex<QueryCache>>>,
+    resource_cache: Option<Arc<StdMutex<ResourceCache>>>,
+    gas_price_provider: Arc<dyn GasPriceProvider>,
+    unvalidated_dry_runs: bool,
 }
 
 impl Provider {
@@ -162,6 +197,10 @@ impl Provider {
             consensus_parameters,
             #[cfg(feature = "coin-cache")]
             cache: Default::default(),
+            query_cache: None,
+            resource_cache: None,
+            gas_price_provider: Arc::new(NodeGasPrice),
+            unvalidated_dry_runs: false,
         })
     }
 
@@ -181,6 +220,11 @@ impl Provider {
             .await?
             .into();
 
+        // Squeezed out transactions leave their coins unspent
+        if !matches!(tx_status, TxStatus::SqueezedOut { .. }) {
+            self.mark_spent_in_resource_cache(&tx);
+        }
+
         #[cfg(feature = "coin-cache")]
         if matches!(
             tx_status,
@@ -194,6 +238,7 @@ impl Provider {
 
     async fn prepare_transaction_for_sending<T: Transaction>(&self, tx: &mut T) -> Result<()> {
         tx.precompute(&self.chain_id())?;
+        tx.verify_signatures(self.chain_id())?;
 
         let chain_info = self.chain_info().await?;
         tx.check(
@@ -218,6 +263,72 @@ impl Provider {
         Ok(self.client.await_transaction_commit(&id).await?.into())
     }
 
+    /// Waits until the transaction is included in a block with at least `confirmations` blocks
+    /// on top of it.
+    ///
+    /// The node is polled every [`ProviderConfig::poll_interval`]. Each time, the block holding
+    /// the transaction is checked to still be the canonical one at its height, so a transaction
+    /// that got reorganized out of the chain is waited on again from its new block.
+    ///
+    /// Gives up with [`Error::Timeout`] if the transaction isn't confirmed within `timeout`, e.g.
+    /// because it was never included or blocks stopped being produced.
+    pub async fn await_confirmations(
+        &self,
+        tx_id: &TxId,
+        confirmations: u32,
+        timeout: Duration,
+    ) -> Result<TxStatus> {
+        let confirmed = async {
+            loop {
+                if let Some(height) = self.canonical_inclusion_height(tx_id).await? {
+                    if self.latest_block_height().await? >= height.saturating_add(confirmations) {
+                        return self.tx_status(tx_id).await;
+                    }
+                }
+
+                tokio::time::sleep(self.config().poll_interval).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, confirmed)
+            .await
+            .map_err(|_| Error::Timeout(timeout))?
+    }
+
+    /// The height of the canonical block holding the committed transaction, if it was included
+    /// yet.
+    async fn canonical_inclusion_height(&self, tx_id: &TxId) -> Result<Option<u32>> {
+        let response = self
+            .client
+            .transaction(tx_id)
+            .await?
+            .map(TransactionResponse::from)
+            .ok_or_else(|| error!(Provider, "transaction `{tx_id}` not found"))?;
+
+        if let TxStatus::SqueezedOut { reason } = response.status {
+            return Err(error!(
+                Provider,
+                "transaction `{tx_id}` was squeezed out: {reason}"
+            ));
+        }
+
+        let Some(block_id) = response.block_id else {
+            return Ok(None);
+        };
+        let Some(block) = self.block(&block_id).await? else {
+            return Ok(None);
+        };
+
+        let canonical_block = self
+            .client
+            .block_by_height(block.header.height)
+            .await?
+            .map(Block::from);
+        let is_canonical = canonical_block.is_some_and(|canonical| canonical.id == block_id);
+
+        Ok(is_canonical.then_some(block.header.height))
+    }
+
     async fn validate_transaction<T: Transaction>(&self, tx: T) -> Result<()> {
         let tolerance = 0.0;
         let TransactionCost {
@@ -235,18 +346,30 @@ impl Provider {
 
     #[cfg(not(feature = "coin-cache"))]
     async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
-        Ok(self.client.submit(&tx.into()).await?)
+        let tx_id = self.client.submit(&tx.clone().into()).await?;
+        self.mark_spent_in_resource_cache(&tx);
+
+        Ok(tx_id)
     }
 
     #[cfg(feature = "coin-cache")]
     async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
         let used_utxos = tx.used_coins();
-        let tx_id = self.client.submit(&tx.into()).await?;
+        let tx_id = self.client.submit(&tx.clone().into()).await?;
+        self.mark_spent_in_resource_cache(&tx);
         self.cache.lock().await.insert_multiple(used_utxos);
 
         Ok(tx_id)
     }
 
+    /// Only called once the node accepted `tx`, so that the coins of a rejected transaction stay
+    /// available.
+    fn mark_spent_in_resource_cache<T: Transaction>(&self, tx: &T) {
+        if let Some(resource_cache) = &self.resource_cache {
+            lock(resource_cache).mark_spent(tx.used_coins());
+        }
+    }
+
     pub async fn tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
         Ok(self.client.transaction_status(tx_id).await?.into())
     }
@@ -304,6 +427,51 @@ impl Provider {
         Ok(Self::tx_status_from_receipts(receipts))
     }
 
+    /// Dry runs `tx` as if it was sent by `address`, without needing its keys. The owners of
+    /// the signed coin and message inputs of `tx` are replaced with `address` and the dry run
+    /// skips UTXO and signature validation, so e.g. `msg_sender()` returns `address`. Meant for
+    /// probing access control logic in tests and analytics.
+    pub async fn simulate_as<T: Transaction>(
+        &self,
+        address: &Bech32Address,
+        tx: T,
+    ) -> Result<TxStatus> {
+        let sender = Address::from(address);
+
+        let mut tx: FuelTransaction = tx.into();
+        let inputs = match &mut tx {
+            FuelTransaction::Script(tx) => tx.inputs_mut(),
+            FuelTransaction::Create(tx) => tx.inputs_mut(),
+            FuelTransaction::Mint(_) => {
+                return Err(error!(Other, "mint transactions cannot be simulated"))
+            }
+        };
+
+        let mut impersonated = false;
+        for input in inputs.iter_mut() {
+            match input {
+                Input::CoinSigned(CoinSigned { owner, .. }) => *owner = sender,
+                Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+                | Input::MessageDataSigned(MessageDataSigned { recipient, .. }) => {
+                    *recipient = sender
+                }
+                _ => continue,
+            }
+            impersonated = true;
+        }
+
+        if !impersonated {
+            return Err(error!(
+                Other,
+                "cannot simulate the transaction as `{address}`: it has no signed inputs"
+            ));
+        }
+
+        let receipts = self.client.dry_run_opt(&tx, Some(false)).await?;
+
+        Ok(Self::tx_status_from_receipts(receipts))
+    }
+
     fn tx_status_from_receipts(receipts: Vec<Receipt>) -> TxStatus {
         let revert_reason = receipts.iter().find_map(|receipt| match receipt {
             Receipt::ScriptResult { result, .. } if *result != ScriptExecutionResult::Success => {
@@ -323,6 +491,10 @@ impl Provider {
     }
 
     pub async fn dry_run<T: Transaction>(&self, tx: T) -> Result<Vec<Receipt>> {
+        if self.unvalidated_dry_runs {
+            return self.dry_run_no_validation(tx).await;
+        }
+
         let receipts = self.client.dry_run(&tx.into()).await?;
 
         Ok(receipts)
@@ -334,8 +506,26 @@ impl Provider {
         Ok(receipts)
     }
 
+    /// Asks the node to run the predicates of `tx` and sets the `predicate_gas_used` of every
+    /// predicate input to the gas it consumed.
+    pub async fn estimate_predicates<T: Transaction>(&self, mut tx: T) -> Result<T> {
+        let estimated = self.client.estimate_predicates(&tx.clone().into()).await?;
+        tx.apply_predicate_estimation(&estimated)?;
+
+        Ok(tx)
+    }
+
     /// Gets all unspent coins owned by address `from`, with asset ID `asset_id`.
     pub async fn get_coins(&self, from: &Bech32Address, asset_id: AssetId) -> Result<Vec<Coin>> {
+        self.get_coins_of(from, Some(&asset_id)).await
+    }
+
+    /// Gets the unspent coins owned by `from`, of every asset unless `asset_id` is given.
+    async fn get_coins_of(
+        &self,
+        from: &Bech32Address,
+        asset_id: Option<&AssetId>,
+    ) -> Result<Vec<Coin>> {
         let mut coins: Vec<Coin> = vec![];
 
         let mut cursor = None;
@@ -345,7 +535,7 @@ impl Provider {
                 .client
                 .coins(
                     &from.into(),
-                    Some(&asset_id),
+                    asset_id,
                     PaginationRequest {
                         cursor: cursor.clone(),
                         results: 100,
@@ -364,7 +554,50 @@ impl Provider {
         Ok(coins)
     }
 
-    async fn request_coins_to_spend(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
+    /// Selects resources like [`Self::select_resources`], leaving out coins that can't be spent
+    /// yet: the node would reject a transaction spending them.
+    async fn request_coins_to_spend(&self, mut filter: ResourceFilter) -> Result<Vec<CoinType>> {
+        loop {
+            let resources = self.select_resources(&filter).await?;
+
+            let immature = self.immature_coins(&resources).await?;
+            if immature.is_empty() {
+                return Ok(resources);
+            }
+
+            filter.excluded_utxos.extend(immature);
+        }
+    }
+
+    /// The coins among `resources` that have yet to mature. The latest block height is only
+    /// queried if some coin has a `maturity`.
+    async fn immature_coins(&self, resources: &[CoinType]) -> Result<Vec<UtxoId>> {
+        let with_maturity = resources
+            .iter()
+            .filter_map(|resource| match resource {
+                CoinType::Coin(coin) if coin.maturity > 0 => Some(coin),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if with_maturity.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block_height = self.latest_block_height().await?;
+
+        Ok(with_maturity
+            .into_iter()
+            .filter(|coin| !coin.is_mature(block_height))
+            .map(|coin| coin.utxo_id)
+            .collect())
+    }
+
+    async fn select_resources(&self, filter: &ResourceFilter) -> Result<Vec<CoinType>> {
+        if let Some(resources) = self.cached_resources_to_spend(filter).await? {
+            return Ok(resources);
+        }
+
         let queries = filter.resource_queries();
 
         let res = self
@@ -383,6 +616,43 @@ impl Provider {
         Ok(res)
     }
 
+    /// Picks the resources to spend from the resource cache, fetching the coins of the owner
+    /// first if needed. `None` if the cache is disabled or the coins can't cover the amount, in
+    /// which case the node should be asked.
+    async fn cached_resources_to_spend(
+        &self,
+        filter: &ResourceFilter,
+    ) -> Result<Option<Vec<CoinType>>> {
+        let Some(resource_cache) = &self.resource_cache else {
+            return Ok(None);
+        };
+
+        let key = (filter.from.clone(), filter.asset_id);
+        let excluded = filter
+            .excluded_utxos
+            .iter()
+            .map(|utxo_id| CoinTypeId::UtxoId(*utxo_id))
+            .chain(
+                filter
+                    .excluded_message_nonces
+                    .iter()
+                    .map(|nonce| CoinTypeId::Nonce(*nonce)),
+            )
+            .collect::<HashSet<_>>();
+
+        let block_height = self.latest_block_height().await?;
+        let cached = lock(resource_cache).select(&key, block_height, filter.amount, &excluded);
+        if cached.is_some() {
+            return Ok(cached);
+        }
+
+        let coins = self.get_coins(&filter.from, filter.asset_id).await?;
+        let mut resource_cache = lock(resource_cache);
+        resource_cache.insert(key.clone(), block_height, coins);
+
+        Ok(resource_cache.select(&key, block_height, filter.amount, &excluded))
+    }
+
     /// Get some spendable coins of asset `asset_id` for address `from` that add up at least to
     /// amount `amount`. The returned coins (UTXOs) are actual coins that can be spent. The number
     /// of coins (UXTOs) is optimized to prevent dust accumulation.
@@ -448,6 +718,25 @@ impl Provider {
             .await?)
     }
 
+    /// The balances of the coins owned by `address`, split into the ones that can be spent at
+    /// the latest block and the ones still locked by their `maturity`. Messages are never locked
+    /// and are left out.
+    pub async fn get_coin_balances(&self, address: &Bech32Address) -> Result<CoinBalances> {
+        let block_height = self.latest_block_height().await?;
+
+        let mut balances = CoinBalances::default();
+        for coin in self.get_coins_of(address, None).await? {
+            let balance = if coin.is_mature(block_height) {
+                &mut balances.spendable
+            } else {
+                &mut balances.locked
+            };
+            *balance.entry(coin.asset_id).or_default() += coin.amount;
+        }
+
+        Ok(balances)
+    }
+
     /// Get the balance of all spendable coins `asset_id` for contract with id `contract_id`.
     pub async fn get_contract_asset_balance(
         &self,
@@ -521,7 +810,38 @@ impl Provider {
     }
 
     pub async fn get_transaction_by_id(&self, tx_id: &TxId) -> Result<Option<TransactionResponse>> {
-        Ok(self.client.transaction(tx_id).await?.map(Into::into))
+        let Some(query_cache) = &self.query_cache else {
+            return Ok(self.client.transaction(tx_id).await?.map(Into::into));
+        };
+
+        let cached = lock(query_cache).transaction(tx_id);
+        if let Some(response) = cached {
+            return Ok(Some(response));
+        }
+
+        let Some(response) = self
+            .client
+            .transaction(tx_id)
+            .await?
+            .map(TransactionResponse::from)
+        else {
+            return Ok(None);
+        };
+
+        let needs_finality_check = lock(query_cache).needs_finality_check();
+        let block_is_final = match response.block_id {
+            Some(block_id) if needs_finality_check => match self.block(&block_id).await? {
+                Some(block) => self.is_final(query_cache, block.header.height).await?,
+                None => false,
+            },
+            Some(_) => true,
+            None => false,
+        };
+        if block_is_final {
+            lock(query_cache).insert_transaction(*tx_id, response.clone());
+        }
+
+        Ok(Some(response))
     }
 
     pub async fn get_transactions(
@@ -557,6 +877,30 @@ impl Provider {
         })
     }
 
+    /// The history of `owner`: a page of the transactions involving it, each classified as
+    /// sent, received, contract call, ... with the contract calls decoded for contracts whose ABI
+    /// is in `abi_registry`. See [`WalletActivity`].
+    pub async fn get_wallet_activity(
+        &self,
+        owner: &Bech32Address,
+        request: PaginationRequest<String>,
+        abi_registry: &AbiRegistry,
+    ) -> Result<PaginatedResult<WalletActivity, String>> {
+        let page = self.get_transactions_by_owner(owner, request).await?;
+        let chain_id = self.chain_id();
+
+        Ok(PaginatedResult {
+            cursor: page.cursor,
+            results: page
+                .results
+                .into_iter()
+                .map(|response| WalletActivity::new(response, owner, chain_id, abi_registry))
+                .collect(),
+            has_next_page: page.has_next_page,
+            has_previous_page: page.has_previous_page,
+        })
+    }
+
     pub async fn latest_block_height(&self) -> Result<u32> {
         Ok(self.chain_info().await?.latest_block.header.height)
     }
@@ -584,6 +928,42 @@ impl Provider {
         Ok(self.client.block(block_id).await?.map(Into::into))
     }
 
+    /// Get block by height.
+    pub async fn block_by_height(&self, height: u32) -> Result<Option<Block>> {
+        let Some(query_cache) = &self.query_cache else {
+            return Ok(self.client.block_by_height(height).await?.map(Into::into));
+        };
+
+        let cached = lock(query_cache).block(height);
+        if let Some(block) = cached {
+            return Ok(Some(block));
+        }
+
+        let Some(block) = self.client.block_by_height(height).await?.map(Block::from) else {
+            return Ok(None);
+        };
+
+        if self.is_final(query_cache, height).await? {
+            lock(query_cache).insert_block(block.clone());
+        }
+
+        Ok(Some(block))
+    }
+
+    /// Whether the block at `height` has at least `finality_depth` blocks on top of it. The
+    /// latest height is only queried when the last one seen is not enough to tell.
+    async fn is_final(&self, query_cache: &StdMutex<QueryCache>, height: u32) -> Result<bool> {
+        if lock(query_cache).is_final(height) {
+            return Ok(true);
+        }
+
+        let latest_height = self.latest_block_height().await?;
+        let mut query_cache = lock(query_cache);
+        query_cache.update_latest_height(latest_height);
+
+        Ok(query_cache.is_final(height))
+    }
+
     // - Get block(s)
     pub async fn get_blocks(
         &self,
@@ -687,11 +1067,117 @@ impl Provider {
         Ok(proof)
     }
 
+    /// Executes a GraphQL `query` against the node and deserializes its `data` into `T`.
+    ///
+    /// Meant for node features the SDK doesn't wrap yet. The request shares the provider's url
+    /// and retry configuration, and fails if the response is larger than
+    /// [`ProviderConfig::max_response_size`].
+    pub async fn raw_graphql<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: impl Serialize,
+    ) -> Result<T> {
+        let variables = serde_json::to_value(variables)?;
+        let mut response = self.client.raw_graphql(query, &variables).await?;
+
+        if let Some(errors) = response.get("errors").and_then(Value::as_array) {
+            let messages = errors
+                .iter()
+                .map(|error| {
+                    error
+                        .get("message")
+                        .and_then(Value::as_str)
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| error.to_string())
+                })
+                .collect::<Vec<_>>();
+
+            return Err(error!(
+                Provider,
+                "graphql query failed: {}",
+                messages.join(", ")
+            ));
+        }
+
+        let data = response
+            .get_mut("data")
+            .map(Value::take)
+            .ok_or_else(|| error!(Provider, "graphql response contains no `data`"))?;
+
+        Ok(serde_json::from_value(data)?)
+    }
+
     pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
         self.client.set_retry_config(retry_config);
 
         self
     }
+
+    /// Applies the timeouts and limits of `config` to all the requests sent to the node. See
+    /// [`ProviderConfig`] for how to override them for a single call.
+    pub fn with_config(mut self, config: ProviderConfig) -> Self {
+        self.client.set_config(config);
+
+        self
+    }
+
+    pub fn config(&self) -> &ProviderConfig {
+        self.client.config()
+    }
+
+    /// Caches blocks fetched by height and committed transactions fetched by id once they are
+    /// final. Clones of the provider share the cache.
+    pub fn with_query_cache(mut self, query_cache_config: QueryCacheConfig) -> Self {
+        self.query_cache = Some(Arc::new(StdMutex::new(QueryCache::new(query_cache_config))));
+
+        self
+    }
+
+    /// Remembers the coins of the accounts spending through this provider, so that building
+    /// transactions only asks the node for its latest block height. The coins are fetched again
+    /// once a new block is produced. Clones of the provider share the cache.
+    pub fn with_resource_cache(mut self) -> Self {
+        self.resource_cache = Some(Arc::new(StdMutex::new(ResourceCache::default())));
+
+        self
+    }
+
+    /// Drops the cached coins of `owner`, e.g. after receiving coins, so that they are fetched
+    /// again on the next transaction.
+    pub fn invalidate_resource_cache(&self, owner: &Bech32Address) {
+        if let Some(resource_cache) = &self.resource_cache {
+            lock(resource_cache).invalidate(owner);
+        }
+    }
+
+    /// Consults `gas_price_provider` for the gas price of transactions that don't set one,
+    /// instead of using the node's minimum gas price.
+    pub fn with_gas_price_provider(
+        mut self,
+        gas_price_provider: impl GasPriceProvider + 'static,
+    ) -> Self {
+        self.gas_price_provider = Arc::new(gas_price_provider);
+
+        self
+    }
+
+    /// Makes [`Provider::dry_run`] skip UTXO and signature validation. Used by read-only
+    /// accounts, whose transactions carry neither real coins nor valid signatures.
+    pub(crate) fn without_dry_run_validation(mut self) -> Self {
+        self.unvalidated_dry_runs = true;
+
+        self
+    }
+
+    /// The gas price given to transactions that don't set one, see
+    /// [`Provider::with_gas_price_provider`].
+    pub async fn gas_price(&self) -> Result<u64> {
+        self.gas_price_provider.gas_price(self).await
+    }
+}
+
+fn lock<T>(cache: &StdMutex<T>) -> std::sync::MutexGuard<'_, T> {
+    cache.lock().expect("cache lock should not be poisoned")
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -707,6 +1193,10 @@ impl DryRunner for Provider {
         Ok(self.node_info().await.map(|ni| ni.min_gas_price)?)
     }
 
+    async fn gas_price(&self) -> Result<u64> {
+        Provider::gas_price(self).await
+    }
+
     fn consensus_parameters(&self) -> &ConsensusParameters {
         self.consensus_parameters()
     }
@@ -0,0 +1,168 @@
+use std::fmt::Debug;
+
+use fuel_core_client::client::pagination::{PageDirection, PaginationRequest};
+use fuels_core::types::{
+    errors::{error, Result},
+    transaction::{Transaction, TransactionType},
+    transaction_response::TransactionResponse,
+};
+
+use crate::provider::Provider;
+
+/// Decides the gas price of transactions whose `TxPolicies` don't set one. The transaction
+/// builders consult it through the [`Provider`] it was given to, see
+/// [`Provider::with_gas_price_provider`].
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait GasPriceProvider: Debug + Send + Sync {
+    async fn gas_price(&self, provider: &Provider) -> Result<u64>;
+}
+
+/// The minimum gas price accepted by the node. Used unless the provider is configured
+/// otherwise.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeGasPrice;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl GasPriceProvider for NodeGasPrice {
+    async fn gas_price(&self, provider: &Provider) -> Result<u64> {
+        Ok(provider.node_info().await?.min_gas_price)
+    }
+}
+
+/// Exponential moving average of the gas prices paid in recent blocks, never below the node's
+/// minimum gas price.
+///
+/// The `sample_size` most recent transactions are fetched and averaged per block. The blocks
+/// are then weighed from the oldest to the newest, each one contributing `smoothing` of its
+/// price to the average. The closer `smoothing` is to `1.0`, the faster the price follows the
+/// network.
+///
+/// ```rust
+/// use fuels_accounts::provider::EmaGasPrice;
+///
+/// let gas_price_provider = EmaGasPrice::new(100, 0.3).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct EmaGasPrice {
+    sample_size: u16,
+    smoothing: f64,
+}
+
+impl EmaGasPrice {
+    pub fn new(sample_size: u16, smoothing: f64) -> Result<Self> {
+        if sample_size == 0 {
+            return Err(error!(Other, "`sample_size` must be greater than `0`"));
+        }
+        if !(smoothing > 0.0 && smoothing <= 1.0) {
+            return Err(error!(Other, "`smoothing` must be in the range (0, 1]"));
+        }
+
+        Ok(Self {
+            sample_size,
+            smoothing,
+        })
+    }
+
+    pub fn sample_size(&self) -> u16 {
+        self.sample_size
+    }
+
+    pub fn smoothing(&self) -> f64 {
+        self.smoothing
+    }
+}
+
+impl Default for EmaGasPrice {
+    fn default() -> Self {
+        Self {
+            sample_size: 100,
+            smoothing: 0.3,
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl GasPriceProvider for EmaGasPrice {
+    async fn gas_price(&self, provider: &Provider) -> Result<u64> {
+        let min_gas_price = provider.node_info().await?.min_gas_price;
+        let recent_transactions = provider
+            .get_transactions(PaginationRequest {
+                cursor: None,
+                results: self.sample_size.into(),
+                direction: PageDirection::Backward,
+            })
+            .await?
+            .results;
+
+        let mut block_prices = block_gas_prices(&recent_transactions);
+        // the transactions come newest first
+        block_prices.reverse();
+
+        let gas_price = exponential_moving_average(&block_prices, self.smoothing).unwrap_or(0);
+
+        Ok(gas_price.max(min_gas_price))
+    }
+}
+
+/// The average gas price of the given transactions, per block, in the order the blocks are
+/// first seen. Transactions without a gas price (i.e. mints) or not included in a block are
+/// skipped.
+fn block_gas_prices(transactions: &[TransactionResponse]) -> Vec<u64> {
+    let mut blocks: Vec<(_, u128, u128)> = vec![];
+    for response in transactions {
+        let gas_price = match &response.transaction {
+            TransactionType::Script(tx) => tx.gas_price(),
+            TransactionType::Create(tx) => tx.gas_price(),
+            TransactionType::Mint(_) => continue,
+        };
+        let Some(block_id) = response.block_id else {
+            continue;
+        };
+
+        match blocks.last_mut() {
+            Some((last_block_id, sum, count)) if *last_block_id == block_id => {
+                *sum += u128::from(gas_price);
+                *count += 1;
+            }
+            _ => blocks.push((block_id, u128::from(gas_price), 1)),
+        }
+    }
+
+    blocks
+        .into_iter()
+        .map(|(_, sum, count)| (sum / count) as u64)
+        .collect()
+}
+
+fn exponential_moving_average(prices: &[u64], smoothing: f64) -> Option<u64> {
+    let (first, rest) = prices.split_first()?;
+
+    let average = rest.iter().fold(*first as f64, |average, price| {
+        smoothing * *price as f64 + (1.0 - smoothing) * average
+    });
+
+    Some(average.ceil() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_prices_weigh_more() {
+        assert_eq!(exponential_moving_average(&[], 0.5), None);
+        assert_eq!(exponential_moving_average(&[10], 0.5), Some(10));
+        assert_eq!(exponential_moving_average(&[10, 20, 40], 0.5), Some(28));
+        assert_eq!(exponential_moving_average(&[10, 20, 40], 1.0), Some(40));
+    }
+
+    #[test]
+    fn smoothing_is_validated() {
+        assert!(EmaGasPrice::new(100, 0.0).is_err());
+        assert!(EmaGasPrice::new(100, 1.5).is_err());
+        assert!(EmaGasPrice::new(0, 0.5).is_err());
+
+        let ema = EmaGasPrice::new(10, 1.0).expect("should be valid");
+        assert_eq!((ema.sample_size(), ema.smoothing()), (10, 1.0));
+    }
+}
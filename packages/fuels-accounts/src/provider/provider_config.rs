@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use crate::provider::CONFIRMATION_POLL_INTERVAL;
+
+/// Timeouts and limits applied to every request a [`Provider`](super::Provider) sends to the
+/// node, see [`Provider::with_config`](super::Provider::with_config).
+///
+/// Clones of a provider share its connection and caches, so a single call can be made with
+/// other settings through a reconfigured clone:
+///
+/// ```ignore
+/// let patient = provider.clone().with_config(ProviderConfig {
+///     request_timeout: Some(Duration::from_secs(60)),
+///     ..*provider.config()
+/// });
+/// let block = patient.block_by_height(height).await?;
+/// ```
+// ANCHOR: provider_config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderConfig {
+    /// How long every attempt at a request may take, see [`RetryConfig`](super::RetryConfig).
+    /// Requests waiting for a transaction to be committed aren't limited, as they last until
+    /// a block is produced. `None` waits for as long as the node takes.
+    pub request_timeout: Option<Duration>,
+    /// How often the node is polled while waiting on it, e.g. by
+    /// [`Provider::await_confirmations`](super::Provider::await_confirmations).
+    pub poll_interval: Duration,
+    /// The largest response, in bytes, accepted by
+    /// [`Provider::raw_graphql`](super::Provider::raw_graphql). Only enforced there: the other
+    /// queries go through `fuel-core-client`, which reads responses of any size. `None` accepts
+    /// any size.
+    pub max_response_size: Option<usize>,
+}
+// ANCHOR_END: provider_config
+
+impl Default for ProviderConfig {
+    fn default() -> Self {
+        Self {
+            request_timeout: None,
+            poll_interval: CONFIRMATION_POLL_INTERVAL,
+            max_response_size: None,
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use fuel_tx::TxId;
+use fuels_core::types::{
+    block::Block,
+    errors::{error, Result},
+    transaction_response::TransactionResponse,
+    tx_status::TxStatus,
+};
+
+/// Configuration for caching the results of queries that cannot change anymore.
+///
+/// Blocks and committed transactions are immutable once final. A block is considered final when
+/// at least `finality_depth` blocks were produced on top of it. Up to `capacity` blocks and
+/// `capacity` transactions are kept, evicting the least recently used ones first.
+///
+/// # Examples
+///
+/// ```rust
+/// use fuels_accounts::provider::QueryCacheConfig;
+///
+/// let cache_config = QueryCacheConfig::new(1000, 0).unwrap();
+/// ```
+// ANCHOR: query_cache_config
+#[derive(Clone, Debug)]
+pub struct QueryCacheConfig {
+    capacity: usize,
+    finality_depth: u32,
+}
+// ANCHOR_END: query_cache_config
+
+impl QueryCacheConfig {
+    pub fn new(capacity: usize, finality_depth: u32) -> Result<Self> {
+        if capacity == 0 {
+            return Err(error!(Other, "`capacity` must be greater than `0`"));
+        }
+
+        Ok(Self {
+            capacity,
+            finality_depth,
+        })
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn finality_depth(&self) -> u32 {
+        self.finality_depth
+    }
+}
+
+impl Default for QueryCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1000,
+            finality_depth: 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct QueryCache {
+    finality_depth: u32,
+    latest_height: u32,
+    blocks: Lru<u32, Block>,
+    transactions: Lru<TxId, TransactionResponse>,
+}
+
+impl QueryCache {
+    pub fn new(config: QueryCacheConfig) -> Self {
+        Self {
+            finality_depth: config.finality_depth,
+            latest_height: 0,
+            blocks: Lru::new(config.capacity),
+            transactions: Lru::new(config.capacity),
+        }
+    }
+
+    pub fn block(&mut self, height: u32) -> Option<Block> {
+        self.blocks.get(&height)
+    }
+
+    pub fn transaction(&mut self, tx_id: &TxId) -> Option<TransactionResponse> {
+        self.transactions.get(tx_id)
+    }
+
+    pub fn needs_finality_check(&self) -> bool {
+        self.finality_depth > 0
+    }
+
+    pub fn is_final(&self, height: u32) -> bool {
+        height.saturating_add(self.finality_depth) <= self.latest_height
+    }
+
+    pub fn update_latest_height(&mut self, latest_height: u32) {
+        self.latest_height = self.latest_height.max(latest_height);
+    }
+
+    pub fn insert_block(&mut self, block: Block) {
+        self.blocks.insert(block.header.height, block);
+    }
+
+    /// Only transactions that were committed to a block can be cached.
+    pub fn insert_transaction(&mut self, tx_id: TxId, response: TransactionResponse) {
+        if matches!(
+            response.status,
+            TxStatus::Success { .. } | TxStatus::Revert { .. }
+        ) {
+            self.transactions.insert(tx_id, response);
+        }
+    }
+}
+
+/// A small least-recently-used map. Lookups are linear in the number of entries when it comes to
+/// updating the usage order, which is negligible next to the network round trip they save.
+#[derive(Debug)]
+struct Lru<K, V> {
+    capacity: usize,
+    entries: HashMap<K, V>,
+    usage: VecDeque<K>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Lru<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::default(),
+            usage: VecDeque::default(),
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.entries.get(key)?.clone();
+        self.mark_used(key);
+
+        Some(value)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.entries.insert(key.clone(), value).is_some() {
+            self.mark_used(&key);
+            return;
+        }
+
+        self.usage.push_back(key);
+        if self.usage.len() > self.capacity {
+            let evicted = self.usage.pop_front().expect("capacity is not zero");
+            self.entries.remove(&evicted);
+        }
+    }
+
+    fn mark_used(&mut self, key: &K) {
+        if let Some(position) = self.usage.iter().position(|used| used == key) {
+            let key = self.usage.remove(position).expect("position is in bounds");
+            self.usage.push_back(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn least_recently_used_entries_are_evicted() {
+        let mut lru = Lru::new(2);
+        lru.insert(1, "one");
+        lru.insert(2, "two");
+
+        // `1` becomes the most recently used
+        assert_eq!(lru.get(&1), Some("one"));
+
+        lru.insert(3, "three");
+
+        assert_eq!(lru.get(&2), None);
+        assert_eq!(lru.get(&1), Some("one"));
+        assert_eq!(lru.get(&3), Some("three"));
+    }
+
+    #[test]
+    fn blocks_are_final_after_finality_depth() {
+        let mut cache = QueryCache::new(QueryCacheConfig::new(10, 3).unwrap());
+        cache.update_latest_height(10);
+
+        assert!(cache.is_final(7));
+        assert!(!cache.is_final(8));
+
+        // the latest known height never decreases
+        cache.update_latest_height(4);
+        assert!(cache.is_final(7));
+    }
+
+    #[test]
+    fn zero_capacity_is_rejected() {
+        let err = QueryCacheConfig::new(0, 0).expect_err("should fail");
+
+        assert_eq!(err.to_string(), "`capacity` must be greater than `0`");
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::{HashMap, HashSet};
+
+use fuels_core::types::{
+    bech32::Bech32Address, coin::Coin, coin_type::CoinType, coin_type_id::CoinTypeId, AssetId,
+};
+
+type ResourceCacheKey = (Bech32Address, AssetId);
+
+#[derive(Debug)]
+struct CachedCoins {
+    block_height: u32,
+    coins: Vec<Coin>,
+}
+
+/// The coins of the accounts sending transactions through a [`Provider`](crate::provider::Provider).
+///
+/// The coins of an owner and asset are fetched once per block and resources to spend are then
+/// picked from them locally. Coins used by submitted transactions are marked as spent right away.
+/// The coins are fetched again once the node reports a new block, as committed transactions may
+/// have spent or created coins, or whenever the cached coins can't cover an amount.
+#[derive(Debug, Default)]
+pub(crate) struct ResourceCache {
+    entries: HashMap<ResourceCacheKey, CachedCoins>,
+    // coins used by submitted transactions, kept out of refreshed entries until the node stops
+    // reporting them as unspent
+    spent: HashMap<ResourceCacheKey, HashSet<CoinTypeId>>,
+}
+
+impl ResourceCache {
+    /// Caches `coins` as fetched at `block_height`.
+    pub fn insert(&mut self, key: ResourceCacheKey, block_height: u32, mut coins: Vec<Coin>) {
+        let spent = self.spent.entry(key.clone()).or_default();
+        spent.retain(|id| {
+            coins
+                .iter()
+                .any(|coin| *id == CoinTypeId::UtxoId(coin.utxo_id))
+        });
+        coins.retain(|coin| !spent.contains(&CoinTypeId::UtxoId(coin.utxo_id)));
+
+        let entry = CachedCoins {
+            block_height,
+            coins,
+        };
+        self.entries.insert(key, entry);
+    }
+
+    /// Picks cached coins, largest first, adding up at least to `amount`. `None` if the coins
+    /// are missing, were fetched before `block_height` or are not enough.
+    pub fn select(
+        &self,
+        key: &ResourceCacheKey,
+        block_height: u32,
+        amount: u64,
+        excluded: &HashSet<CoinTypeId>,
+    ) -> Option<Vec<CoinType>> {
+        let entry = self.entries.get(key)?;
+        if entry.block_height < block_height {
+            return None;
+        }
+
+        let mut candidates = entry
+            .coins
+            .iter()
+            .filter(|coin| !excluded.contains(&CoinTypeId::UtxoId(coin.utxo_id)))
+            .collect::<Vec<_>>();
+        candidates.sort_by(|lhs, rhs| rhs.amount.cmp(&lhs.amount));
+
+        let mut total = 0u64;
+        let mut selected = vec![];
+        for coin in candidates {
+            if total >= amount && !selected.is_empty() {
+                break;
+            }
+            total = total.saturating_add(coin.amount);
+            selected.push(CoinType::Coin(coin.clone()));
+        }
+
+        (total >= amount && !selected.is_empty()).then_some(selected)
+    }
+
+    /// Forgets the coins used by a submitted transaction.
+    pub fn mark_spent(
+        &mut self,
+        used_coins: impl IntoIterator<Item = (ResourceCacheKey, Vec<CoinTypeId>)>,
+    ) {
+        for (key, ids) in used_coins {
+            if let Some(entry) = self.entries.get_mut(&key) {
+                entry
+                    .coins
+                    .retain(|coin| !ids.contains(&CoinTypeId::UtxoId(coin.utxo_id)));
+            }
+            self.spent.entry(key).or_default().extend(ids);
+        }
+    }
+
+    pub fn invalidate(&mut self, owner: &Bech32Address) {
+        self.entries
+            .retain(|(cached_owner, _), _| cached_owner != owner);
+        self.spent
+            .retain(|(cached_owner, _), _| cached_owner != owner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::UtxoId;
+    use fuel_types::Bytes32;
+
+    use super::*;
+
+    fn coin(index: u8, amount: u64) -> Coin {
+        Coin {
+            amount,
+            utxo_id: UtxoId::new(Bytes32::from([index; 32]), 0),
+            ..Default::default()
+        }
+    }
+
+    fn key() -> ResourceCacheKey {
+        (Bech32Address::default(), AssetId::default())
+    }
+
+    #[test]
+    fn coins_are_selected_until_spent() {
+        let mut cache = ResourceCache::default();
+        cache.insert(key(), 5, vec![coin(1, 10), coin(2, 30), coin(3, 20)]);
+
+        let selected = cache
+            .select(&key(), 5, 45, &HashSet::new())
+            .expect("should cover the amount");
+        assert_eq!(
+            selected,
+            vec![CoinType::Coin(coin(2, 30)), CoinType::Coin(coin(3, 20))]
+        );
+
+        cache.mark_spent([(key(), selected.iter().map(CoinType::id).collect())]);
+
+        assert_eq!(cache.select(&key(), 5, 45, &HashSet::new()), None);
+        assert_eq!(
+            cache.select(&key(), 5, 5, &HashSet::new()),
+            Some(vec![CoinType::Coin(coin(1, 10))])
+        );
+        let excluded = HashSet::from([CoinTypeId::UtxoId(coin(1, 10).utxo_id)]);
+        assert_eq!(cache.select(&key(), 5, 5, &excluded), None);
+
+        // the node still reports the spent coins until the transaction is committed
+        cache.insert(key(), 5, vec![coin(1, 10), coin(2, 30), coin(3, 20)]);
+        assert_eq!(cache.select(&key(), 5, 45, &HashSet::new()), None);
+
+        cache.invalidate(&Bech32Address::default());
+        assert_eq!(cache.select(&key(), 5, 5, &HashSet::new()), None);
+    }
+
+    #[test]
+    fn coins_are_refetched_on_a_new_block() {
+        let mut cache = ResourceCache::default();
+        cache.insert(key(), 5, vec![coin(1, 10)]);
+
+        assert!(cache.select(&key(), 5, 5, &HashSet::new()).is_some());
+        assert_eq!(cache.select(&key(), 6, 5, &HashSet::new()), None);
+
+        // a node lagging behind the one the coins were fetched from
+        assert!(cache.select(&key(), 4, 5, &HashSet::new()).is_some());
+    }
+}
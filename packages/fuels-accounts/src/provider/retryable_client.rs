@@ -1,4 +1,4 @@
-use std::{future::Future, io};
+use std::{future::Future, io, time::Duration};
 
 use fuel_core_client::client::{
     pagination::{PaginatedResult, PaginationRequest},
@@ -13,7 +13,10 @@ use fuel_tx::{Receipt, Transaction, TxId, UtxoId};
 use fuel_types::{Address, AssetId, BlockHeight, ContractId, Nonce};
 use fuels_core::types::errors::{error, Error, Result};
 
-use crate::provider::{retry_util, RetryConfig};
+use reqwest::Url;
+use serde_json::{json, Value};
+
+use crate::provider::{retry_util, ProviderConfig, RetryConfig};
 
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum RequestError {
@@ -32,8 +35,10 @@ impl From<RequestError> for Error {
 #[derive(Debug, Clone)]
 pub(crate) struct RetryableClient {
     client: FuelClient,
+    http_client: reqwest::Client,
     url: String,
     retry_config: RetryConfig,
+    config: ProviderConfig,
 }
 
 impl RetryableClient {
@@ -43,8 +48,10 @@ impl RetryableClient {
 
         Ok(Self {
             client,
+            http_client: reqwest::Client::new(),
             retry_config,
             url,
+            config: ProviderConfig::default(),
         })
     }
 
@@ -56,11 +63,72 @@ impl RetryableClient {
         self.retry_config = retry_config;
     }
 
+    pub(crate) fn config(&self) -> &ProviderConfig {
+        &self.config
+    }
+
+    pub(crate) fn set_config(&mut self, config: ProviderConfig) {
+        self.config = config;
+    }
+
     async fn our_retry<T, Fut>(&self, action: impl Fn() -> Fut) -> RequestResult<T>
     where
         Fut: Future<Output = io::Result<T>>,
     {
-        Ok(retry_util::retry(action, &self.retry_config, |result| result.is_err()).await?)
+        self.retry_within(self.config.request_timeout, action).await
+    }
+
+    /// Retries requests waiting for a transaction to be committed, which take as long as block
+    /// production does and so aren't subject to the request timeout.
+    async fn our_retry_until_committed<T, Fut>(&self, action: impl Fn() -> Fut) -> RequestResult<T>
+    where
+        Fut: Future<Output = io::Result<T>>,
+    {
+        self.retry_within(None, action).await
+    }
+
+    async fn retry_within<T, Fut>(
+        &self,
+        timeout: Option<Duration>,
+        action: impl Fn() -> Fut,
+    ) -> RequestResult<T>
+    where
+        Fut: Future<Output = io::Result<T>>,
+    {
+        let attempt = || with_request_timeout(timeout, action());
+
+        Ok(retry_util::retry(attempt, &self.retry_config, |result| result.is_err()).await?)
+    }
+
+    /// Sends `query` to the node's GraphQL endpoint as is and returns the whole response body.
+    pub async fn raw_graphql(&self, query: &str, variables: &Value) -> RequestResult<Value> {
+        let graphql_url = graphql_url(&self.url)?;
+        let body = json!({ "query": query, "variables": variables });
+
+        let max_response_size = self.config.max_response_size;
+
+        self.our_retry(|| async {
+            let response = self
+                .http_client
+                .post(graphql_url.clone())
+                .json(&body)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+            if let Some(size) = response.content_length() {
+                check_response_size(size, max_response_size)?;
+            }
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            check_response_size(bytes.len() as u64, max_response_size)?;
+
+            serde_json::from_slice(&bytes).map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+        })
+        .await
     }
 
     // DELEGATION START
@@ -77,7 +145,7 @@ impl RetryableClient {
     }
 
     pub async fn await_transaction_commit(&self, id: &TxId) -> RequestResult<TransactionStatus> {
-        self.our_retry(|| self.client.await_transaction_commit(id))
+        self.our_retry_until_committed(|| self.client.await_transaction_commit(id))
             .await
     }
 
@@ -85,7 +153,7 @@ impl RetryableClient {
         &self,
         tx: &Transaction,
     ) -> RequestResult<TransactionStatus> {
-        self.our_retry(|| self.client.submit_and_await_commit(tx))
+        self.our_retry_until_committed(|| self.client.submit_and_await_commit(tx))
             .await
     }
 
@@ -114,6 +182,15 @@ impl RetryableClient {
             .await
     }
 
+    pub async fn estimate_predicates(&self, tx: &Transaction) -> RequestResult<Transaction> {
+        self.our_retry(|| async {
+            let mut tx = tx.clone();
+            self.client.estimate_predicates(&mut tx).await?;
+            Ok(tx)
+        })
+        .await
+    }
+
     pub async fn coins(
         &self,
         owner: &Address,
@@ -202,6 +279,10 @@ impl RetryableClient {
         self.our_retry(|| self.client.block(id)).await
     }
 
+    pub async fn block_by_height(&self, height: u32) -> RequestResult<Option<Block>> {
+        self.our_retry(|| self.client.block_by_height(height)).await
+    }
+
     pub async fn blocks(
         &self,
         request: PaginationRequest<String>,
@@ -234,3 +315,79 @@ impl RetryableClient {
     }
     // DELEGATION END
 }
+
+/// Mirrors how `FuelClient` resolves the GraphQL endpoint of a node url.
+fn graphql_url(url: &str) -> io::Result<Url> {
+    let url = if url.starts_with("http") {
+        url.to_string()
+    } else {
+        format!("http://{url}")
+    };
+
+    let mut url = Url::parse(&url).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    url.set_path("/graphql");
+
+    Ok(url)
+}
+
+async fn with_request_timeout<T>(
+    timeout: Option<Duration>,
+    request: impl Future<Output = io::Result<T>>,
+) -> io::Result<T> {
+    let Some(timeout) = timeout else {
+        return request.await;
+    };
+
+    tokio::time::timeout(timeout, request)
+        .await
+        .unwrap_or_else(|_| {
+            Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("the node did not respond within {timeout:?}"),
+            ))
+        })
+}
+
+fn check_response_size(size: u64, max_response_size: Option<usize>) -> io::Result<()> {
+    match max_response_size {
+        Some(max) if size > max as u64 => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "the response of {size} bytes exceeds the maximum response size of {max} bytes"
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::pending;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_give_up_after_the_timeout() {
+        let timeout = Some(Duration::from_millis(10));
+
+        let err = with_request_timeout(timeout, pending::<io::Result<()>>())
+            .await
+            .expect_err("should time out");
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+
+        let answer = with_request_timeout(timeout, async { Ok(42) }).await;
+        assert_eq!(answer.ok(), Some(42));
+    }
+
+    #[test]
+    fn responses_over_the_maximum_size_are_rejected() {
+        assert!(check_response_size(100, None).is_ok());
+        assert!(check_response_size(100, Some(100)).is_ok());
+
+        let err = check_response_size(101, Some(100)).expect_err("should be too big");
+        assert_eq!(
+            err.to_string(),
+            "the response of 101 bytes exceeds the maximum response size of 100 bytes"
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        coin::Coin,
+        coin_type::CoinType,
+        errors::{error, Result},
+        input::Input,
+        transaction_builders::TransactionBuilder,
+        AssetId,
+    },
+};
+
+use crate::{provider::Provider, Account, ViewOnlyAccount};
+
+/// An account that is only able to simulate transactions.
+///
+/// It owns no funds and cannot sign. When asked for inputs it fabricates unsigned coins so that
+/// transactions can be built and dry-run without UTXO or signature validation. It does not
+/// implement [`SubmittingAccount`](crate::SubmittingAccount), so calling `call` or `submit` on
+/// its handlers, or transferring from it, fails to compile.
+#[derive(Debug, Clone)]
+pub struct ReadOnlyAccount {
+    address: Bech32Address,
+    provider: Provider,
+}
+
+impl ReadOnlyAccount {
+    pub fn new(provider: Provider) -> Self {
+        Self {
+            address: Bech32Address::default(),
+            provider: provider.without_dry_run_validation(),
+        }
+    }
+
+    /// Simulate as if the transactions were sent from `address`.
+    pub fn with_address(mut self, address: impl Into<Bech32Address>) -> Self {
+        self.address = address.into();
+        self
+    }
+}
+
+impl ViewOnlyAccount for ReadOnlyAccount {
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        Ok(&self.provider)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Account for ReadOnlyAccount {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        let coin = Coin {
+            amount,
+            asset_id,
+            owner: self.address.clone(),
+            ..Default::default()
+        };
+
+        Ok(vec![Input::resource_signed(CoinType::Coin(coin))])
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(PlaceholderSigner {
+            address: self.address.clone(),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for ReadOnlyAccount {
+    async fn sign(&self, _message: Message) -> Result<Signature> {
+        Err(error!(
+            Other,
+            "read-only account `{}` cannot sign, use a wallet instead", self.address
+        ))
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+/// Fills the witness slot of the fabricated coins with an empty signature. Only ever added to
+/// transactions that are dry-run without validation.
+struct PlaceholderSigner {
+    address: Bech32Address,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for PlaceholderSigner {
+    async fn sign(&self, _message: Message) -> Result<Signature> {
+        Ok(Signature::default())
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
@@ -0,0 +1,22 @@
+//! Signers for keys the VM can verify but that can't own coins directly.
+//!
+//! Coins are owned by the SHA-256 of a secp256k1 public key, checked by the node for every
+//! signed input. Other keys hold their funds in a predicate verifying the signature in the
+//! transaction's witnesses: `ecr1` recovers a secp256r1 public key and `ed19` verifies an
+//! ed25519 signature. The address of such an account is the root of that predicate, with the
+//! key of the signer set in its configurables, so every key gets its own address.
+//!
+//! The signers sign the id of the transaction, like `WalletUnlocked` does, and are added to
+//! transaction builders with `add_signer`. Their 64-byte signatures go into the witnesses in
+//! the order the signers were added, after any witness already in the builder. The predicate
+//! is told the index to read through its data.
+
+#[cfg(feature = "ed25519")]
+mod ed25519;
+#[cfg(feature = "secp256r1")]
+mod secp256r1;
+
+#[cfg(feature = "ed25519")]
+pub use ed25519::Ed25519Signer;
+#[cfg(feature = "secp256r1")]
+pub use secp256r1::Secp256r1Signer;
@@ -0,0 +1,93 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use ed25519_dalek::{Signer as _, SigningKey};
+use fuel_crypto::{Message, Signature};
+use fuel_types::Bytes32;
+use fuels_core::{
+    traits::Signer,
+    types::{bech32::Bech32Address, errors::Result},
+};
+use rand::{CryptoRng, Rng};
+
+/// Signs with an ed25519 key.
+///
+/// `ed19` can't recover keys, so the account's predicate holds the 32-byte public key itself,
+/// see [`Self::public_key`], and verifies the 64-byte signature against it. The message signed
+/// is the 32-byte id of the transaction.
+#[derive(Clone)]
+pub struct Ed25519Signer {
+    signing_key: SigningKey,
+    address: Bech32Address,
+}
+
+impl Ed25519Signer {
+    /// The signer's address is left empty: set the one of its predicate with
+    /// [`Self::with_address`].
+    pub fn new(signing_key: SigningKey) -> Self {
+        Self {
+            signing_key,
+            address: Bech32Address::default(),
+        }
+    }
+
+    pub fn random<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        Self::from_bytes(&rng.gen())
+    }
+
+    pub fn from_bytes(secret_key: &[u8; 32]) -> Self {
+        Self::new(SigningKey::from_bytes(secret_key))
+    }
+
+    /// Sets the address of the predicate holding the account's funds.
+    pub fn with_address(mut self, address: impl Into<Bech32Address>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    pub fn public_key(&self) -> Bytes32 {
+        self.signing_key.verifying_key().to_bytes().into()
+    }
+}
+
+impl fmt::Debug for Ed25519Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ed25519Signer")
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for Ed25519Signer {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let signature = self.signing_key.sign(message.as_ref());
+
+        Ok(Signature::from_bytes(signature.to_bytes()))
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_types::Bytes64;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn signatures_verify_against_the_public_key() -> Result<()> {
+        let signer = Ed25519Signer::from_bytes(&[7; 32]);
+        let message = Message::new("fuel");
+
+        let signature = signer.sign(message).await?;
+
+        fuel_crypto::ed25519::verify(&signer.public_key(), &Bytes64::from(*signature), &message)
+            .expect("the signature should be valid");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use fuel_crypto::{Hasher, Message, Signature};
+use fuel_types::{Bytes32, Bytes64};
+use fuels_core::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+    },
+};
+use p256::{ecdsa::SigningKey, elliptic_curve::sec1::ToEncodedPoint};
+use rand::{CryptoRng, Rng};
+
+/// Signs with a secp256r1 (P-256) key, the curve of passkeys and secure enclaves.
+///
+/// Signatures are 64 bytes: `r`, then `s` normalized to the lower half of the curve order with
+/// the recovery id in its top bit, which is what `ecr1` recovers the public key from. The
+/// account's predicate compares the SHA-256 of the recovered key, see
+/// [`Self::public_key_hash`], with the one in its configurables.
+#[derive(Clone)]
+pub struct Secp256r1Signer {
+    signing_key: SigningKey,
+    address: Bech32Address,
+}
+
+impl Secp256r1Signer {
+    /// The signer's address is left empty: set the one of its predicate with
+    /// [`Self::with_address`].
+    pub fn new(signing_key: SigningKey) -> Self {
+        Self {
+            signing_key,
+            address: Bech32Address::default(),
+        }
+    }
+
+    pub fn random<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        Self::new(SigningKey::random(rng))
+    }
+
+    pub fn from_bytes(secret_key: &[u8; 32]) -> Result<Self> {
+        let signing_key = SigningKey::from_bytes(secret_key.into())
+            .map_err(|e| error!(Other, "invalid secp256r1 secret key: {e}"))?;
+
+        Ok(Self::new(signing_key))
+    }
+
+    /// Sets the address of the predicate holding the account's funds.
+    pub fn with_address(mut self, address: impl Into<Bech32Address>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// The uncompressed public key without its SEC1 tag: `x` followed by `y`, as `ecr1` writes
+    /// it.
+    pub fn public_key(&self) -> Bytes64 {
+        let point = self.signing_key.verifying_key().to_encoded_point(false);
+        let coordinates: [u8; 64] = point.as_bytes()[1..]
+            .try_into()
+            .expect("an uncompressed point is a tag and two 32-byte coordinates");
+
+        coordinates.into()
+    }
+
+    /// The SHA-256 of [`Self::public_key`], as returned by `ec_recover_address_r1`.
+    pub fn public_key_hash(&self) -> Bytes32 {
+        Hasher::hash(*self.public_key())
+    }
+}
+
+impl fmt::Debug for Secp256r1Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Secp256r1Signer")
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for Secp256r1Signer {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let signature = fuel_crypto::secp256r1::sign_prehashed(&self.signing_key, &message)
+            .map_err(|e| error!(Other, "could not sign with the secp256r1 key: {e}"))?;
+
+        Ok(Signature::from_bytes(*signature))
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn signatures_recover_to_the_public_key() -> Result<()> {
+        let signer = Secp256r1Signer::from_bytes(&[7; 32])?;
+        let message = Message::new("fuel");
+
+        let signature = signer.sign(message).await?;
+        let recovered =
+            fuel_crypto::secp256r1::recover(&Bytes64::from(*signature), &message).unwrap();
+
+        assert_eq!(recovered, signer.public_key());
+        assert_eq!(signer.public_key_hash(), Hasher::hash(*recovered));
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::{fmt, ops, path::Path};
 
 use async_trait::async_trait;
 use elliptic_curve::rand_core;
+use fuel_core_client::client::pagination::{PageDirection, PaginationRequest};
 use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
 use fuels_core::{
     traits::Signer,
@@ -9,6 +10,7 @@ use fuels_core::{
         bech32::{Bech32Address, FUEL_BECH32_HRP},
         errors::{error, Result},
         input::Input,
+        transaction::Transaction,
         transaction_builders::TransactionBuilder,
         AssetId,
     },
@@ -16,7 +18,10 @@ use fuels_core::{
 use rand::{CryptoRng, Rng};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
+use crate::{
+    accounts_utils::try_provider_error, provider::Provider, Account, SubmittingAccount,
+    ViewOnlyAccount,
+};
 
 pub const DEFAULT_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
 
@@ -187,6 +192,14 @@ impl WalletUnlocked {
     pub fn address(&self) -> &Bech32Address {
         &self.address
     }
+
+    /// Signs `tx` for the chain of the wallet's provider and adds the signature to its
+    /// witnesses. Signatures are only valid on the chain they were made for.
+    pub async fn sign_transaction(&self, tx: &mut (impl Transaction + Send)) -> Result<Signature> {
+        let chain_id = self.try_provider()?.chain_id();
+
+        tx.sign_with(self, chain_id).await
+    }
 }
 
 impl ViewOnlyAccount for WalletUnlocked {
@@ -225,6 +238,8 @@ impl Account for WalletUnlocked {
     }
 }
 
+impl SubmittingAccount for WalletUnlocked {}
+
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl Signer for WalletUnlocked {
@@ -260,6 +275,61 @@ pub fn generate_mnemonic_phrase<R: Rng>(rng: &mut R, count: usize) -> Result<Str
     Ok(fuel_crypto::generate_mnemonic_phrase(rng, count)?)
 }
 
+/// Restores the accounts of a mnemonic phrase the way wallets do.
+///
+/// Wallets are derived along `m/44'/1179993420'/{index}'/0/0` for increasing account indexes. An
+/// account is in use if it holds funds or has transaction history. The scan stops after
+/// `gap_limit` consecutive unused accounts and the used ones are returned, connected to
+/// `provider`.
+pub async fn discover_accounts(
+    phrase: &str,
+    provider: &Provider,
+    gap_limit: usize,
+) -> Result<Vec<WalletUnlocked>> {
+    let mut accounts = vec![];
+    let mut unused_in_a_row = 0;
+    let mut index = 0;
+
+    while unused_in_a_row < gap_limit {
+        let path = format!("{DEFAULT_DERIVATION_PATH_PREFIX}/{index}'/0/0");
+        let wallet = WalletUnlocked::new_from_mnemonic_phrase_with_path(
+            phrase,
+            Some(provider.clone()),
+            &path,
+        )?;
+
+        if is_account_used(provider, wallet.address()).await? {
+            accounts.push(wallet);
+            unused_in_a_row = 0;
+        } else {
+            unused_in_a_row += 1;
+        }
+
+        index += 1;
+    }
+
+    Ok(accounts)
+}
+
+async fn is_account_used(provider: &Provider, address: &Bech32Address) -> Result<bool> {
+    if !provider.get_balances(address).await?.is_empty() {
+        return Ok(true);
+    }
+
+    let history = provider
+        .get_transactions_by_owner(
+            address,
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await?;
+
+    Ok(!history.results.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use tempfile::tempdir;
@@ -357,3 +427,105 @@ mod tests {
         Ok(())
     }
 }
+
+/// Vectors shared with the other Fuel SDKs (e.g. fuels-ts). They were computed with an
+/// implementation independent of this crate, straight from the BIP-39, BIP-32, RFC 6979 and
+/// Web3 Secret Storage specs, so a mismatch here means we diverged from the standards.
+#[cfg(test)]
+mod interop {
+    use std::str::FromStr;
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    const PHRASE: &str =
+        "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+
+    #[test]
+    fn mnemonic_derivation_with_fuel_derivation_path() -> Result<()> {
+        let vectors = [
+            (
+                "m/44'/1179993420'/0'/0/0",
+                "8feb0111eb79bba37ca4f983da8ad2edc1d6aa2e94cec92afb1cc5a7fd3a1aba",
+                "f18b6446deb8135544ba60333e5b7522685cd2cf64aa4e4c75df725149850b65",
+                "fuel17x9kg3k7hqf42396vqenukm4yf59e5k0vj4yunr4mae9zjv9pdjszy098t",
+            ),
+            (
+                "m/44'/1179993420'/1'/0/0",
+                "1dd1437cfa8c5f5b9cb99db566a84caa5d190e826fb07e6ef161c1a35bf20f2f",
+                "5c25e1f4ca337c66338b17d46bd6ce665fe8125451c3fcb6690ad76c267560c7",
+                "fuel1tsj7rax2xd7xvvutzl2xh4kwve07syj528plednfpttkcfn4vrrsn3gu76",
+            ),
+        ];
+
+        for (path, private_key, plain_address, address) in vectors {
+            let wallet = WalletUnlocked::new_from_mnemonic_phrase_with_path(PHRASE, None, path)?;
+
+            let private_key = SecretKey::from_str(private_key).expect("valid private key");
+            assert_eq!(wallet.private_key, private_key, "for {path}");
+            assert_eq!(
+                wallet.address().hash().to_string(),
+                plain_address,
+                "for {path}"
+            );
+            assert_eq!(wallet.address().to_string(), address, "for {path}");
+        }
+
+        // the default path is the first account of the Fuel derivation path
+        let wallet = WalletUnlocked::new_from_mnemonic_phrase(PHRASE, None)?;
+        assert_eq!(
+            wallet.address().to_string(),
+            "fuel17x9kg3k7hqf42396vqenukm4yf59e5k0vj4yunr4mae9zjv9pdjszy098t"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signatures_are_deterministic_and_compact() -> Result<()> {
+        let wallet = WalletUnlocked::new_from_mnemonic_phrase(PHRASE, None)?;
+        let message = Message::new("fuels interop".as_bytes());
+
+        let signature = wallet.sign(message).await?;
+
+        // 64 bytes: `r` followed by the low `s`, with the recovery id in the top bit of `s`
+        let expected = Signature::from_str("4d5ceb6c40a1882a1ebed28b49ac0a8fe69e63581f15e606efe931a4841b5f523e707b23c1c7350d2cff538a91f53c985562e5f2336deb7c427618a2bb2f473b")?;
+        assert_eq!(signature, expected);
+        assert_eq!(signature.recover(&message)?.hash(), wallet.address().hash());
+
+        Ok(())
+    }
+
+    #[test]
+    fn loads_web3_secret_storage_keystore() -> Result<()> {
+        let dir = tempdir()?;
+        let keystore = r#"{
+            "crypto": {
+                "cipher": "aes-128-ctr",
+                "cipherparams": { "iv": "101112131415161718191a1b1c1d1e1f" },
+                "ciphertext": "b242782cb1fa5957463055859ead8a8d3024027c539b177d0a16c222144d197e",
+                "kdf": "scrypt",
+                "kdfparams": {
+                    "dklen": 32,
+                    "n": 8192,
+                    "p": 1,
+                    "r": 8,
+                    "salt": "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+                },
+                "mac": "2df07565919f35b667805d2a4038ebe08f71d181816d805abf88fc0475ebbbe9"
+            },
+            "id": "0d7d5b8a-2d2f-4d4e-9a55-5c7a1e1f4b11",
+            "version": 3
+        }"#;
+        let path = dir.path().join("0d7d5b8a-2d2f-4d4e-9a55-5c7a1e1f4b11");
+        std::fs::write(&path, keystore)?;
+
+        let wallet = WalletUnlocked::load_keystore(&path, "interop password", None)?;
+
+        let expected = WalletUnlocked::new_from_mnemonic_phrase(PHRASE, None)?;
+        assert_eq!(wallet.address(), expected.address());
+
+        Ok(())
+    }
+}
@@ -11,7 +11,11 @@ use regex::Regex;
 use crate::{
     error::Result,
     program_bindings::{
-        abigen::{abigen_target::ParsedAbigenTarget, bindings::generate_bindings},
+        abigen::{
+            abigen_target::ParsedAbigenTarget, bindings::generate_bindings,
+            conversions::generate_conversions, error_types::generate_error_impls,
+            interfaces::generate_interfaces,
+        },
         custom_types::generate_types,
         generated_code::GeneratedCode,
     },
@@ -21,7 +25,12 @@ use crate::{
 mod abigen_target;
 mod bindings;
 mod configurables;
+mod conversions;
+mod error_types;
+mod filters;
+mod interfaces;
 mod logs;
+mod selectors;
 
 pub struct Abigen;
 
@@ -75,11 +84,19 @@ impl Abigen {
         let custom_types = Self::filter_custom_types(&parsed_targets);
         let shared_types = Self::filter_shared_types(custom_types);
 
+        let conversions = generate_conversions(&parsed_targets, no_std)?;
+        let error_impls = generate_error_impls(&parsed_targets, &shared_types, no_std)?;
+        let interfaces = generate_interfaces(&parsed_targets, no_std)?;
         let bindings = Self::generate_all_bindings(parsed_targets, no_std, &shared_types)?;
         let shared_types = Self::generate_shared_types(shared_types, no_std)?;
 
         let mod_name = ident("abigen_bindings");
-        Ok(shared_types.merge(bindings).wrap_in_mod(mod_name))
+        Ok(shared_types
+            .merge(bindings)
+            .merge(conversions)
+            .merge(error_impls)
+            .merge(interfaces)
+            .wrap_in_mod(mod_name))
     }
 
     fn generate_all_bindings(
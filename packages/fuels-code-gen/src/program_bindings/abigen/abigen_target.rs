@@ -1,11 +1,12 @@
-use std::{convert::TryFrom, path::PathBuf, str::FromStr};
+use std::{collections::HashMap, convert::TryFrom, path::PathBuf, str::FromStr};
 
-use fuel_abi_types::abi::full_program::FullProgramABI;
+use fuel_abi_types::abi::{full_program::FullProgramABI, program::ProgramABI};
 use proc_macro2::Ident;
 
 use crate::{
     error,
     error::{Error, Result},
+    program_bindings::abigen::filters::filter_abi,
     utils::Source,
 };
 
@@ -14,27 +15,66 @@ pub struct AbigenTarget {
     pub name: String,
     pub abi: String,
     pub program_type: ProgramType,
+    /// Name of another target to generate `From` conversions with, between the types that are
+    /// structurally compatible in both.
+    pub convertible_with: Option<String>,
+    /// Glob patterns of the functions and types to generate bindings for. All of them if empty.
+    pub include: Vec<String>,
+    /// Glob patterns of the functions and types not to generate bindings for.
+    pub exclude: Vec<String>,
+    /// Name of a trait to generate for the functions this contract has in common with the
+    /// other contracts naming the same interface, implemented by all their bindings.
+    pub interface: Option<String>,
+    /// Whether to generate the `{name}Api` trait and `{name}Mock` of a contract, for services
+    /// to depend on and test against without a node.
+    pub mock: bool,
+    /// Whether to implement `Display` and `std::error::Error` for the error enums returned by
+    /// the functions in a `Result`. Off by default since it conflicts with the impls users may
+    /// have written themselves.
+    pub impl_errors: bool,
 }
 
 pub(crate) struct Abi {
     pub(crate) path: Option<PathBuf>,
     pub(crate) abi: FullProgramABI,
+    /// The whole JSON ABI, minified, before any functions or types are filtered out.
+    #[cfg_attr(not(feature = "embed-abi"), allow(dead_code))]
+    pub(crate) json: String,
 }
 
 pub(crate) struct ParsedAbigenTarget {
     pub name: String,
     pub source: Abi,
     pub program_type: ProgramType,
+    pub convertible_with: Option<String>,
+    pub interface: Option<String>,
+    pub mock: bool,
+    pub impl_errors: bool,
 }
 
 impl TryFrom<AbigenTarget> for ParsedAbigenTarget {
     type Error = Error;
 
     fn try_from(value: AbigenTarget) -> Result<Self> {
+        if value.mock && value.program_type != ProgramType::Contract {
+            return Err(error!(
+                "`{}` cannot be mocked: only contracts can be mocked",
+                value.name
+            ));
+        }
+
+        let mut source = parse_program_abi(&value.abi)?;
+        filter_abi(&mut source.abi, &value.include, &value.exclude)
+            .map_err(|e| error!("`{}`: {e}", value.name))?;
+
         Ok(Self {
             name: value.name,
-            source: parse_program_abi(&value.abi)?,
+            source,
             program_type: value.program_type,
+            convertible_with: value.convertible_with,
+            interface: value.interface,
+            mock: value.mock,
+            impl_errors: value.impl_errors,
         })
     }
 }
@@ -43,9 +83,29 @@ fn parse_program_abi(abi_source: &str) -> Result<Abi> {
     let source = Source::parse(abi_source).expect("failed to parse JSON ABI");
 
     let json_abi_str = source.get().expect("failed to parse JSON ABI from string");
-    let abi = FullProgramABI::from_json_abi(&json_abi_str)?;
+    let abi: ProgramABI = serde_json::from_str(&json_abi_str)?;
+    check_unique_type_ids(&abi)?;
+    let json = serde_json::to_string(&abi)?;
+    let abi = FullProgramABI::from_counterpart(&abi)?;
     let path = source.path();
-    Ok(Abi { path, abi })
+    Ok(Abi { path, abi, json })
+}
+
+/// Types refer to each other by id, a repeated id would silently replace one of the types with
+/// the other one.
+fn check_unique_type_ids(abi: &ProgramABI) -> Result<()> {
+    let mut declared: HashMap<usize, &str> = HashMap::new();
+
+    for decl in &abi.types {
+        if let Some(previous) = declared.insert(decl.type_id, &decl.type_field) {
+            return Err(error!(
+                "the JSON ABI declares the type id {} more than once: as `{previous}` and as `{}`",
+                decl.type_id, decl.type_field
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -85,3 +145,62 @@ impl TryFrom<Ident> for ProgramType {
             .map_err(|e| Self::Error::new(ident.span(), e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_type_ids_are_reported() {
+        let abi = r#"{
+            "types": [
+                { "typeId": 0, "type": "u64", "components": null, "typeParameters": null },
+                { "typeId": 1, "type": "bool", "components": null, "typeParameters": null },
+                { "typeId": 0, "type": "b256", "components": null, "typeParameters": null }
+            ],
+            "functions": [],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+
+        let err = parse_program_abi(abi)
+            .map(|_| ())
+            .expect_err("should have detected the duplicate");
+
+        assert_eq!(
+            err.to_string(),
+            "the JSON ABI declares the type id 0 more than once: as `u64` and as `b256`"
+        );
+    }
+
+    #[test]
+    fn only_contracts_can_be_mocked() {
+        let abi = r#"{
+            "types": [{ "typeId": 0, "type": "()", "components": [], "typeParameters": null }],
+            "functions": [],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+
+        let err = ParsedAbigenTarget::try_from(AbigenTarget {
+            name: "MyScript".to_string(),
+            abi: abi.to_string(),
+            program_type: ProgramType::Script,
+            convertible_with: None,
+            include: vec![],
+            exclude: vec![],
+            interface: None,
+            mock: true,
+            impl_errors: false,
+        })
+        .map(|_| ())
+        .expect_err("should have rejected the script");
+
+        assert_eq!(
+            err.to_string(),
+            "`MyScript` cannot be mocked: only contracts can be mocked"
+        );
+    }
+}
@@ -14,19 +14,27 @@ use crate::{
 };
 
 mod contract;
+mod embedded_abi;
 mod function_generator;
 mod predicate;
 mod script;
 mod utils;
 
 pub(crate) fn generate_bindings(target: ParsedAbigenTarget, no_std: bool) -> Result<GeneratedCode> {
-    let bindings_generator = match target.program_type {
-        ProgramType::Script => script_bindings,
-        ProgramType::Contract => contract_bindings,
-        ProgramType::Predicate => predicate_bindings,
-    };
-
     let name = ident(&target.name);
     let abi = target.source.abi;
-    bindings_generator(&name, abi, no_std)
+    let bindings = match target.program_type {
+        ProgramType::Script => script_bindings(&name, abi, no_std)?,
+        ProgramType::Contract => contract_bindings(&name, abi, target.mock, no_std)?,
+        ProgramType::Predicate => predicate_bindings(&name, abi, no_std)?,
+    };
+
+    // Predicates have no type to hang the ABI on and `no_std` users can't decompress it.
+    #[cfg(feature = "embed-abi")]
+    if !no_std && target.program_type != ProgramType::Predicate {
+        let code = embedded_abi::embedded_abi(&name, &target.source.json)?;
+        return Ok(bindings.merge(GeneratedCode::new(code, Default::default(), no_std)));
+    }
+
+    Ok(bindings)
 }
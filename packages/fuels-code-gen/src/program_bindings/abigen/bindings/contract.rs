@@ -1,7 +1,7 @@
 use fuel_abi_types::abi::full_program::{FullABIFunction, FullProgramABI};
 use itertools::Itertools;
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, TokenStreamExt};
+use quote::{quote, ToTokens, TokenStreamExt};
 
 use crate::{
     error::Result,
@@ -9,22 +9,27 @@ use crate::{
         abigen::{
             bindings::function_generator::FunctionGenerator,
             configurables::generate_code_for_configurable_constants,
-            logs::log_formatters_instantiation_code,
+            logs::log_formatters_instantiation_code, selectors::check_selector_collisions,
         },
         generated_code::GeneratedCode,
+        resolved_type::TypeResolver,
+        utils::Components,
     },
-    utils::{ident, TypePath},
+    utils::{ident, safe_ident, TypePath},
 };
 
 pub(crate) fn contract_bindings(
     name: &Ident,
     abi: FullProgramABI,
+    mock: bool,
     no_std: bool,
 ) -> Result<GeneratedCode> {
     if no_std {
         return Ok(GeneratedCode::default());
     }
 
+    check_selector_collisions(&abi.functions)?;
+
     let log_formatters =
         log_formatters_instantiation_code(quote! {contract_id.clone().into()}, &abi.logged_types);
 
@@ -36,6 +41,24 @@ pub(crate) fn contract_bindings(
     let constant_configuration_code =
         generate_code_for_configurable_constants(&configuration_struct_name, &abi.configurables)?;
 
+    let api_name = ident(&format!("{name}Api"));
+    let mock_name = ident(&format!("{name}Mock"));
+    let api_code = if mock {
+        generate_api_and_mock(name, &api_name, &mock_name, &abi.functions)?
+    } else {
+        TokenStream::default()
+    };
+
+    let calls_name = ident(&format!("{name}Calls"));
+    let calls_code = generate_calls(&calls_name, &abi.functions)?;
+
+    let src3_code = generate_src3_impl(name, &abi.functions);
+
+    let id_name = ident(&format!("{name}Id"));
+    let id_code = generate_contract_id(name, &id_name);
+    let untyped_new_note =
+        format!("`new` takes a `{id_name}`, create one with `{id_name}::new(contract_id)`");
+
     let code = quote! {
         #[derive(Debug, Clone)]
         pub struct #name<T: ::fuels::accounts::Account> {
@@ -43,38 +66,89 @@ pub(crate) fn contract_bindings(
             account: T,
             log_decoder: ::fuels::core::codec::LogDecoder,
             encoder_config: ::fuels::core::codec::EncoderConfig,
+            external_contracts: ::std::vec::Vec<::fuels::types::bech32::Bech32ContractId>,
+            simulation_cache: ::core::option::Option<::fuels::programs::simulation_cache::SimulationCache>,
         }
 
         impl<T: ::fuels::accounts::Account> #name<T>
         {
             pub fn new(
-                contract_id: impl ::core::convert::Into<::fuels::types::bech32::Bech32ContractId>,
+                contract_id: #id_name,
                 account: T,
             ) -> Self {
                 let contract_id: ::fuels::types::bech32::Bech32ContractId = contract_id.into();
                 let log_decoder = ::fuels::core::codec::LogDecoder::new(#log_formatters);
                 let encoder_config = ::fuels::core::codec::EncoderConfig::default();
-                Self { contract_id, account, log_decoder, encoder_config }
+                Self {
+                    contract_id,
+                    account,
+                    log_decoder,
+                    encoder_config,
+                    external_contracts: ::std::vec::Vec::new(),
+                    simulation_cache: ::core::option::Option::None,
+                }
+            }
+
+            /// Creates the bindings from a plain contract id, as `new` did before taking typed
+            /// ids.
+            #[deprecated(since = "0.55.0", note = #untyped_new_note)]
+            pub fn from_contract_id(
+                contract_id: impl ::core::convert::Into<::fuels::types::bech32::Bech32ContractId>,
+                account: T,
+            ) -> Self {
+                Self::new(#id_name::new(contract_id), account)
             }
 
             pub fn contract_id(&self) -> &::fuels::types::bech32::Bech32ContractId {
                 &self.contract_id
             }
 
+            pub fn typed_contract_id(&self) -> #id_name {
+                #id_name::new(self.contract_id.clone())
+            }
+
             pub fn account(&self) -> T {
                 self.account.clone()
             }
 
-            pub fn with_account<U: ::fuels::accounts::Account>(self, account: U)
+            /// A copy of this instance sending its calls from `account`. The instance itself
+            /// stays connected to its current account. The copy starts without a simulation
+            /// cache, as results may depend on the caller.
+            pub fn with_account<U: ::fuels::accounts::Account>(&self, account: U)
             -> #name<U> {
                 #name {
-                        contract_id: self.contract_id,
+                        contract_id: self.contract_id.clone(),
                         account,
-                        log_decoder: self.log_decoder,
-                        encoder_config: self.encoder_config
+                        log_decoder: self.log_decoder.clone(),
+                        encoder_config: self.encoder_config.clone(),
+                        external_contracts: self.external_contracts.clone(),
+                        simulation_cache: ::core::option::Option::None,
                 }
             }
 
+            /// Use when `contract_id` belongs to an SRC-14 proxy forwarding calls to `target`, a
+            /// contract with this ABI. Every call will include `target` in its transaction.
+            pub fn with_proxy_target(
+                mut self,
+                target: impl ::core::convert::Into<::fuels::types::bech32::Bech32ContractId>,
+            ) -> Self {
+                self.external_contracts.push(target.into());
+                self
+            }
+
+            /// Full instance able to `call`, `submit` and `simulate`.
+            pub fn with_wallet(&self, wallet: ::fuels::accounts::wallet::WalletUnlocked)
+            -> #name<::fuels::accounts::wallet::WalletUnlocked> {
+                self.with_account(wallet)
+            }
+
+            /// Read-only instance. Its methods can only be `simulate`d, calling
+            /// `call` or `submit` on them fails to compile.
+            pub fn with_provider(&self, provider: ::fuels::accounts::provider::Provider)
+            -> #name<::fuels::accounts::read_only::ReadOnlyAccount> {
+                self.with_account(::fuels::accounts::read_only::ReadOnlyAccount::new(provider))
+            }
+
             pub fn with_encoder_config(mut self, encoder_config: ::fuels::core::codec::EncoderConfig)
             -> #name::<T> {
                 self.encoder_config = encoder_config;
@@ -82,6 +156,16 @@ pub(crate) fn contract_bindings(
                 self
             }
 
+            /// Makes `simulate` reuse the results of identical simulations of this instance's
+            /// methods until a new block is produced. Clones of the instance share the cache.
+            pub fn with_simulation_cache(
+                mut self,
+                cache: ::fuels::programs::simulation_cache::SimulationCache,
+            ) -> Self {
+                self.simulation_cache = ::core::option::Option::Some(cache);
+                self
+            }
+
             pub async fn get_balances(&self) -> ::fuels::types::errors::Result<::std::collections::HashMap<::fuels::types::AssetId, u64>> {
                 ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?
                                   .get_contract_balances(&self.contract_id)
@@ -95,6 +179,8 @@ pub(crate) fn contract_bindings(
                     account: self.account.clone(),
                     log_decoder: self.log_decoder.clone(),
                     encoder_config: self.encoder_config.clone(),
+                    external_contracts: self.external_contracts.clone(),
+                    simulation_cache: self.simulation_cache.clone(),
                 }
             }
         }
@@ -105,6 +191,8 @@ pub(crate) fn contract_bindings(
             account: T,
             log_decoder: ::fuels::core::codec::LogDecoder,
             encoder_config: ::fuels::core::codec::EncoderConfig,
+            external_contracts: ::std::vec::Vec<::fuels::types::bech32::Bech32ContractId>,
+            simulation_cache: ::core::option::Option<::fuels::programs::simulation_cache::SimulationCache>,
         }
 
         impl<T: ::fuels::accounts::Account> #methods_name<T> {
@@ -124,17 +212,92 @@ pub(crate) fn contract_bindings(
         }
 
         #constant_configuration_code
+
+        #api_code
+
+        #calls_code
+
+        #src3_code
+
+        #id_code
     };
 
     // All publicly available types generated above should be listed here.
-    let type_paths = [name, &methods_name, &configuration_struct_name]
-        .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
-        .into_iter()
-        .collect();
+    let mock_type_names = if mock {
+        vec![&api_name, &mock_name]
+    } else {
+        vec![]
+    };
+    let type_paths = [
+        name,
+        &methods_name,
+        &configuration_struct_name,
+        &calls_name,
+        &id_name,
+    ]
+    .into_iter()
+    .chain(mock_type_names)
+    .map(|type_name| TypePath::new(type_name).expect("We know the given types are not empty"))
+    .collect();
 
     Ok(GeneratedCode::new(code, type_paths, no_std))
 }
 
+/// A newtype around the id of a contract with this ABI, required by the binding's `new`. Plain
+/// ids only become typed through an explicit `new`, and ids of other contracts can't be passed in
+/// its place, as each binding has its own id type. Typed ids still convert into plain ones.
+fn generate_contract_id(name: &Ident, id_name: &Ident) -> TokenStream {
+    let doc = format!(
+        "The id of a `{name}` contract. Create bindings from it to be sure they aren't given the id of another contract."
+    );
+    let new_doc = format!("Asserts that `contract_id` is the id of a `{name}` contract.");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct #id_name(::fuels::types::bech32::Bech32ContractId);
+
+        impl #id_name {
+            #[doc = #new_doc]
+            pub fn new(
+                contract_id: impl ::core::convert::Into<::fuels::types::bech32::Bech32ContractId>,
+            ) -> Self {
+                Self(contract_id.into())
+            }
+        }
+
+        impl ::core::convert::From<#id_name> for ::fuels::types::bech32::Bech32ContractId {
+            fn from(contract_id: #id_name) -> Self {
+                contract_id.0
+            }
+        }
+
+        impl ::core::convert::From<&#id_name> for ::fuels::types::bech32::Bech32ContractId {
+            fn from(contract_id: &#id_name) -> Self {
+                contract_id.0.clone()
+            }
+        }
+
+        impl ::core::convert::From<#id_name> for ::fuels::types::ContractId {
+            fn from(contract_id: #id_name) -> Self {
+                (&contract_id.0).into()
+            }
+        }
+
+        impl ::core::convert::AsRef<::fuels::types::bech32::Bech32ContractId> for #id_name {
+            fn as_ref(&self) -> &::fuels::types::bech32::Bech32ContractId {
+                &self.0
+            }
+        }
+
+        impl ::std::fmt::Display for #id_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+    }
+}
+
 fn expand_functions(functions: &[FullABIFunction]) -> Result<TokenStream> {
     functions
         .iter()
@@ -177,12 +340,256 @@ pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
                 #is_payable,
                 self.encoder_config.clone(),
             )
+            .with_contract_ids(&self.external_contracts)
+            .with_simulation_cache(self.simulation_cache.clone())
     };
     generator.set_body(body);
 
     Ok(generator.generate())
 }
 
+/// Implements `Src3` if the contract has the SRC-3 `mint(Identity, b256, u64)` and
+/// `burn(b256, u64)` functions.
+fn generate_src3_impl(name: &Ident, functions: &[FullABIFunction]) -> TokenStream {
+    let has_fn = |fn_name: &str, input_types: &[&[&str]]| {
+        functions.iter().any(|function| {
+            let inputs = function.inputs();
+
+            function.name() == fn_name
+                && function.output().type_decl.type_field == "()"
+                && inputs.len() == input_types.len()
+                && inputs.iter().zip(input_types).all(|(input, accepted)| {
+                    accepted.contains(&input.type_decl.type_field.as_str())
+                })
+        })
+    };
+
+    let identity: &[&str] = &["enum Identity", "enum std::identity::Identity"];
+    let is_src3 =
+        has_fn("mint", &[identity, &["b256"], &["u64"]]) && has_fn("burn", &[&["b256"], &["u64"]]);
+
+    if !is_src3 {
+        return TokenStream::default();
+    }
+
+    let methods_name = ident(&format!("{name}Methods"));
+    quote! {
+        impl<T: ::fuels::accounts::Account> ::fuels::programs::src3::Src3<T> for #name<T> {
+            fn mint_call(
+                &self,
+                recipient: ::fuels::types::Identity,
+                sub_id: ::fuels::types::Bits256,
+                amount: ::core::primitive::u64,
+            ) -> ::fuels::programs::contract::ContractCallHandler<T, ()> {
+                #methods_name::mint(&self.methods(), recipient, sub_id, amount)
+            }
+
+            fn burn_call(
+                &self,
+                sub_id: ::fuels::types::Bits256,
+                amount: ::core::primitive::u64,
+            ) -> ::fuels::programs::contract::ContractCallHandler<T, ()> {
+                #methods_name::burn(&self.methods(), sub_id, amount)
+            }
+        }
+    }
+}
+
+/// Generates `calls_name`, which encodes calls to the contract's functions without needing an
+/// account or a node.
+fn generate_calls(calls_name: &Ident, functions: &[FullABIFunction]) -> Result<TokenStream> {
+    let mut selector_entries = vec![];
+    let mut typed_fns = vec![];
+
+    for function in functions {
+        let mut generator = FunctionGenerator::new(function)?;
+        let fn_name = function.name();
+        let fn_selector = generator.fn_selector();
+        let arg_tokens = generator.tokenized_args();
+
+        selector_entries.push(quote! { (#fn_name, #fn_selector) });
+
+        generator
+            .set_doc(format!(
+                "Encodes a call to the contract's `{fn_name}` function"
+            ))
+            .set_output_type(
+                quote! {::fuels::types::errors::Result<::fuels::programs::contract::EncodedFnCall>},
+            )
+            .set_body(quote! {
+                ::fuels::programs::contract::EncodedFnCall::new(
+                    #fn_selector,
+                    &#arg_tokens,
+                    self.encoder_config.clone(),
+                )
+            });
+        typed_fns.push(generator.generate());
+    }
+
+    Ok(quote! {
+        #[derive(Debug, Clone, Default)]
+        pub struct #calls_name {
+            encoder_config: ::fuels::core::codec::EncoderConfig,
+        }
+
+        impl #calls_name {
+            pub fn with_encoder_config(
+                mut self,
+                encoder_config: ::fuels::core::codec::EncoderConfig,
+            ) -> Self {
+                self.encoder_config = encoder_config;
+                self
+            }
+
+            /// Names and selectors of all the contract's functions.
+            pub fn selectors() -> ::std::vec::Vec<(&'static str, ::fuels::types::Selector)> {
+                ::std::vec![#(#selector_entries),*]
+            }
+
+            /// Encodes a call to `fn_name` from already tokenized arguments.
+            pub fn encode_fn_call(
+                &self,
+                fn_name: &str,
+                args: &[::fuels::types::Token],
+            ) -> ::fuels::types::errors::Result<::fuels::programs::contract::EncodedFnCall> {
+                let (_, selector) = Self::selectors()
+                    .into_iter()
+                    .find(|(name, _)| *name == fn_name)
+                    .ok_or_else(|| {
+                        ::fuels::types::errors::Error::Other(
+                            ::std::format!("no function named `{fn_name}` in the contract ABI")
+                        )
+                    })?;
+
+                ::fuels::programs::contract::EncodedFnCall::new(
+                    selector,
+                    args,
+                    self.encoder_config.clone(),
+                )
+            }
+
+            #(#typed_fns)*
+        }
+    })
+}
+
+/// Generates the object-safe `api_name` trait, implemented both by the contract bindings and
+/// by `mock_name`. Services can depend on the trait and use the mock in their unit tests
+/// instead of running a node.
+fn generate_api_and_mock(
+    name: &Ident,
+    api_name: &Ident,
+    mock_name: &Ident,
+    functions: &[FullABIFunction],
+) -> Result<TokenStream> {
+    let mut trait_fns = vec![];
+    let mut contract_fns = vec![];
+    let mut mock_fields = vec![];
+    let mut mock_setters = vec![];
+    let mut mock_fns = vec![];
+
+    for function in functions {
+        let fn_name = safe_ident(function.name());
+        let fn_name_str = function.name();
+        let setter_name = ident(&format!("with_{}", function.name()));
+        let doc = format!("Calls the contract's `{fn_name_str}` function");
+
+        let args = Components::new(function.inputs(), true, TypePath::default())?;
+        let arg_names = args.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        let arg_types = args
+            .iter()
+            .map(|(_, ty)| ty.to_token_stream())
+            .collect::<Vec<_>>();
+        let output = TypeResolver::default().resolve(function.output())?;
+
+        let signature = quote! {
+            fn #fn_name(&self, #(#arg_names: #arg_types),*) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::core::future::Future<Output = ::fuels::types::errors::Result<#output>>
+                        + ::core::marker::Send
+                        + '_,
+                >,
+            >
+        };
+        let mocked_fn_type = quote! {
+            dyn Fn(#(#arg_types),*) -> ::fuels::types::errors::Result<#output>
+                + ::core::marker::Send
+                + ::core::marker::Sync
+        };
+
+        trait_fns.push(quote! {
+            #[doc = #doc]
+            #signature;
+        });
+        contract_fns.push(quote! {
+            #signature {
+                let call_handler = self.methods().#fn_name(#(#arg_names),*);
+                ::std::boxed::Box::pin(async move {
+                    call_handler.call().await.map(|response| response.value)
+                })
+            }
+        });
+        mock_fields.push(quote! {
+            #fn_name: ::core::option::Option<::std::boxed::Box<#mocked_fn_type>>
+        });
+        mock_setters.push(quote! {
+            pub fn #setter_name(
+                mut self,
+                mocked_fn: impl Fn(#(#arg_types),*) -> ::fuels::types::errors::Result<#output>
+                    + ::core::marker::Send
+                    + ::core::marker::Sync
+                    + 'static,
+            ) -> Self {
+                self.#fn_name = ::core::option::Option::Some(::std::boxed::Box::new(mocked_fn));
+                self
+            }
+        });
+        mock_fns.push(quote! {
+            #signature {
+                let result = match &self.#fn_name {
+                    ::core::option::Option::Some(mocked_fn) => mocked_fn(#(#arg_names),*),
+                    ::core::option::Option::None => ::core::result::Result::Err(
+                        ::fuels::types::errors::Error::Other(
+                            ::std::format!("`{}` was not mocked", #fn_name_str)
+                        )
+                    ),
+                };
+                ::std::boxed::Box::pin(async move { result })
+            }
+        });
+    }
+
+    let mockall_attr = if cfg!(feature = "mockall") {
+        quote! {#[::mockall::automock]}
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #mockall_attr
+        pub trait #api_name: ::core::marker::Send + ::core::marker::Sync {
+            #(#trait_fns)*
+        }
+
+        impl<T: ::fuels::accounts::SubmittingAccount> #api_name for #name<T> {
+            #(#contract_fns)*
+        }
+
+        #[derive(Default)]
+        pub struct #mock_name {
+            #(#mock_fields,)*
+        }
+
+        impl #mock_name {
+            #(#mock_setters)*
+        }
+
+        impl #api_name for #mock_name {
+            #(#mock_fns)*
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -194,7 +601,11 @@ mod tests {
     use pretty_assertions::assert_eq;
     use quote::quote;
 
-    use crate::{error::Result, program_bindings::abigen::bindings::contract::expand_fn};
+    use crate::{
+        error::Result,
+        program_bindings::abigen::bindings::contract::{expand_fn, generate_src3_impl},
+        utils::ident,
+    };
 
     #[test]
     fn test_expand_fn_simple_abi() -> Result<()> {
@@ -375,6 +786,8 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_contract_ids(&self.external_contracts)
+                .with_simulation_cache(self.simulation_cache.clone())
             }
         };
 
@@ -431,6 +844,8 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_contract_ids(&self.external_contracts)
+                .with_simulation_cache(self.simulation_cache.clone())
             }
         };
 
@@ -543,6 +958,8 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_contract_ids(&self.external_contracts)
+                .with_simulation_cache(self.simulation_cache.clone())
             }
         };
 
@@ -550,4 +967,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn src3_impl_is_generated_only_for_src3_contracts() -> Result<()> {
+        let types = ["()", "enum Identity", "b256", "u64"]
+            .into_iter()
+            .enumerate()
+            .map(|(type_id, type_field)| {
+                let declaration = TypeDeclaration {
+                    type_id,
+                    type_field: type_field.to_string(),
+                    ..Default::default()
+                };
+                (type_id, declaration)
+            })
+            .collect::<HashMap<_, _>>();
+        let function = |name: &str, input_types: &[usize]| {
+            let inputs = input_types
+                .iter()
+                .map(|&type_id| TypeApplication {
+                    name: format!("arg_{type_id}"),
+                    type_id,
+                    ..Default::default()
+                })
+                .collect();
+            let abi_function = ABIFunction {
+                inputs,
+                name: name.to_string(),
+                ..Default::default()
+            };
+
+            FullABIFunction::from_counterpart(&abi_function, &types)
+        };
+        let name = ident("MyContract");
+
+        let mint = function("mint", &[1, 2, 3])?;
+        let burn = function("burn", &[2, 3])?;
+        let src3_code = generate_src3_impl(&name, &[mint, burn]);
+        assert!(src3_code
+            .to_string()
+            .contains("Src3 < T > for MyContract < T >"));
+
+        let mint = function("mint", &[1, 2, 3])?;
+        let burn_everything = function("burn", &[2])?;
+        let src3_code = generate_src3_impl(&name, &[mint, burn_everything]);
+        assert!(src3_code.is_empty());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,85 @@
+#![cfg(feature = "embed-abi")]
+
+use std::io::Write;
+
+use flate2::{write::DeflateEncoder, Compression};
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+use crate::error::{error, Result};
+
+/// Adds `abi()` and `program_abi()` to the bindings of a contract or script, so that log
+/// decoders, registries and the like can work with the exact ABI the bindings came from. The
+/// JSON is deflated to keep large ABIs from bloating the binary, and decompressed on first use.
+pub(crate) fn embedded_abi(name: &Ident, json_abi: &str) -> Result<TokenStream> {
+    let compressed = Literal::byte_string(&compress(json_abi)?);
+
+    Ok(quote! {
+        impl<T: ::fuels::accounts::Account> #name<T> {
+            /// The JSON ABI these bindings were generated from, including the functions and
+            /// types left out of them.
+            pub fn abi() -> &'static str {
+                static ABI: ::std::sync::OnceLock<::std::string::String> =
+                    ::std::sync::OnceLock::new();
+
+                ABI.get_or_init(|| {
+                    ::fuels::core::codec::decompress_abi(#compressed)
+                        .expect("abigen! embeds a valid compressed ABI")
+                })
+            }
+
+            /// [`Self::abi`], parsed.
+            pub fn program_abi() -> ::std::sync::Arc<::fuels::core::codec::ParsedAbi> {
+                static PROGRAM_ABI: ::std::sync::OnceLock<
+                    ::std::sync::Arc<::fuels::core::codec::ParsedAbi>,
+                > = ::std::sync::OnceLock::new();
+
+                PROGRAM_ABI
+                    .get_or_init(|| {
+                        ::fuels::core::codec::AbiCache::new()
+                            .get_or_insert_json(Self::abi())
+                            .expect("abigen! generated bindings for this ABI")
+                    })
+                    .clone()
+            }
+        }
+    })
+}
+
+fn compress(json_abi: &str) -> Result<Vec<u8>> {
+    let mut encoder = DeflateEncoder::new(vec![], Compression::best());
+    encoder
+        .write_all(json_abi.as_bytes())
+        .and_then(|_| encoder.finish())
+        .map_err(|e| error!("could not compress the JSON ABI: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::DeflateDecoder;
+
+    use super::*;
+
+    #[test]
+    fn embeds_the_compressed_abi() {
+        let json_abi = r#"{"types":[],"functions":[],"loggedTypes":[],"configurables":[]}"#;
+
+        let code = embedded_abi(
+            &Ident::new("MyContract", proc_macro2::Span::call_site()),
+            json_abi,
+        )
+        .unwrap()
+        .to_string();
+
+        assert!(code.contains("pub fn abi () -> & 'static str"));
+        assert!(code.contains("pub fn program_abi ()"));
+
+        let mut decompressed = String::new();
+        DeflateDecoder::new(compress(json_abi).unwrap().as_slice())
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, json_abi);
+    }
+}
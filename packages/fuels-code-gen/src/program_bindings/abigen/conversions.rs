@@ -0,0 +1,296 @@
+use std::collections::BTreeMap;
+
+use fuel_abi_types::abi::full_program::{FullTypeApplication, FullTypeDeclaration};
+use inflector::Inflector;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::{
+    error::{error, Result},
+    program_bindings::{
+        abigen::abigen_target::ParsedAbigenTarget, custom_types::should_skip_codegen,
+        generated_code::GeneratedCode,
+    },
+    utils::{ident, safe_ident},
+};
+
+/// Generates `From` conversions between the types of each target marked as `convertible_with`
+/// another target and the types of that other target, in whichever directions they exist.
+///
+/// Only non-generic structs and enums found at the same path in both programs are considered.
+/// A struct converts into another if both have the same field names and each field converts
+/// into its namesake. An enum converts into another if each of its variants converts into the
+/// variant of the same name, so variants can be added in newer versions. A type converts into
+/// another if they are identical, if it is an unsigned integer that fits into the other or if
+/// it is a custom type that converts as described above. Types identical in both programs are
+/// shared and need no conversion.
+pub(crate) fn generate_conversions(
+    targets: &[ParsedAbigenTarget],
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    let code = targets
+        .iter()
+        .filter_map(|target| Some((target, target.convertible_with.as_ref()?)))
+        .map(|(target, other_name)| {
+            let other = targets
+                .iter()
+                .find(|other| &other.name == other_name)
+                .ok_or_else(|| {
+                    error!(
+                        "`{}` cannot be convertible with `{other_name}`: no target with that name",
+                        target.name
+                    )
+                })?;
+
+            Ok([
+                conversions_between(other, target)?,
+                conversions_between(target, other)?,
+            ])
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten();
+
+    Ok(GeneratedCode::new(
+        quote! {#(#code)*},
+        Default::default(),
+        no_std,
+    ))
+}
+
+/// `From` implementations converting the types of `from` into the types of `to`.
+fn conversions_between(from: &ParsedAbigenTarget, to: &ParsedAbigenTarget) -> Result<TokenStream> {
+    let to_types = generated_types(to);
+
+    let conversions = generated_types(from)
+        .into_iter()
+        .filter_map(|(type_field, from_type)| {
+            let to_type = to_types.get(type_field)?;
+            (from_type != *to_type && converts_into(from_type, to_type))
+                .then_some((from_type, *to_type))
+        })
+        .map(|(from_type, to_type)| {
+            let from_path = type_path_in(&from.name, from_type)?;
+            let to_path = type_path_in(&to.name, to_type)?;
+
+            Ok(conversion(from_type, &from_path, &to_path))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {#(#conversions)*})
+}
+
+/// The non-generic custom types generated for `target`, keyed by their type field (e.g.
+/// `struct some_lib::SomeStruct`). Ordered to keep the generated code stable.
+fn generated_types(target: &ParsedAbigenTarget) -> BTreeMap<&str, &FullTypeDeclaration> {
+    target
+        .source
+        .abi
+        .types
+        .iter()
+        .filter(|ttype| !should_skip_codegen(ttype) && ttype.type_parameters.is_empty())
+        .map(|ttype| (ttype.type_field.as_str(), ttype))
+        .collect()
+}
+
+fn converts_into(from: &FullTypeDeclaration, to: &FullTypeDeclaration) -> bool {
+    let same_custom_type = from.type_field == to.type_field
+        && !should_skip_codegen(from)
+        && from.type_parameters.is_empty()
+        && to.type_parameters.is_empty();
+    if !same_custom_type {
+        return false;
+    }
+
+    let has_counterpart = |component: &FullTypeApplication, candidates: &[FullTypeApplication]| {
+        candidates.iter().any(|candidate| {
+            candidate.name == component.name && application_converts_into(component, candidate)
+        })
+    };
+
+    if from.is_struct_type() {
+        from.components.len() == to.components.len()
+            && from
+                .components
+                .iter()
+                .all(|field| has_counterpart(field, &to.components))
+    } else {
+        from.components
+            .iter()
+            .all(|variant| has_counterpart(variant, &to.components))
+    }
+}
+
+fn application_converts_into(from: &FullTypeApplication, to: &FullTypeApplication) -> bool {
+    let identical = from.type_decl == to.type_decl && from.type_arguments == to.type_arguments;
+
+    identical
+        || is_widening(&from.type_decl, &to.type_decl)
+        || converts_into(&from.type_decl, &to.type_decl)
+}
+
+fn is_widening(from: &FullTypeDeclaration, to: &FullTypeDeclaration) -> bool {
+    let rank = |ttype: &FullTypeDeclaration| {
+        ["u8", "u16", "u32", "u64"]
+            .iter()
+            .position(|int| *int == ttype.type_field)
+    };
+
+    matches!((rank(from), rank(to)), (Some(from), Some(to)) if from < to)
+}
+
+/// Path to the type as generated in the bindings of the target named `target_name`, relative to
+/// the `abigen_bindings` mod.
+fn type_path_in(target_name: &str, ttype: &FullTypeDeclaration) -> Result<TokenStream> {
+    let mod_name = ident(&format!("{}_mod", target_name.to_snake_case()));
+    let type_path = ttype.custom_type_path()?;
+
+    Ok(quote! {#mod_name::#type_path})
+}
+
+fn conversion(
+    from_type: &FullTypeDeclaration,
+    from: &TokenStream,
+    to: &TokenStream,
+) -> TokenStream {
+    let body = if from_type.is_struct_type() {
+        let fields = from_type
+            .components
+            .iter()
+            .map(|component| safe_ident(&component.name.to_snake_case()));
+
+        quote! {
+            Self {
+                #(#fields: ::core::convert::Into::into(value.#fields),)*
+            }
+        }
+    } else {
+        let arms = from_type.components.iter().map(|component| {
+            let variant: Ident = safe_ident(&component.name);
+            if component.type_decl.type_field == "()" {
+                quote! {#from::#variant => Self::#variant}
+            } else {
+                quote! {#from::#variant(inner) => Self::#variant(::core::convert::Into::into(inner))}
+            }
+        });
+
+        quote! {
+            match value {
+                #(#arms,)*
+            }
+        }
+    };
+
+    quote! {
+        impl ::core::convert::From<#from> for #to {
+            fn from(value: #from) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::program_bindings::abigen::{AbigenTarget, ProgramType};
+
+    // struct Balance { amount: <amount_type> }
+    // enum Event { Paused: (), Updated: Balance, <extra_variants> }
+    // struct Unrelated { <name>: u64 }
+    fn target(
+        name: &str,
+        amount_type: &str,
+        extra_variants: &str,
+        convertible_with: Option<&str>,
+    ) -> Result<ParsedAbigenTarget> {
+        let abi = format!(
+            r#"{{
+                "types": [
+                    {{ "typeId": 0, "type": "{amount_type}", "components": null, "typeParameters": null }},
+                    {{ "typeId": 1, "type": "struct Balance", "components": [
+                        {{ "name": "amount", "type": 0, "typeArguments": null }}
+                    ], "typeParameters": null }},
+                    {{ "typeId": 2, "type": "enum Event", "components": [
+                        {{ "name": "Paused", "type": 3, "typeArguments": null }},
+                        {{ "name": "Updated", "type": 1, "typeArguments": null }}
+                        {extra_variants}
+                    ], "typeParameters": null }},
+                    {{ "typeId": 3, "type": "()", "components": null, "typeParameters": null }},
+                    {{ "typeId": 4, "type": "struct Unrelated", "components": [
+                        {{ "name": "{name}", "type": 5, "typeArguments": null }}
+                    ], "typeParameters": null }},
+                    {{ "typeId": 5, "type": "u64", "components": null, "typeParameters": null }}
+                ],
+                "functions": [],
+                "loggedTypes": [],
+                "messagesTypes": [],
+                "configurables": []
+            }}"#
+        );
+
+        AbigenTarget {
+            name: name.to_string(),
+            abi,
+            program_type: ProgramType::Contract,
+            convertible_with: convertible_with.map(ToString::to_string),
+            include: vec![],
+            exclude: vec![],
+            interface: None,
+            mock: false,
+            impl_errors: false,
+        }
+        .try_into()
+    }
+
+    #[test]
+    fn generates_conversions_between_compatible_types() -> Result<()> {
+        let resumed = r#", { "name": "Resumed", "type": 3, "typeArguments": null }"#;
+        let targets = [
+            target("V1", "u32", "", None)?,
+            target("V2", "u64", resumed, Some("V1"))?,
+        ];
+
+        let code = generate_conversions(&targets, false)?.code();
+
+        // `amount` can only be widened and `Resumed` has no counterpart in `V1`, so the
+        // conversions only go one way. `Unrelated` has different field names in each version.
+        let expected = quote! {
+            impl ::core::convert::From<v1_mod::Event> for v2_mod::Event {
+                fn from(value: v1_mod::Event) -> Self {
+                    match value {
+                        v1_mod::Event::Paused => Self::Paused,
+                        v1_mod::Event::Updated(inner) => Self::Updated(::core::convert::Into::into(inner)),
+                    }
+                }
+            }
+            impl ::core::convert::From<v1_mod::Balance> for v2_mod::Balance {
+                fn from(value: v1_mod::Balance) -> Self {
+                    Self {
+                        amount: ::core::convert::Into::into(value.amount),
+                    }
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_targets_are_reported() -> Result<()> {
+        let targets = [target("V2", "u64", "", Some("V1"))?];
+
+        let err = generate_conversions(&targets, false).expect_err("should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "`V2` cannot be convertible with `V1`: no target with that name"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, HashSet};
+
+use fuel_abi_types::abi::full_program::FullTypeDeclaration;
+use inflector::Inflector;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    error::Result,
+    program_bindings::{
+        abigen::abigen_target::ParsedAbigenTarget, custom_types::should_skip_codegen,
+        generated_code::GeneratedCode,
+    },
+    utils::ident,
+};
+
+/// Implements `Display` and `std::error::Error` for the enums used as the error type of a
+/// `Result` returned by any of the functions of the `targets` built with `impl_errors`, so that
+/// contract-level failures can be handled like any other Rust error. Only non-generic enums
+/// generated by abigen qualify. Nothing is generated for `no_std` since `std::error::Error`
+/// isn't available there.
+pub(crate) fn generate_error_impls(
+    targets: &[ParsedAbigenTarget],
+    shared_types: &HashSet<FullTypeDeclaration>,
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    if no_std {
+        return Ok(GeneratedCode::default());
+    }
+
+    // keyed by path to generate a single impl for error types shared between targets
+    let mut error_types = BTreeMap::new();
+    for target in targets.iter().filter(|target| target.impl_errors) {
+        for error_type in returned_error_types(target) {
+            let type_path = error_type.custom_type_path()?;
+            let path = if shared_types.contains(error_type) {
+                quote! {shared_types::#type_path}
+            } else {
+                let mod_name = ident(&format!("{}_mod", target.name.to_snake_case()));
+                quote! {#mod_name::#type_path}
+            };
+
+            error_types.insert(path.to_string(), path);
+        }
+    }
+
+    let impls = error_types.into_values().map(|path| {
+        quote! {
+            impl ::core::fmt::Display for #path {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(self, f)
+                }
+            }
+
+            impl ::std::error::Error for #path {}
+        }
+    });
+
+    Ok(GeneratedCode::new(
+        quote! {#(#impls)*},
+        Default::default(),
+        no_std,
+    ))
+}
+
+fn returned_error_types(target: &ParsedAbigenTarget) -> impl Iterator<Item = &FullTypeDeclaration> {
+    target
+        .source
+        .abi
+        .functions
+        .iter()
+        .map(|function| function.output())
+        .filter(|output| {
+            ["enum std::result::Result", "enum Result"]
+                .contains(&output.type_decl.type_field.as_str())
+        })
+        .filter_map(|output| output.type_arguments.get(1))
+        .map(|error_type| &error_type.type_decl)
+        .filter(|error_type| {
+            error_type.is_enum_type()
+                && error_type.type_parameters.is_empty()
+                && !should_skip_codegen(error_type)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::program_bindings::abigen::{AbigenTarget, ProgramType};
+
+    // fn withdraw(amount: u64) -> Result<u64, TokenError>
+    fn target(name: &str, impl_errors: bool) -> Result<ParsedAbigenTarget> {
+        let abi = r#"{
+            "types": [
+                { "typeId": 0, "type": "u64", "components": null, "typeParameters": null },
+                { "typeId": 1, "type": "enum std::result::Result", "components": [
+                    { "name": "Ok", "type": 2, "typeArguments": null },
+                    { "name": "Err", "type": 3, "typeArguments": null }
+                ], "typeParameters": [2, 3] },
+                { "typeId": 2, "type": "generic T", "components": null, "typeParameters": null },
+                { "typeId": 3, "type": "generic E", "components": null, "typeParameters": null },
+                { "typeId": 4, "type": "enum token::TokenError", "components": [
+                    { "name": "InsufficientFunds", "type": 5, "typeArguments": null }
+                ], "typeParameters": null },
+                { "typeId": 5, "type": "()", "components": null, "typeParameters": null }
+            ],
+            "functions": [{
+                "name": "withdraw",
+                "inputs": [{ "name": "amount", "type": 0, "typeArguments": null }],
+                "output": { "name": "", "type": 1, "typeArguments": [
+                    { "name": "", "type": 0, "typeArguments": null },
+                    { "name": "", "type": 4, "typeArguments": null }
+                ]},
+                "attributes": null
+            }],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+
+        AbigenTarget {
+            name: name.to_string(),
+            abi: abi.to_string(),
+            program_type: ProgramType::Contract,
+            convertible_with: None,
+            include: vec![],
+            exclude: vec![],
+            interface: None,
+            mock: false,
+            impl_errors,
+        }
+        .try_into()
+    }
+
+    #[test]
+    fn implements_error_for_returned_error_enums() -> Result<()> {
+        let targets = [target("Token", true)?];
+
+        let code = generate_error_impls(&targets, &HashSet::default(), false)?.code();
+
+        let expected = quote! {
+            impl ::core::fmt::Display for token_mod::token::TokenError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Debug::fmt(self, f)
+                }
+            }
+
+            impl ::std::error::Error for token_mod::token::TokenError {}
+        };
+        assert_eq!(code.to_string(), expected.to_string());
+
+        let no_std_code = generate_error_impls(&targets, &HashSet::default(), true)?;
+        assert!(no_std_code.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_impls_are_opt_in() -> Result<()> {
+        let targets = [target("Token", false)?];
+
+        let code = generate_error_impls(&targets, &HashSet::default(), false)?;
+
+        assert!(code.is_empty());
+
+        Ok(())
+    }
+}
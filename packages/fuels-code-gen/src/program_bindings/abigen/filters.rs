@@ -0,0 +1,202 @@
+use std::{collections::HashSet, iter};
+
+use fuel_abi_types::abi::full_program::{FullProgramABI, FullTypeApplication, FullTypeDeclaration};
+
+use crate::error::{error, Result};
+
+/// Restricts the bindings of `abi` to the functions and types whose names match a pattern of
+/// `include`, if there are any, and none of `exclude`. Patterns are matched against function
+/// names and the names of structs and enums, without their path, `*` standing for any run of
+/// characters and `?` for a single one.
+///
+/// Types used by the remaining functions, logs, messages or configurables are kept even if
+/// excluded, the bindings wouldn't compile without them. Other types are only kept if they
+/// match the patterns themselves. Patterns matching nothing are reported, as they are most
+/// likely typos.
+pub(crate) fn filter_abi(
+    abi: &mut FullProgramABI,
+    include: &[String],
+    exclude: &[String],
+) -> Result<()> {
+    if include.is_empty() && exclude.is_empty() {
+        return Ok(());
+    }
+
+    let names = abi
+        .functions
+        .iter()
+        .map(|function| function.name().to_string())
+        .chain(abi.types.iter().filter_map(custom_type_name))
+        .collect::<Vec<_>>();
+    if let Some(pattern) = include
+        .iter()
+        .chain(exclude)
+        .find(|pattern| !names.iter().any(|name| glob_matches(pattern, name)))
+    {
+        return Err(error!(
+            "pattern `{pattern}` matches no function or type of the ABI"
+        ));
+    }
+
+    let is_selected = |name: &str| {
+        (include.is_empty() || include.iter().any(|pattern| glob_matches(pattern, name)))
+            && !exclude.iter().any(|pattern| glob_matches(pattern, name))
+    };
+
+    abi.functions
+        .retain(|function| is_selected(function.name()));
+
+    let mut used_types = HashSet::new();
+    abi.functions
+        .iter()
+        .flat_map(|function| {
+            function
+                .inputs()
+                .iter()
+                .chain(iter::once(function.output()))
+        })
+        .chain(abi.logged_types.iter().map(|logged| &logged.application))
+        .chain(
+            abi.messages_types
+                .iter()
+                .map(|message| &message.application),
+        )
+        .chain(
+            abi.configurables
+                .iter()
+                .map(|configurable| &configurable.application),
+        )
+        .for_each(|application| collect_used_types(application, &mut used_types));
+
+    abi.types.retain(|ttype| {
+        !ttype.is_custom_type()
+            || used_types.contains(&ttype.type_field)
+            || custom_type_name(ttype).is_some_and(|name| is_selected(&name))
+    });
+
+    Ok(())
+}
+
+fn collect_used_types(application: &FullTypeApplication, used_types: &mut HashSet<String>) {
+    let type_decl = &application.type_decl;
+    if used_types.insert(type_decl.type_field.clone()) {
+        for component in &type_decl.components {
+            collect_used_types(component, used_types);
+        }
+    }
+    // the same generic type can be applied to different arguments
+    for argument in &application.type_arguments {
+        collect_used_types(argument, used_types);
+    }
+}
+
+fn custom_type_name(ttype: &FullTypeDeclaration) -> Option<String> {
+    let type_path = ttype.custom_type_path().ok()?;
+
+    type_path.ident().map(ToString::to_string)
+}
+
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    fn matches(pattern: &[u8], name: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => name.is_empty(),
+            Some((b'*', rest)) => (0..=name.len()).any(|skipped| matches(rest, &name[skipped..])),
+            Some((b'?', rest)) => !name.is_empty() && matches(rest, &name[1..]),
+            Some((byte, rest)) => name.first() == Some(byte) && matches(rest, &name[1..]),
+        }
+    }
+
+    matches(pattern.as_bytes(), name.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"{
+        "types": [
+            { "typeId": 0, "type": "()", "components": null, "typeParameters": null },
+            { "typeId": 1, "type": "u64", "components": null, "typeParameters": null },
+            { "typeId": 2, "type": "struct Balance", "components": [
+                { "name": "amount", "type": 1, "typeArguments": null }
+            ], "typeParameters": null },
+            { "typeId": 3, "type": "struct AdminConfig", "components": [
+                { "name": "fee", "type": 1, "typeArguments": null }
+            ], "typeParameters": null },
+            { "typeId": 4, "type": "struct Unused", "components": [], "typeParameters": null }
+        ],
+        "functions": [
+            {
+                "inputs": [],
+                "name": "get_balance",
+                "output": { "name": "", "type": 2, "typeArguments": null },
+                "attributes": null
+            },
+            {
+                "inputs": [{ "name": "config", "type": 3, "typeArguments": null }],
+                "name": "admin_set_config",
+                "output": { "name": "", "type": 0, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    fn filtered(include: &[&str], exclude: &[&str]) -> Result<(Vec<String>, Vec<String>)> {
+        let mut abi = FullProgramABI::from_json_abi(ABI)?;
+        let to_strings = |patterns: &[&str]| patterns.iter().map(ToString::to_string).collect();
+
+        filter_abi(&mut abi, &to_strings(include), &to_strings(exclude))?;
+
+        let functions = abi
+            .functions
+            .iter()
+            .map(|function| function.name().to_string())
+            .collect();
+        let types = abi.types.iter().filter_map(custom_type_name).collect();
+
+        Ok((functions, types))
+    }
+
+    #[test]
+    fn only_selected_functions_and_the_types_they_use_are_kept() -> Result<()> {
+        let (functions, types) = filtered(&["get_*"], &[])?;
+        assert_eq!(functions, ["get_balance"]);
+        assert_eq!(types, ["Balance"]);
+
+        let (functions, types) = filtered(&[], &["admin_*"])?;
+        assert_eq!(functions, ["get_balance"]);
+        assert_eq!(types, ["Balance", "AdminConfig", "Unused"]);
+
+        // used types are kept even if excluded
+        let (_, types) = filtered(&["get_balance"], &["Balance"])?;
+        assert_eq!(types, ["Balance"]);
+
+        let (functions, types) = filtered(&[], &[])?;
+        assert_eq!(functions.len(), 2);
+        assert_eq!(types.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn patterns_matching_nothing_are_reported() {
+        let err = filtered(&["get_balanse"], &[]).expect_err("should match nothing");
+
+        assert_eq!(
+            err.to_string(),
+            "pattern `get_balanse` matches no function or type of the ABI"
+        );
+    }
+
+    #[test]
+    fn globs_match_whole_names() {
+        assert!(glob_matches("get_*", "get_balance"));
+        assert!(glob_matches("*_config", "admin_set_config"));
+        assert!(glob_matches("b?lance", "balance"));
+        assert!(!glob_matches("get", "get_balance"));
+        assert!(!glob_matches("?", ""));
+    }
+}
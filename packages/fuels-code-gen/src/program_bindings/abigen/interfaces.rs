@@ -0,0 +1,342 @@
+use std::{collections::BTreeMap, iter::zip};
+
+use fuel_abi_types::abi::full_program::{FullABIFunction, FullTypeApplication};
+use inflector::Inflector;
+use itertools::Itertools;
+use proc_macro2::Ident;
+use quote::{quote, ToTokens};
+
+use crate::{
+    error::{error, Result},
+    program_bindings::{
+        abigen::{abigen_target::ParsedAbigenTarget, ProgramType},
+        generated_code::GeneratedCode,
+        resolved_type::TypeResolver,
+        utils::Components,
+    },
+    utils::{ident, safe_ident, TypePath},
+};
+
+/// Generates a trait for every interface named by the `interface` of contract targets,
+/// implemented by the bindings of each of those contracts. The trait holds the functions the
+/// contracts have in common, i.e. with the same name, argument types and output type, so that
+/// contracts implementing the same Sway `abi` can be used through `Box<dyn Interface>`.
+///
+/// The trait is generated in its own mod, e.g. `src20_mod::Src20`, and refers to the custom
+/// types as generated for the first contract. Types used by several contracts are shared, so
+/// they are the same Rust types for all of them.
+pub(crate) fn generate_interfaces(
+    targets: &[ParsedAbigenTarget],
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    if no_std {
+        return Ok(GeneratedCode::default());
+    }
+
+    let mut implementors: BTreeMap<&str, Vec<&ParsedAbigenTarget>> = BTreeMap::new();
+    for target in targets {
+        let Some(interface) = &target.interface else {
+            continue;
+        };
+        if target.program_type != ProgramType::Contract {
+            return Err(error!(
+                "`{}` cannot implement `{interface}`: only contracts can implement interfaces",
+                target.name
+            ));
+        }
+        if targets.iter().any(|other| &other.name == interface) {
+            return Err(error!(
+                "the interface `{interface}` cannot have the name of a target"
+            ));
+        }
+        implementors.entry(interface).or_default().push(target);
+    }
+
+    implementors
+        .into_iter()
+        .map(|(interface, contracts)| generate_interface(interface, &contracts, no_std))
+        .fold_ok(GeneratedCode::default(), GeneratedCode::merge)
+}
+
+fn generate_interface(
+    interface: &str,
+    contracts: &[&ParsedAbigenTarget],
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    let (first, others) = contracts
+        .split_first()
+        .expect("an interface has at least one contract");
+
+    let functions = common_functions(first, others);
+    if functions.is_empty() {
+        let names = contracts
+            .iter()
+            .map(|contract| format!("`{}`", contract.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(error!(
+            "the contracts implementing `{interface}` ({names}) have no function in common"
+        ));
+    }
+
+    let interface_name = ident(interface);
+    let first_mod = contract_mod(&first.name);
+
+    let mut trait_fns = vec![];
+    let mut impl_fns = vec![];
+    for function in functions {
+        let fn_name = safe_ident(function.name());
+        let doc = format!("Calls the contract's `{}` function", function.name());
+
+        let args = Components::new(function.inputs(), true, TypePath::default())?;
+        let arg_names = args.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        let arg_types = args
+            .iter()
+            .map(|(_, ty)| ty.to_token_stream())
+            .collect::<Vec<_>>();
+        let output = TypeResolver::default().resolve(function.output())?;
+
+        let signature = quote! {
+            fn #fn_name(&self, #(#arg_names: #arg_types),*)
+                -> ::fuels::programs::contract::ContractCallHandler<T, #output>
+        };
+
+        trait_fns.push(quote! {
+            #[doc = #doc]
+            #signature;
+        });
+        impl_fns.push(quote! {
+            #signature {
+                self.methods().#fn_name(#(#arg_names),*)
+            }
+        });
+    }
+
+    let impls = contracts.iter().map(|contract| {
+        let contract_mod = contract_mod(&contract.name);
+        let contract_name = ident(&contract.name);
+
+        quote! {
+            impl<T: ::fuels::accounts::Account> #interface_name<T>
+                for super::#contract_mod::#contract_name<T>
+            {
+                #(#impl_fns)*
+            }
+        }
+    });
+
+    let doc = format!(
+        "The functions shared by the contracts implementing `{interface}`. Use \
+        `Box<dyn {interface}>` to call any of them."
+    );
+    let code = quote! {
+        // the custom types of the interface, as generated for its first contract
+        #[allow(unused_imports)]
+        use super::#first_mod::*;
+
+        #[doc = #doc]
+        pub trait #interface_name<
+            T: ::fuels::accounts::Account = ::fuels::accounts::wallet::WalletUnlocked,
+        >: ::core::marker::Send + ::core::marker::Sync
+        {
+            #(#trait_fns)*
+        }
+
+        #(#impls)*
+    };
+
+    let type_paths = [TypePath::new(&interface_name).expect("the interface name is not empty")]
+        .into_iter()
+        .collect();
+    let mod_name = ident(&format!("{}_mod", interface.to_snake_case()));
+
+    Ok(GeneratedCode::new(code, type_paths, no_std).wrap_in_mod(mod_name))
+}
+
+/// The functions of `first` that all of `others` have as well. Argument names may differ.
+fn common_functions<'a>(
+    first: &'a ParsedAbigenTarget,
+    others: &[&ParsedAbigenTarget],
+) -> Vec<&'a FullABIFunction> {
+    first
+        .source
+        .abi
+        .functions
+        .iter()
+        .filter(|function| {
+            others.iter().all(|other| {
+                other
+                    .source
+                    .abi
+                    .functions
+                    .iter()
+                    .any(|candidate| have_same_signature(function, candidate))
+            })
+        })
+        .collect()
+}
+
+fn have_same_signature(lhs: &FullABIFunction, rhs: &FullABIFunction) -> bool {
+    lhs.name() == rhs.name()
+        && lhs.inputs().len() == rhs.inputs().len()
+        && zip(lhs.inputs(), rhs.inputs()).all(|(lhs, rhs)| have_same_type(lhs, rhs))
+        && have_same_type(lhs.output(), rhs.output())
+}
+
+fn have_same_type(lhs: &FullTypeApplication, rhs: &FullTypeApplication) -> bool {
+    lhs.type_decl == rhs.type_decl && lhs.type_arguments == rhs.type_arguments
+}
+
+fn contract_mod(contract_name: &str) -> Ident {
+    ident(&format!("{}_mod", contract_name.to_snake_case()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::program_bindings::abigen::AbigenTarget;
+
+    fn target(name: &str, functions: &str) -> Result<ParsedAbigenTarget> {
+        let abi = format!(
+            r#"{{
+            "types": [
+                {{ "typeId": 0, "type": "u64", "components": null, "typeParameters": null }},
+                {{ "typeId": 1, "type": "bool", "components": null, "typeParameters": null }}
+            ],
+            "functions": [{functions}],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }}"#
+        );
+
+        AbigenTarget {
+            name: name.to_string(),
+            abi,
+            program_type: ProgramType::Contract,
+            convertible_with: None,
+            include: vec![],
+            exclude: vec![],
+            interface: Some("Counter".to_string()),
+            mock: false,
+            impl_errors: false,
+        }
+        .try_into()
+    }
+
+    const INCREMENT: &str = r#"{
+        "name": "increment", "attributes": null,
+        "inputs": [{ "name": "amount", "type": 0, "typeArguments": null }],
+        "output": { "name": "", "type": 0, "typeArguments": null }
+    }"#;
+    const INCREMENT_BY_OTHER_NAME: &str = r#"{
+        "name": "increment", "attributes": null,
+        "inputs": [{ "name": "value", "type": 0, "typeArguments": null }],
+        "output": { "name": "", "type": 0, "typeArguments": null }
+    }"#;
+    const IS_PAUSED: &str = r#"{
+        "name": "is_paused", "attributes": null,
+        "inputs": [],
+        "output": { "name": "", "type": 1, "typeArguments": null }
+    }"#;
+    const IS_PAUSED_AS_U64: &str = r#"{
+        "name": "is_paused", "attributes": null,
+        "inputs": [],
+        "output": { "name": "", "type": 0, "typeArguments": null }
+    }"#;
+
+    #[test]
+    fn only_functions_with_the_same_types_are_common() -> Result<()> {
+        let first = target("CounterA", &format!("{INCREMENT}, {IS_PAUSED}"))?;
+        let second = target(
+            "CounterB",
+            &format!("{INCREMENT_BY_OTHER_NAME}, {IS_PAUSED_AS_U64}"),
+        )?;
+
+        let common = common_functions(&first, &[&second])
+            .into_iter()
+            .map(|function| function.name().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(common, ["increment"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn interfaces_are_implemented_by_every_contract() -> Result<()> {
+        let targets = [
+            target("CounterA", INCREMENT)?,
+            target("CounterB", INCREMENT_BY_OTHER_NAME)?,
+        ];
+
+        let code = generate_interfaces(&targets, false)?.code();
+
+        let expected = quote! {
+            #[allow(clippy::too_many_arguments)]
+            #[no_implicit_prelude]
+            pub mod counter_mod {
+                use ::core::{
+                    clone::Clone,
+                    convert::{Into, TryFrom, From},
+                    iter::IntoIterator,
+                    iter::Iterator,
+                    marker::Sized,
+                    panic,
+                };
+
+                use ::std::{string::ToString, format, vec, default::Default};
+
+                #[allow(unused_imports)]
+                use super::counter_a_mod::*;
+
+                #[doc = "The functions shared by the contracts implementing `Counter`. Use `Box<dyn Counter>` to call any of them."]
+                pub trait Counter<
+                    T: ::fuels::accounts::Account = ::fuels::accounts::wallet::WalletUnlocked,
+                >: ::core::marker::Send + ::core::marker::Sync
+                {
+                    #[doc = "Calls the contract's `increment` function"]
+                    fn increment(&self, amount: ::core::primitive::u64)
+                        -> ::fuels::programs::contract::ContractCallHandler<T, ::core::primitive::u64>;
+                }
+
+                impl<T: ::fuels::accounts::Account> Counter<T> for super::counter_a_mod::CounterA<T> {
+                    fn increment(&self, amount: ::core::primitive::u64)
+                        -> ::fuels::programs::contract::ContractCallHandler<T, ::core::primitive::u64>
+                    {
+                        self.methods().increment(amount)
+                    }
+                }
+
+                impl<T: ::fuels::accounts::Account> Counter<T> for super::counter_b_mod::CounterB<T> {
+                    fn increment(&self, amount: ::core::primitive::u64)
+                        -> ::fuels::programs::contract::ContractCallHandler<T, ::core::primitive::u64>
+                    {
+                        self.methods().increment(amount)
+                    }
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn contracts_without_common_functions_are_reported() -> Result<()> {
+        let targets = [
+            target("CounterA", IS_PAUSED)?,
+            target("CounterB", IS_PAUSED_AS_U64)?,
+        ];
+
+        let err = generate_interfaces(&targets, false).expect_err("should have no common function");
+
+        assert_eq!(
+            err.to_string(),
+            "the contracts implementing `Counter` (`CounterA`, `CounterB`) have no function in common"
+        );
+
+        Ok(())
+    }
+}
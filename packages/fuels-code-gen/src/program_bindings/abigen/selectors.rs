@@ -0,0 +1,313 @@
+use std::{collections::HashMap, iter::zip};
+
+use fuel_abi_types::abi::full_program::{
+    FullABIFunction, FullTypeApplication, FullTypeDeclaration,
+};
+use sha2::{Digest, Sha256};
+
+use crate::error::{error, Result};
+
+/// Fails if two of the contract's functions have the same selector. Contracts dispatch calls
+/// on the selector alone, so calling either of them could execute the other one.
+///
+/// The selectors are computed the way `fuels_core::codec::resolve_fn_selector` computes them
+/// from the `param_type` of the generated types, which can't be called from here as
+/// `fuels-core` depends on this crate. A test checks that both agree on every test ABI.
+/// Functions using types not known to the SDK are skipped, their bindings fail to generate
+/// anyway.
+pub(crate) fn check_selector_collisions(functions: &[FullABIFunction]) -> Result<()> {
+    let mut seen: HashMap<[u8; 4], String> = HashMap::new();
+
+    for signature in functions.iter().filter_map(fn_signature) {
+        let selector = selector(&signature);
+
+        if let Some(other) = seen.insert(selector, signature.clone()) {
+            let selector = selector
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>();
+            return Err(error!(
+                "functions `{other}` and `{signature}` have the same selector 0x{selector}, \
+                calling either of them could execute the other one. Rename one of them"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn selector(signature: &str) -> [u8; 4] {
+    let hash = Sha256::digest(signature.as_bytes());
+
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn fn_signature(function: &FullABIFunction) -> Option<String> {
+    let args = function
+        .inputs()
+        .iter()
+        .map(|input| resolve(input, &[]).and_then(|ty| ty.signature()))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(format!("{}({})", function.name(), args.join(",")))
+}
+
+/// A type with its generic parameters replaced by the types they were given.
+#[derive(Debug, Clone)]
+struct ResolvedType<'a> {
+    type_field: &'a str,
+    components: Vec<ResolvedType<'a>>,
+    /// The type arguments of generic structs and enums.
+    generics: Vec<ResolvedType<'a>>,
+}
+
+fn resolve<'a>(
+    type_application: &'a FullTypeApplication,
+    parent_generics: &[(&'a FullTypeDeclaration, ResolvedType<'a>)],
+) -> Option<ResolvedType<'a>> {
+    let type_decl = &type_application.type_decl;
+
+    if type_decl.type_field.starts_with("generic ") {
+        return parent_generics
+            .iter()
+            .find(|(generic, _)| *generic == type_decl)
+            .map(|(_, resolved)| resolved.clone());
+    }
+
+    // Arrays, tuples, etc. don't declare generic parameters, their components use the ones of
+    // the enclosing type.
+    let (generic_lookup, generics) = if type_decl.type_parameters.is_empty() {
+        (parent_generics.to_vec(), vec![])
+    } else {
+        let generics = type_application
+            .type_arguments
+            .iter()
+            .map(|argument| resolve(argument, parent_generics))
+            .collect::<Option<Vec<_>>>()?;
+
+        (
+            zip(&type_decl.type_parameters, generics.clone()).collect(),
+            generics,
+        )
+    };
+
+    let components = type_decl
+        .components
+        .iter()
+        .map(|component| resolve(component, &generic_lookup))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(ResolvedType {
+        type_field: &type_decl.type_field,
+        components,
+        generics,
+    })
+}
+
+impl ResolvedType<'_> {
+    /// The type as it appears in a function signature, see
+    /// `fuels_core::codec::resolve_fn_signature`.
+    fn signature(&self) -> Option<String> {
+        let signature = match self.type_field {
+            "u8" | "u16" | "u32" | "u64" | "u256" | "bool" | "b256" | "()" | "str" => {
+                self.type_field.to_string()
+            }
+            "struct std::u128::U128" | "struct U128" => "s(u64,u64)".to_string(),
+            "struct std::u256::U256" | "struct U256" => "u256".to_string(),
+            "struct std::bytes::Bytes" | "struct Bytes" => "s(s(rawptr,u64),u64)".to_string(),
+            "struct std::string::String" | "struct String" => "s(s(s(rawptr,u64),u64))".to_string(),
+            "raw untyped slice" => "rawslice".to_string(),
+            "struct std::vec::Vec" | "struct Vec" => {
+                let [element] = self.generics.as_slice() else {
+                    return None;
+                };
+                let element = element.signature()?;
+
+                format!("s<{element}>(s<{element}>(rawptr,u64),u64)")
+            }
+            field if field.starts_with("str[") => field.to_string(),
+            field if field.starts_with("[_;") => {
+                let len = field.strip_prefix("[_;")?.strip_suffix(']')?.trim();
+                let [element] = self.components.as_slice() else {
+                    return None;
+                };
+
+                format!("a[{};{len}]", element.signature()?)
+            }
+            field if field.starts_with('(') => format!("({})", join(&self.components)?),
+            field if field.starts_with("struct ") => self.with_generics("s")?,
+            field if field.starts_with("enum ") => self.with_generics("e")?,
+            _ => return None,
+        };
+
+        Some(signature)
+    }
+
+    fn with_generics(&self, prefix: &str) -> Option<String> {
+        let generics = if self.generics.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", join(&self.generics)?)
+        };
+
+        Some(format!("{prefix}{generics}({})", join(&self.components)?))
+    }
+}
+
+fn join(types: &[ResolvedType]) -> Option<String> {
+    let signatures = types
+        .iter()
+        .map(ResolvedType::signature)
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(signatures.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use fuel_abi_types::abi::full_program::FullProgramABI;
+    use fuels_core::codec::AbiCache;
+
+    use super::*;
+
+    fn functions(functions: &str) -> Vec<FullABIFunction> {
+        let abi = format!(
+            r#"{{
+            "types": [
+                {{ "typeId": 0, "type": "u64", "components": null, "typeParameters": null }},
+                {{ "typeId": 1, "type": "generic T", "components": null, "typeParameters": null }},
+                {{ "typeId": 2, "type": "[_; 2]", "components": [{{ "name": "__array_element", "type": 1, "typeArguments": null }}], "typeParameters": null }},
+                {{ "typeId": 3, "type": "struct Wrapper", "components": [{{ "name": "inner", "type": 2, "typeArguments": null }}], "typeParameters": [1] }},
+                {{ "typeId": 4, "type": "enum Option", "components": [{{ "name": "None", "type": 5, "typeArguments": null }}, {{ "name": "Some", "type": 1, "typeArguments": null }}], "typeParameters": [1] }},
+                {{ "typeId": 5, "type": "()", "components": [], "typeParameters": null }}
+            ],
+            "functions": [{functions}],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }}"#
+        );
+        FullProgramABI::from_json_abi(&abi)
+            .expect("the ABI is valid")
+            .functions
+    }
+
+    fn function(name: &str, input_type: &str) -> String {
+        format!(
+            r#"{{
+            "name": "{name}", "attributes": null,
+            "inputs": [{input_type}],
+            "output": {{ "name": "", "type": 5, "typeArguments": null }}
+        }}"#
+        )
+    }
+
+    #[test]
+    fn signatures_match_the_sdk() {
+        let wrapped_option = r#"{ "name": "arg", "type": 3, "typeArguments": [
+            { "name": "", "type": 4, "typeArguments": [{ "name": "", "type": 0, "typeArguments": null }] }
+        ] }"#;
+
+        let functions = functions(&function("wrap", wrapped_option));
+
+        assert_eq!(
+            fn_signature(&functions[0]).expect("the types are known"),
+            "wrap(s<e<u64>((),u64)>(a[e<u64>((),u64);2]))"
+        );
+    }
+
+    #[test]
+    fn conforms_to_shared_selector_vectors() {
+        let vectors: serde_json::Value = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../test-vectors/fn_selectors.json"
+        )))
+        .expect("the vectors are valid JSON");
+        let functions = FullProgramABI::from_json_abi(&vectors["abi"].to_string())
+            .expect("the ABI is valid")
+            .functions;
+
+        for vector in vectors["selectors"]
+            .as_array()
+            .expect("selectors are a list")
+        {
+            let function = functions
+                .iter()
+                .find(|function| function.name() == vector["function"])
+                .expect("the function is in the ABI");
+            let signature = fn_signature(function).expect("the types are known");
+            let selector = selector(&signature)
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>();
+
+            assert_eq!(signature, vector["signature"]);
+            assert_eq!(selector, vector["selector"], "for {signature}");
+        }
+    }
+
+    fn test_abis(dir: &Path, abis: &mut Vec<PathBuf>) {
+        for entry in std::fs::read_dir(dir).expect("the directory is readable") {
+            let path = entry.expect("the entry is readable").path();
+            if path.is_dir() {
+                test_abis(&path, abis);
+            } else if path.to_string_lossy().ends_with("-abi.json") {
+                abis.push(path);
+            }
+        }
+    }
+
+    // The selectors are computed here again because `fuels-core` depends on this crate. The
+    // Sway test projects must be built beforehand, as for the tests of `fuels`.
+    #[test]
+    fn selectors_match_the_ones_of_fuels_core() {
+        let mut abis = vec![];
+        test_abis(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuels/tests"),
+            &mut abis,
+        );
+        assert!(!abis.is_empty(), "the Sway test projects weren't built");
+
+        for path in abis {
+            let json_abi = std::fs::read_to_string(&path).expect("the ABI is readable");
+            let full_abi = FullProgramABI::from_json_abi(&json_abi).expect("the ABI is valid");
+            let parsed_abi = AbiCache::new()
+                .get_or_insert_json(&json_abi)
+                .expect("the ABI is valid");
+
+            for function in &full_abi.functions {
+                let Ok(expected) = parsed_abi.function_info(function.name()) else {
+                    continue;
+                };
+                let signature = fn_signature(function).unwrap_or_else(|| {
+                    panic!("`{}` in {path:?} should have a signature", function.name())
+                });
+
+                assert_eq!(signature, expected.signature, "in {path:?}");
+                assert_eq!(selector(&signature), expected.selector[4..], "in {path:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn colliding_selectors_are_a_compile_error() {
+        // found by brute force, both hash to 0xb4572897
+        let amount = r#"{ "name": "amount", "type": 0, "typeArguments": null }"#;
+        let functions = functions(&format!(
+            "{}, {}",
+            function("transfer_120131", amount),
+            function("transfer_132578", amount)
+        ));
+
+        let err = check_selector_collisions(&functions).expect_err("should have collided");
+
+        assert_eq!(
+            err.to_string(),
+            "functions `transfer_120131(u64)` and `transfer_132578(u64)` have the same selector \
+            0xb4572897, calling either of them could execute the other one. Rename one of them"
+        );
+        assert!(check_selector_collisions(&functions[..1]).is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+//! Decodes a page of logs, as an indexer would when going through historical transactions.
+//! Compare the runs with and without the `parallel-decoding` feature:
+//!
+//! ```text
+//! cargo bench -p fuels-core --bench decode_logs
+//! cargo bench -p fuels-core --bench decode_logs --features parallel-decoding
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fuel_tx::{ContractId, Receipt};
+use fuels_core::{
+    codec::{log_formatters_lookup, ABIEncoder, LogDecoder, LogFormatter},
+    traits::Tokenizable,
+    types::Bits256,
+};
+
+const PAGE_SIZE: u64 = 10_000;
+const LOG_ID: u64 = 1;
+
+type Log = (u64, Bits256, bool, [u64; 4]);
+
+fn log_page(contract_id: ContractId) -> Vec<Receipt> {
+    (0..PAGE_SIZE)
+        .map(|n| {
+            let log: Log = (n, Bits256([n as u8; 32]), n % 2 == 0, [n; 4]);
+            let data = ABIEncoder::default()
+                .encode(&[log.into_token()])
+                .expect("log should encode")
+                .resolve(0);
+
+            Receipt::LogData {
+                id: contract_id,
+                ra: 0,
+                rb: LOG_ID,
+                ptr: 0,
+                len: data.len() as u64,
+                digest: Default::default(),
+                data: Some(data),
+                pc: 0,
+                is: 0,
+            }
+        })
+        .collect()
+}
+
+fn decode_logs(c: &mut Criterion) {
+    let contract_id = ContractId::from([1; 32]);
+    let log_decoder = LogDecoder::new(log_formatters_lookup(
+        vec![(LOG_ID, LogFormatter::new::<Log>())],
+        contract_id,
+    ));
+    let receipts = log_page(contract_id);
+
+    let mut group = c.benchmark_group("decode 10k logs");
+    group.bench_function("as strings", |b| {
+        b.iter(|| log_decoder.decode_logs(black_box(&receipts)))
+    });
+    group.bench_function("with type", |b| {
+        b.iter(|| log_decoder.decode_logs_with_type::<Log>(black_box(&receipts)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, decode_logs);
+criterion_main!(benches);
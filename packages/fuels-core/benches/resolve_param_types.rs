@@ -0,0 +1,129 @@
+//! Resolves the types of every function of an ABI with deeply nested generic types, as done
+//! when describing or encoding the calls of a contract known only by its JSON ABI:
+//!
+//! ```text
+//! cargo bench -p fuels-core --bench resolve_param_types
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fuel_abi_types::abi::program::ProgramABI;
+use fuels_core::{
+    codec::ParsedAbi,
+    types::param_types::{ParamType, ParamTypeCache},
+};
+use serde_json::{json, Value};
+
+const NESTING_DEPTH: usize = 200;
+const FUNCTIONS: usize = 100;
+
+const U64_TYPE_ID: usize = 0;
+const GENERIC_TYPE_ID: usize = 1;
+
+fn primitive(type_id: usize, type_field: &str) -> Value {
+    json!({
+        "typeId": type_id,
+        "type": type_field,
+        "components": null,
+        "typeParameters": null,
+    })
+}
+
+fn application(type_id: usize, type_arguments: Value) -> Value {
+    json!({"name": "", "type": type_id, "typeArguments": type_arguments})
+}
+
+/// `struct Level<n><T> { value: T, inner: Level<n - 1><T> }`, with every function taking and
+/// returning the deepest level instantiated with `u64`.
+fn nested_generics_abi() -> ProgramABI {
+    let level_type_id = |level: usize| GENERIC_TYPE_ID + 1 + level;
+    let generic_argument = || json!([application(GENERIC_TYPE_ID, Value::Null)]);
+
+    let mut types = vec![
+        primitive(U64_TYPE_ID, "u64"),
+        primitive(GENERIC_TYPE_ID, "generic T"),
+    ];
+    types.extend((0..NESTING_DEPTH).map(|level| {
+        let mut components =
+            vec![json!({"name": "value", "type": GENERIC_TYPE_ID, "typeArguments": null})];
+        if level > 0 {
+            components.push(json!({
+                "name": "inner",
+                "type": level_type_id(level - 1),
+                "typeArguments": generic_argument(),
+            }));
+        }
+
+        json!({
+            "typeId": level_type_id(level),
+            "type": format!("struct Level{level}"),
+            "components": components,
+            "typeParameters": [GENERIC_TYPE_ID],
+        })
+    }));
+
+    let deepest = || {
+        application(
+            level_type_id(NESTING_DEPTH - 1),
+            json!([application(U64_TYPE_ID, Value::Null)]),
+        )
+    };
+    let functions = (0..FUNCTIONS)
+        .map(|index| {
+            json!({
+                "name": format!("function_{index}"),
+                "inputs": [deepest()],
+                "output": deepest(),
+                "attributes": null,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::from_value(json!({
+        "types": types,
+        "functions": functions,
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": [],
+    }))
+    .expect("should be a valid ABI")
+}
+
+fn resolve_param_types(c: &mut Criterion) {
+    let parsed_abi = ParsedAbi::new(nested_generics_abi());
+    let applications = parsed_abi
+        .abi
+        .functions
+        .iter()
+        .flat_map(|function| function.inputs.iter().chain([&function.output]))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("resolve 100 functions");
+    group.bench_function("one call per type", |b| {
+        b.iter(|| {
+            for application in &applications {
+                ParamType::try_from_type_application(
+                    black_box(application),
+                    &parsed_abi.type_lookup,
+                )
+                .expect("should resolve");
+            }
+        })
+    });
+    group.bench_function("shared cache", |b| {
+        b.iter(|| {
+            let mut cache = ParamTypeCache::default();
+            for application in &applications {
+                ParamType::try_from_type_application_cached(
+                    black_box(application),
+                    &parsed_abi.type_lookup,
+                    &mut cache,
+                )
+                .expect("should resolve");
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, resolve_param_types);
+criterion_main!(benches);
@@ -1,13 +1,36 @@
+mod abi_cache;
 mod abi_decoder;
 mod abi_encoder;
+mod abi_parsing;
+mod abi_schema;
+mod bytes_literal;
+mod disassembler;
+mod embedded_abi;
 mod function_selector;
 mod logs;
+mod multi_abi_logs;
+mod snapshot;
+mod token_json;
+mod tokenizer;
 mod utils;
+mod word_codec;
 
+pub use abi_cache::*;
 pub use abi_decoder::*;
 pub use abi_encoder::*;
+pub use abi_parsing::*;
+pub use abi_schema::*;
+pub use bytes_literal::*;
+pub use disassembler::*;
+#[cfg(feature = "embed-abi")]
+pub use embedded_abi::*;
 pub use function_selector::*;
 pub use logs::*;
+pub use multi_abi_logs::*;
+pub use snapshot::*;
+pub use token_json::*;
+pub use tokenizer::*;
+pub use word_codec::*;
 
 use crate::{
     traits::{Parameterize, Tokenizable},
@@ -0,0 +1,665 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use fuel_abi_types::abi::program::{ABIFunction, ProgramABI, TypeApplication, TypeDeclaration};
+use fuel_tx::Receipt;
+use fuel_types::{Bytes32, ContractId};
+use itertools::Itertools;
+
+use crate::{
+    codec::{
+        check_selector_collisions, log_of, resolve_fn_selector, resolve_fn_signature, tokenize,
+        ABIDecoder, ABIEncoder,
+    },
+    traits::CanonicalHash,
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        param_types::{ParamType, ParamTypeCache},
+        Selector, Token,
+    },
+};
+
+/// A `ProgramABI` together with its type declarations indexed by type id, ready to be used
+/// for decoding. The types resolved through it are remembered for its whole lifetime, see
+/// [`Self::resolve`].
+#[derive(Debug)]
+pub struct ParsedAbi {
+    pub abi: ProgramABI,
+    pub type_lookup: HashMap<usize, TypeDeclaration>,
+    param_type_cache: Mutex<ParamTypeCache>,
+}
+
+impl ParsedAbi {
+    pub fn new(abi: ProgramABI) -> Self {
+        let type_lookup = abi
+            .types
+            .iter()
+            .map(|decl| (decl.type_id, decl.clone()))
+            .collect();
+
+        Self {
+            abi,
+            type_lookup,
+            param_type_cache: Mutex::default(),
+        }
+    }
+
+    /// Converts a type application of this ABI into a `ParamType`. Nested types are shared
+    /// between the functions, logs, etc. of an ABI, so they're resolved once and reused by
+    /// every later call.
+    pub fn resolve(&self, type_application: &TypeApplication) -> Result<ParamType> {
+        let mut cache = self
+            .param_type_cache
+            .lock()
+            .expect("param type cache lock poisoned");
+
+        ParamType::try_from_type_application_cached(type_application, &self.type_lookup, &mut cache)
+    }
+
+    /// Fails if the ABI declares the same type id more than once. Types refer to each other by
+    /// id, so one of the declarations would silently replace the other one in `type_lookup`.
+    pub fn check_type_ids(&self) -> Result<()> {
+        let mut declared: HashMap<usize, &str> = HashMap::new();
+
+        for decl in &self.abi.types {
+            if let Some(previous) = declared.insert(decl.type_id, &decl.type_field) {
+                return Err(error!(
+                    Codec,
+                    "the ABI declares the type id {} more than once: as `{previous}` and as `{}`",
+                    decl.type_id,
+                    decl.type_field
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fails if two functions of the ABI have the same selector, see
+    /// [`check_selector_collisions`]. Functions with types the SDK can't represent are
+    /// skipped since they can't be called through it anyway.
+    pub fn check_selectors(&self) -> Result<()> {
+        let signatures = self
+            .abi
+            .functions
+            .iter()
+            .filter_map(|function| {
+                let inputs = function
+                    .inputs
+                    .iter()
+                    .map(|input| self.resolve(input))
+                    .collect::<Result<Vec<_>>>()
+                    .ok()?;
+
+                Some(resolve_fn_signature(&function.name, &inputs))
+            })
+            .collect::<Vec<_>>();
+
+        check_selector_collisions(signatures.iter().map(String::as_str))
+    }
+
+    /// Describes every function of the ABI, in declaration order.
+    pub fn functions(&self) -> Result<Vec<FunctionInfo>> {
+        self.abi
+            .functions
+            .iter()
+            .map(|function| FunctionInfo::new(function, self))
+            .collect()
+    }
+
+    /// Describes `fn_name`, see [`FunctionInfo`].
+    pub fn function_info(&self, fn_name: &str) -> Result<FunctionInfo> {
+        let function = self.existing_function(fn_name)?;
+
+        FunctionInfo::new(function, self)
+    }
+
+    /// The function as declared in the JSON ABI, see [`Self::function_info`] for its resolved
+    /// types.
+    pub fn function(&self, name: &str) -> Option<&ABIFunction> {
+        self.abi
+            .functions
+            .iter()
+            .find(|function| function.name == name)
+    }
+
+    /// Encodes the arguments of a call to `fn_name`. `values` are parsed according to the
+    /// function's inputs, as described in [`tokenize`], and must match them in number, i.e. be
+    /// empty for a function without inputs, which encodes to no bytes at all.
+    pub fn encode(&self, fn_name: &str, values: &[&str]) -> Result<Vec<u8>> {
+        let function = self.existing_function(fn_name)?;
+
+        if values.len() != function.inputs.len() {
+            let parameters = function
+                .inputs
+                .iter()
+                .map(|input| format!("{}: {}", input.name, self.type_name(input.type_id)))
+                .join(", ");
+
+            return Err(error!(
+                Codec,
+                "expected {} args for {fn_name}, got {}; parameters: ({parameters})",
+                function.inputs.len(),
+                values.len()
+            ));
+        }
+
+        let tokens = self
+            .input_types(function)?
+            .iter()
+            .zip(values)
+            .map(|(param_type, value)| tokenize(param_type, value))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ABIEncoder::default().encode(&tokens)?.resolve(0))
+    }
+
+    /// The selector of `fn_name` concatenated with its arguments encoded as by [`Self::encode`]:
+    ///
+    /// ```ignore
+    /// let calldata = abi.encode_with_function_selector("set_value", &["42"])?;
+    ///
+    /// let selector = abi.selector_only("set_value")?;
+    /// let args = abi.encode("set_value", &["42"])?;
+    /// assert_eq!(calldata, [selector.to_vec(), args].concat());
+    /// ```
+    ///
+    /// `std::low_level_call::call_with_function_selector` takes the selector and the arguments
+    /// as two separate `Bytes`, pass it `selector` and `args` rather than the concatenation.
+    pub fn encode_with_function_selector(&self, fn_name: &str, values: &[&str]) -> Result<Vec<u8>> {
+        let encoded_args = self.encode(fn_name, values)?;
+
+        let mut calldata = self.selector_only(fn_name)?.to_vec();
+        calldata.extend(encoded_args);
+
+        Ok(calldata)
+    }
+
+    /// The selector of `fn_name`, which is all a call to a function without inputs needs.
+    pub fn selector_only(&self, fn_name: &str) -> Result<Selector> {
+        let function = self.existing_function(fn_name)?;
+
+        Ok(resolve_fn_selector(
+            &function.name,
+            &self.input_types(function)?,
+        ))
+    }
+
+    fn existing_function(&self, fn_name: &str) -> Result<&ABIFunction> {
+        self.function(fn_name)
+            .ok_or_else(|| error!(Codec, "function `{fn_name}` not found in the ABI"))
+    }
+
+    fn input_types(&self, function: &ABIFunction) -> Result<Vec<ParamType>> {
+        function
+            .inputs
+            .iter()
+            .map(|input| self.resolve(input))
+            .collect()
+    }
+
+    fn type_name(&self, type_id: usize) -> &str {
+        self.type_lookup
+            .get(&type_id)
+            .map(|decl| decl.type_field.as_str())
+            .unwrap_or("unknown type")
+    }
+
+    /// Decodes a single field of the encoded output of `fn_name`. See
+    /// [`ABIDecoder::decode_field`] for the format of `path`.
+    pub fn decode_field(&self, fn_name: &str, bytes: &[u8], path: &str) -> Result<Token> {
+        let function = self.existing_function(fn_name)?;
+
+        ABIDecoder::default().decode_field(function, &self.type_lookup, bytes, path)
+    }
+
+    /// Decodes the data of the log with id `log_id`, i.e. the `rb` register of its receipt.
+    pub fn decode_log(&self, log_id: u64, bytes: &[u8]) -> Result<Token> {
+        let logged_type = self
+            .abi
+            .logged_types
+            .iter()
+            .flatten()
+            .find(|logged_type| logged_type.log_id == log_id)
+            .ok_or_else(|| error!(Codec, "log id {log_id} not found in the ABI"))?;
+        let param_type = self.resolve(&logged_type.application)?;
+
+        ABIDecoder::default().decode(&param_type, bytes)
+    }
+}
+
+/// A function of a [`ParsedAbi`] with its types resolved, e.g. for tools listing what a
+/// contract offers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionInfo {
+    pub name: String,
+    /// The signature the selector is computed from, see [`resolve_fn_signature`].
+    pub signature: String,
+    pub selector: Selector,
+    /// The name and type of every argument.
+    pub inputs: Vec<(String, ParamType)>,
+    pub output: ParamType,
+}
+
+impl FunctionInfo {
+    fn new(function: &ABIFunction, abi: &ParsedAbi) -> Result<Self> {
+        let inputs = function
+            .inputs
+            .iter()
+            .map(|input| Ok((input.name.clone(), abi.resolve(input)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let input_types = inputs
+            .iter()
+            .map(|(_, param_type)| param_type.clone())
+            .collect::<Vec<_>>();
+
+        Ok(Self {
+            name: function.name.clone(),
+            signature: resolve_fn_signature(&function.name, &input_types),
+            selector: resolve_fn_selector(&function.name, &input_types),
+            inputs,
+            output: abi.resolve(&function.output)?,
+        })
+    }
+}
+
+/// Thread-safe cache of parsed ABIs keyed by their canonical hash. Cloning the cache is cheap
+/// and all clones share the same entries, so every handle working with the same ABI ends up
+/// sharing a single `ParsedAbi`.
+#[derive(Debug, Clone, Default)]
+pub struct AbiCache {
+    entries: Arc<RwLock<HashMap<Bytes32, Arc<ParsedAbi>>>>,
+}
+
+impl AbiCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached entry for `abi`, parsing and caching it first if needed. ABIs
+    /// declaring a type id twice or with colliding function selectors are rejected, see
+    /// [`ParsedAbi::check_type_ids`] and [`ParsedAbi::check_selectors`].
+    pub fn get_or_insert(&self, abi: ProgramABI) -> Result<Arc<ParsedAbi>> {
+        let hash = abi.canonical_hash()?;
+
+        if let Some(parsed_abi) = self.get(&hash) {
+            return Ok(parsed_abi);
+        }
+
+        let new_entry = ParsedAbi::new(abi);
+        new_entry.check_type_ids()?;
+        new_entry.check_selectors()?;
+
+        let mut entries = self.entries.write().expect("abi cache lock poisoned");
+        let parsed_abi = entries.entry(hash).or_insert_with(|| Arc::new(new_entry));
+
+        Ok(Arc::clone(parsed_abi))
+    }
+
+    /// Same as `get_or_insert` but starts from the JSON ABI.
+    pub fn get_or_insert_json(&self, json_abi: &str) -> Result<Arc<ParsedAbi>> {
+        self.get_or_insert(serde_json::from_str(json_abi)?)
+    }
+
+    pub fn get(&self, hash: &Bytes32) -> Option<Arc<ParsedAbi>> {
+        self.entries
+            .read()
+            .expect("abi cache lock poisoned")
+            .get(hash)
+            .cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().expect("abi cache lock poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The ABIs of deployed contracts, by contract id. Used to decode the calls a transaction
+/// makes, see `ScriptTransaction::explain`.
+#[derive(Debug, Clone, Default)]
+pub struct AbiRegistry {
+    abis: HashMap<ContractId, Arc<ParsedAbi>>,
+}
+
+impl AbiRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, contract_id: impl Into<ContractId>, abi: Arc<ParsedAbi>) {
+        self.abis.insert(contract_id.into(), abi);
+    }
+
+    pub fn with_abi(mut self, contract_id: impl Into<ContractId>, abi: Arc<ParsedAbi>) -> Self {
+        self.register(contract_id, abi);
+        self
+    }
+
+    pub fn get(&self, contract_id: &ContractId) -> Option<&ParsedAbi> {
+        self.abis.get(contract_id).map(Arc::as_ref)
+    }
+
+    /// Decodes, in order, the logs found in `receipts`, each with the ABI of the contract that
+    /// emitted it. Logs of contracts without a registered ABI are skipped. Scripts log with a
+    /// zeroed contract id, so their logs are decoded by registering the script's ABI under
+    /// `ContractId::zeroed()`.
+    ///
+    /// See [`MultiAbiLogDecoder`](crate::codec::MultiAbiLogDecoder) to also learn which calls led to each log.
+    pub fn decode_logs(&self, receipts: &[Receipt]) -> Vec<DecodedLog> {
+        receipts
+            .iter()
+            .filter_map(|receipt| {
+                let (contract_id, log_id, data) = log_of(receipt)?;
+                let abi = self.get(&contract_id)?;
+
+                Some(DecodedLog {
+                    contract_id: contract_id.into(),
+                    log_id,
+                    value: abi.decode_log(log_id, &data).map_err(|err| err.to_string()),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A log decoded by [`AbiRegistry::decode_logs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedLog {
+    pub contract_id: Bech32ContractId,
+    pub log_id: u64,
+    /// The logged value, or why it couldn't be decoded.
+    pub value: std::result::Result<Token, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"{
+        "types": [{"typeId": 0, "type": "u64", "components": null, "typeParameters": null}],
+        "functions": [{"inputs": [], "name": "get", "output": {"name": "", "type": 0, "typeArguments": null}, "attributes": null}],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    #[test]
+    fn clones_share_parsed_abis() -> Result<()> {
+        let cache = AbiCache::new();
+        let other_handle = cache.clone();
+
+        let parsed_abi = cache.get_or_insert_json(ABI)?;
+        let reformatted_abi = ABI.split_whitespace().collect::<String>();
+        let same_parsed_abi = other_handle.get_or_insert_json(&reformatted_abi)?;
+
+        assert!(Arc::ptr_eq(&parsed_abi, &same_parsed_abi));
+        assert_eq!(cache.len(), 1);
+        assert!(parsed_abi.function("get").is_some());
+        assert!(parsed_abi.type_lookup.contains_key(&0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn encode_checks_the_number_of_args() -> Result<()> {
+        let abi = r#"{
+            "types": [
+                {"typeId": 0, "type": "()", "components": null, "typeParameters": null},
+                {"typeId": 1, "type": "u64", "components": null, "typeParameters": null},
+                {"typeId": 2, "type": "b256", "components": null, "typeParameters": null}
+            ],
+            "functions": [{
+                "inputs": [
+                    {"name": "amount", "type": 1, "typeArguments": null},
+                    {"name": "recipient", "type": 2, "typeArguments": null}
+                ],
+                "name": "transfer",
+                "output": {"name": "", "type": 0, "typeArguments": null},
+                "attributes": null
+            }],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+        let parsed_abi = AbiCache::new().get_or_insert_json(abi)?;
+        let recipient = format!("0x{}", "01".repeat(32));
+
+        let encoded = parsed_abi.encode("transfer", &["42", &recipient])?;
+        assert_eq!(
+            encoded,
+            [vec![0, 0, 0, 0, 0, 0, 0, 42], vec![1; 32]].concat()
+        );
+
+        for values in [&["42"][..], &["42", &recipient, "1"]] {
+            let err = parsed_abi
+                .encode("transfer", values)
+                .expect_err("should fail because of the number of args");
+
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "codec: expected 2 args for transfer, got {}; parameters: (amount: u64, recipient: b256)",
+                    values.len()
+                )
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn functions_without_inputs_encode_to_their_selector_only() -> Result<()> {
+        let parsed_abi = AbiCache::new().get_or_insert_json(ABI)?;
+        let selector = resolve_fn_selector("get", &[]);
+
+        assert!(parsed_abi.encode("get", &[])?.is_empty());
+        assert_eq!(parsed_abi.selector_only("get")?, selector);
+        assert_eq!(
+            parsed_abi.encode_with_function_selector("get", &[])?,
+            selector
+        );
+
+        let err = parsed_abi
+            .encode_with_function_selector("get", &["1"])
+            .expect_err("`get` takes no args");
+        assert_eq!(
+            err.to_string(),
+            "codec: expected 0 args for get, got 1; parameters: ()"
+        );
+        assert!(parsed_abi.selector_only("set").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn functions_are_described_with_resolved_types() -> Result<()> {
+        let abi = r#"{
+            "types": [
+                {"typeId": 0, "type": "()", "components": null, "typeParameters": null},
+                {"typeId": 1, "type": "u64", "components": null, "typeParameters": null},
+                {"typeId": 2, "type": "b256", "components": null, "typeParameters": null}
+            ],
+            "functions": [
+                {"inputs": [], "name": "total", "output": {"name": "", "type": 1, "typeArguments": null}, "attributes": null},
+                {
+                    "inputs": [
+                        {"name": "amount", "type": 1, "typeArguments": null},
+                        {"name": "recipient", "type": 2, "typeArguments": null}
+                    ],
+                    "name": "transfer",
+                    "output": {"name": "", "type": 0, "typeArguments": null},
+                    "attributes": null
+                }
+            ],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+        let parsed_abi = AbiCache::new().get_or_insert_json(abi)?;
+
+        let functions = parsed_abi.functions()?;
+
+        let names = functions
+            .iter()
+            .map(|function| function.signature.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["total()", "transfer(u64,b256)"]);
+
+        let transfer = parsed_abi.function_info("transfer")?;
+        assert_eq!(transfer, functions[1]);
+        assert_eq!(
+            transfer.selector,
+            resolve_fn_selector("transfer", &[ParamType::U64, ParamType::B256])
+        );
+        assert_eq!(
+            transfer.inputs,
+            [
+                ("amount".to_string(), ParamType::U64),
+                ("recipient".to_string(), ParamType::B256)
+            ]
+        );
+        assert_eq!(transfer.output, ParamType::Unit);
+
+        let err = parsed_abi
+            .function_info("burn")
+            .expect_err("should not exist");
+        assert_eq!(
+            err.to_string(),
+            "codec: function `burn` not found in the ABI"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn abis_with_duplicate_type_ids_are_rejected() {
+        let abi = r#"{
+            "types": [
+                {"typeId": 0, "type": "u64", "components": null, "typeParameters": null},
+                {"typeId": 0, "type": "bool", "components": null, "typeParameters": null}
+            ],
+            "functions": [],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+
+        let err = AbiCache::new()
+            .get_or_insert_json(abi)
+            .expect_err("should have detected the duplicate");
+
+        assert_eq!(
+            err.to_string(),
+            "codec: the ABI declares the type id 0 more than once: as `u64` and as `bool`"
+        );
+    }
+
+    #[test]
+    fn abis_with_colliding_selectors_are_rejected() {
+        let function = |name: &str| {
+            format!(
+                r#"{{"inputs": [{{"name": "amount", "type": 0, "typeArguments": null}}], "name": "{name}", "output": {{"name": "", "type": 0, "typeArguments": null}}, "attributes": null}}"#
+            )
+        };
+        let abi = format!(
+            r#"{{
+            "types": [{{"typeId": 0, "type": "u64", "components": null, "typeParameters": null}}],
+            "functions": [{}, {}],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }}"#,
+            function("transfer_120131"),
+            function("transfer_132578")
+        );
+        let cache = AbiCache::new();
+
+        let err = cache
+            .get_or_insert_json(&abi)
+            .expect_err("should have rejected the ABI");
+
+        assert!(err
+            .to_string()
+            .contains("`transfer_120131(u64)` and `transfer_132578(u64)` have the same selector"));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn logs_of_several_contracts_are_decoded_with_their_own_abi() -> Result<()> {
+        let abi_logging = |type_field: &str| {
+            format!(
+                r#"{{
+                "types": [{{"typeId": 0, "type": "{type_field}", "components": null, "typeParameters": null}}],
+                "functions": [],
+                "loggedTypes": [{{"logId": 7, "loggedType": {{"name": "", "type": 0, "typeArguments": null}}}}],
+                "messagesTypes": [],
+                "configurables": []
+            }}"#
+            )
+        };
+        let cache = AbiCache::new();
+        let (counter, flag, unknown) = (
+            ContractId::from([1; 32]),
+            ContractId::from([2; 32]),
+            ContractId::from([3; 32]),
+        );
+        let registry = AbiRegistry::new()
+            .with_abi(counter, cache.get_or_insert_json(&abi_logging("u64"))?)
+            .with_abi(flag, cache.get_or_insert_json(&abi_logging("bool"))?);
+
+        let log = |id| Receipt::Log {
+            id,
+            ra: 1,
+            rb: 7,
+            rc: 0,
+            rd: 0,
+            pc: 0,
+            is: 0,
+        };
+        let log_data = |id, rb| Receipt::LogData {
+            id,
+            ra: 0,
+            rb,
+            ptr: 0,
+            len: 1,
+            digest: Default::default(),
+            data: Some(vec![1]),
+            pc: 0,
+            is: 0,
+        };
+        let receipts = [
+            log(counter),
+            log(unknown),
+            log_data(flag, 7),
+            log_data(flag, 8),
+        ];
+
+        let decoded = registry.decode_logs(&receipts);
+
+        let values = decoded
+            .into_iter()
+            .map(|log| (ContractId::from(&log.contract_id), log.log_id, log.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                (counter, 7, Ok(Token::U64(1))),
+                (flag, 7, Ok(Token::Bool(true))),
+                (
+                    flag,
+                    8,
+                    Err("codec: log id 8 not found in the ABI".to_string())
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -1,12 +1,24 @@
 mod bounded_decoder;
 #[cfg(experimental)]
 mod experimental_bounded_decoder;
+mod field_path;
+
+use std::{collections::HashMap, ops::Range};
+
+use fuel_abi_types::abi::program::{ABIFunction, TypeDeclaration};
 
 #[cfg(experimental)]
 use crate::codec::abi_decoder::experimental_bounded_decoder::ExperimentalBoundedDecoder;
 use crate::{
-    codec::abi_decoder::bounded_decoder::BoundedDecoder,
-    types::{errors::Result, param_types::ParamType, Token},
+    codec::{
+        abi_decoder::{bounded_decoder::BoundedDecoder, field_path::locate_field},
+        WordCodec,
+    },
+    types::{
+        errors::{error, Result},
+        param_types::ParamType,
+        Token,
+    },
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -17,6 +29,10 @@ pub struct DecoderConfig {
     /// Every decoded Token will increase the token count. Decoding will fail if the current
     /// token count becomes greater than `max_tokens` configured here.
     pub max_tokens: usize,
+    /// The number of elements an array type may declare, e.g. the `N` of `[_; N]`. Decoding
+    /// will fail if an array longer than `max_array_length` is encountered, regardless of the
+    /// number of bytes given.
+    pub max_array_length: usize,
 }
 
 // ANCHOR: default_decoder_config
@@ -25,11 +41,64 @@ impl Default for DecoderConfig {
         Self {
             max_depth: 45,
             max_tokens: 10_000,
+            max_array_length: 10_000,
         }
     }
 }
 // ANCHOR_END: default_decoder_config
 
+/// Selects which side of an `ABIFunction` should be used as the decoding schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeTarget {
+    /// The function arguments, e.g. as found in the script data of a contract call.
+    Inputs,
+    /// The value returned by the function.
+    Output,
+    /// Same as `Output` but a returned tuple is flattened into one `Token` per element. See
+    /// [`ABIDecoder::decode_fn_named_output`] to get the elements along with their names.
+    FlattenedOutput,
+}
+
+/// The outcome of [`ABIDecoder::decode_tolerant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialDecoding {
+    /// The values decoded before decoding failed, in the order they are encoded in, each along
+    /// with its path, e.g. `value.1[2]`. Structs, tuples and arrays are broken down into their
+    /// elements.
+    pub decoded: Vec<(String, Token)>,
+    /// Where and why decoding failed, `None` if everything was decoded.
+    pub failure: Option<DecodingFailure>,
+}
+
+impl PartialDecoding {
+    pub fn is_complete(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// The value [`ABIDecoder::decode_tolerant`] couldn't decode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodingFailure {
+    /// Path of the value, e.g. `value.1[2]`.
+    pub path: String,
+    /// The bytes the value was expected to be encoded in. Can extend past the end of the input.
+    pub byte_range: Range<usize>,
+    pub reason: String,
+}
+
+/// Breaks a returned tuple into its elements, each named after its index. Any other value is
+/// kept whole and named `value`.
+pub fn flatten_output(token: Token) -> Vec<(String, Token)> {
+    match token {
+        Token::Tuple(tokens) => tokens
+            .into_iter()
+            .enumerate()
+            .map(|(index, token)| (index.to_string(), token))
+            .collect(),
+        token => vec![("value".to_string(), token)],
+    }
+}
+
 #[derive(Default)]
 pub struct ABIDecoder {
     pub config: DecoderConfig,
@@ -82,6 +151,223 @@ impl ABIDecoder {
         BoundedDecoder::new(self.config).decode_multiple(param_types, bytes)
     }
 
+    /// Same as `decode`, but instead of failing as a whole, returns the values decoded up to the
+    /// point decoding failed, along with the path and the byte range of the value that couldn't
+    /// be decoded and the reason why. Meant for debugging layout mismatches, e.g. between the
+    /// SDK and the compiler that built a program.
+    ///
+    /// # Examples
+    /// ```
+    /// use fuels_core::codec::ABIDecoder;
+    /// use fuels_core::types::{param_types::ParamType, Token};
+    ///
+    /// let param_type = ParamType::Tuple(vec![ParamType::U64, ParamType::B256]);
+    /// let bytes = [0, 0, 0, 0, 0, 0, 0, 7, 1, 2, 3];
+    ///
+    /// let partial = ABIDecoder::default().decode_tolerant(&param_type, &bytes);
+    ///
+    /// assert_eq!(partial.decoded, vec![("value.0".to_string(), Token::U64(7))]);
+    ///
+    /// let failure = partial.failure.unwrap();
+    /// assert_eq!(failure.path, "value.1");
+    /// assert_eq!(failure.byte_range, 8..40);
+    /// ```
+    pub fn decode_tolerant(&self, param_type: &ParamType, bytes: &[u8]) -> PartialDecoding {
+        BoundedDecoder::new(self.config).decode_tolerant(param_type, bytes)
+    }
+
+    /// Decodes a vector whose elements are heap types themselves, e.g. `Vec<Vec<u64>>`,
+    /// `Vec<Bytes>` or `Vec<String>`. The VM doesn't keep the data of such elements next to each
+    /// other, so it is returned separately for each element: `elements` holds the encoded data
+    /// of each element, in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use fuels_core::codec::ABIDecoder;
+    /// use fuels_core::types::param_types::ParamType;
+    /// use fuels_core::types::Token;
+    ///
+    /// let decoder = ABIDecoder::default();
+    /// let param_type = ParamType::Vector(Box::new(ParamType::String));
+    ///
+    /// let token = decoder
+    ///     .decode_heap_elements(&param_type, &[b"hello".to_vec(), b"world".to_vec()])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     token,
+    ///     Token::Vector(vec![
+    ///         Token::String("hello".to_string()),
+    ///         Token::String("world".to_string())
+    ///     ])
+    /// );
+    /// ```
+    pub fn decode_heap_elements(
+        &self,
+        param_type: &ParamType,
+        elements: &[Vec<u8>],
+    ) -> Result<Token> {
+        let element_type = param_type.nested_heap_element().ok_or_else(|| {
+            error!(
+                Codec,
+                "`{param_type:?}` is not a vector whose elements are heap types"
+            )
+        })?;
+
+        BoundedDecoder::new(self.config).decode_heap_elements(element_type, elements)
+    }
+
+    /// Decodes `bytes` using either the inputs or the output of `function` as the schema.
+    ///
+    /// Decoding the `Output` always yields a single `Token`, while `FlattenedOutput` yields one
+    /// `Token` per element if the function returns a tuple. Decoding the `Inputs` yields one
+    /// `Token` per argument. Arguments containing heap types are encoded as pointers and
+    /// cannot be decoded this way.
+    ///
+    /// # Arguments
+    ///
+    /// * `function`:    The ABI function whose types describe `bytes`.
+    /// * `type_lookup`: A HashMap of the ABI's TypeDeclarations where the type id is the key.
+    /// * `target`:      Whether `bytes` hold the encoded inputs or the encoded output.
+    /// * `bytes`:       The bytes to be used in the decoding process.
+    pub fn decode_fn(
+        &self,
+        function: &ABIFunction,
+        type_lookup: &HashMap<usize, TypeDeclaration>,
+        target: DecodeTarget,
+        bytes: &[u8],
+    ) -> Result<Vec<Token>> {
+        match target {
+            DecodeTarget::Output => {
+                let param_type =
+                    ParamType::try_from_type_application(&function.output, type_lookup)?;
+
+                Ok(vec![self.decode(&param_type, bytes)?])
+            }
+            DecodeTarget::FlattenedOutput => {
+                let param_type =
+                    ParamType::try_from_type_application(&function.output, type_lookup)?;
+
+                match self.decode(&param_type, bytes)? {
+                    Token::Tuple(tokens) => Ok(tokens),
+                    token => Ok(vec![token]),
+                }
+            }
+            DecodeTarget::Inputs => {
+                let param_types = function
+                    .inputs
+                    .iter()
+                    .map(|input| ParamType::try_from_type_application(input, type_lookup))
+                    .collect::<Result<Vec<_>>>()?;
+
+                if param_types
+                    .iter()
+                    .any(|param_type| param_type.is_extra_receipt_needed(true))
+                {
+                    return Err(error!(
+                        Codec,
+                        "cannot decode inputs of `{}`: heap type arguments are encoded as pointers",
+                        function.name
+                    ));
+                }
+
+                // A lone argument is encoded on its own, while multiple arguments are
+                // word-aligned the same way tuple elements are.
+                match param_types.as_slice() {
+                    [param_type] => Ok(vec![self.decode(param_type, bytes)?]),
+                    _ => match self.decode(&ParamType::Tuple(param_types), bytes)? {
+                        Token::Tuple(tokens) => Ok(tokens),
+                        _ => unreachable!("decoding a tuple must yield a tuple token"),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Same as decoding the `FlattenedOutput` of `function`, but every `Token` comes along with
+    /// the name the ABI gives it. Sway doesn't name tuple elements, so unnamed elements are named
+    /// after their index, as done by [`flatten_output`].
+    pub fn decode_fn_named_output(
+        &self,
+        function: &ABIFunction,
+        type_lookup: &HashMap<usize, TypeDeclaration>,
+        bytes: &[u8],
+    ) -> Result<Vec<(String, Token)>> {
+        let param_type = ParamType::try_from_type_application(&function.output, type_lookup)?;
+        let mut named = flatten_output(self.decode(&param_type, bytes)?);
+
+        let components = type_lookup
+            .get(&function.output.type_id)
+            .filter(|_| matches!(param_type, ParamType::Tuple(_)))
+            .and_then(|declaration| declaration.components.as_ref());
+        if let Some(components) = components {
+            for ((name, _), component) in named.iter_mut().zip(components) {
+                if !component.name.is_empty() && component.name != "__tuple_element" {
+                    name.clone_from(&component.name);
+                }
+            }
+        }
+
+        Ok(named)
+    }
+
+    /// Decodes a single field of a function's encoded output, without decoding the rest of it.
+    /// The field's offset is computed from the static layout of the output type, so outputs
+    /// containing heap types are not supported.
+    ///
+    /// `path` is made of struct field names, enum variant names, tuple indexes and array
+    /// indexes, e.g. `inner.balances[3].amount`, `1.0` or `Some.amount` for a field of an
+    /// `Option<Balance>`. An empty path selects the whole output.
+    ///
+    /// Going through an enum short-circuits if it holds another variant than the path names:
+    /// the enum itself is decoded instead, e.g. `None` for `Some.amount`, rather than reading
+    /// the field out of the padding of the other variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `function`:    The ABI function whose output is encoded in `bytes`.
+    /// * `type_lookup`: A HashMap of the ABI's TypeDeclarations where the type id is the key.
+    /// * `bytes`:       The encoded output.
+    /// * `path`:        The path to the field to decode.
+    pub fn decode_field(
+        &self,
+        function: &ABIFunction,
+        type_lookup: &HashMap<usize, TypeDeclaration>,
+        bytes: &[u8],
+        path: &str,
+    ) -> Result<Token> {
+        let output_type = ParamType::try_from_type_application(&function.output, type_lookup)?;
+        if path.is_empty() {
+            return self.decode(&output_type, bytes);
+        }
+
+        let location = locate_field(&function.output, &output_type, type_lookup, path)?;
+        let bytes_at = |offset: usize| {
+            bytes.get(offset..).ok_or_else(|| {
+                error!(
+                    Codec,
+                    "field `{path}` starts at byte {offset} but only {} bytes were given",
+                    bytes.len()
+                )
+            })
+        };
+
+        for (enum_layout, discriminant) in &location.variants {
+            let enum_bytes = bytes_at(enum_layout.offset())?;
+            let actual_discriminant = WordCodec::read(enum_bytes)
+                .ok_or_else(|| error!(Codec, "missing the discriminant of an enum in `{path}`"))?;
+
+            if actual_discriminant != *discriminant {
+                return self.decode(enum_layout.param_type(), enum_bytes);
+            }
+        }
+
+        self.decode(
+            location.layout.param_type(),
+            bytes_at(location.layout.offset())?,
+        )
+    }
+
     #[cfg(experimental)]
     pub fn experimental_decode(&self, param_type: &ParamType, bytes: &[u8]) -> Result<Token> {
         ExperimentalBoundedDecoder::new(self.config).decode(param_type, bytes)
@@ -103,6 +389,8 @@ mod tests {
 
     use ParamType::*;
 
+    use fuel_abi_types::abi::program::TypeApplication;
+
     use super::*;
     use crate::{
         constants::WORD_SIZE,
@@ -247,6 +535,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn decode_array_of_arrays_of_structs() -> Result<()> {
+        // struct MyStruct {
+        //     foo: u64,
+        //     bar: bool,
+        // }
+        // [[MyStruct; 2]; 2]
+        let my_struct = ParamType::Struct {
+            fields: vec![ParamType::U64, ParamType::Bool],
+            generics: vec![],
+        };
+        let param_type = ParamType::Array(Box::new(ParamType::Array(Box::new(my_struct), 2)), 2);
+        let data = [
+            0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x1, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, // [0][0]
+            0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x2, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, // [0][1]
+            0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x3, 0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, // [1][0]
+            0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+            0x0, // [1][1]
+        ];
+
+        let decoded = ABIDecoder::default().decode(&param_type, &data)?;
+
+        let my_struct = |foo, bar| Token::Struct(vec![Token::U64(foo), Token::Bool(bar)]);
+        let expected = Token::Array(vec![
+            Token::Array(vec![my_struct(1, true), my_struct(2, false)]),
+            Token::Array(vec![my_struct(3, true), my_struct(4, false)]),
+        ]);
+        assert_eq!(decoded, expected);
+        param_type.validate_array_shape(&decoded)?;
+
+        Ok(())
+    }
+
     #[test]
     fn decode_struct() -> Result<()> {
         // struct MyStruct {
@@ -283,6 +607,166 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn tolerant_decoding_reports_where_decoding_failed() {
+        // struct { a: u64, b: [str[2]; 2], c: u64 }
+        let param_type = ParamType::Struct {
+            fields: vec![
+                ParamType::U64,
+                ParamType::Array(Box::new(ParamType::StringArray(2)), 2),
+                ParamType::U64,
+            ],
+            generics: vec![],
+        };
+        let valid_bytes = [
+            [0, 0, 0, 0, 0, 0, 0, 7],
+            [b'a', b'b', 0, 0, 0, 0, 0, 0],
+            [b'c', b'd', 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 9],
+        ]
+        .concat();
+        let decoder = ABIDecoder::default();
+        let str_token =
+            |value: &str| Token::StringArray(StaticStringToken::new(value.into(), Some(2)));
+
+        let partial = decoder.decode_tolerant(&param_type, &valid_bytes);
+
+        assert!(partial.is_complete());
+        assert_eq!(
+            partial.decoded,
+            vec![
+                ("value.0".to_string(), Token::U64(7)),
+                ("value.1[0]".to_string(), str_token("ab")),
+                ("value.1[1]".to_string(), str_token("cd")),
+                ("value.2".to_string(), Token::U64(9)),
+            ]
+        );
+
+        let mut invalid_bytes = valid_bytes.clone();
+        invalid_bytes[16] = 0xFF;
+
+        let partial = decoder.decode_tolerant(&param_type, &invalid_bytes);
+
+        assert_eq!(
+            partial.decoded,
+            vec![
+                ("value.0".to_string(), Token::U64(7)),
+                ("value.1[0]".to_string(), str_token("ab")),
+            ]
+        );
+        let failure = partial.failure.expect("should fail");
+        assert_eq!(failure.path, "value.1[1]");
+        assert_eq!(failure.byte_range, 16..24);
+        assert!(failure.reason.contains("invalid utf-8"));
+
+        let partial = decoder.decode_tolerant(&param_type, &valid_bytes[..28]);
+
+        assert_eq!(partial.decoded.len(), 3);
+        let failure = partial.failure.expect("should fail");
+        assert_eq!(failure.path, "value.2");
+        assert_eq!(failure.byte_range, 24..32);
+    }
+
+    #[test]
+    fn tolerant_decoding_respects_the_limits() {
+        let config = DecoderConfig {
+            max_depth: 2,
+            max_tokens: 3,
+            ..Default::default()
+        };
+        let decoder = ABIDecoder::new(config);
+
+        let too_many_tokens = ParamType::Array(Box::new(ParamType::U64), 10);
+        let partial = decoder.decode_tolerant(&too_many_tokens, &[0; 10 * WORD_SIZE]);
+
+        assert_eq!(
+            partial.decoded,
+            vec![
+                ("value[0]".to_string(), Token::U64(0)),
+                ("value[1]".to_string(), Token::U64(0)),
+            ]
+        );
+        let failure = partial.failure.expect("should fail");
+        assert_eq!(failure.path, "value[2]");
+        assert!(failure.reason.contains("token limit `3` reached"));
+
+        let u8_array = |inner| ParamType::Array(Box::new(inner), 1);
+        let too_deep = u8_array(u8_array(u8_array(ParamType::U8)));
+        let partial = decoder.decode_tolerant(&too_deep, &[0; WORD_SIZE]);
+
+        assert!(partial.decoded.is_empty());
+        let failure = partial.failure.expect("should fail");
+        assert_eq!(failure.path, "value[0][0]");
+        assert!(failure.reason.contains("depth limit `2` reached"));
+    }
+
+    #[test]
+    fn decode_heap_elements() -> Result<()> {
+        let decoder = ABIDecoder::default();
+        let words = |words: &[u64]| {
+            words
+                .iter()
+                .flat_map(|w| w.to_be_bytes())
+                .collect::<Vec<_>>()
+        };
+
+        let vec_of_vecs = ParamType::Vector(Box::new(ParamType::Vector(Box::new(ParamType::U64))));
+        let decoded =
+            decoder.decode_heap_elements(&vec_of_vecs, &[words(&[1, 2]), vec![], words(&[3])])?;
+
+        let vector =
+            |values: &[u64]| Token::Vector(values.iter().copied().map(Token::U64).collect());
+        let expected = Token::Vector(vec![vector(&[1, 2]), vector(&[]), vector(&[3])]);
+        assert_eq!(decoded, expected);
+
+        let vec_of_bytes = ParamType::Vector(Box::new(ParamType::Bytes));
+        let decoded = decoder.decode_heap_elements(&vec_of_bytes, &[vec![1, 2, 3], vec![4]])?;
+
+        let expected = Token::Vector(vec![Token::Bytes(vec![1, 2, 3]), Token::Bytes(vec![4])]);
+        assert_eq!(decoded, expected);
+
+        let err = decoder
+            .decode_heap_elements(&ParamType::Vector(Box::new(ParamType::U64)), &[])
+            .expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "codec: `Vector(U64)` is not a vector whose elements are heap types"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_heap_type_in_enum() -> Result<()> {
+        // Option<Bytes>
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::Bytes])?;
+        let param_type = ParamType::Enum {
+            variants: variants.clone(),
+            generics: vec![ParamType::Bytes],
+        };
+
+        // the discriminant and the (ptr, cap, len) of the bytes, followed by the bytes themselves
+        let some = [
+            [0, 0, 0, 0, 0, 0, 0, 1],
+            [0; 8],
+            [0; 8],
+            [0, 0, 0, 0, 0, 0, 0, 3],
+        ]
+        .concat();
+        let decoded = ABIDecoder::default().decode(&param_type, &[some, vec![7, 8, 9]].concat())?;
+
+        let expected = Token::Enum(Box::new((1, Token::Bytes(vec![7, 8, 9]), variants.clone())));
+        assert_eq!(decoded, expected);
+
+        let none = [0; 32];
+        let decoded = ABIDecoder::default().decode(&param_type, &none)?;
+
+        let expected = Token::Enum(Box::new((0, Token::Unit, variants)));
+        assert_eq!(decoded, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn decode_enum() -> Result<()> {
         // enum MyEnum {
@@ -618,38 +1102,45 @@ mod tests {
     }
 
     #[test]
-    fn decoding_enum_with_more_than_one_heap_type_variant_fails() -> Result<()> {
-        let mut param_types = vec![
+    fn decoding_enum_with_more_than_one_heap_type_variant() -> Result<()> {
+        let variants = EnumVariants::new(vec![
             ParamType::U64,
             ParamType::Bool,
             ParamType::Vector(Box::from(ParamType::U64)),
-        ];
-        // empty data
-        let data = [
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        let variants = EnumVariants::new(param_types.clone())?;
+            ParamType::Bytes,
+        ])?;
         let enum_param_type = ParamType::Enum {
-            variants,
+            variants: variants.clone(),
             generics: vec![],
         };
-        // it works if there is only one heap type
-        let _ = ABIDecoder::default().decode(&enum_param_type, &data)?;
-
-        param_types.append(&mut vec![ParamType::Bytes]);
-        let variants = EnumVariants::new(param_types)?;
-        let enum_param_type = ParamType::Enum {
-            variants,
-            generics: vec![],
+        // the discriminant, the (ptr, cap, len) of the variant and the heap data returned in a
+        // receipt of its own
+        let encoded = |discriminant: u8, heap_data: &[u8]| {
+            [&[0, 0, 0, 0, 0, 0, 0, discriminant], &[0; 24], heap_data].concat()
         };
-        // fails if there is more than one variant using heap type in the enum
-        let error = ABIDecoder::default()
-            .decode(&enum_param_type, &data)
-            .expect_err("should fail");
-        let expected_error =
-            "codec: enums currently support only one heap-type variant. Found: 2".to_string();
-        assert_eq!(error.to_string(), expected_error);
+
+        let decoded = ABIDecoder::default()
+            .decode(&enum_param_type, &encoded(2, &[0, 0, 0, 0, 0, 0, 0, 5]))?;
+        assert_eq!(
+            decoded,
+            Token::Enum(Box::new((
+                2,
+                Token::Vector(vec![Token::U64(5)]),
+                variants.clone()
+            )))
+        );
+
+        let decoded = ABIDecoder::default().decode(&enum_param_type, &encoded(3, &[1, 2, 3]))?;
+        assert_eq!(
+            decoded,
+            Token::Enum(Box::new((3, Token::Bytes(vec![1, 2, 3]), variants.clone())))
+        );
+
+        // variants that aren't heap types have no extra data
+        let mut data = encoded(0, &[]);
+        data[31] = 7;
+        let decoded = ABIDecoder::default().decode(&enum_param_type, &data)?;
+        assert_eq!(decoded, Token::Enum(Box::new((0, Token::U64(7), variants))));
 
         Ok(())
     }
@@ -683,6 +1174,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn max_array_length_surpassed() {
+        let config = DecoderConfig {
+            max_array_length: 3,
+            ..Default::default()
+        };
+        let data = [0; 4 * WORD_SIZE];
+
+        let decoded = ABIDecoder::new(config)
+            .decode(&Array(Box::new(U64), 3), &data)
+            .unwrap();
+        assert_eq!(decoded, Token::Array(vec![Token::U64(0); 3]));
+
+        let err = ABIDecoder::new(config)
+            .decode(&Array(Box::new(U64), 4), &data)
+            .expect_err("should have failed");
+        assert_eq!(
+            err.to_string(),
+            "codec: array of 4 elements exceeds the maximum array length of 3. Try increasing it"
+        );
+
+        // the limit applies regardless of how many bytes there are
+        let err = ABIDecoder::default()
+            .decode(&Array(Box::new(U8), usize::MAX / WORD_SIZE), &[])
+            .expect_err("should have failed");
+        assert!(err
+            .to_string()
+            .contains("exceeds the maximum array length of 10000"));
+    }
+
+    #[test]
+    fn arrays_missing_elements_report_how_many_are_left() {
+        let err = ABIDecoder::default()
+            .decode(&Array(Box::new(U64), 3), &[0; 2 * WORD_SIZE + 1])
+            .expect_err("should have failed");
+
+        assert_eq!(
+            err.to_string(),
+            "codec: array declares 3 `U64` elements (24 bytes) but only 17 bytes, enough for 2 \
+            elements, are left"
+        );
+    }
+
     #[test]
     fn max_depth_surpassed() {
         const MAX_DEPTH: usize = 2;
@@ -812,6 +1346,349 @@ mod tests {
         assert_eq!(actual_msg, msg);
     }
 
+    #[test]
+    fn decodes_fn_inputs_and_output() -> Result<()> {
+        let type_application = |type_id| TypeApplication {
+            name: "".to_string(),
+            type_id,
+            type_arguments: None,
+        };
+        let type_lookup = [(0, "u8"), (1, "bool"), (2, "u64")]
+            .into_iter()
+            .map(|(type_id, type_field)| {
+                let declaration = TypeDeclaration {
+                    type_id,
+                    type_field: type_field.to_string(),
+                    components: None,
+                    type_parameters: None,
+                };
+                (type_id, declaration)
+            })
+            .collect::<HashMap<_, _>>();
+        let function = ABIFunction {
+            inputs: vec![type_application(0), type_application(1)],
+            name: "some_fn".to_string(),
+            output: type_application(2),
+            attributes: None,
+        };
+        let decoder = ABIDecoder::default();
+
+        let inputs = decoder.decode_fn(
+            &function,
+            &type_lookup,
+            DecodeTarget::Inputs,
+            &[7, 0, 0, 0, 0, 0, 0, 0, 1],
+        )?;
+        let output = decoder.decode_fn(
+            &function,
+            &type_lookup,
+            DecodeTarget::Output,
+            &[0, 0, 0, 0, 0, 0, 0, 42],
+        )?;
+
+        assert_eq!(inputs, vec![Token::U8(7), Token::Bool(true)]);
+        assert_eq!(output, vec![Token::U64(42)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flattens_returned_tuples() -> Result<()> {
+        let u64_application = TypeApplication {
+            name: "__tuple_element".to_string(),
+            type_id: 1,
+            type_arguments: None,
+        };
+        let declarations = [
+            TypeDeclaration {
+                type_id: 0,
+                type_field: "(_, _)".to_string(),
+                components: Some(vec![u64_application.clone(), u64_application]),
+                type_parameters: None,
+            },
+            TypeDeclaration {
+                type_id: 1,
+                type_field: "u64".to_string(),
+                components: None,
+                type_parameters: None,
+            },
+        ];
+        let type_lookup = declarations
+            .into_iter()
+            .map(|decl| (decl.type_id, decl))
+            .collect::<HashMap<_, _>>();
+        let function = ABIFunction {
+            inputs: vec![],
+            name: "main".to_string(),
+            output: TypeApplication {
+                name: "".to_string(),
+                type_id: 0,
+                type_arguments: None,
+            },
+            attributes: None,
+        };
+        let data = [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2];
+        let decoder = ABIDecoder::default();
+
+        let output = decoder.decode_fn(&function, &type_lookup, DecodeTarget::Output, &data)?;
+        let flattened = decoder.decode_fn(
+            &function,
+            &type_lookup,
+            DecodeTarget::FlattenedOutput,
+            &data,
+        )?;
+
+        assert_eq!(
+            output,
+            vec![Token::Tuple(vec![Token::U64(1), Token::U64(2)])]
+        );
+        assert_eq!(flattened, vec![Token::U64(1), Token::U64(2)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn names_flattened_output_elements() -> Result<()> {
+        let application = |name: &str, type_id| TypeApplication {
+            name: name.to_string(),
+            type_id,
+            type_arguments: None,
+        };
+        let declarations = [
+            TypeDeclaration {
+                type_id: 0,
+                type_field: "(_, _)".to_string(),
+                components: Some(vec![
+                    application("__tuple_element", 1),
+                    application("amount", 1),
+                ]),
+                type_parameters: None,
+            },
+            TypeDeclaration {
+                type_id: 1,
+                type_field: "u64".to_string(),
+                components: None,
+                type_parameters: None,
+            },
+        ];
+        let type_lookup = declarations
+            .into_iter()
+            .map(|decl| (decl.type_id, decl))
+            .collect::<HashMap<_, _>>();
+        let function = |output_type_id| ABIFunction {
+            inputs: vec![],
+            name: "main".to_string(),
+            output: application("", output_type_id),
+            attributes: None,
+        };
+        let data = [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2];
+        let decoder = ABIDecoder::default();
+
+        let tuple = decoder.decode_fn_named_output(&function(0), &type_lookup, &data)?;
+        let single = decoder.decode_fn_named_output(&function(1), &type_lookup, &data[..8])?;
+
+        assert_eq!(
+            tuple,
+            vec![
+                ("0".to_string(), Token::U64(1)),
+                ("amount".to_string(), Token::U64(2))
+            ]
+        );
+        assert_eq!(single, vec![("value".to_string(), Token::U64(1))]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decodes_single_output_fields() -> Result<()> {
+        let type_application = |name: &str, type_id| TypeApplication {
+            name: name.to_string(),
+            type_id,
+            type_arguments: None,
+        };
+        // struct Balance { id: u8, amount: u64 }
+        // struct Inner { flag: bool, balances: [Balance; 2] }
+        // struct Outer { count: u32, inner: Inner, pair: (u8, u64) }
+        let declarations = [
+            (0, "u8", None),
+            (1, "bool", None),
+            (2, "u32", None),
+            (3, "u64", None),
+            (
+                4,
+                "struct Balance",
+                Some(vec![
+                    type_application("id", 0),
+                    type_application("amount", 3),
+                ]),
+            ),
+            (
+                5,
+                "[_; 2]",
+                Some(vec![type_application("__array_element", 4)]),
+            ),
+            (
+                6,
+                "struct Inner",
+                Some(vec![
+                    type_application("flag", 1),
+                    type_application("balances", 5),
+                ]),
+            ),
+            (
+                7,
+                "(_, _)",
+                Some(vec![
+                    type_application("__tuple_element", 0),
+                    type_application("__tuple_element", 3),
+                ]),
+            ),
+            (
+                8,
+                "struct Outer",
+                Some(vec![
+                    type_application("count", 2),
+                    type_application("inner", 6),
+                    type_application("pair", 7),
+                ]),
+            ),
+        ];
+        let type_lookup = declarations
+            .into_iter()
+            .map(|(type_id, type_field, components)| {
+                let declaration = TypeDeclaration {
+                    type_id,
+                    type_field: type_field.to_string(),
+                    components,
+                    type_parameters: None,
+                };
+                (type_id, declaration)
+            })
+            .collect::<HashMap<_, _>>();
+        let function = ABIFunction {
+            inputs: vec![],
+            name: "get_outer".to_string(),
+            output: type_application("", 8),
+            attributes: None,
+        };
+        // single byte values are left aligned in their word, the others are big endian
+        let byte = |value: u8| [value, 0, 0, 0, 0, 0, 0, 0];
+        let word = |value: u8| [0, 0, 0, 0, 0, 0, 0, value];
+        let data = [
+            word(1), // count
+            byte(1), // inner.flag
+            byte(2), // inner.balances[0].id
+            word(3), // inner.balances[0].amount
+            byte(4), // inner.balances[1].id
+            word(5), // inner.balances[1].amount
+            byte(6), // pair.0
+            word(7), // pair.1
+        ]
+        .concat();
+        let decoder = ABIDecoder::default();
+
+        let decode_field = |path| decoder.decode_field(&function, &type_lookup, &data, path);
+
+        assert_eq!(decode_field("count")?, Token::U32(1));
+        assert_eq!(decode_field("inner.balances[1].amount")?, Token::U64(5));
+        assert_eq!(
+            decode_field("inner.balances[0]")?,
+            Token::Struct(vec![Token::U8(2), Token::U64(3)])
+        );
+        assert_eq!(decode_field("pair.1")?, Token::U64(7));
+
+        let err = decode_field("inner.balances[2]").expect_err("index is out of bounds");
+        assert!(matches!(err, Error::Codec(_)));
+
+        let err = decode_field("inner.missing").expect_err("field doesn't exist");
+        assert_eq!(
+            err.to_string(),
+            "codec: `struct Inner` has no field `missing`"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fields_of_a_none_option_are_not_read_from_the_padding() -> Result<()> {
+        let type_application = |name: &str, type_id| TypeApplication {
+            name: name.to_string(),
+            type_id,
+            type_arguments: None,
+        };
+        // struct Balance { id: u8, amount: u64 }
+        // fn get_balance() -> Option<Balance>
+        let declarations = [
+            (0, "u8", None, None),
+            (1, "u64", None, None),
+            (
+                2,
+                "struct Balance",
+                Some(vec![
+                    type_application("id", 0),
+                    type_application("amount", 1),
+                ]),
+                None,
+            ),
+            (3, "()", None, None),
+            (4, "generic T", None, None),
+            (
+                5,
+                "enum Option",
+                Some(vec![
+                    type_application("None", 3),
+                    type_application("Some", 4),
+                ]),
+                Some(vec![4]),
+            ),
+        ];
+        let type_lookup = declarations
+            .into_iter()
+            .map(|(type_id, type_field, components, type_parameters)| {
+                let declaration = TypeDeclaration {
+                    type_id,
+                    type_field: type_field.to_string(),
+                    components,
+                    type_parameters,
+                };
+                (type_id, declaration)
+            })
+            .collect::<HashMap<_, _>>();
+        let function = ABIFunction {
+            inputs: vec![],
+            name: "get_balance".to_string(),
+            output: TypeApplication {
+                type_arguments: Some(vec![type_application("", 2)]),
+                ..type_application("", 5)
+            },
+            attributes: None,
+        };
+        let word = |value: u8| [0, 0, 0, 0, 0, 0, 0, value];
+        let some = [word(1), [2, 0, 0, 0, 0, 0, 0, 0], word(3)].concat();
+        let none = [word(0), [0; 8], [0; 8]].concat();
+        let decoder = ABIDecoder::default();
+
+        let decode_field =
+            |data: &[u8], path| decoder.decode_field(&function, &type_lookup, data, path);
+
+        assert_eq!(decode_field(&some, "Some.amount")?, Token::U64(3));
+        assert_eq!(
+            decode_field(&some, "Some")?,
+            Token::Struct(vec![Token::U8(2), Token::U64(3)])
+        );
+
+        let token = decode_field(&none, "Some.amount")?;
+        assert!(
+            matches!(&token, Token::Enum(selector) if selector.0 == 0 && selector.1 == Token::Unit)
+        );
+        assert_eq!(token, decode_field(&none, "")?);
+
+        let err = decode_field(&none, "Ok").expect_err("should not be a variant");
+        assert_eq!(err.to_string(), "codec: `enum Option` has no variant `Ok`");
+
+        Ok(())
+    }
+
     fn nested_struct(depth: usize) -> ParamType {
         let fields = if depth == 1 {
             vec![]
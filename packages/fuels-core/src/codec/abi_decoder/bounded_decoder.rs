@@ -4,12 +4,12 @@ use crate::{
     checked_round_up_to_word_alignment,
     codec::{
         utils::{CodecDirection, CounterWithLimit},
-        DecoderConfig,
+        DecoderConfig, DecodingFailure, PartialDecoding, WordCodec,
     },
     constants::WORD_SIZE,
     types::{
         enum_variants::EnumVariants,
-        errors::{error, Result},
+        errors::{error, Error, Result},
         param_types::ParamType,
         StaticStringToken, Token, U256,
     },
@@ -74,6 +74,154 @@ impl BoundedDecoder {
         Ok(tokens)
     }
 
+    pub(crate) fn decode_tolerant(
+        &mut self,
+        param_type: &ParamType,
+        bytes: &[u8],
+    ) -> PartialDecoding {
+        let path = "value";
+        let mut decoded = vec![];
+
+        let failure = match param_type {
+            ParamType::Struct { .. } | ParamType::Tuple(_) | ParamType::Array(..) => self
+                .decode_tolerant_at(param_type, bytes, 0, path, &mut decoded)
+                .err(),
+            // top-level values are decoded differently, e.g. a `u8` is returned as a whole word
+            _ => match self.decode(param_type, bytes) {
+                Ok(token) => {
+                    decoded.push((path.to_string(), token));
+                    None
+                }
+                Err(err) => Some(DecodingFailure {
+                    path: path.to_string(),
+                    byte_range: 0..bytes.len(),
+                    reason: err.to_string(),
+                }),
+            },
+        };
+
+        PartialDecoding { decoded, failure }
+    }
+
+    /// Decodes the elements of structs, tuples and arrays one by one, stopping at the first one
+    /// that fails to decode. Returns the number of bytes read. Containers count against the
+    /// depth and token limits the same way they do when decoded as a whole.
+    fn decode_tolerant_at(
+        &mut self,
+        param_type: &ParamType,
+        bytes: &[u8],
+        offset: usize,
+        path: &str,
+        decoded: &mut Vec<(String, Token)>,
+    ) -> std::result::Result<usize, DecodingFailure> {
+        let failure = |err: Error| tolerant_failure(param_type, offset, path, err);
+
+        match param_type {
+            ParamType::Struct { .. } | ParamType::Tuple(_) | ParamType::Array(..) => {
+                self.token_tracker.increase().map_err(failure)?;
+                self.depth_tracker.increase().map_err(failure)?;
+
+                let res = self.decode_tolerant_elements(param_type, bytes, offset, path, decoded);
+
+                self.depth_tracker.decrease();
+                res
+            }
+            _ => {
+                let res = skip(bytes, offset)
+                    .and_then(|bytes| self.decode_param(param_type, bytes))
+                    .map_err(failure)?;
+                decoded.push((path.to_string(), res.token));
+
+                Ok(res.bytes_read)
+            }
+        }
+    }
+
+    fn decode_tolerant_elements(
+        &mut self,
+        param_type: &ParamType,
+        bytes: &[u8],
+        offset: usize,
+        path: &str,
+        decoded: &mut Vec<(String, Token)>,
+    ) -> std::result::Result<usize, DecodingFailure> {
+        let failure = |err: Error| tolerant_failure(param_type, offset, path, err);
+
+        match param_type {
+            ParamType::Struct {
+                fields: param_types,
+                ..
+            }
+            | ParamType::Tuple(param_types) => {
+                let mut bytes_read = 0;
+                for (index, param_type) in param_types.iter().enumerate() {
+                    // padding has to be taken into account
+                    bytes_read = checked_round_up_to_word_alignment(bytes_read).map_err(failure)?;
+                    bytes_read += self.decode_tolerant_at(
+                        param_type,
+                        bytes,
+                        offset + bytes_read,
+                        &format!("{path}.{index}"),
+                        decoded,
+                    )?;
+                }
+
+                Ok(bytes_read)
+            }
+            ParamType::Array(param_type, length) => {
+                self.check_array_length(*length).map_err(failure)?;
+
+                let mut bytes_read = 0;
+                for index in 0..*length {
+                    bytes_read += self.decode_tolerant_at(
+                        param_type,
+                        bytes,
+                        offset + bytes_read,
+                        &format!("{path}[{index}]"),
+                        decoded,
+                    )?;
+                }
+
+                Ok(bytes_read)
+            }
+            _ => unreachable!("only structs, tuples and arrays have elements"),
+        }
+    }
+
+    pub(crate) fn decode_heap_elements(
+        &mut self,
+        element_type: &ParamType,
+        elements: &[Vec<u8>],
+    ) -> Result<Token> {
+        self.token_tracker.increase()?;
+        let decoded = self.run_w_depth_tracking(|ctx| {
+            let tokens = elements
+                .iter()
+                .map(|bytes| ctx.decode_param(element_type, bytes).map(|res| res.token))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Decoded {
+                token: Token::Vector(tokens),
+                bytes_read: elements.iter().map(Vec::len).sum(),
+            })
+        })?;
+
+        Ok(decoded.token)
+    }
+
+    fn check_array_length(&self, length: usize) -> Result<()> {
+        let max_array_length = self.config.max_array_length;
+        if length > max_array_length {
+            return Err(error!(
+                Codec,
+                "array of {length} elements exceeds the maximum array length of \
+                    {max_array_length}. Try increasing it"
+            ));
+        }
+
+        Ok(())
+    }
+
     fn run_w_depth_tracking(
         &mut self,
         decoder: impl FnOnce(&mut Self) -> Result<Decoded>,
@@ -210,6 +358,25 @@ impl BoundedDecoder {
         bytes: &[u8],
         length: usize,
     ) -> Result<Decoded> {
+        self.check_array_length(length)?;
+
+        let element_width = param_type.compute_encoding_in_bytes()?;
+        let array_width = element_width.checked_mul(length).ok_or_else(|| {
+            error!(
+                Codec,
+                "array of {length} `{param_type:?}` elements is too large to be decoded"
+            )
+        })?;
+        if array_width > bytes.len() {
+            let available_elements = bytes.len().checked_div(element_width).unwrap_or(length);
+            return Err(error!(
+                Codec,
+                "array declares {length} `{param_type:?}` elements ({array_width} bytes) but only \
+                    {} bytes, enough for {available_elements} elements, are left",
+                bytes.len()
+            ));
+        }
+
         let (tokens, bytes_read) =
             self.decode_params(std::iter::repeat(param_type).take(length), bytes)?;
 
@@ -330,11 +497,11 @@ impl BoundedDecoder {
         let discriminant = peek_u64(bytes)?;
         let selected_variant = variants.param_type_of_variant(discriminant)?;
 
-        let skip_extra_in_bytes = match variants.heap_type_variant() {
-            Some((heap_type_discriminant, heap_type)) if heap_type_discriminant == discriminant => {
-                heap_type.compute_encoding_in_bytes()?
-            }
-            _ => 0,
+        // The data of a heap type variant comes from its own receipt, appended after the enum
+        let skip_extra_in_bytes = if selected_variant.is_extra_receipt_needed(false) {
+            selected_variant.compute_encoding_in_bytes()?
+        } else {
+            0
         };
 
         let bytes_to_skip = enum_width_in_bytes - selected_variant.compute_encoding_in_bytes()?
@@ -375,6 +542,21 @@ struct Decoded {
     bytes_read: usize,
 }
 
+fn tolerant_failure(
+    param_type: &ParamType,
+    offset: usize,
+    path: &str,
+    err: Error,
+) -> DecodingFailure {
+    let expected_len = param_type.compute_encoding_in_bytes().unwrap_or_default();
+
+    DecodingFailure {
+        path: path.to_string(),
+        byte_range: offset..offset.saturating_add(expected_len),
+        reason: err.to_string(),
+    }
+}
+
 fn peek_u128(bytes: &[u8]) -> Result<u128> {
     let slice = peek_fixed::<U128_BYTES_SIZE>(bytes)?;
     Ok(u128::from_be_bytes(*slice))
@@ -387,27 +569,17 @@ fn peek_u256(bytes: &[u8]) -> Result<U256> {
 
 fn peek_u64(bytes: &[u8]) -> Result<u64> {
     let slice = peek_fixed::<WORD_SIZE>(bytes)?;
-    Ok(u64::from_be_bytes(*slice))
+    Ok(WordCodec::decode(*slice))
 }
 
 fn peek_u32(bytes: &[u8]) -> Result<u32> {
-    const BYTES: usize = std::mem::size_of::<u32>();
-
     let slice = peek_fixed::<WORD_SIZE>(bytes)?;
-    let bytes = slice[WORD_SIZE - BYTES..]
-        .try_into()
-        .expect("peek_u32: You must use a slice containing exactly 4B");
-    Ok(u32::from_be_bytes(bytes))
+    Ok(u32::from_be_bytes(WordCodec::right_aligned_value(slice)))
 }
 
 fn peek_u16(bytes: &[u8]) -> Result<u16> {
-    const BYTES: usize = std::mem::size_of::<u16>();
-
     let slice = peek_fixed::<WORD_SIZE>(bytes)?;
-    let bytes = slice[WORD_SIZE - BYTES..]
-        .try_into()
-        .expect("peek_u16: You must use a slice containing exactly 2B");
-    Ok(u16::from_be_bytes(bytes))
+    Ok(u16::from_be_bytes(WordCodec::right_aligned_value(slice)))
 }
 
 fn peek_u8(bytes: &[u8]) -> Result<u8> {
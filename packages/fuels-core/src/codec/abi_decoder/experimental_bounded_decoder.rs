@@ -16,6 +16,7 @@ use crate::{
 pub(crate) struct ExperimentalBoundedDecoder {
     depth_tracker: CounterWithLimit,
     token_tracker: CounterWithLimit,
+    max_array_length: usize,
 }
 
 const U8_BYTES_SIZE: usize = 1;
@@ -35,6 +36,7 @@ impl ExperimentalBoundedDecoder {
         Self {
             depth_tracker,
             token_tracker,
+            max_array_length: config.max_array_length,
         }
     }
 
@@ -230,6 +232,15 @@ impl ExperimentalBoundedDecoder {
         bytes: &[u8],
         length: usize,
     ) -> Result<Decoded> {
+        if length > self.max_array_length {
+            return Err(error!(
+                Codec,
+                "array of {length} elements exceeds the maximum array length of {}. Try \
+                    increasing it",
+                self.max_array_length
+            ));
+        }
+
         let (tokens, bytes_read) = self.decode_params(repeat(param_type).take(length), bytes)?;
 
         Ok(Decoded {
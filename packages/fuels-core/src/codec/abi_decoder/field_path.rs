@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use fuel_abi_types::abi::program::{TypeApplication, TypeDeclaration};
+
+use crate::types::{
+    errors::{error, Result},
+    param_types::{parse_field_path, ParamType, PathSegment, TypeLayout},
+};
+
+/// Where a field is placed in an encoded value, see [`locate_field`].
+#[derive(Debug)]
+pub(crate) struct FieldLocation {
+    pub layout: TypeLayout,
+    /// The enums the path goes through, with the discriminant of the variant it takes in each.
+    /// The field is only present if every enum holds that variant.
+    pub variants: Vec<(TypeLayout, u64)>,
+}
+
+/// Walks `path` starting from `root`, returning the location of the field found at the end of
+/// it. Struct fields and enum variants are resolved by name using the ABI. Only types with a
+/// static layout can be walked.
+pub(crate) fn locate_field(
+    root: &TypeApplication,
+    root_type: &ParamType,
+    type_lookup: &HashMap<usize, TypeDeclaration>,
+    path: &str,
+) -> Result<FieldLocation> {
+    if root_type.is_extra_receipt_needed(true) {
+        return Err(error!(
+            Codec,
+            "cannot decode fields of `{root_type:?}`: its layout is not static"
+        ));
+    }
+
+    let mut layout = root_type.layout()?;
+    let mut application = root.clone();
+    let mut generics: HashMap<usize, TypeApplication> = HashMap::new();
+    let mut variants = vec![];
+
+    for segment in parse_field_path(path)? {
+        let declaration = type_lookup.get(&application.type_id).ok_or_else(|| {
+            error!(
+                Codec,
+                "type id {} not found in the ABI", application.type_id
+            )
+        })?;
+        let components = declaration.components.as_deref().unwrap_or_default();
+
+        let (field_index, component_index) = match (layout.param_type(), &segment) {
+            (ParamType::Struct { .. }, PathSegment::Field(name)) => {
+                let index = components
+                    .iter()
+                    .position(|component| component.name == *name)
+                    .ok_or_else(|| {
+                        error!(Codec, "`{}` has no field `{name}`", declaration.type_field)
+                    })?;
+
+                (index, index)
+            }
+            (ParamType::Enum { .. }, PathSegment::Field(name)) => {
+                let index = components
+                    .iter()
+                    .position(|component| component.name == *name)
+                    .ok_or_else(|| {
+                        error!(
+                            Codec,
+                            "`{}` has no variant `{name}`", declaration.type_field
+                        )
+                    })?;
+                variants.push((layout.clone(), index as u64));
+
+                (index, index)
+            }
+            (ParamType::Tuple(_), PathSegment::Index(index)) => (*index, *index),
+            (ParamType::Array(..), PathSegment::Index(index)) => (*index, 0),
+            _ => {
+                return Err(error!(
+                    Codec,
+                    "cannot access `{segment:?}` of `{}` in `{path}`", declaration.type_field
+                ))
+            }
+        };
+
+        layout = layout.child(field_index)?.ok_or_else(|| {
+            error!(
+                Codec,
+                "`{}` has no element at index {field_index} in `{path}`", declaration.type_field
+            )
+        })?;
+
+        // the type arguments of this application are what its type parameters stand for
+        let type_parameters = declaration.type_parameters.as_deref().unwrap_or_default();
+        let type_arguments = application.type_arguments.as_deref().unwrap_or_default();
+        for (parameter, argument) in type_parameters.iter().zip(type_arguments) {
+            let argument = generics.get(&argument.type_id).unwrap_or(argument).clone();
+            generics.insert(*parameter, argument);
+        }
+
+        let component = components
+            .get(component_index)
+            .ok_or_else(|| error!(Codec, "`{}` has no components", declaration.type_field))?;
+        application = generics
+            .get(&component.type_id)
+            .unwrap_or(component)
+            .clone();
+    }
+
+    Ok(FieldLocation { layout, variants })
+}
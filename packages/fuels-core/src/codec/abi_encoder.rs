@@ -17,6 +17,8 @@ pub struct EncoderConfig {
     /// The total memory size of the top-level token must fit in the available memory of the
     /// system.
     pub max_total_enum_width: usize,
+    /// Which strings can be encoded as string slices and string arrays.
+    pub string_policy: StringPolicy,
 }
 
 // ANCHOR: default_encoder_config
@@ -26,11 +28,24 @@ impl Default for EncoderConfig {
             max_depth: 45,
             max_tokens: 10_000,
             max_total_enum_width: 10_000,
+            string_policy: StringPolicy::Ascii,
         }
     }
 }
 // ANCHOR_END: default_encoder_config
 
+/// Sway string slices (`str`) and arrays (`str[N]`) hold raw bytes, the SDK encodes the bytes
+/// of the given string as they are. `std::string::String` values aren't affected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StringPolicy {
+    /// Only ASCII strings are encoded, so that every character takes a single byte.
+    #[default]
+    Ascii,
+    /// Any string is encoded as its UTF-8 bytes. A `str[N]` then takes strings of `N` bytes,
+    /// which can be fewer than `N` characters.
+    Utf8,
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct ABIEncoder {
     pub config: EncoderConfig,
@@ -551,6 +566,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn enum_variant_must_be_encoded_in_its_declared_width() {
+        let variants = EnumVariants::new(vec![ParamType::U64, ParamType::Bool]).unwrap();
+        let mismatched_token = Token::Enum(Box::new((0, Token::Bool(true), variants)));
+
+        let error = ABIEncoder::default()
+            .encode(&[mismatched_token])
+            .expect_err("should fail");
+
+        assert!(matches!(error, Error::Codec(_)));
+        assert!(error
+            .to_string()
+            .contains("enum variant `U64` was encoded in 1 bytes, expected 8"));
+    }
+
+    #[cfg(any(debug_assertions, feature = "encoding-checks"))]
+    #[test]
+    fn array_elements_must_be_encoded_in_the_same_width() {
+        let mismatched_array = Token::Array(vec![Token::U8(1), Token::U64(2)]);
+
+        let error = ABIEncoder::default()
+            .encode(&[mismatched_array])
+            .expect_err("should fail");
+
+        assert!(matches!(error, Error::Codec(_)));
+        assert!(error
+            .to_string()
+            .contains("array elements must all be encoded in the same width"));
+    }
+
     #[test]
     fn encoding_enums_with_deeply_nested_types() -> Result<()> {
         /*
@@ -1072,6 +1117,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn string_policy_decides_whether_utf8_strings_are_encoded() -> Result<()> {
+        // "é" takes two bytes
+        let token = Token::StringArray(StaticStringToken::new("café".to_string(), Some(5)));
+
+        let err = ABIEncoder::default()
+            .encode(&[token.clone()])
+            .expect_err("should reject non-ascii strings by default");
+        assert!(err
+            .to_string()
+            .contains("can only have ascii values under `StringPolicy::Ascii`"));
+
+        let utf8_encoder = ABIEncoder::new(EncoderConfig {
+            string_policy: StringPolicy::Utf8,
+            ..Default::default()
+        });
+        let encoded = utf8_encoder.encode(&[token])?.resolve(0);
+        assert_eq!(encoded, [b"caf\xc3\xa9".as_slice(), &[0, 0, 0]].concat());
+
+        let too_long = Token::StringArray(StaticStringToken::new("café".to_string(), Some(4)));
+        utf8_encoder
+            .encode(&[too_long])
+            .expect_err("the length is counted in bytes");
+
+        Ok(())
+    }
+
     #[test]
     fn encoding_large_unsigned_integers() -> Result<()> {
         let token = Token::U128(u128::MAX);
@@ -1177,4 +1249,85 @@ mod tests {
 
         Token::Tuple(fields)
     }
+
+    mod encoding_width {
+        use proptest::{collection::vec, prelude::*, sample::Index};
+
+        use super::*;
+
+        /// Types of a static size together with a token of that type.
+        fn static_type_and_token() -> impl Strategy<Value = (ParamType, Token)> {
+            let leaf = prop_oneof![
+                Just((ParamType::Unit, Token::Unit)),
+                any::<bool>().prop_map(|value| (ParamType::Bool, Token::Bool(value))),
+                any::<u8>().prop_map(|value| (ParamType::U8, Token::U8(value))),
+                any::<u16>().prop_map(|value| (ParamType::U16, Token::U16(value))),
+                any::<u32>().prop_map(|value| (ParamType::U32, Token::U32(value))),
+                any::<u64>().prop_map(|value| (ParamType::U64, Token::U64(value))),
+                any::<u128>().prop_map(|value| (ParamType::U128, Token::U128(value))),
+                any::<[u8; 32]>().prop_map(|value| (ParamType::B256, Token::B256(value))),
+                "[a-z]{0,9}".prop_map(|string| {
+                    let len = string.len();
+                    (
+                        ParamType::StringArray(len),
+                        Token::StringArray(StaticStringToken::new(string, Some(len))),
+                    )
+                }),
+            ];
+
+            leaf.prop_recursive(4, 32, 4, |inner| {
+                prop_oneof![
+                    vec(inner.clone(), 0..4).prop_map(|elements| {
+                        let (types, tokens) = elements.into_iter().unzip();
+                        (ParamType::Tuple(types), Token::Tuple(tokens))
+                    }),
+                    vec(inner.clone(), 0..4).prop_map(|fields| {
+                        let (fields, tokens) = fields.into_iter().unzip();
+                        let param_type = ParamType::Struct {
+                            fields,
+                            generics: vec![],
+                        };
+                        (param_type, Token::Struct(tokens))
+                    }),
+                    (inner.clone(), 0..4usize).prop_map(|((element, token), len)| {
+                        (
+                            ParamType::Array(Box::new(element), len),
+                            Token::Array(vec![token; len]),
+                        )
+                    }),
+                    (vec(inner, 1..4), any::<Index>()).prop_map(|(variants, selected)| {
+                        let discriminant = selected.index(variants.len());
+                        let (types, mut tokens): (Vec<_>, Vec<_>) = variants.into_iter().unzip();
+                        let variants = EnumVariants::new(types).expect("variants are not empty");
+                        let token = tokens.swap_remove(discriminant);
+                        let param_type = ParamType::Enum {
+                            variants: variants.clone(),
+                            generics: vec![],
+                        };
+                        (
+                            param_type,
+                            Token::Enum(Box::new((discriminant as u64, token, variants))),
+                        )
+                    }),
+                ]
+            })
+        }
+
+        proptest! {
+            #[test]
+            fn values_are_encoded_in_the_width_of_their_type(
+                (param_type, token) in static_type_and_token()
+            ) {
+                let encoded = ABIEncoder::default()
+                    .encode(slice::from_ref(&token))
+                    .map_err(|err| TestCaseError::fail(err.to_string()))?
+                    .resolve(0);
+                let width = param_type
+                    .compute_encoding_width()
+                    .map_err(|err| TestCaseError::fail(err.to_string()))?;
+
+                prop_assert_eq!(encoded.len(), width * WORD_SIZE);
+            }
+        }
+    }
 }
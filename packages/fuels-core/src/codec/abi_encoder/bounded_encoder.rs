@@ -4,7 +4,7 @@ use crate::{
     checked_round_up_to_word_alignment,
     codec::{
         utils::{CodecDirection, CounterWithLimit},
-        EncoderConfig,
+        EncoderConfig, StringPolicy, WordCodec,
     },
     error,
     types::{
@@ -19,6 +19,7 @@ pub(crate) struct BoundedEncoder {
     depth_tracker: CounterWithLimit,
     token_tracker: CounterWithLimit,
     max_total_enum_width: usize,
+    string_policy: StringPolicy,
 }
 
 impl BoundedEncoder {
@@ -31,6 +32,7 @@ impl BoundedEncoder {
             depth_tracker,
             token_tracker,
             max_total_enum_width: config.max_total_enum_width,
+            string_policy: config.string_policy,
         }
     }
 
@@ -103,8 +105,8 @@ impl BoundedEncoder {
             Token::Bool(arg_bool) => vec![Self::encode_bool_as_byte(*arg_bool)],
             Token::B256(arg_bits256) => vec![Self::encode_b256(arg_bits256)],
             Token::RawSlice(data) => Self::encode_raw_slice(data.to_vec())?,
-            Token::StringSlice(arg_string) => Self::encode_string_slice(arg_string)?,
-            Token::StringArray(arg_string) => vec![Self::encode_string_array(arg_string)?],
+            Token::StringSlice(arg_string) => self.encode_string_slice(arg_string)?,
+            Token::StringArray(arg_string) => vec![self.encode_string_array(arg_string)?],
             Token::Array(arg_array) => {
                 self.run_w_depth_tracking(|ctx| ctx.encode_array(arg_array))?
             }
@@ -121,6 +123,11 @@ impl BoundedEncoder {
             Token::String(string) => Self::encode_bytes(string.clone().into_bytes())?,
         };
 
+        #[cfg(any(debug_assertions, feature = "encoding-checks"))]
+        if matches!(arg, Token::Array(_) | Token::Struct(_) | Token::Tuple(_)) {
+            check_encoded_width(arg, &encoded_token)?;
+        }
+
         Ok(encoded_token)
     }
 
@@ -149,7 +156,7 @@ impl BoundedEncoder {
     }
 
     fn encode_bool_as_u64(arg_bool: bool) -> Data {
-        Data::Inline(vec![0, 0, 0, 0, 0, 0, 0, u8::from(arg_bool)])
+        Data::Inline(WordCodec::right_aligned(&[u8::from(arg_bool)]).to_vec())
     }
 
     fn encode_u128(arg_u128: u128) -> Data {
@@ -163,7 +170,7 @@ impl BoundedEncoder {
     }
 
     fn encode_u64(arg_u64: u64) -> Data {
-        Data::Inline(arg_u64.to_be_bytes().to_vec())
+        Data::Inline(WordCodec::encode(arg_u64).to_vec())
     }
 
     fn encode_u32(arg_u32: u32) -> Data {
@@ -179,7 +186,7 @@ impl BoundedEncoder {
     }
 
     fn encode_u8_as_u64(arg_u8: u8) -> Data {
-        Data::Inline(vec![0, 0, 0, 0, 0, 0, 0, arg_u8])
+        Data::Inline(WordCodec::right_aligned(&[arg_u8]).to_vec())
     }
 
     fn encode_enum(&mut self, selector: &EnumSelector) -> Result<Vec<Data>> {
@@ -203,6 +210,18 @@ impl BoundedEncoder {
             encoded_enum.push(Data::Inline(vec![0; padding_amount]));
 
             let token_data = self.encode_token(token_within_enum)?;
+
+            // Catches both layout bugs and tokens not matching their variant's type, either
+            // of which would otherwise silently corrupt everything encoded after the enum.
+            let encoded_width: usize = token_data.iter().map(Data::size_in_bytes).sum();
+            let expected_width = variant_param_type.compute_encoding_in_bytes()?;
+            if encoded_width != expected_width {
+                return Err(error!(
+                    Codec,
+                    "enum variant `{variant_param_type:?}` was encoded in {encoded_width} bytes, expected {expected_width}"
+                ));
+            }
+
             encoded_enum.extend(token_data);
         }
 
@@ -243,8 +262,8 @@ impl BoundedEncoder {
         ])
     }
 
-    fn encode_string_slice(arg_string: &StaticStringToken) -> Result<Vec<Data>> {
-        let encodable_str = arg_string.get_encodable_str()?;
+    fn encode_string_slice(&self, arg_string: &StaticStringToken) -> Result<Vec<Data>> {
+        let encodable_str = arg_string.get_encodable_str_with(self.string_policy)?;
 
         let encoded_data = Data::Inline(encodable_str.as_bytes().to_vec());
         let len = Self::encode_u64(encodable_str.len() as u64);
@@ -252,9 +271,9 @@ impl BoundedEncoder {
         Ok(vec![Data::Dynamic(vec![encoded_data]), len])
     }
 
-    fn encode_string_array(arg_string: &StaticStringToken) -> Result<Data> {
+    fn encode_string_array(&self, arg_string: &StaticStringToken) -> Result<Data> {
         Ok(Data::Inline(crate::types::pad_string(
-            arg_string.get_encodable_str()?,
+            arg_string.get_encodable_str_with(self.string_policy)?,
         )))
     }
 
@@ -274,6 +293,81 @@ impl BoundedEncoder {
     }
 }
 
+/// Checks that `token` was encoded in as many bytes as `ParamType::compute_encoding_in_bytes`
+/// expects of its type. Catches layout bugs, e.g. when a new type is added, before they corrupt
+/// whatever is encoded after the token.
+#[cfg(any(debug_assertions, feature = "encoding-checks"))]
+fn check_encoded_width(token: &Token, encoded: &[Data]) -> Result<()> {
+    let param_type = param_type_of(token)?;
+    let encoded_width: usize = encoded.iter().map(Data::size_in_bytes).sum();
+    let expected_width = param_type.compute_encoding_in_bytes()?;
+
+    if encoded_width != expected_width {
+        return Err(error!(
+            Codec,
+            "`{param_type:?}` was encoded in {encoded_width} bytes, expected {expected_width}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// The type `token` is encoded as. Only the widths matter, so the element type of empty
+/// vectors and arrays is left as `Unit`.
+#[cfg(any(debug_assertions, feature = "encoding-checks"))]
+fn param_type_of(token: &Token) -> Result<crate::types::param_types::ParamType> {
+    use crate::types::param_types::ParamType;
+
+    let param_types_of = |tokens: &[Token]| tokens.iter().map(param_type_of).collect::<Result<_>>();
+
+    let param_type = match token {
+        Token::Unit => ParamType::Unit,
+        Token::Bool(_) => ParamType::Bool,
+        Token::U8(_) => ParamType::U8,
+        Token::U16(_) => ParamType::U16,
+        Token::U32(_) => ParamType::U32,
+        Token::U64(_) => ParamType::U64,
+        Token::U128(_) => ParamType::U128,
+        Token::U256(_) => ParamType::U256,
+        Token::B256(_) => ParamType::B256,
+        Token::Bytes(_) => ParamType::Bytes,
+        Token::String(_) => ParamType::String,
+        Token::RawSlice(_) => ParamType::RawSlice,
+        Token::StringSlice(_) => ParamType::StringSlice,
+        Token::StringArray(string) => ParamType::StringArray(string.data().len()),
+        Token::Vector(_) => ParamType::Vector(Box::new(ParamType::Unit)),
+        Token::Array(elements) => {
+            let element_types: Vec<ParamType> = param_types_of(elements)?;
+            let element_width = match element_types.first() {
+                Some(first) => first.compute_encoding_in_bytes()?,
+                None => 0,
+            };
+            for element_type in &element_types {
+                if element_type.compute_encoding_in_bytes()? != element_width {
+                    return Err(error!(
+                        Codec,
+                        "array elements must all be encoded in the same width, `{element_type:?}` is not {element_width} bytes wide"
+                    ));
+                }
+            }
+
+            let element_type = element_types.into_iter().next().unwrap_or(ParamType::Unit);
+            ParamType::Array(Box::new(element_type), elements.len())
+        }
+        Token::Struct(fields) => ParamType::Struct {
+            fields: param_types_of(fields)?,
+            generics: vec![],
+        },
+        Token::Tuple(elements) => ParamType::Tuple(param_types_of(elements)?),
+        Token::Enum(selector) => ParamType::Enum {
+            variants: selector.2.clone(),
+            generics: vec![],
+        },
+    };
+
+    Ok(param_type)
+}
+
 fn zeropad_to_word_alignment(data: &mut Vec<u8>) {
     let padded_length = padded_len_usize(data.len());
     data.resize(padded_length, 0);
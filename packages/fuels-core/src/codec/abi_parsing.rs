@@ -0,0 +1,124 @@
+use fuel_abi_types::abi::program::ProgramABI;
+use serde_json::Value;
+
+use crate::types::errors::{error, Result};
+
+/// How to treat JSON ABI fields the SDK doesn't know about.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AbiParsingMode {
+    /// Unknown fields are ignored, so ABIs produced by newer compilers still load.
+    #[default]
+    Lenient,
+    /// Unknown fields are reported as errors. Useful in CI, to notice when the compiler starts
+    /// emitting information the SDK would silently drop.
+    Strict,
+}
+
+/// Parses a JSON ABI, treating unknown fields according to `mode`.
+pub fn parse_program_abi(json_abi: &str, mode: AbiParsingMode) -> Result<ProgramABI> {
+    let abi: ProgramABI = serde_json::from_str(json_abi)?;
+
+    if mode == AbiParsingMode::Strict {
+        let original: Value = serde_json::from_str(json_abi)?;
+        let understood = serde_json::to_value(&abi)?;
+
+        let mut unknown_fields = vec![];
+        collect_unknown_fields(&original, &understood, "", &mut unknown_fields);
+
+        if !unknown_fields.is_empty() {
+            return Err(error!(
+                Codec,
+                "ABI contains unknown fields: {}",
+                unknown_fields.join(", ")
+            ));
+        }
+    }
+
+    Ok(abi)
+}
+
+/// Collects the paths of the fields present in `original` but dropped in `understood`, the
+/// same ABI after a round trip through `ProgramABI`. Null fields carry no information and are
+/// not reported.
+fn collect_unknown_fields(
+    original: &Value,
+    understood: &Value,
+    path: &str,
+    unknown_fields: &mut Vec<String>,
+) {
+    match (original, understood) {
+        (Value::Object(original), Value::Object(understood)) => {
+            for (key, value) in original {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+
+                match understood.get(key) {
+                    Some(understood_value) => {
+                        collect_unknown_fields(value, understood_value, &field_path, unknown_fields)
+                    }
+                    None if value.is_null() => {}
+                    None => unknown_fields.push(field_path),
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(understood)) => {
+            for (index, (value, understood_value)) in original.iter().zip(understood).enumerate() {
+                let element_path = format!("{path}[{index}]");
+                collect_unknown_fields(value, understood_value, &element_path, unknown_fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI_FROM_THE_FUTURE: &str = r#"{
+        "encoding": "1",
+        "types": [
+            {
+                "typeId": 0,
+                "type": "u64",
+                "components": null,
+                "typeParameters": null,
+                "metadata": { "size": 8 }
+            }
+        ],
+        "functions": [
+            {
+                "inputs": [],
+                "name": "get",
+                "output": { "name": "", "type": 0, "typeArguments": null },
+                "attributes": null
+            }
+        ],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    #[test]
+    fn unknown_fields_are_ignored_by_default() -> Result<()> {
+        let abi = parse_program_abi(ABI_FROM_THE_FUTURE, AbiParsingMode::default())?;
+
+        assert_eq!(abi.functions[0].name, "get");
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict_mode_reports_unknown_fields() {
+        let err = parse_program_abi(ABI_FROM_THE_FUTURE, AbiParsingMode::Strict)
+            .expect_err("should fail because of the unknown fields");
+
+        assert_eq!(
+            err.to_string(),
+            "codec: ABI contains unknown fields: encoding, types[0].metadata"
+        );
+    }
+}
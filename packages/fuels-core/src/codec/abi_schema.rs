@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+
+use fuel_abi_types::abi::program::{TypeApplication, TypeDeclaration};
+use serde_json::{json, Value};
+
+use crate::{
+    codec::ParsedAbi,
+    types::{
+        errors::{error, Error, Result},
+        param_types::ParamType,
+    },
+};
+
+/// Describes the inputs of `fn_name` in a JSON-Schema-like format, so that generic frontends
+/// can render a form for the arguments. The described value is an array with one element per
+/// argument, in the format of [`token_to_json`](crate::codec::token_to_json), so whatever the
+/// form produces can be turned into tokens with [`tokenize_json`](crate::codec::tokenize_json).
+///
+/// Every node has a `title` (the name of the argument, field or variant, when it has one) and
+/// the Sway type it describes under `x-sway-type`. Structs and tuples are arrays with one
+/// `prefixItems` entry per field, arrays have exactly `minItems` elements and enums are a
+/// `oneOf` over their variants, each an object with the `variant` index and its `value`.
+pub fn fn_to_schema(abi: &ParsedAbi, fn_name: &str) -> Result<Value> {
+    let function = abi
+        .function(fn_name)
+        .ok_or_else(|| error!(Codec, "function `{fn_name}` not found in the ABI"))?;
+
+    let args = function
+        .inputs
+        .iter()
+        .map(|input| {
+            let param_type = abi.resolve(input)?;
+            let schema = SchemaBuilder {
+                type_lookup: &abi.type_lookup,
+            }
+            .build(input, &param_type, &HashMap::new())?;
+
+            Ok(titled(schema, &input.name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "title": fn_name,
+        "type": "array",
+        "prefixItems": args,
+        "minItems": args.len(),
+        "maxItems": args.len(),
+    }))
+}
+
+struct SchemaBuilder<'a> {
+    type_lookup: &'a HashMap<usize, TypeDeclaration>,
+}
+
+impl SchemaBuilder<'_> {
+    /// `application` gives the names and the Sway type, `param_type` the resolved structure.
+    /// `generics` maps the type parameters in scope to what they stand for.
+    fn build(
+        &self,
+        application: &TypeApplication,
+        param_type: &ParamType,
+        generics: &HashMap<usize, TypeApplication>,
+    ) -> Result<Value> {
+        let declaration = self.declaration(application)?;
+        let components = declaration.components.as_deref().unwrap_or_default();
+
+        // the type arguments of this application are what its type parameters stand for
+        let mut inner_generics = generics.clone();
+        let type_parameters = declaration.type_parameters.as_deref().unwrap_or_default();
+        let type_arguments = application.type_arguments.as_deref().unwrap_or_default();
+        for (parameter, argument) in type_parameters.iter().zip(type_arguments) {
+            let argument = generics.get(&argument.type_id).unwrap_or(argument).clone();
+            inner_generics.insert(*parameter, argument);
+        }
+        let resolve = |component: &TypeApplication| {
+            inner_generics
+                .get(&component.type_id)
+                .unwrap_or(component)
+                .clone()
+        };
+
+        let schema = match param_type {
+            ParamType::Unit => json!({"type": "null"}),
+            ParamType::Bool => json!({"type": "boolean"}),
+            ParamType::U8 => uint_schema(u8::MAX.into()),
+            ParamType::U16 => uint_schema(u16::MAX.into()),
+            ParamType::U32 => uint_schema(u32::MAX.into()),
+            ParamType::U64 => uint_schema(u64::MAX),
+            ParamType::U128 | ParamType::U256 => {
+                json!({"type": "string", "pattern": "^[0-9]+$"})
+            }
+            ParamType::B256 => json!({"type": "string", "pattern": "^0x[0-9a-fA-F]{64}$"}),
+            ParamType::Bytes | ParamType::RawSlice => {
+                json!({"type": "string", "pattern": "^0x([0-9a-fA-F]{2})*$"})
+            }
+            ParamType::String | ParamType::StringSlice => json!({"type": "string"}),
+            ParamType::StringArray(len) => {
+                json!({"type": "string", "minLength": len, "maxLength": len})
+            }
+            ParamType::Array(element_type, len) => {
+                let element = components
+                    .first()
+                    .ok_or_else(|| missing_components(declaration))?;
+                let items = self.build(&resolve(element), element_type, &inner_generics)?;
+
+                json!({"type": "array", "items": items, "minItems": len, "maxItems": len})
+            }
+            ParamType::Vector(element_type) => {
+                let element = type_arguments
+                    .first()
+                    .ok_or_else(|| missing_components(declaration))?;
+                let items = self.build(&resolve(element), element_type, generics)?;
+
+                json!({"type": "array", "items": items})
+            }
+            ParamType::Tuple(element_types)
+            | ParamType::Struct {
+                fields: element_types,
+                ..
+            } => {
+                let items =
+                    self.build_each(components, element_types, &resolve, &inner_generics)?;
+
+                json!({
+                    "type": "array",
+                    "prefixItems": items,
+                    "minItems": items.len(),
+                    "maxItems": items.len(),
+                })
+            }
+            ParamType::Enum { variants, .. } => {
+                let variants = self
+                    .build_each(
+                        components,
+                        variants.param_types(),
+                        &resolve,
+                        &inner_generics,
+                    )?
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, variant)| {
+                        let title = variant.get("title").cloned().unwrap_or_default();
+                        json!({
+                            "title": title,
+                            "type": "object",
+                            "properties": {
+                                "variant": {"const": index},
+                                "value": variant,
+                            },
+                            "required": ["variant", "value"],
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                json!({"oneOf": variants})
+            }
+        };
+
+        Ok(with_field(schema, "x-sway-type", &declaration.type_field))
+    }
+
+    fn build_each(
+        &self,
+        components: &[TypeApplication],
+        param_types: &[ParamType],
+        resolve: &impl Fn(&TypeApplication) -> TypeApplication,
+        generics: &HashMap<usize, TypeApplication>,
+    ) -> Result<Vec<Value>> {
+        components
+            .iter()
+            .zip(param_types)
+            .map(|(component, param_type)| {
+                let schema = self.build(&resolve(component), param_type, generics)?;
+
+                Ok(titled(schema, &component.name))
+            })
+            .collect()
+    }
+
+    fn declaration(&self, application: &TypeApplication) -> Result<&TypeDeclaration> {
+        self.type_lookup.get(&application.type_id).ok_or_else(|| {
+            error!(
+                Codec,
+                "type id {} not found in the ABI", application.type_id
+            )
+        })
+    }
+}
+
+fn missing_components(declaration: &TypeDeclaration) -> Error {
+    error!(Codec, "`{}` has no components", declaration.type_field)
+}
+
+fn uint_schema(max: u64) -> Value {
+    json!({"type": "integer", "minimum": 0, "maximum": max})
+}
+
+fn titled(schema: Value, name: &str) -> Value {
+    // the compiler names tuple and array elements `__tuple_element` and `__array_element`
+    if name.is_empty() || name.starts_with("__") {
+        return schema;
+    }
+
+    with_field(schema, "title", name)
+}
+
+fn with_field(mut schema: Value, key: &str, value: &str) -> Value {
+    if let Value::Object(object) = &mut schema {
+        object.insert(key.to_string(), Value::String(value.to_string()));
+    }
+
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::{tokenize_json, AbiCache};
+
+    #[test]
+    fn schema_describes_names_lengths_and_variants() -> Result<()> {
+        let abi = r#"{
+            "types": [
+                {"typeId": 0, "type": "()", "components": null, "typeParameters": null},
+                {"typeId": 1, "type": "u64", "components": null, "typeParameters": null},
+                {"typeId": 2, "type": "[u64; 2]", "components": [{"name": "__array_element", "type": 1, "typeArguments": null}], "typeParameters": null},
+                {"typeId": 3, "type": "struct Order", "components": [
+                    {"name": "amount", "type": 1, "typeArguments": null},
+                    {"name": "limits", "type": 2, "typeArguments": null}
+                ], "typeParameters": null},
+                {"typeId": 4, "type": "enum Side", "components": [
+                    {"name": "Buy", "type": 0, "typeArguments": null},
+                    {"name": "Sell", "type": 1, "typeArguments": null}
+                ], "typeParameters": null}
+            ],
+            "functions": [{
+                "inputs": [
+                    {"name": "order", "type": 3, "typeArguments": null},
+                    {"name": "side", "type": 4, "typeArguments": null}
+                ],
+                "name": "place",
+                "output": {"name": "", "type": 0, "typeArguments": null},
+                "attributes": null
+            }],
+            "loggedTypes": [],
+            "messagesTypes": [],
+            "configurables": []
+        }"#;
+        let parsed_abi = AbiCache::new().get_or_insert_json(abi)?;
+
+        let schema = fn_to_schema(&parsed_abi, "place")?;
+
+        let order = &schema["prefixItems"][0];
+        assert_eq!(order["title"], "order");
+        assert_eq!(order["x-sway-type"], "struct Order");
+        assert_eq!(order["prefixItems"][1]["title"], "limits");
+        assert_eq!(order["prefixItems"][1]["minItems"], 2);
+        let side = &schema["prefixItems"][1];
+        assert_eq!(side["oneOf"][1]["title"], "Sell");
+        assert_eq!(side["oneOf"][1]["properties"]["variant"]["const"], 1);
+
+        // a value matching the schema goes back through `tokenize_json`
+        let function = parsed_abi.function("place").expect("should exist");
+        let order_type =
+            ParamType::try_from_type_application(&function.inputs[0], &parsed_abi.type_lookup)?;
+        tokenize_json(&order_type, &json!([10, [1, 2]]))?;
+
+        let err = fn_to_schema(&parsed_abi, "cancel").expect_err("should not exist");
+        assert_eq!(
+            err.to_string(),
+            "codec: function `cancel` not found in the ABI"
+        );
+
+        Ok(())
+    }
+}
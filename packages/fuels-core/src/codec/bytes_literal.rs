@@ -0,0 +1,153 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::types::{
+    errors::{error, Result},
+    param_types::ParamType,
+    Token,
+};
+
+/// Parses a byte sequence written as `0x`-prefixed hex, `b64:`-prefixed base64 or a bracketed
+/// list of decimals such as `[1, 2, 3]`.
+pub fn parse_bytes_literal(literal: &str) -> Result<Vec<u8>> {
+    let literal = literal.trim();
+
+    if let Some(hex_digits) = literal.strip_prefix("0x") {
+        return hex::decode(hex_digits)
+            .map_err(|e| error!(Codec, "invalid hex literal `{literal}`: {e}"));
+    }
+
+    if let Some(base64_digits) = literal.strip_prefix("b64:") {
+        return STANDARD
+            .decode(base64_digits)
+            .map_err(|e| error!(Codec, "invalid base64 literal `{literal}`: {e}"));
+    }
+
+    let Some(elements) = literal
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+    else {
+        return Err(error!(
+            Codec,
+            "expected `0x...`, `b64:...` or `[...]` bytes literal, got `{literal}`"
+        ));
+    };
+
+    elements
+        .split(',')
+        .map(str::trim)
+        .filter(|element| !element.is_empty())
+        .map(|element| {
+            element
+                .parse::<u8>()
+                .map_err(|_| error!(Codec, "`{element}` is not a valid byte in `{literal}`"))
+        })
+        .collect()
+}
+
+/// Tokenizes a bytes literal (see [`parse_bytes_literal`]) as a value of `param_type`, which can
+/// be `b256`, `[u8; N]`, `Vec<u8>`, `Bytes` or a raw slice. Fixed-size types require the literal
+/// to have exactly the right length.
+pub fn tokenize_bytes(param_type: &ParamType, literal: &str) -> Result<Token> {
+    let bytes = parse_bytes_literal(literal)?;
+
+    let expect_len = |expected: usize| {
+        if bytes.len() == expected {
+            Ok(())
+        } else {
+            Err(error!(
+                Codec,
+                "`{param_type:?}` expects {expected} bytes, but `{literal}` has {}",
+                bytes.len()
+            ))
+        }
+    };
+    let u8_tokens = |bytes: Vec<u8>| bytes.into_iter().map(Token::U8).collect();
+
+    match param_type {
+        ParamType::B256 => {
+            expect_len(32)?;
+            let b256 = bytes.try_into().expect("length checked above");
+            Ok(Token::B256(b256))
+        }
+        ParamType::Array(element_type, len) if **element_type == ParamType::U8 => {
+            expect_len(*len)?;
+            Ok(Token::Array(u8_tokens(bytes)))
+        }
+        ParamType::Vector(element_type) if **element_type == ParamType::U8 => {
+            Ok(Token::Vector(u8_tokens(bytes)))
+        }
+        ParamType::Bytes => Ok(Token::Bytes(bytes)),
+        ParamType::RawSlice => Ok(Token::RawSlice(bytes)),
+        _ => Err(error!(
+            Codec,
+            "cannot tokenize a bytes literal as `{param_type:?}`"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_notations_give_the_same_bytes() -> Result<()> {
+        let expected = vec![0xde, 0xad, 0xbe, 0xef];
+
+        assert_eq!(parse_bytes_literal("0xdeadbeef")?, expected);
+        assert_eq!(parse_bytes_literal("b64:3q2+7w==")?, expected);
+        assert_eq!(parse_bytes_literal("[222, 173, 190, 239]")?, expected);
+        assert_eq!(parse_bytes_literal("[]")?, Vec::<u8>::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tokenizes_byte_like_types() -> Result<()> {
+        let hash = format!("0x{}", "ab".repeat(32));
+
+        assert_eq!(
+            tokenize_bytes(&ParamType::B256, &hash)?,
+            Token::B256([0xab; 32])
+        );
+        assert_eq!(
+            tokenize_bytes(&ParamType::Array(Box::new(ParamType::U8), 2), "b64:AQI=")?,
+            Token::Array(vec![Token::U8(1), Token::U8(2)])
+        );
+        assert_eq!(
+            tokenize_bytes(&ParamType::Vector(Box::new(ParamType::U8)), "[1, 2]")?,
+            Token::Vector(vec![Token::U8(1), Token::U8(2)])
+        );
+        assert_eq!(
+            tokenize_bytes(&ParamType::Bytes, "0x0102")?,
+            Token::Bytes(vec![1, 2])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_length_mismatches() {
+        let err = tokenize_bytes(&ParamType::B256, "0x0102")
+            .expect_err("should fail because of the length");
+
+        assert_eq!(
+            err.to_string(),
+            "codec: `B256` expects 32 bytes, but `0x0102` has 2"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_literals() {
+        let err = parse_bytes_literal("[1, 256]").expect_err("should fail because of 256");
+        assert_eq!(
+            err.to_string(),
+            "codec: `256` is not a valid byte in `[1, 256]`"
+        );
+
+        let err = tokenize_bytes(&ParamType::U64, "0x01").expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "codec: cannot tokenize a bytes literal as `U64`"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt;
+
+use crate::{codec::WordCodec, constants::WORD_SIZE};
+
+/// A part of a payload, as guessed by [`disassemble`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    /// A word that looks like an integer, a length or an offset.
+    Word { offset: usize, value: u64 },
+    /// Four words that look like a hash, an id or an address.
+    B256 { offset: usize, value: [u8; 32] },
+    /// Printable ASCII, padded with zeroes to a whole number of words.
+    String { offset: usize, value: String },
+    /// Trailing bytes that don't make up a word.
+    Bytes { offset: usize, value: Vec<u8> },
+}
+
+impl Segment {
+    pub fn offset(&self) -> usize {
+        match self {
+            Segment::Word { offset, .. }
+            | Segment::B256 { offset, .. }
+            | Segment::String { offset, .. }
+            | Segment::Bytes { offset, .. } => *offset,
+        }
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#06x}: ", self.offset())?;
+        match self {
+            Segment::Word { value, .. } => write!(f, "word {value}"),
+            Segment::B256 { value, .. } => write!(f, "b256 0x{}", hex::encode(value)),
+            Segment::String { value, .. } => write!(f, "string {value:?}"),
+            Segment::Bytes { value, .. } => write!(f, "bytes 0x{}", hex::encode(value)),
+        }
+    }
+}
+
+/// Words below this are taken for integers, lengths or offsets rather than parts of a hash.
+const SMALL_WORD: u64 = 1 << 32;
+/// Shorter runs of printable bytes are more likely to be numbers than strings.
+const MIN_STRING_LEN: usize = 4;
+
+/// Guesses the layout of ABI-encoded `data` without knowing its types, e.g. to inspect the
+/// script data of a transaction for which no ABI is registered. Encoded values take whole
+/// words, so the data is split into:
+///
+/// * strings: runs of words holding printable ASCII, at least 4 characters long
+/// * `b256`s: four words, the first one and at least two others too big to be integers
+/// * words: everything else
+///
+/// These are guesses: a `u64` may well look like part of a string and a `b256` starting with
+/// zeroes will be taken for words.
+///
+/// ```
+/// use fuels_core::codec::{disassemble, Segment};
+///
+/// let data = [42u64.to_be_bytes().as_slice(), b"fuel\0\0\0\0"].concat();
+///
+/// assert_eq!(
+///     disassemble(&data),
+///     vec![
+///         Segment::Word { offset: 0, value: 42 },
+///         Segment::String { offset: 8, value: "fuel".to_string() },
+///     ]
+/// );
+/// ```
+pub fn disassemble(data: &[u8]) -> Vec<Segment> {
+    let words = data.chunks_exact(WORD_SIZE).collect::<Vec<_>>();
+    let mut segments = vec![];
+
+    let mut index = 0;
+    while index < words.len() {
+        let offset = index * WORD_SIZE;
+
+        if let Some((value, len)) = string_at(&words[index..]) {
+            segments.push(Segment::String { offset, value });
+            index += len;
+        } else if let Some(value) = b256_at(&words[index..]) {
+            segments.push(Segment::B256 { offset, value });
+            index += 4;
+        } else {
+            segments.push(Segment::Word {
+                offset,
+                value: word_value(words[index]),
+            });
+            index += 1;
+        }
+    }
+
+    let remainder = data.chunks_exact(WORD_SIZE).remainder();
+    if !remainder.is_empty() {
+        segments.push(Segment::Bytes {
+            offset: words.len() * WORD_SIZE,
+            value: remainder.to_vec(),
+        });
+    }
+
+    segments
+}
+
+/// The string starting at the first word, if any, and the number of words it takes.
+fn string_at(words: &[&[u8]]) -> Option<(String, usize)> {
+    let mut bytes = vec![];
+    let mut num_words = 0;
+    for word in words {
+        let printable_len = word.iter().take_while(|byte| is_printable(**byte)).count();
+        let is_padded = word[printable_len..].iter().all(|byte| *byte == 0);
+        if printable_len == 0 || !is_padded {
+            break;
+        }
+
+        bytes.extend_from_slice(&word[..printable_len]);
+        num_words += 1;
+        if printable_len < WORD_SIZE {
+            // the padding ends the string
+            break;
+        }
+    }
+
+    (bytes.len() >= MIN_STRING_LEN)
+        .then(|| (String::from_utf8_lossy(&bytes).into_owned(), num_words))
+}
+
+fn b256_at(words: &[&[u8]]) -> Option<[u8; 32]> {
+    let candidate = words.get(..4)?;
+    if word_value(candidate[0]) < SMALL_WORD {
+        return None;
+    }
+    let big_words = candidate
+        .iter()
+        .filter(|word| word_value(word) >= SMALL_WORD)
+        .count();
+    if big_words < 3 {
+        return None;
+    }
+
+    Some(
+        candidate
+            .concat()
+            .try_into()
+            .expect("four words are 32 bytes"),
+    )
+}
+
+fn word_value(word: &[u8]) -> u64 {
+    WordCodec::read(word).expect("words are whole")
+}
+
+fn is_printable(byte: u8) -> bool {
+    byte.is_ascii_graphic() || byte == b' '
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payloads_are_split_into_plausible_values() {
+        let id = [0xab; 32];
+        let data = [
+            7u64.to_be_bytes().as_slice(),
+            &id,
+            b"hello, world\0\0\0\0",
+            &[1, 2, 3],
+        ]
+        .concat();
+
+        let segments = disassemble(&data);
+
+        assert_eq!(
+            segments,
+            vec![
+                Segment::Word {
+                    offset: 0,
+                    value: 7
+                },
+                Segment::B256 {
+                    offset: 8,
+                    value: id
+                },
+                Segment::String {
+                    offset: 40,
+                    value: "hello, world".to_string()
+                },
+                Segment::Bytes {
+                    offset: 56,
+                    value: vec![1, 2, 3]
+                },
+            ]
+        );
+        assert_eq!(segments[2].to_string(), r#"0x0028: string "hello, world""#);
+    }
+
+    #[test]
+    fn small_words_are_not_taken_for_hashes_or_strings() {
+        let data = [1u64, 2, 3, u64::MAX]
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .collect::<Vec<_>>();
+
+        assert!(disassemble(&data)
+            .iter()
+            .all(|segment| matches!(segment, Segment::Word { .. })));
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "embed-abi")]
+
+use std::io::Read;
+
+use flate2::read::DeflateDecoder;
+
+use crate::types::errors::{error, Result};
+
+/// Decompresses a JSON ABI that `abigen!` deflated into the bindings it generated, see the
+/// `abi()` function of contract and script bindings.
+pub fn decompress_abi(compressed: &[u8]) -> Result<String> {
+    let mut json_abi = String::new();
+    DeflateDecoder::new(compressed)
+        .read_to_string(&mut json_abi)
+        .map_err(|e| error!(Codec, "could not decompress the embedded ABI: {e}"))?;
+
+    Ok(json_abi)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::DeflateEncoder, Compression};
+
+    use super::*;
+
+    #[test]
+    fn decompresses_what_abigen_compressed() {
+        let json_abi = r#"{"types":[],"functions":[],"loggedTypes":[],"configurables":[]}"#;
+        let mut encoder = DeflateEncoder::new(vec![], Compression::best());
+        encoder.write_all(json_abi.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(decompress_abi(&compressed).unwrap(), json_abi);
+
+        let err = decompress_abi(&compressed[..compressed.len() / 2]).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("codec: could not decompress the embedded ABI"));
+    }
+}
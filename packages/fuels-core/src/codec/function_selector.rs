@@ -1,6 +1,12 @@
+use std::collections::HashMap;
+
 use sha2::{Digest, Sha256};
 
-use crate::types::{param_types::ParamType, ByteArray};
+use crate::types::{
+    errors::{error, Result},
+    param_types::ParamType,
+    ByteArray,
+};
 
 /// Given a function name and its inputs  will return a ByteArray representing
 /// the function selector as specified in the Fuel specs.
@@ -10,12 +16,37 @@ pub fn resolve_fn_selector(name: &str, inputs: &[ParamType]) -> ByteArray {
     first_four_bytes_of_sha256_hash(&fn_signature)
 }
 
-fn resolve_fn_signature(name: &str, inputs: &[ParamType]) -> String {
+/// Returns the human readable signature that gets hashed into the function selector.
+/// Useful for comparing against the signature the Sway compiler produced when a call
+/// reverts because the selector didn't match any function.
+pub fn resolve_fn_signature(name: &str, inputs: &[ParamType]) -> String {
     let fn_args = resolve_args(inputs);
 
     format!("{name}({fn_args})")
 }
 
+/// Fails if two of the given signatures, as returned by [`resolve_fn_signature`], have the
+/// same selector. Contracts dispatch calls on the selector alone, so calling either of two
+/// colliding functions could execute the other one.
+pub fn check_selector_collisions<'a>(signatures: impl IntoIterator<Item = &'a str>) -> Result<()> {
+    let mut seen: HashMap<ByteArray, &str> = HashMap::new();
+
+    for signature in signatures {
+        let selector = first_four_bytes_of_sha256_hash(signature);
+
+        if let Some(other) = seen.insert(selector, signature) {
+            return Err(error!(
+                Codec,
+                "functions `{other}` and `{signature}` have the same selector 0x{}, calling \
+                either of them could execute the other one",
+                hex::encode(&selector[4..])
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn resolve_args(arg: &[ParamType]) -> String {
     arg.iter().map(resolve_arg).collect::<Vec<_>>().join(",")
 }
@@ -119,7 +150,23 @@ pub use calldata;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::enum_variants::EnumVariants;
+    use crate::{codec::ParsedAbi, types::enum_variants::EnumVariants};
+
+    #[test]
+    fn colliding_selectors_are_reported() {
+        // found by brute force, both hash to 0xb4572897
+        let colliding = ["transfer_120131(u64)", "transfer_132578(u64)"];
+
+        let err = check_selector_collisions(["get()"].into_iter().chain(colliding))
+            .expect_err("should have detected the collision");
+
+        assert_eq!(
+            err.to_string(),
+            "codec: functions `transfer_120131(u64)` and `transfer_132578(u64)` have the same \
+            selector 0xb4572897, calling either of them could execute the other one"
+        );
+        assert!(check_selector_collisions(["get()", "transfer_120131(u64)"]).is_ok());
+    }
 
     #[test]
     fn handles_primitive_types() {
@@ -212,6 +259,35 @@ mod tests {
         assert_eq!(selector, format!("some_fun(e<u32>(u64,u32))"));
     }
 
+    #[test]
+    fn conforms_to_shared_selector_vectors() {
+        let vectors: serde_json::Value = serde_json::from_str(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../test-vectors/fn_selectors.json"
+        )))
+        .expect("the vectors are valid JSON");
+        let abi = ParsedAbi::new(
+            serde_json::from_value(vectors["abi"].clone()).expect("the ABI is valid"),
+        );
+
+        for vector in vectors["selectors"]
+            .as_array()
+            .expect("selectors are a list")
+        {
+            let function = abi
+                .function_info(vector["function"].as_str().expect("function is a name"))
+                .expect("the function is in the ABI");
+
+            assert_eq!(function.signature, vector["signature"]);
+            assert_eq!(
+                hex::encode(&function.selector[4..]),
+                vector["selector"],
+                "for {}",
+                function.signature
+            );
+        }
+    }
+
     #[test]
     fn ultimate_test() {
         let fields = vec![ParamType::Struct {
@@ -6,11 +6,13 @@ use std::{
 };
 
 use fuel_tx::{ContractId, Receipt};
+#[cfg(feature = "parallel-decoding")]
+use rayon::prelude::*;
 
 #[cfg(not(experimental))]
 use crate::types::param_types::ParamType;
 use crate::{
-    codec::{ABIDecoder, DecoderConfig},
+    codec::{ABIDecoder, DecoderConfig, WordCodec},
     traits::{Parameterize, Tokenizable},
     types::errors::{error, Error, Result},
 };
@@ -121,11 +123,15 @@ impl LogDecoder {
         self
     }
 
-    /// Get all logs results from the given receipts as `Result<String>`
+    /// Get all logs results from the given receipts as `Result<String>`. With the
+    /// `parallel-decoding` feature the logs are decoded in parallel, keeping their order.
     pub fn decode_logs(&self, receipts: &[Receipt]) -> LogResult {
-        let results = receipts
-            .iter()
-            .extract_log_id_and_data()
+        let logs = receipts.iter().extract_log_id_and_data();
+
+        #[cfg(feature = "parallel-decoding")]
+        let logs = logs.collect::<Vec<_>>().into_par_iter();
+
+        let results = logs
             .map(|(log_id, data)| self.format_log(&log_id, &data))
             .collect();
 
@@ -179,6 +185,8 @@ impl LogDecoder {
 
     /// Get decoded logs with specific type from the given receipts.
     /// Note that this method returns the actual type and not a `String` representation.
+    /// With the `parallel-decoding` feature the logs are decoded in parallel, keeping their
+    /// order.
     pub fn decode_logs_with_type<T: Tokenizable + Parameterize + 'static>(
         &self,
         receipts: &[Receipt],
@@ -190,23 +198,27 @@ impl LogDecoder {
             .map(|(log_id, _)| log_id.clone())
             .collect();
 
-        receipts
+        let logs = receipts
             .iter()
             .extract_log_id_and_data()
-            .filter_map(|(log_id, bytes)| {
-                target_ids.contains(&log_id).then(|| {
-                    #[cfg(experimental)]
-                    let token = ABIDecoder::new(self.decoder_config)
-                        .experimental_decode(&T::param_type(), &bytes)?;
-
-                    #[cfg(not(experimental))]
-                    let token =
-                        ABIDecoder::new(self.decoder_config).decode(&T::param_type(), &bytes)?;
-
-                    T::from_token(token)
-                })
-            })
-            .collect()
+            .filter(|(log_id, _)| target_ids.contains(log_id));
+
+        #[cfg(feature = "parallel-decoding")]
+        let logs = logs.collect::<Vec<_>>().into_par_iter();
+
+        let decoder = ABIDecoder::new(self.decoder_config);
+        let param_type = T::param_type();
+        #[cfg(experimental)]
+        let decode = |bytes: &[u8]| decoder.experimental_decode(&param_type, bytes);
+        #[cfg(not(experimental))]
+        let decode = |bytes: &[u8]| decoder.decode(&param_type, bytes);
+
+        // `T` itself isn't required to be `Send`, only the tokens cross threads
+        let tokens = logs
+            .map(|(_, bytes)| decode(&bytes))
+            .collect::<Result<Vec<_>>>()?;
+
+        tokens.into_iter().map(T::from_token).collect()
     }
 
     pub fn merge(&mut self, log_decoder: LogDecoder) {
@@ -222,16 +234,22 @@ trait ExtractLogIdData {
 impl<'a, I: Iterator<Item = &'a Receipt>> ExtractLogIdData for I {
     type Output = FilterMap<Self, fn(&Receipt) -> Option<(LogId, Vec<u8>)>>;
     fn extract_log_id_and_data(self) -> Self::Output {
-        self.filter_map(|r| match r {
-            Receipt::LogData {
-                rb,
-                data: Some(data),
-                id,
-                ..
-            } => Some((LogId(*id, *rb), data.clone())),
-            Receipt::Log { ra, rb, id, .. } => Some((LogId(*id, *rb), ra.to_be_bytes().to_vec())),
-            _ => None,
-        })
+        self.filter_map(|r| log_of(r).map(|(id, log_id, data)| (LogId(id, log_id), data)))
+    }
+}
+
+/// The id of the contract that emitted the log in `receipt`, its log id and its data, if the
+/// receipt is a log. Scripts log with a zeroed contract id.
+pub(crate) fn log_of(receipt: &Receipt) -> Option<(ContractId, u64, Vec<u8>)> {
+    match receipt {
+        Receipt::LogData {
+            id,
+            rb,
+            data: Some(data),
+            ..
+        } => Some((*id, *rb, data.clone())),
+        Receipt::Log { id, ra, rb, .. } => Some((*id, *rb, WordCodec::encode(*ra).to_vec())),
+        _ => None,
     }
 }
 
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use fuel_tx::Receipt;
+use fuel_types::ContractId;
+
+use crate::{
+    codec::{log_of, AbiRegistry, ParsedAbi},
+    types::{bech32::Bech32ContractId, Token},
+};
+
+/// Decodes the logs of a transaction touching several contracts, each with the ABI of the
+/// contract that emitted it.
+///
+/// The emitter of a log is the `id` of its receipt: the script for a zeroed id, the contract
+/// otherwise. `Call` and `Return` receipts are followed to tell which calls were in progress
+/// when the log was emitted, e.g. that a token contract logged a transfer while a pool was
+/// being called.
+///
+/// ```ignore
+/// let decoder = MultiAbiLogDecoder::new()
+///     .with_contract(pool_id, abi_cache.get_or_insert_json(POOL_ABI)?)
+///     .with_contract(token_id, abi_cache.get_or_insert_json(TOKEN_ABI)?);
+///
+/// for log in response.decode_logs_with(&decoder) {
+///     println!("{} logged {:?}", log.emitter, log.value);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultiAbiLogDecoder {
+    registry: AbiRegistry,
+}
+
+impl MultiAbiLogDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_contract(
+        mut self,
+        contract_id: impl Into<ContractId>,
+        abi: Arc<ParsedAbi>,
+    ) -> Self {
+        self.registry.register(contract_id, abi);
+        self
+    }
+
+    /// The ABI of the script the transaction runs, used for the logs it emits itself.
+    pub fn with_script(mut self, abi: Arc<ParsedAbi>) -> Self {
+        self.registry.register(ContractId::zeroed(), abi);
+        self
+    }
+
+    /// Decodes the logs found in `receipts`, in the order they were emitted. Unlike
+    /// [`AbiRegistry::decode_logs`], the logs of emitters without a registered ABI are kept,
+    /// with an error as their value, so that no log goes missing.
+    pub fn decode_logs(&self, receipts: &[Receipt]) -> Vec<EmittedLog> {
+        let mut call_stack: Vec<ContractId> = vec![];
+        let mut logs = vec![];
+
+        for (receipt_index, receipt) in receipts.iter().enumerate() {
+            match receipt {
+                Receipt::Call { to, .. } => call_stack.push(*to),
+                Receipt::Return { id, .. } | Receipt::ReturnData { id, .. }
+                    if call_stack.last() == Some(id) =>
+                {
+                    call_stack.pop();
+                }
+                _ => {
+                    let Some((emitter_id, log_id, data)) = log_of(receipt) else {
+                        continue;
+                    };
+
+                    let emitter = emitter(emitter_id);
+                    let value = match self.registry.get(&emitter_id) {
+                        Some(abi) => abi.decode_log(log_id, &data).map_err(|err| err.to_string()),
+                        None => Err(format!("no ABI registered for the {emitter}")),
+                    };
+
+                    logs.push(EmittedLog {
+                        receipt_index,
+                        emitter,
+                        call_path: call_stack.iter().map(|id| (*id).into()).collect(),
+                        log_id,
+                        value,
+                    });
+                }
+            }
+        }
+
+        logs
+    }
+}
+
+impl From<AbiRegistry> for MultiAbiLogDecoder {
+    fn from(registry: AbiRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+fn emitter(id: ContractId) -> LogEmitter {
+    if id == ContractId::zeroed() {
+        LogEmitter::Script
+    } else {
+        LogEmitter::Contract(id.into())
+    }
+}
+
+/// What emitted a log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogEmitter {
+    Script,
+    Contract(Bech32ContractId),
+}
+
+impl std::fmt::Display for LogEmitter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogEmitter::Script => write!(f, "script"),
+            LogEmitter::Contract(id) => write!(f, "contract `{id}`"),
+        }
+    }
+}
+
+/// A log decoded by [`MultiAbiLogDecoder::decode_logs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmittedLog {
+    /// The position of the log's receipt among the receipts of the transaction.
+    pub receipt_index: usize,
+    pub emitter: LogEmitter,
+    /// The contracts being called when the log was emitted, outermost first. A contract's log
+    /// has its emitter last, a script's log has an empty path.
+    pub call_path: Vec<Bech32ContractId>,
+    pub log_id: u64,
+    /// The logged value, or why it couldn't be decoded.
+    pub value: std::result::Result<Token, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{codec::AbiCache, types::errors::Result};
+
+    const U64_LOGGING_ABI: &str = r#"{
+        "types": [{"typeId": 0, "type": "u64", "components": null, "typeParameters": null}],
+        "functions": [],
+        "loggedTypes": [{"logId": 7, "loggedType": {"name": "", "type": 0, "typeArguments": null}}],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    fn call(id: ContractId, to: ContractId) -> Receipt {
+        Receipt::Call {
+            id,
+            to,
+            amount: 0,
+            asset_id: Default::default(),
+            gas: 0,
+            param1: 0,
+            param2: 0,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    fn ret(id: ContractId) -> Receipt {
+        Receipt::Return {
+            id,
+            val: 0,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    fn log(id: ContractId, ra: u64) -> Receipt {
+        Receipt::Log {
+            id,
+            ra,
+            rb: 7,
+            rc: 0,
+            rd: 0,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    #[test]
+    fn logs_are_attributed_to_their_emitter_across_nested_calls() -> Result<()> {
+        let abi = AbiCache::new().get_or_insert_json(U64_LOGGING_ABI)?;
+        let script = ContractId::zeroed();
+        let (pool, token, unknown) = (
+            ContractId::from([1; 32]),
+            ContractId::from([2; 32]),
+            ContractId::from([3; 32]),
+        );
+        let decoder = MultiAbiLogDecoder::new()
+            .with_script(abi.clone())
+            .with_contract(pool, abi.clone())
+            .with_contract(token, abi);
+
+        let receipts = [
+            call(script, pool),
+            log(pool, 1),
+            call(pool, token),
+            log(token, 2),
+            ret(token),
+            call(pool, unknown),
+            log(unknown, 3),
+            ret(unknown),
+            log(pool, 4),
+            ret(pool),
+            log(script, 5),
+        ];
+
+        let logs = decoder
+            .decode_logs(&receipts)
+            .into_iter()
+            .map(|log| {
+                let call_path = log
+                    .call_path
+                    .iter()
+                    .map(ContractId::from)
+                    .collect::<Vec<_>>();
+                (log.receipt_index, log.emitter, call_path, log.value)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            logs,
+            [
+                (1, emitter(pool), vec![pool], Ok(Token::U64(1))),
+                (3, emitter(token), vec![pool, token], Ok(Token::U64(2))),
+                (
+                    6,
+                    emitter(unknown),
+                    vec![pool, unknown],
+                    Err(format!(
+                        "no ABI registered for the contract `{}`",
+                        Bech32ContractId::from(unknown)
+                    ))
+                ),
+                (8, emitter(pool), vec![pool], Ok(Token::U64(4))),
+                (10, LogEmitter::Script, vec![], Ok(Token::U64(5))),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::Write;
+
+use crate::types::Token;
+
+/// Renders `token` as indented text, one value per line, so that decoded values can be compared
+/// against snapshots and differences show up line by line. Integers are suffixed with their
+/// type, byte sequences are hex encoded and enums show the index of their variant:
+///
+/// ```
+/// use fuels_core::{codec::render_token, traits::Tokenizable};
+///
+/// let rendered = render_token(&(42u8, vec![true, false]).into_token());
+///
+/// assert_eq!(rendered, "(\n    42u8,\n    Vec[\n        true,\n        false,\n    ],\n)");
+/// ```
+pub fn render_token(token: &Token) -> String {
+    let mut rendered = String::new();
+    render_into(&mut rendered, token, 0);
+
+    rendered
+}
+
+fn render_into(out: &mut String, token: &Token, depth: usize) {
+    let (open, close, children) = match token {
+        Token::Array(elements) => ("[", "]", elements.iter().collect::<Vec<_>>()),
+        Token::Vector(elements) => ("Vec[", "]", elements.iter().collect()),
+        Token::Tuple(elements) => ("(", ")", elements.iter().collect()),
+        Token::Struct(fields) => ("{", "}", fields.iter().collect()),
+        Token::Enum(selector) => {
+            let (discriminant, variant, _) = &**selector;
+            let _ = write!(out, "Enum({discriminant}: ");
+            render_into(out, variant, depth);
+            out.push(')');
+            return;
+        }
+        _ => {
+            out.push_str(&render_leaf(token));
+            return;
+        }
+    };
+
+    out.push_str(open);
+    if !children.is_empty() {
+        let indent = "    ".repeat(depth + 1);
+        for child in children {
+            out.push('\n');
+            out.push_str(&indent);
+            render_into(out, child, depth + 1);
+            out.push(',');
+        }
+        out.push('\n');
+        out.push_str(&"    ".repeat(depth));
+    }
+    out.push_str(close);
+}
+
+fn render_leaf(token: &Token) -> String {
+    match token {
+        Token::Unit => "()".to_string(),
+        Token::Bool(value) => value.to_string(),
+        Token::U8(value) => format!("{value}u8"),
+        Token::U16(value) => format!("{value}u16"),
+        Token::U32(value) => format!("{value}u32"),
+        Token::U64(value) => format!("{value}u64"),
+        Token::U128(value) => format!("{value}u128"),
+        Token::U256(value) => format!("{value}u256"),
+        Token::B256(bytes) => format!("0x{}", hex::encode(bytes)),
+        Token::Bytes(bytes) => format!("Bytes(0x{})", hex::encode(bytes)),
+        Token::RawSlice(bytes) => format!("RawSlice(0x{})", hex::encode(bytes)),
+        Token::String(string) => format!("String({string:?})"),
+        Token::StringSlice(string) => format!("str({:?})", string.data()),
+        Token::StringArray(string) => format!("str[{}]({:?})", string.data().len(), string.data()),
+        Token::Array(_)
+        | Token::Vector(_)
+        | Token::Tuple(_)
+        | Token::Struct(_)
+        | Token::Enum(_) => {
+            unreachable!("nested tokens are rendered by `render_into`")
+        }
+    }
+}
+
+/// Compares `actual` with `snapshot`, ignoring the indentation common to all lines of `snapshot`
+/// and its leading and trailing empty lines, so that snapshots can be written as indented raw
+/// strings. Returns a line diff if they differ, lines only in the snapshot are prefixed with `-`
+/// and lines only in `actual` with `+`.
+pub fn snapshot_diff(actual: &str, snapshot: &str) -> Option<String> {
+    let expected = normalize_snapshot(snapshot);
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    if expected == actual {
+        return None;
+    }
+
+    Some(line_diff(&expected, &actual))
+}
+
+fn normalize_snapshot(snapshot: &str) -> Vec<&str> {
+    let lines = snapshot.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(start, |last| last + 1);
+    let lines = &lines[start..end];
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default().trim_end())
+        .collect()
+}
+
+/// A diff based on the longest common subsequence of lines. Snapshots are small enough for the
+/// quadratic table not to matter.
+fn line_diff(expected: &[&str], actual: &[&str]) -> String {
+    let mut common = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || (i < expected.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            diff.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+
+    diff.join("\n")
+}
+
+/// Asserts that a decoded value, anything `Tokenizable` such as a contract response's value or
+/// the logs of a type, renders as `snapshot` (see `render_token`). On failure, panics with a
+/// line diff between the two.
+///
+/// ```ignore
+/// assert_decoded_eq!(
+///     response.value,
+///     r#"
+///     {
+///         42u64,
+///         str[3]("fue"),
+///     }
+///     "#
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_decoded_eq {
+    ($value: expr, $snapshot: expr $(,)?) => {
+        if let Some(diff) = $crate::codec::snapshot_diff(
+            &$crate::codec::render_token(&$crate::traits::Tokenizable::into_token($value)),
+            $snapshot,
+        ) {
+            panic!("decoded value does not match the snapshot:\n{diff}");
+        }
+    };
+}
+
+pub use assert_decoded_eq;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        traits::Tokenizable,
+        types::{enum_variants::EnumVariants, param_types::ParamType, SizedAsciiString},
+    };
+
+    #[test]
+    fn renders_nested_tokens() -> crate::types::errors::Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::U64])?;
+        let token = Token::Struct(vec![
+            SizedAsciiString::<3>::new("fue".to_string())?.into_token(),
+            Token::Enum(Box::new((1, Token::U64(7), variants))),
+            Token::Vector(vec![]),
+            Token::Bytes(vec![0xab, 0xcd]),
+        ]);
+
+        let expected = r#"{
+    str[3]("fue"),
+    Enum(1: 7u64),
+    Vec[],
+    Bytes(0xabcd),
+}"#;
+        assert_eq!(render_token(&token), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn snapshots_ignore_common_indentation() {
+        let actual = render_token(&(1u8, true).into_token());
+
+        let snapshot = r#"
+            (
+                1u8,
+                true,
+            )
+        "#;
+        assert_eq!(snapshot_diff(&actual, snapshot), None);
+
+        let snapshot = r#"
+            (
+                2u8,
+                true,
+            )
+        "#;
+        assert_eq!(
+            snapshot_diff(&actual, snapshot).unwrap(),
+            "  (\n-     2u8,\n+     1u8,\n      true,\n  )"
+        );
+    }
+
+    #[test]
+    fn decoded_values_are_asserted_against_snapshots() {
+        assert_decoded_eq!(
+            (42u64, true),
+            r#"
+            (
+                42u64,
+                true,
+            )
+            "#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "decoded value does not match the snapshot")]
+    fn mismatching_snapshots_panic() {
+        assert_decoded_eq!(7u64, "8u64");
+    }
+}
@@ -0,0 +1,232 @@
+use serde_json::{json, Value};
+
+use crate::{
+    codec::tokenize,
+    types::{
+        errors::{error, Result},
+        param_types::ParamType,
+        StaticStringToken, Token,
+    },
+};
+
+/// Converts `token` to JSON, for exporting decoded values to tools that know nothing about the
+/// ABI:
+///
+/// * unit: `null`
+/// * `bool`: a boolean
+/// * `u8` to `u64`: a number
+/// * `u128` and `u256`: a string of decimal digits, since JSON numbers can't hold them exactly
+/// * `b256`, `Bytes` and raw slices: a `0x`-prefixed hex string
+/// * strings: a string
+/// * arrays, vectors, tuples and structs: an array of their elements, struct fields in
+///   declaration order
+/// * enums: `{"variant": <index of the variant>, "value": <value of the variant>}`
+///
+/// ```
+/// use fuels_core::{codec::token_to_json, traits::Tokenizable};
+///
+/// let json = token_to_json(&(42u64, true, u128::MAX).into_token());
+///
+/// assert_eq!(
+///     json.to_string(),
+///     r#"[42,true,"340282366920938463463374607431768211455"]"#
+/// );
+/// ```
+pub fn token_to_json(token: &Token) -> Value {
+    match token {
+        Token::Unit => Value::Null,
+        Token::Bool(value) => json!(value),
+        Token::U8(value) => json!(value),
+        Token::U16(value) => json!(value),
+        Token::U32(value) => json!(value),
+        Token::U64(value) => json!(value),
+        Token::U128(value) => json!(value.to_string()),
+        Token::U256(value) => json!(value.to_string()),
+        Token::B256(bytes) => json!(format!("0x{}", hex::encode(bytes))),
+        Token::Bytes(bytes) | Token::RawSlice(bytes) => {
+            json!(format!("0x{}", hex::encode(bytes)))
+        }
+        Token::String(string) => json!(string),
+        Token::StringSlice(string) | Token::StringArray(string) => json!(string.data()),
+        Token::Array(elements)
+        | Token::Vector(elements)
+        | Token::Tuple(elements)
+        | Token::Struct(elements) => Value::Array(elements.iter().map(token_to_json).collect()),
+        Token::Enum(selector) => {
+            let (discriminant, variant, _) = &**selector;
+            json!({
+                "variant": discriminant,
+                "value": token_to_json(variant),
+            })
+        }
+    }
+}
+
+/// The inverse of [`token_to_json`]: parses `value`, in the format `token_to_json` produces, as
+/// a value of `param_type`. Integers and byte strings are also accepted in the formats of
+/// [`tokenize`], e.g. `u64`s as strings.
+///
+/// ```
+/// use fuels_core::{
+///     codec::tokenize_json,
+///     types::{param_types::ParamType, Token},
+/// };
+/// use serde_json::json;
+///
+/// let param_type = ParamType::Tuple(vec![ParamType::U64, ParamType::Bool]);
+/// let token = tokenize_json(&param_type, &json!([42, true])).unwrap();
+///
+/// assert_eq!(token, Token::Tuple(vec![Token::U64(42), Token::Bool(true)]));
+/// ```
+pub fn tokenize_json(param_type: &ParamType, value: &Value) -> Result<Token> {
+    let token = tokenize_json_value(param_type, value)?;
+    param_type.validate_array_shape(&token)?;
+
+    Ok(token)
+}
+
+fn tokenize_json_value(param_type: &ParamType, value: &Value) -> Result<Token> {
+    let token = match (param_type, value) {
+        (ParamType::Unit, Value::Null) => Token::Unit,
+        (ParamType::String, Value::String(string)) => Token::String(string.clone()),
+        (ParamType::StringSlice, Value::String(string)) => {
+            Token::StringSlice(StaticStringToken::new(string.clone(), None))
+        }
+        (ParamType::StringArray(len), Value::String(string)) => {
+            if string.len() != *len {
+                return Err(error!(
+                    Codec,
+                    "`{param_type:?}` expects {len} bytes, got {} in `{value}`",
+                    string.len()
+                ));
+            }
+            Token::StringArray(StaticStringToken::new(string.clone(), Some(*len)))
+        }
+        (ParamType::Array(element_type, _), Value::Array(elements)) => {
+            Token::Array(tokenize_all_json(element_type, elements)?)
+        }
+        (ParamType::Vector(element_type), Value::Array(elements)) => {
+            Token::Vector(tokenize_all_json(element_type, elements)?)
+        }
+        (ParamType::Tuple(element_types), Value::Array(elements)) => {
+            Token::Tuple(tokenize_each_json(element_types, elements)?)
+        }
+        (ParamType::Struct { fields, .. }, Value::Array(elements)) => {
+            Token::Struct(tokenize_each_json(fields, elements)?)
+        }
+        (ParamType::Enum { variants, .. }, Value::Object(object)) => {
+            let discriminant = object
+                .get("variant")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| error!(Codec, "expected a `variant` index in `{value}`"))?;
+            let variant_type = variants.param_type_of_variant(discriminant)?;
+            let variant_value = object.get("value").unwrap_or(&Value::Null);
+
+            let variant_token = tokenize_json_value(variant_type, variant_value)?;
+            Token::Enum(Box::new((discriminant, variant_token, variants.clone())))
+        }
+        (_, Value::Bool(_) | Value::Number(_)) => tokenize(param_type, &value.to_string())?,
+        (_, Value::String(string)) => tokenize(param_type, string)?,
+        _ => {
+            return Err(error!(
+                Codec,
+                "`{value}` is not a valid `{param_type:?}` value"
+            ))
+        }
+    };
+
+    Ok(token)
+}
+
+fn tokenize_all_json(element_type: &ParamType, elements: &[Value]) -> Result<Vec<Token>> {
+    elements
+        .iter()
+        .map(|element| tokenize_json_value(element_type, element))
+        .collect()
+}
+
+fn tokenize_each_json(element_types: &[ParamType], elements: &[Value]) -> Result<Vec<Token>> {
+    if element_types.len() != elements.len() {
+        return Err(error!(
+            Codec,
+            "expected {} elements, got {}",
+            element_types.len(),
+            elements.len()
+        ));
+    }
+
+    element_types
+        .iter()
+        .zip(elements)
+        .map(|(element_type, element)| tokenize_json_value(element_type, element))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::enum_variants::EnumVariants;
+
+    #[test]
+    fn converts_nested_tokens() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::B256])?;
+        let token = Token::Struct(vec![
+            Token::StringArray(StaticStringToken::new("fuel".to_string(), Some(4))),
+            Token::Vector(vec![Token::U8(1), Token::U8(2)]),
+            Token::Enum(Box::new((1, Token::B256([0xab; 32]), variants))),
+            Token::Unit,
+        ]);
+
+        let expected = json!([
+            "fuel",
+            [1, 2],
+            {"variant": 1, "value": format!("0x{}", "ab".repeat(32))},
+            null,
+        ]);
+        assert_eq!(token_to_json(&token), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_values_convert_back_to_tokens() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::B256])?;
+        let param_type = ParamType::Struct {
+            fields: vec![
+                ParamType::StringArray(4),
+                ParamType::Vector(Box::new(ParamType::U8)),
+                ParamType::Enum {
+                    variants: variants.clone(),
+                    generics: vec![],
+                },
+                ParamType::U128,
+            ],
+            generics: vec![],
+        };
+        let token = Token::Struct(vec![
+            Token::StringArray(StaticStringToken::new("fuel".to_string(), Some(4))),
+            Token::Vector(vec![Token::U8(1), Token::U8(2)]),
+            Token::Enum(Box::new((1, Token::B256([0xab; 32]), variants))),
+            Token::U128(u128::MAX),
+        ]);
+
+        assert_eq!(tokenize_json(&param_type, &token_to_json(&token))?, token);
+
+        let err = tokenize_json(&ParamType::U8, &json!(256)).expect_err("should not fit a u8");
+        assert_eq!(err.to_string(), "codec: `256` is not a valid `U8` value");
+
+        Ok(())
+    }
+
+    #[test]
+    fn arrays_of_the_wrong_shape_are_rejected() {
+        let param_type =
+            ParamType::Array(Box::new(ParamType::Array(Box::new(ParamType::U64), 2)), 2);
+
+        let err = tokenize_json(&param_type, &json!([[1, 2], [3, 4, 5]])).expect_err("ragged");
+        assert_eq!(
+            err.to_string(),
+            "codec: array shape mismatch at `value[1]`: expected 2 elements, got 3"
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use crate::{
+    codec::{tokenize_bytes, ABIEncoder, EncoderConfig},
+    types::{
+        enum_variants::EnumVariants,
+        errors::{error, Error, Result},
+        param_types::ParamType,
+        StaticStringToken, Token, U256,
+    },
+};
+
+/// Encodes values given as `(type, value)` string pairs, without needing an ABI. Types are
+/// parsed as Sway types (see `ParamType::from_str`) and values as described in [`tokenize`].
+///
+/// ```
+/// use fuels_core::codec::encode_params;
+///
+/// let encoded = encode_params(&[("u64", "42"), ("bool", "true")]).unwrap();
+///
+/// assert_eq!(encoded, [[0, 0, 0, 0, 0, 0, 0, 42], [0, 0, 0, 0, 0, 0, 0, 1]].concat());
+/// ```
+pub fn encode_params(params: &[(&str, &str)]) -> Result<Vec<u8>> {
+    let tokens = params
+        .iter()
+        .map(|(type_str, value)| tokenize(&type_str.parse::<ParamType>()?, value))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ABIEncoder::new(EncoderConfig::default())
+        .encode(&tokens)?
+        .resolve(0))
+}
+
+/// Parses `value` as a value of `param_type`:
+///
+/// * unit: `()`
+/// * `bool`: `true` or `false`
+/// * integers: decimal or `0x`-prefixed hex
+/// * `b256`, `Bytes`, raw slices and `u8` arrays and vectors: `0x...`, `b64:...` or `[1, 2, ...]`
+/// * strings: the string, optionally in double quotes
+/// * arrays and vectors: `[v1, v2, ...]`
+/// * tuples: `(v1, v2, ...)`
+/// * structs: `{v1, v2, ...}`, the fields in declaration order
+/// * enums: `discriminant:value`, e.g. `1:42` or `0:()`
+/// * options: `None` or `Some(value)`, e.g. `Some({1, 2})`, as well as `0:()` and `1:value`
+///
+/// Every dimension of a (nested) array must have the length `param_type` prescribes, see
+/// [`ParamType::validate_array_shape`].
+pub fn tokenize(param_type: &ParamType, value: &str) -> Result<Token> {
+    let token = tokenize_value(param_type, value)?;
+    param_type.validate_array_shape(&token)?;
+
+    Ok(token)
+}
+
+fn tokenize_value(param_type: &ParamType, value: &str) -> Result<Token> {
+    let value = value.trim();
+
+    let token = match param_type {
+        ParamType::Unit => {
+            if value != "()" {
+                return Err(invalid_value(param_type, value));
+            }
+            Token::Unit
+        }
+        ParamType::Bool => Token::Bool(
+            value
+                .parse()
+                .map_err(|_| invalid_value(param_type, value))?,
+        ),
+        ParamType::U8 => Token::U8(parse_uint(param_type, value)?),
+        ParamType::U16 => Token::U16(parse_uint(param_type, value)?),
+        ParamType::U32 => Token::U32(parse_uint(param_type, value)?),
+        ParamType::U64 => Token::U64(parse_uint(param_type, value)?),
+        ParamType::U128 => Token::U128(parse_uint(param_type, value)?),
+        ParamType::U256 => {
+            let parsed = match value.strip_prefix("0x") {
+                Some(hex_digits) => U256::from_str_radix(hex_digits, 16).ok(),
+                None => U256::from_dec_str(value).ok(),
+            };
+            Token::U256(parsed.ok_or_else(|| invalid_value(param_type, value))?)
+        }
+        ParamType::B256 | ParamType::Bytes | ParamType::RawSlice => {
+            tokenize_bytes(param_type, value)?
+        }
+        ParamType::Array(element_type, _) | ParamType::Vector(element_type)
+            if **element_type == ParamType::U8 && !value.starts_with('[') =>
+        {
+            tokenize_bytes(param_type, value)?
+        }
+        ParamType::Array(element_type, _) => {
+            Token::Array(tokenize_all(element_type, delimited(value, '[', ']')?)?)
+        }
+        ParamType::Vector(element_type) => {
+            Token::Vector(tokenize_all(element_type, delimited(value, '[', ']')?)?)
+        }
+        ParamType::Tuple(element_types) => {
+            Token::Tuple(tokenize_each(element_types, delimited(value, '(', ')')?)?)
+        }
+        ParamType::Struct { fields, .. } => {
+            Token::Struct(tokenize_each(fields, delimited(value, '{', '}')?)?)
+        }
+        ParamType::Enum { variants, .. } => {
+            let (discriminant, variant_value) = match option_variant(variants, value) {
+                Some(selected) => selected,
+                None => {
+                    let (discriminant, variant_value) = value
+                        .split_once(':')
+                        .ok_or_else(|| invalid_value(param_type, value))?;
+                    let discriminant = discriminant
+                        .trim()
+                        .parse()
+                        .map_err(|_| invalid_value(param_type, value))?;
+
+                    (discriminant, variant_value)
+                }
+            };
+            let variant_type = variants.param_type_of_variant(discriminant)?;
+
+            let variant_token = tokenize_value(variant_type, variant_value)?;
+            Token::Enum(Box::new((discriminant, variant_token, variants.clone())))
+        }
+        ParamType::String => Token::String(unquote(value).to_string()),
+        ParamType::StringSlice => {
+            Token::StringSlice(StaticStringToken::new(unquote(value).to_string(), None))
+        }
+        ParamType::StringArray(len) => {
+            let string = unquote(value);
+            if string.len() != *len {
+                return Err(error!(
+                    Codec,
+                    "`{param_type:?}` expects {len} bytes, got {} in `{value}`",
+                    string.len()
+                ));
+            }
+            Token::StringArray(StaticStringToken::new(string.to_string(), Some(*len)))
+        }
+    };
+
+    Ok(token)
+}
+
+/// Parses `None` and `Some(value)` for enums shaped like `Option`: two variants, the first one
+/// a unit.
+fn option_variant<'a>(variants: &EnumVariants, value: &'a str) -> Option<(u64, &'a str)> {
+    if !matches!(variants.param_types(), [ParamType::Unit, _]) {
+        return None;
+    }
+    if value == "None" {
+        return Some((0, "()"));
+    }
+
+    value
+        .strip_prefix("Some(")?
+        .strip_suffix(')')
+        .map(|inner| (1, inner))
+}
+
+fn invalid_value(param_type: &ParamType, value: &str) -> Error {
+    error!(Codec, "`{value}` is not a valid `{param_type:?}` value")
+}
+
+fn parse_uint<T>(param_type: &ParamType, value: &str) -> Result<T>
+where
+    T: TryFrom<u128>,
+{
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex_digits) => u128::from_str_radix(hex_digits, 16),
+        None => value.parse(),
+    };
+
+    parsed
+        .ok()
+        .and_then(|parsed| T::try_from(parsed).ok())
+        .ok_or_else(|| invalid_value(param_type, value))
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn tokenize_all(element_type: &ParamType, elements: Vec<&str>) -> Result<Vec<Token>> {
+    elements
+        .into_iter()
+        .map(|element| tokenize_value(element_type, element))
+        .collect()
+}
+
+fn tokenize_each(element_types: &[ParamType], elements: Vec<&str>) -> Result<Vec<Token>> {
+    if element_types.len() != elements.len() {
+        return Err(error!(
+            Codec,
+            "expected {} elements, got {}",
+            element_types.len(),
+            elements.len()
+        ));
+    }
+
+    element_types
+        .iter()
+        .zip(elements)
+        .map(|(element_type, element)| tokenize_value(element_type, element))
+        .collect()
+}
+
+/// Strips `open` and `close` around `value` and splits what's inside on the commas that aren't
+/// nested in brackets or strings.
+fn delimited(value: &str, open: char, close: char) -> Result<Vec<&str>> {
+    let inner = value
+        .strip_prefix(open)
+        .and_then(|rest| rest.strip_suffix(close))
+        .ok_or_else(|| {
+            error!(
+                Codec,
+                "expected `{value}` to be enclosed in `{open}{close}`"
+            )
+        })?;
+
+    let mut elements = vec![];
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut start = 0;
+    for (index, char) in inner.char_indices() {
+        match char {
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| error!(Codec, "unbalanced `{char}` in `{value}`"))?;
+            }
+            ',' if !in_string && depth == 0 => {
+                elements.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = &inner[start..];
+    if !last.trim().is_empty() || !elements.is_empty() {
+        elements.push(last);
+    }
+
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        codec::ABIDecoder,
+        traits::{Parameterize, Tokenizable},
+        types::{Bits256, SizedAsciiString},
+    };
+
+    #[test]
+    fn encodes_like_typed_values() -> Result<()> {
+        let encoded = encode_params(&[
+            ("(u8, bool)", "(0x2a, true)"),
+            ("[u16; 2]", "[1, 2]"),
+            ("str[3]", "\"abc\""),
+            ("b256", &format!("0x{}", "01".repeat(32))),
+            ("Vec<u64>", "[7, 8]"),
+        ])?;
+
+        let expected = ABIEncoder::default()
+            .encode(&[
+                (42u8, true).into_token(),
+                [1u16, 2u16].into_token(),
+                SizedAsciiString::<3>::new("abc".to_string())?.into_token(),
+                Bits256([1; 32]).into_token(),
+                vec![7u64, 8u64].into_token(),
+            ])?
+            .resolve(0);
+
+        assert_eq!(encoded, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tokenizes_nested_values() -> Result<()> {
+        let param_type: ParamType = "[(u8, Vec<u8>); 2]".parse()?;
+
+        let token = tokenize(&param_type, "[(1, 0x0203), (4, [5, 6])]")?;
+
+        let u8s = |values: &[u8]| values.iter().copied().map(Token::U8).collect::<Vec<_>>();
+        assert_eq!(
+            token,
+            Token::Array(vec![
+                Token::Tuple(vec![Token::U8(1), Token::Vector(u8s(&[2, 3]))]),
+                Token::Tuple(vec![Token::U8(4), Token::Vector(u8s(&[5, 6]))]),
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn arrays_of_the_wrong_shape_are_rejected() -> Result<()> {
+        let param_type: ParamType = "[[u64; 2]; 2]".parse()?;
+
+        let err = tokenize(&param_type, "[[1, 2], [3]]").expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "codec: array shape mismatch at `value[1]`: expected 2 elements, got 1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tokenizes_enums() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::U64])?;
+        let param_type = ParamType::Enum {
+            variants: variants.clone(),
+            generics: vec![],
+        };
+
+        assert_eq!(
+            tokenize(&param_type, "1:42")?,
+            Token::Enum(Box::new((1, Token::U64(42), variants.clone())))
+        );
+        assert_eq!(
+            tokenize(&param_type, "0:()")?,
+            Token::Enum(Box::new((0, Token::Unit, variants)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn options_of_structs_round_trip() -> Result<()> {
+        // `Option<(u8, u64)>` is laid out like an `Option` of a struct with the same fields
+        let param_type = Option::<(u8, u64)>::param_type();
+        let round_trip = |value: &str| -> Result<Option<(u8, u64)>> {
+            let token = tokenize(&param_type, value)?;
+            let encoded = ABIEncoder::default().encode(&[token])?.resolve(0);
+            let decoded = ABIDecoder::default().decode(&param_type, &encoded)?;
+
+            Option::from_token(decoded)
+        };
+
+        assert_eq!(round_trip("Some((2, 3))")?, Some((2, 3)));
+        assert_eq!(round_trip("1:(2, 3)")?, Some((2, 3)));
+        assert_eq!(round_trip("None")?, None);
+        assert_eq!(round_trip("0:()")?, None);
+
+        let nested = Option::<Option<u64>>::param_type();
+        assert_eq!(
+            tokenize(&nested, "Some(None)")?,
+            Some(None::<u64>).into_token()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_invalid_values() {
+        let err = encode_params(&[("u8", "256")]).expect_err("should fail because of overflow");
+        assert_eq!(err.to_string(), "codec: `256` is not a valid `U8` value");
+
+        let err = encode_params(&[("[u64; 3]", "[1, 2]")]).expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "codec: array shape mismatch at `value`: expected 3 elements, got 2"
+        );
+    }
+}
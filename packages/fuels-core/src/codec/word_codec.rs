@@ -0,0 +1,91 @@
+pub use crate::constants::WORD_SIZE;
+
+/// How the VM lays out its words: [`WORD_SIZE`] bytes, most significant byte first. The word
+/// handling of the encoder and decoder goes through these helpers. Other places still read and
+/// write words directly, e.g. `u128` and `u256` values, the disassembler and the call scripts,
+/// so they'd need changes too for a VM with another word size or byte order.
+///
+/// Values narrower than a word, e.g. a `u32` or a `bool` passed on its own, take a whole word
+/// and are right-aligned in it.
+pub struct WordCodec;
+
+impl WordCodec {
+    pub fn encode(value: u64) -> [u8; WORD_SIZE] {
+        value.to_be_bytes()
+    }
+
+    pub fn decode(word: [u8; WORD_SIZE]) -> u64 {
+        u64::from_be_bytes(word)
+    }
+
+    /// Decodes the word at the start of `bytes`, if they hold a whole one.
+    pub fn read(bytes: &[u8]) -> Option<u64> {
+        let word = bytes.get(..WORD_SIZE)?;
+
+        Some(Self::decode(
+            word.try_into().expect("the slice is a word long"),
+        ))
+    }
+
+    /// Places the big-endian bytes of a value narrower than a word at the end of a word.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` are longer than a word.
+    pub fn right_aligned(bytes: &[u8]) -> [u8; WORD_SIZE] {
+        let mut word = [0; WORD_SIZE];
+        word[WORD_SIZE - bytes.len()..].copy_from_slice(bytes);
+
+        word
+    }
+
+    /// The last `N` bytes of `word`, where [`Self::right_aligned`] placed a value of `N`
+    /// bytes.
+    pub fn right_aligned_value<const N: usize>(word: &[u8; WORD_SIZE]) -> [u8; N] {
+        word[WORD_SIZE - N..]
+            .try_into()
+            .expect("the slice is N bytes long")
+    }
+
+    /// `len` rounded up to a whole number of words, `None` if that overflows.
+    pub fn padded_len(len: usize) -> Option<usize> {
+        len.checked_next_multiple_of(WORD_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_big_endian() {
+        let word = WordCodec::encode(0x0102);
+
+        assert_eq!(word, [0, 0, 0, 0, 0, 0, 1, 2]);
+        assert_eq!(WordCodec::decode(word), 0x0102);
+        assert_eq!(
+            WordCodec::read(&[word.as_slice(), &[9]].concat()),
+            Some(0x0102)
+        );
+        assert_eq!(WordCodec::read(&word[1..]), None);
+    }
+
+    #[test]
+    fn narrow_values_are_right_aligned() {
+        let word = WordCodec::right_aligned(&7u16.to_be_bytes());
+
+        assert_eq!(word, WordCodec::encode(7));
+        assert_eq!(
+            WordCodec::right_aligned_value::<2>(&word),
+            7u16.to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn lengths_are_padded_to_whole_words() {
+        assert_eq!(WordCodec::padded_len(0), Some(0));
+        assert_eq!(WordCodec::padded_len(1), Some(WORD_SIZE));
+        assert_eq!(WordCodec::padded_len(WORD_SIZE), Some(WORD_SIZE));
+        assert_eq!(WordCodec::padded_len(usize::MAX), None);
+    }
+}
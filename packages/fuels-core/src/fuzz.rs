@@ -0,0 +1,239 @@
+//! Random values for fuzzing contract functions. Requires the `fuzz` feature, off by default.
+//!
+//! ```
+//! use fuels_core::{
+//!     fuzz::{arbitrary_token_for, FuzzConfig},
+//!     types::param_types::ParamType,
+//! };
+//! use rand::{rngs::StdRng, SeedableRng};
+//!
+//! let param_type: ParamType = "(u64, str[4], Vec<bool>)".parse().unwrap();
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! let token = arbitrary_token_for(&param_type, &mut rng, &FuzzConfig::default());
+//! ```
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::types::{
+    errors::{error, Result},
+    param_types::ParamType,
+    StaticStringToken, Token, U256,
+};
+
+/// Bounds the values generated by [`arbitrary_token_for`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzConfig {
+    max_len: usize,
+    charset: Vec<u8>,
+}
+
+impl FuzzConfig {
+    /// Vectors, `Bytes`, raw slices and strings of dynamic length will have at most `max_len`
+    /// elements.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Strings will only be made of characters from `charset`. Sway strings are ASCII, so
+    /// `charset` must be non-empty ASCII.
+    pub fn with_charset(mut self, charset: &str) -> Result<Self> {
+        if charset.is_empty() || !charset.is_ascii() {
+            return Err(error!(
+                Other,
+                "`charset` must be non-empty ASCII, got `{charset}`"
+            ));
+        }
+
+        self.charset = charset.as_bytes().to_vec();
+        Ok(self)
+    }
+
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    pub fn charset(&self) -> &str {
+        std::str::from_utf8(&self.charset).expect("the charset is ASCII")
+    }
+}
+
+impl Default for FuzzConfig {
+    fn default() -> Self {
+        Self {
+            max_len: 16,
+            charset: (b' '..=b'~').collect(),
+        }
+    }
+}
+
+/// Generates a random valid [`Token`] of `param_type`: arrays and string arrays have their
+/// declared length, enums hold one of their variants and strings are made of `config`'s charset.
+/// Paired with `Tokenizable::from_token` this gives random arguments for generated bindings.
+pub fn arbitrary_token_for<R: Rng + ?Sized>(
+    param_type: &ParamType,
+    rng: &mut R,
+    config: &FuzzConfig,
+) -> Token {
+    match param_type {
+        ParamType::Unit => Token::Unit,
+        ParamType::Bool => Token::Bool(rng.gen()),
+        ParamType::U8 => Token::U8(rng.gen()),
+        ParamType::U16 => Token::U16(rng.gen()),
+        ParamType::U32 => Token::U32(rng.gen()),
+        ParamType::U64 => Token::U64(rng.gen()),
+        ParamType::U128 => Token::U128(rng.gen()),
+        ParamType::U256 => Token::U256(U256::from_big_endian(&rng.gen::<[u8; 32]>())),
+        ParamType::B256 => Token::B256(rng.gen()),
+        ParamType::Bytes => Token::Bytes(arbitrary_bytes(rng, config)),
+        ParamType::RawSlice => Token::RawSlice(arbitrary_bytes(rng, config)),
+        ParamType::String => Token::String(arbitrary_string(rng, config, None)),
+        ParamType::StringSlice => Token::StringSlice(StaticStringToken::new(
+            arbitrary_string(rng, config, None),
+            None,
+        )),
+        ParamType::StringArray(len) => Token::StringArray(StaticStringToken::new(
+            arbitrary_string(rng, config, Some(*len)),
+            Some(*len),
+        )),
+        ParamType::Array(element_type, len) => {
+            Token::Array(arbitrary_tokens(element_type, *len, rng, config))
+        }
+        ParamType::Vector(element_type) => {
+            let len = rng.gen_range(0..=config.max_len);
+            Token::Vector(arbitrary_tokens(element_type, len, rng, config))
+        }
+        ParamType::Tuple(element_types) => Token::Tuple(
+            element_types
+                .iter()
+                .map(|element_type| arbitrary_token_for(element_type, rng, config))
+                .collect(),
+        ),
+        ParamType::Struct { fields, .. } => Token::Struct(
+            fields
+                .iter()
+                .map(|field_type| arbitrary_token_for(field_type, rng, config))
+                .collect(),
+        ),
+        ParamType::Enum { variants, .. } => {
+            // `EnumVariants` always has at least one variant
+            let discriminant = rng.gen_range(0..variants.param_types().len());
+            let variant_token =
+                arbitrary_token_for(&variants.param_types()[discriminant], rng, config);
+
+            Token::Enum(Box::new((
+                discriminant as u64,
+                variant_token,
+                variants.clone(),
+            )))
+        }
+    }
+}
+
+fn arbitrary_tokens<R: Rng + ?Sized>(
+    element_type: &ParamType,
+    len: usize,
+    rng: &mut R,
+    config: &FuzzConfig,
+) -> Vec<Token> {
+    (0..len)
+        .map(|_| arbitrary_token_for(element_type, rng, config))
+        .collect()
+}
+
+fn arbitrary_bytes<R: Rng + ?Sized>(rng: &mut R, config: &FuzzConfig) -> Vec<u8> {
+    let len = rng.gen_range(0..=config.max_len);
+
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+fn arbitrary_string<R: Rng + ?Sized>(
+    rng: &mut R,
+    config: &FuzzConfig,
+    len: Option<usize>,
+) -> String {
+    let len = len.unwrap_or_else(|| rng.gen_range(0..=config.max_len));
+
+    (0..len)
+        .map(|_| {
+            char::from(
+                *config
+                    .charset
+                    .choose(rng)
+                    .expect("the charset is not empty"),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{codec::ABIEncoder, types::enum_variants::EnumVariants};
+
+    #[test]
+    fn generated_tokens_have_the_expected_shape() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::StringArray(3)])?;
+        let param_type = ParamType::Tuple(vec![
+            ParamType::Array(Box::new(ParamType::U8), 4),
+            ParamType::Vector(Box::new(ParamType::String)),
+            ParamType::Enum {
+                variants,
+                generics: vec![],
+            },
+        ]);
+        let config = FuzzConfig::default().with_max_len(3).with_charset("ab")?;
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let token = arbitrary_token_for(&param_type, &mut rng, &config);
+
+            let Token::Tuple(elements) = &token else {
+                panic!("expected a tuple, got {token:?}")
+            };
+            assert!(matches!(&elements[0], Token::Array(bytes) if bytes.len() == 4));
+            match &elements[1] {
+                Token::Vector(strings) => {
+                    assert!(strings.len() <= 3);
+                    for string in strings {
+                        let Token::String(string) = string else {
+                            panic!("expected a string, got {string:?}")
+                        };
+                        assert!(string.len() <= 3);
+                        assert!(string.chars().all(|c| c == 'a' || c == 'b'));
+                    }
+                }
+                other => panic!("expected a vector, got {other:?}"),
+            }
+            match &elements[2] {
+                Token::Enum(selector) => match &**selector {
+                    (0, Token::Unit, _) => {}
+                    (1, Token::StringArray(string), _) => {
+                        assert_eq!(string.get_encodable_str()?.len(), 3)
+                    }
+                    other => panic!("unexpected enum variant {other:?}"),
+                },
+                other => panic!("expected an enum, got {other:?}"),
+            }
+
+            ABIEncoder::default().encode(&[token])?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn charset_must_be_ascii() {
+        let err = FuzzConfig::default()
+            .with_charset("é")
+            .expect_err("should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "`charset` must be non-empty ASCII, got `é`"
+        );
+    }
+}
@@ -1,4 +1,6 @@
 pub mod codec;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
 pub mod traits;
 pub mod types;
 mod utils;
@@ -1,7 +1,11 @@
+mod canonical_hash;
 mod parameterize;
+mod signature_ext;
 mod signer;
 mod tokenizable;
 
+pub use canonical_hash::*;
 pub use parameterize::*;
+pub use signature_ext::*;
 pub use signer::*;
 pub use tokenizable::*;
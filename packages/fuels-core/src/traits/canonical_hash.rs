@@ -0,0 +1,178 @@
+use std::cmp::Ordering;
+
+use fuel_abi_types::abi::program::ProgramABI;
+use fuel_types::Bytes32;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::types::errors::Result;
+
+/// Hashes the content of an ABI regardless of how it was formatted.
+pub trait CanonicalHash {
+    /// Computes a SHA256 hash over the canonical JSON form of `self`. Object keys are
+    /// sorted, whitespace is removed, duplicate type declarations are dropped and the
+    /// types, functions, logged types and configurables are ordered by their ids/names, ids
+    /// numerically.
+    fn canonical_hash(&self) -> Result<Bytes32>;
+}
+
+impl CanonicalHash for ProgramABI {
+    fn canonical_hash(&self) -> Result<Bytes32> {
+        let mut abi = serde_json::to_value(self)?;
+
+        sort_array_by_key(&mut abi, "types", "typeId");
+        sort_array_by_key(&mut abi, "functions", "name");
+        sort_array_by_key(&mut abi, "loggedTypes", "logId");
+        sort_array_by_key(&mut abi, "messagesTypes", "messageId");
+        sort_array_by_key(&mut abi, "configurables", "name");
+
+        // `serde_json::Map` keeps its keys sorted and `to_vec` emits no whitespace
+        let canonical_json = serde_json::to_vec(&abi)?;
+        let hash: [u8; 32] = Sha256::digest(canonical_json).into();
+
+        Ok(hash.into())
+    }
+}
+
+/// Sorts the elements by `key`, e.g. ids numerically, and elements sharing a key by their
+/// content, so that the order doesn't depend on the input and duplicates end up next to each
+/// other.
+fn sort_array_by_key(abi: &mut Value, array_name: &str, key: &str) {
+    if let Some(Value::Array(elements)) = abi.get_mut(array_name) {
+        elements.sort_by(|a, b| cmp_values(&a[key], &b[key]).then_with(|| cmp_values(a, b)));
+        elements.dedup();
+    }
+}
+
+/// A total order over JSON values, following their structure rather than their text: `2` comes
+/// before `10`. Values of different kinds are ordered by kind.
+fn cmp_values(a: &Value, b: &Value) -> Ordering {
+    fn kind(value: &Value) -> u8 {
+        match value {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            Value::Array(_) => 4,
+            Value::Object(_) => 5,
+        }
+    }
+
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(a), Value::Number(b)) => match (a.as_u64(), b.as_u64()) {
+            (Some(a), Some(b)) => a.cmp(b),
+            _ => {
+                let (a, b) = (
+                    a.as_f64().unwrap_or_default(),
+                    b.as_f64().unwrap_or_default(),
+                );
+                a.total_cmp(&b)
+            }
+        },
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Array(a), Value::Array(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| cmp_values(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        // `serde_json::Map` iterates over its keys in order
+        (Value::Object(a), Value::Object(b)) => a
+            .iter()
+            .zip(b)
+            .map(|((a_key, a), (b_key, b))| a_key.cmp(b_key).then_with(|| cmp_values(a, b)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        _ => kind(a).cmp(&kind(b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_does_not_depend_on_formatting_or_ordering() -> Result<()> {
+        let abi: ProgramABI = serde_json::from_str(
+            r#"{
+                "types": [
+                    {"typeId": 0, "type": "bool", "components": null, "typeParameters": null},
+                    {"typeId": 1, "type": "u64", "components": null, "typeParameters": null}
+                ],
+                "functions": [
+                    {"inputs": [], "name": "a", "output": {"name": "", "type": 0, "typeArguments": null}, "attributes": null},
+                    {"inputs": [], "name": "b", "output": {"name": "", "type": 1, "typeArguments": null}, "attributes": null}
+                ],
+                "loggedTypes": [],
+                "messagesTypes": [],
+                "configurables": []
+            }"#,
+        )?;
+        let reformatted_abi: ProgramABI = serde_json::from_str(
+            r#"{"configurables":[],"loggedTypes":[],"messagesTypes":[],
+            "functions":[{"attributes":null,"inputs":[],"name":"b","output":{"name":"","type":1,"typeArguments":null}},
+            {"attributes":null,"inputs":[],"name":"a","output":{"name":"","type":0,"typeArguments":null}}],
+            "types":[{"components":null,"type":"u64","typeId":1,"typeParameters":null},
+            {"components":null,"type":"bool","typeId":0,"typeParameters":null},
+            {"components":null,"type":"bool","typeId":0,"typeParameters":null}]}"#,
+        )?;
+
+        assert_eq!(abi.canonical_hash()?, reformatted_abi.canonical_hash()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ordering_follows_the_structure_of_the_values() -> Result<()> {
+        let types = |type_ids: &[(usize, &str)]| -> Result<ProgramABI> {
+            let types = type_ids
+                .iter()
+                .map(|(type_id, type_field)| {
+                    format!(
+                        r#"{{"typeId": {type_id}, "type": "{type_field}", "components": null, "typeParameters": null}}"#
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            Ok(serde_json::from_str(&format!(
+                r#"{{"types": [{types}], "functions": [], "loggedTypes": [], "messagesTypes": [], "configurables": []}}"#
+            ))?)
+        };
+
+        let mut abi = serde_json::to_value(types(&[(10, "u64"), (2, "bool"), (2, "u8")])?)?;
+        sort_array_by_key(&mut abi, "types", "typeId");
+
+        let sorted = abi["types"]
+            .as_array()
+            .expect("is an array")
+            .iter()
+            .map(|declaration| declaration["type"].as_str().expect("is a string"))
+            .collect::<Vec<_>>();
+        assert_eq!(sorted, ["bool", "u8", "u64"]);
+
+        // declarations sharing an id are ordered by content, not by their position
+        assert_eq!(
+            types(&[(2, "u8"), (2, "bool")])?.canonical_hash()?,
+            types(&[(2, "bool"), (2, "u8")])?.canonical_hash()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hash_depends_on_content() -> Result<()> {
+        let abi: ProgramABI = serde_json::from_str(
+            r#"{"types": [], "functions": [], "loggedTypes": [], "messagesTypes": [], "configurables": []}"#,
+        )?;
+        let mut changed_abi = abi.clone();
+        changed_abi.functions.push(serde_json::from_str(
+            r#"{"inputs": [], "name": "a", "output": {"name": "", "type": 0, "typeArguments": null}, "attributes": null}"#,
+        )?);
+
+        assert_ne!(abi.canonical_hash()?, changed_abi.canonical_hash()?);
+
+        Ok(())
+    }
+}
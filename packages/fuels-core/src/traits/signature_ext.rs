@@ -0,0 +1,159 @@
+use fuel_crypto::{Message, SecretKey, Signature};
+
+use crate::types::{
+    bech32::{Bech32Address, FUEL_BECH32_HRP},
+    errors::{error, Result},
+    U256,
+};
+
+/// Order of the secp256k1 curve, big endian.
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Helpers for moving between the signature forms found in the wild.
+///
+/// Fuel signatures are *compact*: 64 bytes of `r` followed by a low `s`, with the recovery id
+/// stored in the most significant bit of `s`. Other ecosystems usually exchange *recoverable*
+/// signatures: 65 bytes of `r`, `s` and a trailing recovery id.
+pub trait SignatureExt: Sized {
+    /// Signs `message` using a nonce derived deterministically from the key and the message
+    /// (RFC 6979), so the same inputs always produce the same signature.
+    fn sign_deterministic(secret: &SecretKey, message: &Message) -> Self;
+
+    /// Recovers the address of the account that signed `message`.
+    fn recover_address(&self, message: &Message) -> Result<Bech32Address>;
+
+    fn recovery_id(&self) -> u8;
+
+    /// Returns `r || s || recovery_id`.
+    fn to_recoverable_bytes(&self) -> [u8; 65];
+
+    /// Builds a compact signature out of `r || s || recovery_id`. A high `s` is normalized,
+    /// flipping the recovery id accordingly. Recovery ids of 27 and 28 are accepted as well.
+    fn from_recoverable_bytes(bytes: &[u8; 65]) -> Result<Self>;
+}
+
+impl SignatureExt for Signature {
+    fn sign_deterministic(secret: &SecretKey, message: &Message) -> Self {
+        Signature::sign(secret, message)
+    }
+
+    fn recover_address(&self, message: &Message) -> Result<Bech32Address> {
+        let public_key = self.recover(message)?;
+
+        Ok(Bech32Address::new(FUEL_BECH32_HRP, public_key.hash()))
+    }
+
+    fn recovery_id(&self) -> u8 {
+        self[32] >> 7
+    }
+
+    fn to_recoverable_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0; 65];
+        bytes[..64].copy_from_slice(&**self);
+        bytes[32] &= 0x7f;
+        bytes[64] = self.recovery_id();
+
+        bytes
+    }
+
+    fn from_recoverable_bytes(bytes: &[u8; 65]) -> Result<Self> {
+        let mut recovery_id = match bytes[64] {
+            id @ (0 | 1) => id,
+            id @ (27 | 28) => id - 27,
+            id => return Err(error!(Other, "invalid recovery id: {id}")),
+        };
+
+        let order = U256::from_big_endian(&SECP256K1_ORDER);
+        let mut s = U256::from_big_endian(&bytes[32..64]);
+        if s.is_zero() || s >= order {
+            return Err(error!(Other, "signature `s` is out of range"));
+        }
+        if s > order / 2 {
+            s = order - s;
+            recovery_id ^= 1;
+        }
+
+        let mut compact = [0; 64];
+        compact[..32].copy_from_slice(&bytes[..32]);
+        s.to_big_endian(&mut compact[32..]);
+        compact[32] |= recovery_id << 7;
+
+        Ok(Signature::from_bytes(compact))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    // signature of `Message::new("fuels interop")` by the address below
+    const SIGNATURE: &str = "4d5ceb6c40a1882a1ebed28b49ac0a8fe69e63581f15e606efe931a4841b5f523e707b23c1c7350d2cff538a91f53c985562e5f2336deb7c427618a2bb2f473b";
+    const SIGNER: &str = "fuel17x9kg3k7hqf42396vqenukm4yf59e5k0vj4yunr4mae9zjv9pdjszy098t";
+
+    #[test]
+    fn recovers_signer_address() -> Result<()> {
+        let signature = Signature::from_str(SIGNATURE)?;
+        let message = Message::new("fuels interop".as_bytes());
+
+        let address = signature.recover_address(&message)?;
+
+        assert_eq!(address.to_string(), SIGNER);
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_between_compact_and_recoverable_forms() -> Result<()> {
+        let signature = Signature::from_str(SIGNATURE)?;
+
+        let recoverable = signature.to_recoverable_bytes();
+        assert_eq!(recoverable[64], signature.recovery_id());
+        assert_eq!(recoverable[32] & 0x80, 0);
+
+        assert_eq!(Signature::from_recoverable_bytes(&recoverable)?, signature);
+
+        let mut ethereum_style = recoverable;
+        ethereum_style[64] += 27;
+        assert_eq!(
+            Signature::from_recoverable_bytes(&ethereum_style)?,
+            signature
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalizes_high_s() -> Result<()> {
+        let signature = Signature::from_str(SIGNATURE)?;
+        let recoverable = signature.to_recoverable_bytes();
+
+        // (r, n - s) with the opposite recovery id is the same signature
+        let order = U256::from_big_endian(&SECP256K1_ORDER);
+        let high_s = order - U256::from_big_endian(&recoverable[32..64]);
+        let mut malleated = recoverable;
+        high_s.to_big_endian(&mut malleated[32..64]);
+        malleated[64] ^= 1;
+
+        assert_eq!(Signature::from_recoverable_bytes(&malleated)?, signature);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_recovery_id() {
+        let mut recoverable = Signature::from_str(SIGNATURE)
+            .unwrap()
+            .to_recoverable_bytes();
+        recoverable[64] = 2;
+
+        let err = Signature::from_recoverable_bytes(&recoverable)
+            .expect_err("should fail because of the recovery id");
+
+        assert_eq!(err.to_string(), "invalid recovery id: 2");
+    }
+}
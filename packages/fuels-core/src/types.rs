@@ -7,9 +7,12 @@ pub use fuel_types::{
 };
 
 pub use crate::types::{core::*, wrappers::*};
-use crate::types::{
-    enum_variants::EnumVariants,
-    errors::{error, Error, Result},
+use crate::{
+    codec::{StringPolicy, WordCodec},
+    types::{
+        enum_variants::EnumVariants,
+        errors::{error, Error, Result},
+    },
 };
 
 pub mod bech32;
@@ -17,16 +20,34 @@ mod core;
 pub mod enum_variants;
 pub mod errors;
 pub mod param_types;
+pub mod signing_request;
+mod token_access;
 pub mod transaction_builders;
+pub mod tx_explanation;
+pub mod tx_size;
 pub mod tx_status;
 pub mod unresolved_bytes;
+pub mod wallet_activity;
 mod wrappers;
 
 pub type ByteArray = [u8; 8];
 pub type Selector = ByteArray;
 pub type EnumSelector = (u64, Token, EnumVariants);
 
-#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+/// Two string tokens are equal only if both their data and their expected length are. Ordering
+/// compares the data first.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 pub struct StaticStringToken {
     data: String,
     expected_len: Option<usize>,
@@ -42,6 +63,23 @@ impl StaticStringToken {
             return Err(error!(Codec, "string data can only have ascii values"));
         }
 
+        self.validate_len()
+    }
+
+    fn validate_with(&self, policy: StringPolicy) -> Result<()> {
+        if policy == StringPolicy::Ascii && !self.data.is_ascii() {
+            return Err(error!(
+                Codec,
+                "string data can only have ascii values under `StringPolicy::Ascii`, use \
+                `StringPolicy::Utf8` in the `EncoderConfig` to encode its UTF-8 bytes. Got: `{}`",
+                self.data
+            ));
+        }
+
+        self.validate_len()
+    }
+
+    fn validate_len(&self) -> Result<()> {
         if let Some(expected_len) = self.expected_len {
             if self.data.len() != expected_len {
                 return Err(error!(
@@ -56,10 +94,22 @@ impl StaticStringToken {
         Ok(())
     }
 
+    /// The string, whether or not it is valid.
+    pub(crate) fn data(&self) -> &str {
+        &self.data
+    }
+
     pub fn get_encodable_str(&self) -> Result<&str> {
         self.validate()?;
         Ok(self.data.as_str())
     }
+
+    /// Same as `get_encodable_str`, but checking the string against `policy` instead of
+    /// requiring ASCII.
+    pub fn get_encodable_str_with(&self, policy: StringPolicy) -> Result<&str> {
+        self.validate_with(policy)?;
+        Ok(self.data.as_str())
+    }
 }
 
 impl TryFrom<StaticStringToken> for String {
@@ -70,7 +120,17 @@ impl TryFrom<StaticStringToken> for String {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// A value ready to be encoded, or the result of decoding one.
+///
+/// Tokens can be hashed and totally ordered, e.g. to be used as map keys. Equality is
+/// structural: tokens of different variants are never equal, even if they would encode to the
+/// same bytes (e.g. `U8(1)` and `U16(1)`), and enum tokens also compare their `EnumVariants`.
+/// Tokens of different variants are ordered as the variants are declared below, while tokens
+/// of the same variant are ordered by their contents, element by element for collections.
+/// Since no variant holds a float, the ordering is total.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 pub enum Token {
     // Used for unit type variants in Enum. An "empty" enum is not represented as Enum<empty box>,
     // because this way we can have both unit and non-unit type variants.
@@ -109,16 +169,12 @@ impl Default for Token {
 
 /// Converts a u16 to a right aligned array of 8 bytes.
 pub fn pad_u16(value: u16) -> ByteArray {
-    let mut padded = ByteArray::default();
-    padded[6..].copy_from_slice(&value.to_be_bytes());
-    padded
+    WordCodec::right_aligned(&value.to_be_bytes())
 }
 
 /// Converts a u32 to a right aligned array of 8 bytes.
 pub fn pad_u32(value: u32) -> ByteArray {
-    let mut padded = [0u8; 8];
-    padded[4..].copy_from_slice(&value.to_be_bytes());
-    padded
+    WordCodec::right_aligned(&value.to_be_bytes())
 }
 
 pub fn pad_string(s: &str) -> Vec<u8> {
@@ -130,3 +186,53 @@ pub fn pad_string(s: &str) -> Vec<u8> {
 
     padded
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use super::*;
+    use crate::types::param_types::ParamType;
+
+    #[test]
+    fn tokens_can_be_deduplicated() {
+        let tokens = [
+            Token::Struct(vec![Token::U64(1), Token::String("a".to_string())]),
+            Token::U16(1),
+            Token::U8(1),
+            Token::Struct(vec![Token::U64(1), Token::String("a".to_string())]),
+        ];
+
+        let hashed = tokens.iter().cloned().collect::<HashSet<_>>();
+        let ordered = tokens.into_iter().collect::<BTreeSet<_>>();
+
+        assert_eq!(hashed.len(), 3);
+        assert_eq!(
+            ordered.into_iter().collect::<Vec<_>>(),
+            vec![
+                Token::U8(1),
+                Token::U16(1),
+                Token::Struct(vec![Token::U64(1), Token::String("a".to_string())]),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokens_of_the_same_variant_are_ordered_by_contents() -> Result<()> {
+        assert!(Token::U64(1) < Token::U64(2));
+        assert!(
+            Token::Vector(vec![Token::U8(1)]) < Token::Vector(vec![Token::U8(1), Token::U8(0)])
+        );
+        assert!(
+            Token::StringArray(StaticStringToken::new("ab".to_string(), Some(2)))
+                < Token::StringArray(StaticStringToken::new("b".to_string(), Some(1)))
+        );
+
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::U64])?;
+        let enum_token =
+            |discriminant, token| Token::Enum(Box::new((discriminant, token, variants.clone())));
+        assert!(enum_token(0, Token::Unit) < enum_token(1, Token::U64(0)));
+
+        Ok(())
+    }
+}
@@ -7,7 +7,9 @@ use crate::{
     utils::checked_round_up_to_word_alignment,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 pub struct EnumVariants {
     param_types: Vec<ParamType>,
 }
@@ -34,11 +36,22 @@ impl EnumVariants {
         })
     }
 
-    pub fn heap_type_variant(&self) -> Option<(u64, &ParamType)> {
+    /// The discriminants and types of the variants whose data lives on the heap, e.g. `Vec<u64>`,
+    /// `Bytes` or `String`.
+    pub fn heap_type_variants(&self) -> impl Iterator<Item = (u64, &ParamType)> {
         self.param_types()
             .iter()
             .enumerate()
-            .find_map(|(d, p)| p.is_extra_receipt_needed(false).then_some((d as u64, p)))
+            .filter(|(_, p)| p.is_extra_receipt_needed(false))
+            .map(|(d, p)| (d as u64, p))
+    }
+
+    #[deprecated(
+        since = "0.55.0",
+        note = "enums can have more than one heap type variant, use `heap_type_variants`"
+    )]
+    pub fn heap_type_variant(&self) -> Option<(u64, &ParamType)> {
+        self.heap_type_variants().next()
     }
 
     pub fn only_units_inside(&self) -> bool {
@@ -79,26 +92,31 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_get_heap_type_variant_discriminant() -> Result<()> {
+    fn test_get_heap_type_variant_discriminants() -> Result<()> {
+        let discriminants = |param_types| -> Result<Vec<u64>> {
+            let variants = EnumVariants::new(param_types)?;
+            Ok(variants.heap_type_variants().map(|(d, _)| d).collect())
+        };
+
         let param_types = vec![
             ParamType::U64,
             ParamType::Bool,
             ParamType::Vector(Box::from(ParamType::U64)),
         ];
-        let variants = EnumVariants::new(param_types)?;
-        assert_eq!(variants.heap_type_variant().unwrap().0, 2);
+        assert_eq!(discriminants(param_types)?, [2]);
 
         let param_types = vec![
             ParamType::Vector(Box::from(ParamType::U64)),
             ParamType::U64,
             ParamType::Bool,
         ];
-        let variants = EnumVariants::new(param_types)?;
-        assert_eq!(variants.heap_type_variant().unwrap().0, 0);
+        assert_eq!(discriminants(param_types)?, [0]);
+
+        let param_types = vec![ParamType::Bytes, ParamType::U64, ParamType::String];
+        assert_eq!(discriminants(param_types)?, [0, 2]);
 
         let param_types = vec![ParamType::U64, ParamType::Bool];
-        let variants = EnumVariants::new(param_types)?;
-        assert!(variants.heap_type_variant().is_none());
+        assert!(discriminants(param_types)?.is_empty());
         Ok(())
     }
 }
@@ -38,6 +38,8 @@ pub enum Error {
     Transaction(Reason),
     #[error("provider: {0}")]
     Provider(String),
+    #[error("timed out after {0:?}")]
+    Timeout(std::time::Duration),
     #[error("{0}")]
     Other(String),
 }
@@ -2,19 +2,29 @@ use std::{collections::HashMap, fmt, iter::zip};
 
 use fuel_abi_types::{
     abi::program::{TypeApplication, TypeDeclaration},
-    utils::{extract_array_len, extract_generic_name, extract_str_len, has_tuple_format},
+    utils::{extract_generic_name, extract_str_len, has_tuple_format},
 };
 use itertools::chain;
 
 use crate::{
     checked_round_up_to_word_alignment,
+    constants::WORD_SIZE,
     types::{
         enum_variants::EnumVariants,
         errors::{error, Error, Result},
+        Token,
     },
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+mod from_str;
+mod layout;
+
+pub use layout::TypeLayout;
+pub(crate) use layout::{parse_field_path, PathSegment};
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 pub enum ParamType {
     Unit,
     Bool,
@@ -51,7 +61,7 @@ pub enum ReturnLocation {
 impl ParamType {
     // Depending on the type, the returned value will be stored
     // either in `Return` or `ReturnData`.
-    pub fn get_return_location(&self) -> ReturnLocation {
+    pub fn return_location(&self) -> ReturnLocation {
         match self {
             Self::Unit | Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::Bool => {
                 ReturnLocation::Return
@@ -61,8 +71,14 @@ impl ParamType {
         }
     }
 
+    #[deprecated(since = "0.55.0", note = "renamed to `return_location`")]
+    pub fn get_return_location(&self) -> ReturnLocation {
+        self.return_location()
+    }
+
     /// Given a [ParamType], return the number of elements of that [ParamType] that can fit in
     /// `available_bytes`: it is the length of the corresponding heap type.
+    #[doc(hidden)]
     pub fn calculate_num_of_elements(
         param_type: &ParamType,
         available_bytes: usize,
@@ -89,6 +105,7 @@ impl ParamType {
         Ok(num_of_elements)
     }
 
+    #[doc(hidden)]
     pub fn children_need_extra_receipts(&self) -> bool {
         match self {
             ParamType::Array(inner, _) | ParamType::Vector(inner) => {
@@ -120,18 +137,6 @@ impl ParamType {
                     "enums currently support only one level deep heap types"
                 ));
             }
-
-            let num_of_children_needing_receipts = all_param_types
-                .iter()
-                .filter(|param_type| param_type.is_extra_receipt_needed(false))
-                .count();
-            if num_of_children_needing_receipts > 1 {
-                return Err(error!(
-                    Codec,
-                    "enums currently support only one heap-type variant. Found: \
-                        {num_of_children_needing_receipts}"
-                ));
-            }
         } else if self.children_need_extra_receipts() {
             return Err(error!(
                 Codec,
@@ -144,6 +149,65 @@ impl ParamType {
         Ok(())
     }
 
+    /// Checks that every (nested) array inside `token` has the length `self` prescribes for
+    /// that dimension. Errors point to the offending element, e.g. `value[1][0]`.
+    pub fn validate_array_shape(&self, token: &Token) -> Result<()> {
+        self.validate_array_shape_at(token, "value")
+    }
+
+    fn validate_array_shape_at(&self, token: &Token, path: &str) -> Result<()> {
+        match (self, token) {
+            (ParamType::Array(element_type, len), Token::Array(elements)) => {
+                if elements.len() != *len {
+                    return Err(error!(
+                        Codec,
+                        "array shape mismatch at `{path}`: expected {len} elements, got {}",
+                        elements.len()
+                    ));
+                }
+
+                elements
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(index, element)| {
+                        element_type.validate_array_shape_at(element, &format!("{path}[{index}]"))
+                    })
+            }
+            (ParamType::Array(..), token) => Err(error!(
+                Codec,
+                "array shape mismatch at `{path}`: expected an array, got `{token}`"
+            )),
+            (ParamType::Vector(element_type), Token::Vector(elements)) => elements
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, element)| {
+                    element_type.validate_array_shape_at(element, &format!("{path}[{index}]"))
+                }),
+            (ParamType::Tuple(param_types), Token::Tuple(tokens))
+            | (
+                ParamType::Struct {
+                    fields: param_types,
+                    ..
+                },
+                Token::Struct(tokens),
+            ) => {
+                zip(param_types, tokens)
+                    .enumerate()
+                    .try_for_each(|(index, (param_type, token))| {
+                        param_type.validate_array_shape_at(token, &format!("{path}.{index}"))
+                    })
+            }
+            (ParamType::Enum { variants, .. }, Token::Enum(selector)) => {
+                let (discriminant, token, _) = selector.as_ref();
+                variants
+                    .param_type_of_variant(*discriminant)?
+                    .validate_array_shape_at(token, &format!("{path}::{discriminant}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[doc(hidden)]
     pub fn is_extra_receipt_needed(&self, top_level_type: bool) -> bool {
         match self {
             ParamType::Vector(_) | ParamType::Bytes | ParamType::String => true,
@@ -161,7 +225,23 @@ impl ParamType {
         }
     }
 
+    /// The element type of a vector whose elements are heap types themselves, e.g.
+    /// `Vec<Vec<u64>>`, `Vec<Bytes>` or `Vec<String>`. When returned by a contract, the data of
+    /// each element is found in a receipt of its own, see `ABIDecoder::decode_heap_elements`.
+    pub fn nested_heap_element(&self) -> Option<&ParamType> {
+        let ParamType::Vector(element) = self else {
+            return None;
+        };
+
+        match element.as_ref() {
+            ParamType::Bytes | ParamType::String => Some(element),
+            ParamType::Vector(inner) if !inner.is_extra_receipt_needed(false) => Some(element),
+            _ => None,
+        }
+    }
+
     /// Compute the inner memory size of a containing heap type (`Bytes` or `Vec`s).
+    #[doc(hidden)]
     pub fn heap_inner_element_size(&self, top_level_type: bool) -> Result<Option<usize>> {
         let heap_bytes_size = match &self {
             ParamType::Vector(inner_param_type) => {
@@ -215,6 +295,20 @@ impl ParamType {
         }
     }
 
+    /// Calculates the number of words the VM expects this parameter to be encoded in. Useful
+    /// for preallocating buffers or computing offsets within script data.
+    pub fn compute_encoding_width(&self) -> Result<usize> {
+        let bytes = checked_round_up_to_word_alignment(self.compute_encoding_in_bytes()?)?;
+
+        Ok(bytes / WORD_SIZE)
+    }
+
+    /// Computes where the value is placed in the encoding. Its fields are laid out on demand,
+    /// see [`TypeLayout::field`].
+    pub fn layout(&self) -> Result<TypeLayout> {
+        TypeLayout::new(self)
+    }
+
     /// For when you need to convert a ABI JSON's TypeApplication into a ParamType.
     ///
     /// # Arguments
@@ -226,10 +320,79 @@ impl ParamType {
         type_application: &TypeApplication,
         type_lookup: &HashMap<usize, TypeDeclaration>,
     ) -> Result<Self> {
-        Type::try_from(type_application, type_lookup)?.try_into()
+        Self::try_from_type_application_cached(
+            type_application,
+            type_lookup,
+            &mut ParamTypeCache::default(),
+        )
+    }
+
+    /// Same as [`Self::try_from_type_application`], reusing the types resolved by the previous
+    /// calls given the same `cache`. Type ids only mean something within their ABI, so a cache
+    /// must always be used with the same `type_lookup`.
+    pub fn try_from_type_application_cached(
+        type_application: &TypeApplication,
+        type_lookup: &HashMap<usize, TypeDeclaration>,
+        cache: &mut ParamTypeCache,
+    ) -> Result<Self> {
+        Type::resolve(type_application, type_lookup, &[], &mut cache.0)?.try_into()
     }
 }
 
+/// The types resolved by [`ParamType::try_from_type_application_cached`], keyed by type id
+/// and type arguments.
+#[derive(Debug, Clone, Default)]
+pub struct ParamTypeCache(TypeCache);
+
+/// Converts many `TypeApplication`s of the same ABI into `ParamType`s, remembering every
+/// type resolved along the way. Prefer it over repeated calls to
+/// `ParamType::try_from_type_application` when resolving all the functions, logs, etc. of
+/// an ABI since nested types are shared between them.
+pub struct ParamTypeResolver<'a> {
+    type_lookup: &'a HashMap<usize, TypeDeclaration>,
+    cache: ParamTypeCache,
+}
+
+impl<'a> ParamTypeResolver<'a> {
+    pub fn new(type_lookup: &'a HashMap<usize, TypeDeclaration>) -> Self {
+        Self {
+            type_lookup,
+            cache: ParamTypeCache::default(),
+        }
+    }
+
+    pub fn resolve(&mut self, type_application: &TypeApplication) -> Result<ParamType> {
+        ParamType::try_from_type_application_cached(
+            type_application,
+            self.type_lookup,
+            &mut self.cache,
+        )
+    }
+}
+
+/// Identifies a type application regardless of its name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct TypeApplicationKey {
+    type_id: usize,
+    type_arguments: Vec<TypeApplicationKey>,
+}
+
+impl From<&TypeApplication> for TypeApplicationKey {
+    fn from(type_application: &TypeApplication) -> Self {
+        Self {
+            type_id: type_application.type_id,
+            type_arguments: type_application
+                .type_arguments
+                .iter()
+                .flatten()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+type TypeCache = HashMap<TypeApplicationKey, Type>;
+
 #[derive(Debug, Clone)]
 struct Type {
     type_field: String,
@@ -239,23 +402,39 @@ struct Type {
 
 impl Type {
     /// Will recursively drill down the given generic parameters until all types are
-    /// resolved.
-    ///
-    /// # Arguments
-    ///
-    /// * `type_application`: the type we wish to resolve
-    /// * `types`: all types used in the function call
-    pub fn try_from(
+    /// resolved. Types not inheriting any generic parameters are fully described by their type
+    /// application, so they're resolved only once and then taken from `cache`.
+    fn resolve(
         type_application: &TypeApplication,
         type_lookup: &HashMap<usize, TypeDeclaration>,
+        parent_generic_params: &[(usize, Type)],
+        cache: &mut TypeCache,
     ) -> Result<Self> {
-        Self::resolve(type_application, type_lookup, &[])
+        if !parent_generic_params.is_empty() {
+            return Self::resolve_uncached(
+                type_application,
+                type_lookup,
+                parent_generic_params,
+                cache,
+            );
+        }
+
+        let key = TypeApplicationKey::from(type_application);
+        if let Some(resolved) = cache.get(&key) {
+            return Ok(resolved.clone());
+        }
+
+        let resolved = Self::resolve_uncached(type_application, type_lookup, &[], cache)?;
+        cache.insert(key, resolved.clone());
+
+        Ok(resolved)
     }
 
-    fn resolve(
+    fn resolve_uncached(
         type_application: &TypeApplication,
         type_lookup: &HashMap<usize, TypeDeclaration>,
         parent_generic_params: &[(usize, Type)],
+        cache: &mut TypeCache,
     ) -> Result<Self> {
         let type_declaration = type_lookup.get(&type_application.type_id).ok_or_else(|| {
             error!(
@@ -287,6 +466,7 @@ impl Type {
             type_lookup,
             type_declaration,
             parent_generic_params,
+            cache,
         )?;
 
         // Resolve the enclosed components (if any) with the newly resolved generic
@@ -295,7 +475,7 @@ impl Type {
             .components
             .iter()
             .flatten()
-            .map(|component| Self::resolve(component, type_lookup, &generic_params_lookup))
+            .map(|component| Self::resolve(component, type_lookup, &generic_params_lookup, cache))
             .collect::<Result<Vec<_>>>()?;
 
         Ok(Type {
@@ -322,6 +502,7 @@ impl Type {
         type_lookup: &HashMap<usize, TypeDeclaration>,
         type_declaration: &TypeDeclaration,
         parent_generic_params: &[(usize, Type)],
+        cache: &mut TypeCache,
     ) -> Result<Vec<(usize, Self)>> {
         match &type_declaration.type_parameters {
             // The presence of type_parameters indicates that the current type
@@ -332,7 +513,7 @@ impl Type {
                     .type_arguments
                     .iter()
                     .flatten()
-                    .map(|ty| Self::resolve(ty, type_lookup, parent_generic_params))
+                    .map(|ty| Self::resolve(ty, type_lookup, parent_generic_params, cache))
                     .collect::<Result<Vec<_>>>()?;
 
                 let generics_to_use = if !generic_params_from_current_type.is_empty() {
@@ -516,7 +697,7 @@ fn try_str_slice(the_type: &Type) -> Result<Option<ParamType>> {
 }
 
 fn try_array(the_type: &Type) -> Result<Option<ParamType>> {
-    if let Some(len) = extract_array_len(&the_type.type_field) {
+    if let Some(len) = parse_array_len(&the_type.type_field)? {
         return match the_type.components.as_slice() {
             [single_type] => {
                 let array_type = single_type.try_into()?;
@@ -532,6 +713,36 @@ fn try_array(the_type: &Type) -> Result<Option<ParamType>> {
     Ok(None)
 }
 
+/// Parses the length of an array type such as `[_; 10]`. Lengths that aren't a number or don't
+/// fit into a `usize` are reported, how many elements are decoded is then capped by
+/// `DecoderConfig::max_array_length`.
+fn parse_array_len(type_field: &str) -> Result<Option<usize>> {
+    let Some(len) = type_field
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .and_then(|inner| inner.split_once(';'))
+        .and_then(|(element, len)| (element.trim() == "_").then_some(len.trim()))
+    else {
+        return Ok(None);
+    };
+
+    if len.is_empty() || !len.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(error!(
+            Codec,
+            "invalid array length `{len}` in array type `{type_field}`"
+        ));
+    }
+
+    len.parse().map(Some).map_err(|_| {
+        error!(
+            Codec,
+            "array length `{len}` in array type `{type_field}` is too large, at most {} \
+                elements are supported",
+            usize::MAX
+        )
+    })
+}
+
 fn try_primitive(the_type: &Type) -> Result<Option<ParamType>> {
     let result = match the_type.type_field.as_str() {
         "bool" => Some(ParamType::Bool),
@@ -644,6 +855,73 @@ mod tests {
     const WIDTH_OF_U32: usize = 8;
     const WIDTH_OF_BOOL: usize = 1;
 
+    #[test]
+    fn validates_shape_of_nested_arrays() -> Result<()> {
+        let param_type =
+            ParamType::Array(Box::new(ParamType::Array(Box::new(ParamType::U8), 2)), 2);
+        let row = |len| Token::Array(vec![Token::U8(0); len]);
+
+        param_type.validate_array_shape(&Token::Array(vec![row(2), row(2)]))?;
+
+        let err = param_type
+            .validate_array_shape(&Token::Array(vec![row(2), row(3)]))
+            .expect_err("should fail for a ragged array");
+        assert_eq!(
+            err.to_string(),
+            "codec: array shape mismatch at `value[1]`: expected 2 elements, got 3"
+        );
+
+        let err = param_type
+            .validate_array_shape(&Token::Array(vec![row(2)]))
+            .expect_err("should fail for a missing row");
+        assert_eq!(
+            err.to_string(),
+            "codec: array shape mismatch at `value`: expected 2 elements, got 1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validates_shape_of_arrays_inside_structs() {
+        let param_type = ParamType::Struct {
+            fields: vec![
+                ParamType::U64,
+                ParamType::Array(Box::new(ParamType::Bool), 1),
+            ],
+            generics: vec![],
+        };
+        let token = Token::Struct(vec![Token::U64(0), Token::Array(vec![])]);
+
+        let err = param_type
+            .validate_array_shape(&token)
+            .expect_err("should fail for a short array field");
+
+        assert_eq!(
+            err.to_string(),
+            "codec: array shape mismatch at `value.1`: expected 1 elements, got 0"
+        );
+    }
+
+    #[test]
+    fn encoding_width_is_rounded_up_to_whole_words() -> Result<()> {
+        for (param_type, expected_width) in [
+            (ParamType::Unit, 1),
+            (ParamType::Bool, 1),
+            (ParamType::U64, 1),
+            (ParamType::U128, 2),
+            (ParamType::B256, 4),
+            (ParamType::Vector(Box::new(ParamType::U8)), 3),
+            (ParamType::StringArray(9), 2),
+            (ParamType::Array(Box::new(ParamType::U8), 9), 2),
+            (ParamType::Tuple(vec![ParamType::U8, ParamType::Bool]), 2),
+        ] {
+            assert_eq!(param_type.compute_encoding_width()?, expected_width);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn array_size_dependent_on_num_of_elements() {
         const NUM_ELEMENTS: usize = 11;
@@ -798,6 +1076,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn array_lengths_are_parsed_without_panicking() -> Result<()> {
+        assert_eq!(parse_array_len("[_; 10]")?, Some(10));
+        assert_eq!(parse_array_len("[_;7]")?, Some(7));
+        assert_eq!(
+            parse_array_len(&format!("[_; {}]", usize::MAX))?,
+            Some(usize::MAX)
+        );
+        assert_eq!(parse_array_len("(_, _)")?, None);
+        assert_eq!(parse_array_len("str[10]")?, None);
+
+        let err = parse_array_len("[_; 99999999999999999999999]").expect_err("should overflow");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "codec: array length `99999999999999999999999` in array type \
+                `[_; 99999999999999999999999]` is too large, at most {} elements are supported",
+                usize::MAX
+            )
+        );
+
+        let err = parse_array_len("[_; -1]").expect_err("should be invalid");
+        assert_eq!(
+            err.to_string(),
+            "codec: invalid array length `-1` in array type `[_; -1]`"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn handles_vectors() -> Result<()> {
         // given
@@ -948,6 +1256,82 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn resolver_distinguishes_generic_instantiations() -> Result<()> {
+        // given
+        let declarations = [
+            TypeDeclaration {
+                type_id: 1,
+                type_field: "generic T".to_string(),
+                components: None,
+                type_parameters: None,
+            },
+            TypeDeclaration {
+                type_id: 2,
+                type_field: "struct SomeStruct".to_string(),
+                components: Some(vec![TypeApplication {
+                    name: "field".to_string(),
+                    type_id: 1,
+                    type_arguments: None,
+                }]),
+                type_parameters: Some(vec![1]),
+            },
+            TypeDeclaration {
+                type_id: 3,
+                type_field: "u8".to_string(),
+                components: None,
+                type_parameters: None,
+            },
+            TypeDeclaration {
+                type_id: 4,
+                type_field: "u64".to_string(),
+                components: None,
+                type_parameters: None,
+            },
+        ];
+        let some_struct_of = |type_id| TypeApplication {
+            name: "arg".to_string(),
+            type_id: 2,
+            type_arguments: Some(vec![TypeApplication {
+                name: "".to_string(),
+                type_id,
+                type_arguments: None,
+            }]),
+        };
+
+        let type_lookup = declarations
+            .into_iter()
+            .map(|decl| (decl.type_id, decl))
+            .collect::<HashMap<_, _>>();
+        let mut resolver = ParamTypeResolver::new(&type_lookup);
+
+        // when
+        let results = [3, 4, 3]
+            .map(|type_id| resolver.resolve(&some_struct_of(type_id)))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        // then
+        let some_struct = |param_type: ParamType| ParamType::Struct {
+            fields: vec![param_type.clone()],
+            generics: vec![param_type],
+        };
+        assert_eq!(
+            results,
+            vec![
+                some_struct(ParamType::U8),
+                some_struct(ParamType::U64),
+                some_struct(ParamType::U8)
+            ]
+        );
+        assert_eq!(
+            results[0],
+            ParamType::try_from_type_application(&some_struct_of(3), &type_lookup)?
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn handles_enums() -> Result<()> {
         // given
@@ -1518,18 +1902,13 @@ mod tests {
             generics: param_types_no_bytes.clone(),
         });
 
-        let expected =
-            "codec: enums currently support only one heap-type variant. Found: 2".to_string();
-        assert_eq!(
-            ParamType::Enum {
-                variants: variants_two_bytes_type.clone(),
-                generics: param_types_no_bytes.clone(),
-            }
-            .validate_is_decodable(max_depth)
-            .expect_err("should not be decodable")
-            .to_string(),
-            expected
-        );
+        // every heap type variant returns its data in a receipt of its own
+        assert!(ParamType::Enum {
+            variants: variants_two_bytes_type.clone(),
+            generics: param_types_no_bytes.clone(),
+        }
+        .validate_is_decodable(max_depth)
+        .is_ok());
 
         can_be_decoded(ParamType::Enum {
             variants: variants_no_bytes_type,
@@ -1541,18 +1920,13 @@ mod tests {
             generics: param_types_containing_bytes.clone(),
         });
 
-        let expected =
-            "codec: enums currently support only one heap-type variant. Found: 2".to_string();
-        assert_eq!(
-            ParamType::Enum {
-                variants: variants_two_bytes_type.clone(),
-                generics: param_types_containing_bytes.clone(),
-            }
-            .validate_is_decodable(max_depth)
-            .expect_err("should not be decodable")
-            .to_string(),
-            expected
-        );
+        // every heap type variant returns its data in a receipt of its own
+        assert!(ParamType::Enum {
+            variants: variants_two_bytes_type.clone(),
+            generics: param_types_containing_bytes.clone(),
+        }
+        .validate_is_decodable(max_depth)
+        .is_ok());
 
         Ok(())
     }
@@ -1627,18 +2001,13 @@ mod tests {
             generics: param_types_no_string.clone(),
         });
 
-        let expected =
-            "codec: enums currently support only one heap-type variant. Found: 2".to_string();
-        assert_eq!(
-            ParamType::Enum {
-                variants: variants_two_string_type.clone(),
-                generics: param_types_no_string.clone(),
-            }
-            .validate_is_decodable(1)
-            .expect_err("should not be decodable")
-            .to_string(),
-            expected
-        );
+        // every heap type variant returns its data in a receipt of its own
+        assert!(ParamType::Enum {
+            variants: variants_two_string_type.clone(),
+            generics: param_types_no_string.clone(),
+        }
+        .validate_is_decodable(1)
+        .is_ok());
 
         can_be_decoded(ParamType::Enum {
             variants: variants_no_string_type,
@@ -1650,18 +2019,13 @@ mod tests {
             generics: param_types_containing_string.clone(),
         });
 
-        let expected =
-            "codec: enums currently support only one heap-type variant. Found: 2".to_string();
-        assert_eq!(
-            ParamType::Enum {
-                variants: variants_two_string_type.clone(),
-                generics: param_types_containing_string.clone(),
-            }
-            .validate_is_decodable(1)
-            .expect_err("should not be decodable")
-            .to_string(),
-            expected
-        );
+        // every heap type variant returns its data in a receipt of its own
+        assert!(ParamType::Enum {
+            variants: variants_two_string_type.clone(),
+            generics: param_types_containing_string.clone(),
+        }
+        .validate_is_decodable(1)
+        .is_ok());
 
         Ok(())
     }
@@ -1746,18 +2110,13 @@ mod tests {
             generics: param_types_no_vector.clone(),
         });
 
-        let expected =
-            "codec: enums currently support only one heap-type variant. Found: 2".to_string();
-        assert_eq!(
-            ParamType::Enum {
-                variants: variants_two_vector_type.clone(),
-                generics: param_types_no_vector.clone(),
-            }
-            .validate_is_decodable(max_depth)
-            .expect_err("should not be decodable")
-            .to_string(),
-            expected
-        );
+        // every heap type variant returns its data in a receipt of its own
+        assert!(ParamType::Enum {
+            variants: variants_two_vector_type.clone(),
+            generics: param_types_no_vector.clone(),
+        }
+        .validate_is_decodable(max_depth)
+        .is_ok());
         can_be_decoded(ParamType::Enum {
             variants: variants_no_vector_type,
             generics: param_types_containing_vector.clone(),
@@ -1766,18 +2125,13 @@ mod tests {
             variants: variants_one_vector_type,
             generics: param_types_containing_vector.clone(),
         });
-        let expected =
-            "codec: enums currently support only one heap-type variant. Found: 2".to_string();
-        assert_eq!(
-            ParamType::Enum {
-                variants: variants_two_vector_type.clone(),
-                generics: param_types_containing_vector.clone(),
-            }
-            .validate_is_decodable(max_depth)
-            .expect_err("should not be decodable")
-            .to_string(),
-            expected
-        );
+        // every heap type variant returns its data in a receipt of its own
+        assert!(ParamType::Enum {
+            variants: variants_two_vector_type.clone(),
+            generics: param_types_containing_vector.clone(),
+        }
+        .validate_is_decodable(max_depth)
+        .is_ok());
 
         Ok(())
     }
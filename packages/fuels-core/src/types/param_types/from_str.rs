@@ -0,0 +1,366 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::types::{
+    enum_variants::EnumVariants,
+    errors::{error, Error, Result},
+    param_types::ParamType,
+};
+
+impl FromStr for ParamType {
+    type Err = Error;
+
+    /// Parses a Sway-style type string such as `(u64, bool)`, `[u8; 32]`, `str[10]` or
+    /// `Option<Vec<u8>>`. Named structs and enums are not known here, use
+    /// `ParamType::parse_with_types` for those.
+    fn from_str(type_str: &str) -> Result<Self> {
+        Self::parse_with_types(type_str, &HashMap::new())
+    }
+}
+
+impl ParamType {
+    /// Parses a Sway-style type string. Besides the primitives, `Vec<T>`, `Option<T>` and
+    /// `Result<T, E>` are known. Any other name (e.g. a struct or an enum) is resolved against
+    /// `types`. Generic types are looked up as instantiated, without whitespace, e.g.
+    /// `MyStruct<u64,bool>` for `MyStruct<u64, bool>`: their type parameters can't be
+    /// substituted in a `ParamType`.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_str`: The type string to parse, e.g. `(MyStruct, [Vec<u8>; 2])`
+    /// * `types`:    The type table used to resolve named types.
+    pub fn parse_with_types(type_str: &str, types: &HashMap<String, ParamType>) -> Result<Self> {
+        let mut parser = TypeParser {
+            input: type_str,
+            pos: 0,
+            types,
+        };
+
+        let param_type = parser.parse_type()?;
+
+        parser.skip_whitespace();
+        if !parser.rest().is_empty() {
+            return Err(error!(
+                Codec,
+                "unexpected `{}` after type in `{type_str}`",
+                parser.rest()
+            ));
+        }
+
+        Ok(param_type)
+    }
+}
+
+struct TypeParser<'a> {
+    input: &'a str,
+    pos: usize,
+    types: &'a HashMap<String, ParamType>,
+}
+
+impl<'a> TypeParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.pos = self.input.len() - self.rest().trim_start().len();
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        let found = self.rest().starts_with(expected);
+        if found {
+            self.pos += expected.len_utf8();
+        }
+
+        found
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{expected}`")))
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+
+        &rest[..len]
+    }
+
+    fn error(&self, msg: &str) -> Error {
+        error!(
+            Codec,
+            "{msg} at position {} while parsing type `{}`", self.pos, self.input
+        )
+    }
+
+    fn parse_len(&mut self) -> Result<usize> {
+        self.take_while(|c| c.is_ascii_digit())
+            .parse()
+            .map_err(|_| self.error("expected a length"))
+    }
+
+    fn parse_type(&mut self) -> Result<ParamType> {
+        if self.eat('(') {
+            return self.parse_tuple();
+        }
+
+        if self.eat('[') {
+            let element_type = self.parse_type()?;
+            self.expect(';')?;
+            let len = self.parse_len()?;
+            self.expect(']')?;
+
+            return Ok(ParamType::Array(Box::new(element_type), len));
+        }
+
+        let param_type = match self.take_while(|c| c.is_alphanumeric() || c == '_' || c == ':') {
+            "u8" => ParamType::U8,
+            "u16" => ParamType::U16,
+            "u32" => ParamType::U32,
+            "u64" => ParamType::U64,
+            "u128" => ParamType::U128,
+            "u256" => ParamType::U256,
+            "bool" => ParamType::Bool,
+            "b256" => ParamType::B256,
+            "Bytes" => ParamType::Bytes,
+            "String" => ParamType::String,
+            "raw_slice" => ParamType::RawSlice,
+            "str" if self.eat('[') => {
+                let len = self.parse_len()?;
+                self.expect(']')?;
+
+                ParamType::StringArray(len)
+            }
+            "str" => ParamType::StringSlice,
+            "Vec" => {
+                let [element_type] = self.parse_type_args("Vec")?;
+
+                ParamType::Vector(Box::new(element_type))
+            }
+            "Option" => {
+                let [some_type] = self.parse_type_args("Option")?;
+                let variants = EnumVariants::new(vec![ParamType::Unit, some_type.clone()])?;
+
+                ParamType::Enum {
+                    variants,
+                    generics: vec![some_type],
+                }
+            }
+            "Result" => {
+                let [ok_type, err_type] = self.parse_type_args("Result")?;
+                let variants = EnumVariants::new(vec![ok_type.clone(), err_type.clone()])?;
+
+                ParamType::Enum {
+                    variants,
+                    generics: vec![ok_type, err_type],
+                }
+            }
+            "" => return Err(self.error("expected a type")),
+            name => {
+                let name = self.instantiated_name(name)?;
+                self.types
+                    .get(&name)
+                    .cloned()
+                    .ok_or_else(|| error!(Codec, "unknown type `{name}` in `{}`", self.input))?
+            }
+        };
+
+        Ok(param_type)
+    }
+
+    /// Parses the `N` type arguments of `name`, e.g. the `<T, E>` of `Result<T, E>`.
+    fn parse_type_args<const N: usize>(&mut self, name: &str) -> Result<[ParamType; N]> {
+        self.expect('<')?;
+        let mut args = vec![self.parse_type()?];
+        while self.eat(',') {
+            args.push(self.parse_type()?);
+        }
+        self.expect('>')?;
+
+        let num_args = args.len();
+        args.try_into().map_err(|_| {
+            self.error(&format!(
+                "`{name}` takes {N} type argument(s) but {num_args} were given"
+            ))
+        })
+    }
+
+    /// `name` followed by its type arguments, if any, without whitespace, e.g.
+    /// `MyStruct<u64,bool>`. The arguments are only checked to be valid types.
+    fn instantiated_name(&mut self, name: &str) -> Result<String> {
+        self.skip_whitespace();
+        let args_start = self.pos;
+        if !self.eat('<') {
+            return Ok(name.to_string());
+        }
+
+        self.parse_type()?;
+        while self.eat(',') {
+            self.parse_type()?;
+        }
+        self.expect('>')?;
+
+        let args: String = self.input[args_start..self.pos]
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+
+        Ok(format!("{name}{args}"))
+    }
+
+    // The opening parenthesis has already been consumed.
+    fn parse_tuple(&mut self) -> Result<ParamType> {
+        if self.eat(')') {
+            return Ok(ParamType::Unit);
+        }
+
+        let mut elements = vec![self.parse_type()?];
+        let mut has_trailing_comma = false;
+        while !self.eat(')') {
+            self.expect(',')?;
+            if self.eat(')') {
+                has_trailing_comma = true;
+                break;
+            }
+            elements.push(self.parse_type()?);
+        }
+
+        // `(u64)` is just a parenthesized `u64`, while `(u64,)` is a single element tuple
+        if elements.len() == 1 && !has_trailing_comma {
+            return Ok(elements.pop().expect("has one element"));
+        }
+
+        Ok(ParamType::Tuple(elements))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_primitives() -> Result<()> {
+        for (type_str, expected) in [
+            ("u8", ParamType::U8),
+            ("u64", ParamType::U64),
+            ("u256", ParamType::U256),
+            ("bool", ParamType::Bool),
+            ("b256", ParamType::B256),
+            ("()", ParamType::Unit),
+            ("str", ParamType::StringSlice),
+            ("str[10]", ParamType::StringArray(10)),
+            ("Bytes", ParamType::Bytes),
+            ("String", ParamType::String),
+            ("raw_slice", ParamType::RawSlice),
+        ] {
+            assert_eq!(type_str.parse::<ParamType>()?, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_nested_tuples_arrays_and_vectors() -> Result<()> {
+        let param_type: ParamType = " ( u64, ([u8; 32], Vec<bool>), (str[2],) ) ".parse()?;
+
+        let expected = ParamType::Tuple(vec![
+            ParamType::U64,
+            ParamType::Tuple(vec![
+                ParamType::Array(Box::new(ParamType::U8), 32),
+                ParamType::Vector(Box::new(ParamType::Bool)),
+            ]),
+            ParamType::Tuple(vec![ParamType::StringArray(2)]),
+        ]);
+        assert_eq!(param_type, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolves_named_types_from_type_table() -> Result<()> {
+        let my_struct = ParamType::Struct {
+            fields: vec![ParamType::U32],
+            generics: vec![],
+        };
+        let my_enum = ParamType::Enum {
+            variants: EnumVariants::new(vec![ParamType::Unit, ParamType::U64])?,
+            generics: vec![],
+        };
+        let types = HashMap::from([
+            ("MyStruct".to_string(), my_struct.clone()),
+            ("MyEnum".to_string(), my_enum.clone()),
+        ]);
+
+        let param_type = ParamType::parse_with_types("[(MyStruct, MyEnum); 2]", &types)?;
+
+        let expected = ParamType::Array(Box::new(ParamType::Tuple(vec![my_struct, my_enum])), 2);
+        assert_eq!(param_type, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_generic_types() -> Result<()> {
+        let option = |param_type: ParamType| ParamType::Enum {
+            variants: EnumVariants::new(vec![ParamType::Unit, param_type.clone()])
+                .expect("has variants"),
+            generics: vec![param_type],
+        };
+        let my_struct = ParamType::Struct {
+            fields: vec![ParamType::U64, ParamType::Bool],
+            generics: vec![ParamType::U64, ParamType::Bool],
+        };
+        let types = HashMap::from([("MyStruct<u64,bool>".to_string(), my_struct.clone())]);
+
+        let param_type = ParamType::parse_with_types(
+            "(Option<Vec<u8>>, Result<u64, bool>, MyStruct< u64 , bool >)",
+            &types,
+        )?;
+
+        let expected = ParamType::Tuple(vec![
+            option(ParamType::Vector(Box::new(ParamType::U8))),
+            ParamType::Enum {
+                variants: EnumVariants::new(vec![ParamType::U64, ParamType::Bool])?,
+                generics: vec![ParamType::U64, ParamType::Bool],
+            },
+            my_struct,
+        ]);
+        assert_eq!(param_type, expected);
+
+        let err = "Option<u8, u8>"
+            .parse::<ParamType>()
+            .expect_err("should have the wrong number of arguments");
+        assert!(err
+            .to_string()
+            .contains("`Option` takes 1 type argument(s) but 2 were given"));
+
+        let err = ParamType::parse_with_types("MyStruct<u8>", &types)
+            .expect_err("should only know `MyStruct<u64,bool>`");
+        assert!(err.to_string().contains("unknown type `MyStruct<u8>`"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_malformed_input() {
+        for type_str in [
+            "",
+            "[u8; ]",
+            "(u8, bool",
+            "u64 u64",
+            "Vec<u8",
+            "MyStruct",
+            "Vec<>",
+        ] {
+            let result = type_str.parse::<ParamType>();
+
+            assert!(result.is_err(), "`{type_str}` should fail to parse");
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{
+    checked_round_up_to_word_alignment,
+    constants::WORD_SIZE,
+    types::{
+        errors::{error, Result},
+        param_types::ParamType,
+    },
+};
+
+/// Where a value is placed in the encoding of a [`ParamType`], and where its fields are.
+///
+/// Struct and tuple elements are word aligned, array elements are packed one after the other
+/// and enum variants are placed at the end of the enum, after the discriminant and the padding.
+/// Heap types (vectors, `Bytes`, `String`) are only described by their in-place header, their
+/// data is encoded separately.
+///
+/// The layouts of fields are computed when asked for, so looking up an element of a large array
+/// doesn't lay out the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    param_type: ParamType,
+    offset: usize,
+    size: usize,
+    alignment: usize,
+}
+
+impl TypeLayout {
+    pub fn new(param_type: &ParamType) -> Result<Self> {
+        Self::at(param_type, 0, WORD_SIZE)
+    }
+
+    fn at(param_type: &ParamType, offset: usize, alignment: usize) -> Result<Self> {
+        Ok(Self {
+            param_type: param_type.clone(),
+            offset,
+            size: param_type.compute_encoding_in_bytes()?,
+            alignment,
+        })
+    }
+
+    /// The type laid out.
+    pub fn param_type(&self) -> &ParamType {
+        &self.param_type
+    }
+
+    /// Offset in bytes from the start of the outermost type's encoding.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Number of bytes the value is encoded in, not counting any padding after it.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The boundary, in bytes, the value's offset is aligned to within its parent.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// Number of struct fields, tuple elements, array elements or enum variants.
+    pub fn field_count(&self) -> usize {
+        match &self.param_type {
+            ParamType::Tuple(elements)
+            | ParamType::Struct {
+                fields: elements, ..
+            } => elements.len(),
+            ParamType::Array(_, len) => *len,
+            ParamType::Enum { variants, .. } => variants.param_types().len(),
+            _ => 0,
+        }
+    }
+
+    /// Layout of the struct field, tuple element, array element or enum variant at `index`.
+    pub fn field_at(&self, index: usize) -> Result<TypeLayout> {
+        self.child(index)?.ok_or_else(|| {
+            error!(
+                Codec,
+                "`{:?}` has no field at index {index}", self.param_type
+            )
+        })
+    }
+
+    /// Looks up a nested field by its path of indexes, e.g. `1.0[3]` for the 4th array element
+    /// of the 1st element of the 2nd field. Struct fields are selected by their position since
+    /// `ParamType`s don't carry field names. Only the fields along the path are laid out.
+    pub fn field(&self, path: &str) -> Result<TypeLayout> {
+        parse_field_path(path)?
+            .into_iter()
+            .try_fold(self.clone(), |layout, segment| {
+                let PathSegment::Index(index) = segment else {
+                    return Err(error!(
+                        Codec,
+                        "expected only indexes in `{path}`: `ParamType`s don't have field names"
+                    ));
+                };
+
+                layout.child(index)?.ok_or_else(|| {
+                    error!(
+                        Codec,
+                        "`{:?}` has no field at index {index} in `{path}`", layout.param_type
+                    )
+                })
+            })
+    }
+
+    /// Lays out the field at `index`, `None` if there is no such field.
+    pub(crate) fn child(&self, index: usize) -> Result<Option<TypeLayout>> {
+        let overflow_error = || {
+            error!(
+                Codec,
+                "reached overflow while computing layout of {:?}", self.param_type
+            )
+        };
+
+        match &self.param_type {
+            ParamType::Tuple(elements)
+            | ParamType::Struct {
+                fields: elements, ..
+            } => {
+                let Some(element) = elements.get(index) else {
+                    return Ok(None);
+                };
+
+                let mut element_offset = self.offset;
+                for preceding in &elements[..index] {
+                    element_offset =
+                        checked_round_up_to_word_alignment(preceding.compute_encoding_in_bytes()?)?
+                            .checked_add(element_offset)
+                            .ok_or_else(overflow_error)?;
+                }
+
+                Self::at(element, element_offset, WORD_SIZE).map(Some)
+            }
+            ParamType::Array(element, len) => {
+                if index >= *len {
+                    return Ok(None);
+                }
+
+                let element_offset = index
+                    .checked_mul(element.compute_encoding_in_bytes()?)
+                    .and_then(|relative| relative.checked_add(self.offset))
+                    .ok_or_else(overflow_error)?;
+
+                Self::at(element, element_offset, 1).map(Some)
+            }
+            ParamType::Enum { variants, .. } => {
+                let Some(variant) = variants.param_types().get(index) else {
+                    return Ok(None);
+                };
+
+                // `enum_width` is at least as big as any variant
+                let enum_width = variants.compute_enum_width_in_bytes()?;
+                let variant_offset = enum_width - variant.compute_encoding_in_bytes()?;
+
+                Self::at(variant, self.offset + variant_offset, 1).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// One step of a field path such as `inner.balances[3].amount`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PathSegment<'a> {
+    /// A struct field.
+    Field(&'a str),
+    /// A tuple element (`.0`), an array element (`[0]`) or a field by position.
+    Index(usize),
+}
+
+pub(crate) fn parse_field_path(path: &str) -> Result<Vec<PathSegment>> {
+    let invalid_path = || error!(Codec, "invalid field path `{path}`");
+
+    let mut segments = vec![];
+    for part in path.split('.') {
+        let (name, mut indexes) = match part.find('[') {
+            Some(bracket) => (&part[..bracket], &part[bracket..]),
+            None => (part, ""),
+        };
+
+        match name.parse() {
+            Ok(index) => segments.push(PathSegment::Index(index)),
+            Err(_) if !name.is_empty() => segments.push(PathSegment::Field(name)),
+            Err(_) if segments.is_empty() && !indexes.is_empty() => {}
+            Err(_) => return Err(invalid_path()),
+        }
+
+        while !indexes.is_empty() {
+            let (index, rest) = indexes
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once(']'))
+                .ok_or_else(invalid_path)?;
+            segments.push(PathSegment::Index(
+                index.parse().map_err(|_| invalid_path())?,
+            ));
+            indexes = rest;
+        }
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::enum_variants::EnumVariants;
+
+    #[test]
+    fn parses_field_paths() -> Result<()> {
+        assert_eq!(
+            parse_field_path("inner.balances[3].amount")?,
+            vec![
+                PathSegment::Field("inner"),
+                PathSegment::Field("balances"),
+                PathSegment::Index(3),
+                PathSegment::Field("amount"),
+            ]
+        );
+        assert_eq!(
+            parse_field_path("[1][2].0")?,
+            vec![
+                PathSegment::Index(1),
+                PathSegment::Index(2),
+                PathSegment::Index(0)
+            ]
+        );
+
+        let err = parse_field_path("inner..amount").expect_err("should fail");
+        assert_eq!(err.to_string(), "codec: invalid field path `inner..amount`");
+
+        Ok(())
+    }
+
+    #[test]
+    fn lays_out_nested_types() -> Result<()> {
+        // (bool, [(u8, u64); 2], u128)
+        let element = ParamType::Tuple(vec![ParamType::U8, ParamType::U64]);
+        let param_type = ParamType::Tuple(vec![
+            ParamType::Bool,
+            ParamType::Array(Box::new(element), 2),
+            ParamType::U128,
+        ]);
+
+        let layout = param_type.layout()?;
+        let placement = |path| -> Result<_> {
+            let field = layout.field(path)?;
+            Ok((field.offset(), field.size(), field.alignment()))
+        };
+
+        assert_eq!(placement("0")?, (0, 1, 8));
+        assert_eq!(placement("1")?, (8, 32, 8));
+        assert_eq!(placement("1[1]")?, (24, 16, 1));
+        assert_eq!(placement("1[1].1")?, (32, 8, 8));
+        assert_eq!(placement("2")?, (40, 16, 8));
+        assert_eq!(layout.size(), 56);
+
+        let err = layout.field("1[2]").expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "codec: `Array(Tuple([U8, U64]), 2)` has no field at index 2 in `1[2]`"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_elements_are_laid_out_on_demand() -> Result<()> {
+        let param_type = ParamType::Array(Box::new(ParamType::U64), 1_000_000_000);
+
+        let layout = param_type.layout()?;
+
+        assert_eq!(layout.field_count(), 1_000_000_000);
+        assert_eq!(layout.field("[999999999]")?.offset(), 7_999_999_992);
+
+        Ok(())
+    }
+
+    #[test]
+    fn enum_variants_are_placed_at_the_end() -> Result<()> {
+        let variants = EnumVariants::new(vec![ParamType::U8, ParamType::B256])?;
+        let param_type = ParamType::Enum {
+            variants,
+            generics: vec![],
+        };
+
+        let layout = param_type.layout()?;
+
+        assert_eq!(layout.size(), 40);
+        assert_eq!(layout.field("0")?.offset(), 39);
+        assert_eq!(layout.field("1")?.offset(), 8);
+
+        Ok(())
+    }
+}
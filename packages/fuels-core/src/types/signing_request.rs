@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use fuel_crypto::Signature;
+use fuel_types::{AssetId, Bytes32, ChainId};
+
+use crate::{
+    traits::Signer,
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+        transaction::Transaction,
+        tx_explanation::{ContractCallSummary, InputSummary, OutputSummary, TxExplanation},
+    },
+};
+
+/// What a signer is asked to sign: a transaction and what it does with the signer's assets.
+/// Wallet frontends render it and let the user confirm before the [`Signer`] is invoked, see
+/// [`SigningRequest::sign_with`]. Created with `ScriptTransaction::signing_request` or
+/// `CreateTransaction::signing_request`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SigningRequest {
+    pub signer: Bech32Address,
+    pub chain_id: ChainId,
+    /// The id of the transaction on `chain_id`, i.e. the message that gets signed.
+    pub tx_id: Bytes32,
+    pub intent: Intent,
+    pub explanation: TxExplanation,
+}
+
+/// The decoded intent of a transaction, from the point of view of the signer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Intent {
+    /// The contract calls made by the script, for contracts whose ABI was registered.
+    pub contract_calls: Vec<ContractCallSummary>,
+    /// The most the signer can spend, by asset: the sum of the signer's coins and messages
+    /// used by the transaction. What isn't spent goes back as change.
+    pub max_spent: BTreeMap<AssetId, u64>,
+    /// Coins sent to other owners.
+    pub transfers: Vec<Transfer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+    pub to: Bech32Address,
+    pub amount: u64,
+    pub asset_id: AssetId,
+}
+
+impl SigningRequest {
+    pub(crate) fn new(
+        tx: &impl Transaction,
+        explanation: TxExplanation,
+        signer: &Bech32Address,
+        chain_id: ChainId,
+    ) -> Self {
+        let mut max_spent = BTreeMap::new();
+        for input in &explanation.inputs {
+            if let InputSummary::Resource {
+                owner,
+                amount,
+                asset_id,
+                ..
+            } = input
+            {
+                if owner == signer {
+                    let spent = max_spent.entry(*asset_id).or_insert(0u64);
+                    *spent = spent.saturating_add(*amount);
+                }
+            }
+        }
+
+        let transfers = explanation
+            .outputs
+            .iter()
+            .filter_map(|output| match output {
+                OutputSummary::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                } if to != signer => Some(Transfer {
+                    to: to.clone(),
+                    amount: *amount,
+                    asset_id: *asset_id,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            signer: signer.clone(),
+            chain_id,
+            tx_id: tx.id(chain_id),
+            intent: Intent {
+                contract_calls: explanation.contract_calls.clone(),
+                max_spent,
+                transfers,
+            },
+            explanation,
+        }
+    }
+
+    /// Asks `confirm` whether to go ahead and, if so, signs `tx` with `signer` and adds the
+    /// signature to its witnesses. Fails without invoking the signer if the request is
+    /// rejected, was made for another signer or no longer matches `tx`.
+    pub async fn sign_with<T, S>(
+        &self,
+        tx: &mut T,
+        signer: &S,
+        confirm: impl FnOnce(&SigningRequest) -> bool,
+    ) -> Result<Signature>
+    where
+        T: Transaction + Send,
+        S: Signer + Send + Sync,
+    {
+        if signer.address() != &self.signer {
+            return Err(error!(
+                Other,
+                "signing request is for `{}`, not for `{}`",
+                self.signer,
+                signer.address()
+            ));
+        }
+        if tx.id(self.chain_id) != self.tx_id {
+            return Err(error!(
+                Other,
+                "the transaction changed since the signing request was made"
+            ));
+        }
+        if !confirm(self) {
+            return Err(error!(Other, "signing request rejected"));
+        }
+
+        tx.sign_with(signer, self.chain_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_crypto::Message;
+
+    use super::*;
+    use crate::{
+        codec::AbiRegistry,
+        types::{
+            coin::Coin, transaction::ScriptTransaction, transaction_builders::create_coin_input,
+        },
+    };
+
+    #[derive(Clone, Debug)]
+    struct MockSigner {
+        address: Bech32Address,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl Signer for MockSigner {
+        async fn sign(&self, _message: Message) -> Result<Signature> {
+            Ok(Signature::default())
+        }
+
+        fn address(&self) -> &Bech32Address {
+            &self.address
+        }
+    }
+
+    fn transfer_tx(from: &Bech32Address, to: &Bech32Address) -> ScriptTransaction {
+        let coin = Coin {
+            amount: 100,
+            owner: from.clone(),
+            ..Default::default()
+        };
+
+        fuel_tx::TransactionBuilder::script(vec![], vec![])
+            .add_input(create_coin_input(coin, 0))
+            .add_output(fuel_tx::Output::coin(to.into(), 30, AssetId::default()))
+            .finalize()
+            .into()
+    }
+
+    #[tokio::test]
+    async fn the_signer_is_only_invoked_once_confirmed() -> Result<()> {
+        let signer = MockSigner {
+            address: Bech32Address::new("fuel", [1; 32]),
+        };
+        let recipient = Bech32Address::new("fuel", [2; 32]);
+        let mut tx = transfer_tx(&signer.address, &recipient);
+        let chain_id = ChainId::default();
+
+        let request = tx.signing_request(&signer.address, chain_id, &AbiRegistry::new());
+        assert_eq!(
+            request.intent.max_spent,
+            BTreeMap::from([(AssetId::default(), 100)])
+        );
+        assert_eq!(
+            request.intent.transfers,
+            vec![Transfer {
+                to: recipient,
+                amount: 30,
+                asset_id: AssetId::default(),
+            }]
+        );
+
+        let err = request
+            .sign_with(&mut tx, &signer, |_| false)
+            .await
+            .expect_err("should be rejected");
+        assert_eq!(err.to_string(), "signing request rejected");
+        assert!(tx.witnesses().is_empty());
+
+        request.sign_with(&mut tx, &signer, |_| true).await?;
+        assert_eq!(tx.witnesses().len(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+use crate::types::{
+    errors::{error, Error, Result},
+    param_types::{parse_field_path, PathSegment},
+    Token, U256,
+};
+
+/// Accessors to pull values out of decoded tokens without matching on every level, e.g. in
+/// scripts working from a JSON ABI instead of `abigen!` bindings:
+///
+/// ```
+/// # use fuels_core::types::{errors::Result, Token};
+/// # fn example() -> Result<()> {
+/// let token = Token::Struct(vec![
+///     Token::U8(1),
+///     Token::Vector(vec![Token::U64(10), Token::U64(20)]),
+/// ]);
+///
+/// assert_eq!(token.get("1[1]")?.as_u64()?, 20);
+/// assert_eq!(token.index(0)?.as_u8()?, 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Every accessor fails with a `Codec` error naming what it expected and the token it got.
+impl Token {
+    /// The element at `index` of an array, vector or tuple, or the field at position `index`
+    /// of a struct.
+    pub fn index(&self, index: usize) -> Result<&Token> {
+        let elements = match self {
+            Token::Array(elements)
+            | Token::Vector(elements)
+            | Token::Tuple(elements)
+            | Token::Struct(elements) => elements,
+            _ => {
+                return Err(error!(
+                    Codec,
+                    "cannot index into `{self}`: expected an array, a vector, a tuple or a struct"
+                ))
+            }
+        };
+
+        elements.get(index).ok_or_else(|| {
+            error!(
+                Codec,
+                "index {index} is out of bounds for `{self}` of {} elements",
+                elements.len()
+            )
+        })
+    }
+
+    /// Walks `path` down nested tokens, in the format of `ABIDecoder::decode_field`, e.g.
+    /// `1.2[3]`. Tokens don't carry the names of struct fields, so these are given by
+    /// position: `0` is the first field. A segment applied to an enum selects the value of the
+    /// variant with that discriminant, failing if the enum holds another variant.
+    ///
+    /// To address fields by name, decode them with `ParsedAbi::decode_field`, which looks the
+    /// names up in the ABI.
+    pub fn get(&self, path: &str) -> Result<&Token> {
+        parse_field_path(path)?
+            .into_iter()
+            .try_fold(self, |token, segment| match segment {
+                PathSegment::Index(index) => token.step(index),
+                PathSegment::Field(name) => Err(error!(
+                    Codec,
+                    "cannot get field `{name}` of `{token}`: tokens don't carry field names, \
+                    give the position of the field instead"
+                )),
+            })
+    }
+
+    fn step(&self, index: usize) -> Result<&Token> {
+        let Token::Enum(selector) = self else {
+            return self.index(index);
+        };
+
+        let (discriminant, value, _) = selector.as_ref();
+        if *discriminant != index as u64 {
+            return Err(error!(
+                Codec,
+                "expected variant {index} of the enum, but it holds variant {discriminant}"
+            ));
+        }
+
+        Ok(value)
+    }
+
+    /// The discriminant of an enum and the value of its variant.
+    pub fn as_variant(&self) -> Result<(u64, &Token)> {
+        match self {
+            Token::Enum(selector) => Ok((selector.0, &selector.1)),
+            _ => Err(self.unexpected("an enum")),
+        }
+    }
+
+    pub fn as_bool(&self) -> Result<bool> {
+        match self {
+            Token::Bool(value) => Ok(*value),
+            _ => Err(self.unexpected("a `bool`")),
+        }
+    }
+
+    pub fn as_u8(&self) -> Result<u8> {
+        match self {
+            Token::U8(value) => Ok(*value),
+            _ => Err(self.unexpected("a `u8`")),
+        }
+    }
+
+    pub fn as_u16(&self) -> Result<u16> {
+        match self {
+            Token::U16(value) => Ok(*value),
+            _ => Err(self.unexpected("a `u16`")),
+        }
+    }
+
+    pub fn as_u32(&self) -> Result<u32> {
+        match self {
+            Token::U32(value) => Ok(*value),
+            _ => Err(self.unexpected("a `u32`")),
+        }
+    }
+
+    /// The value of a `u64`. Narrower integers are widened, as a quick script rarely cares how
+    /// many bits the contract used.
+    pub fn as_u64(&self) -> Result<u64> {
+        match self {
+            Token::U8(value) => Ok(u64::from(*value)),
+            Token::U16(value) => Ok(u64::from(*value)),
+            Token::U32(value) => Ok(u64::from(*value)),
+            Token::U64(value) => Ok(*value),
+            _ => Err(self.unexpected("an unsigned integer of at most 64 bits")),
+        }
+    }
+
+    pub fn as_u128(&self) -> Result<u128> {
+        match self {
+            Token::U128(value) => Ok(*value),
+            _ => self.as_u64().map(u128::from),
+        }
+        .map_err(|_| self.unexpected("an unsigned integer of at most 128 bits"))
+    }
+
+    pub fn as_u256(&self) -> Result<U256> {
+        match self {
+            Token::U256(value) => Ok(*value),
+            _ => self.as_u128().map(U256::from),
+        }
+        .map_err(|_| self.unexpected("an unsigned integer"))
+    }
+
+    pub fn as_b256(&self) -> Result<[u8; 32]> {
+        match self {
+            Token::B256(value) => Ok(*value),
+            _ => Err(self.unexpected("a `b256`")),
+        }
+    }
+
+    /// The contents of a `String`, a string slice or a string array.
+    pub fn as_str(&self) -> Result<&str> {
+        match self {
+            Token::String(value) => Ok(value),
+            Token::StringSlice(value) | Token::StringArray(value) => Ok(value.data()),
+            _ => Err(self.unexpected("a string")),
+        }
+    }
+
+    /// The contents of `Bytes` or a raw slice.
+    pub fn as_bytes(&self) -> Result<&[u8]> {
+        match self {
+            Token::Bytes(value) | Token::RawSlice(value) => Ok(value),
+            _ => Err(self.unexpected("bytes")),
+        }
+    }
+
+    /// The elements of an array, a vector or a tuple, or the fields of a struct.
+    pub fn as_slice(&self) -> Result<&[Token]> {
+        match self {
+            Token::Array(elements)
+            | Token::Vector(elements)
+            | Token::Tuple(elements)
+            | Token::Struct(elements) => Ok(elements),
+            _ => Err(self.unexpected("an array, a vector, a tuple or a struct")),
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> Error {
+        error!(Codec, "expected {expected}, got `{self}`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{enum_variants::EnumVariants, param_types::ParamType};
+
+    fn balance() -> Token {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::U64]).unwrap();
+
+        Token::Struct(vec![
+            Token::B256([7; 32]),
+            Token::Vector(vec![
+                Token::Enum(Box::new((1, Token::U32(5), variants.clone()))),
+                Token::Enum(Box::new((0, Token::Unit, variants))),
+            ]),
+        ])
+    }
+
+    #[test]
+    fn nested_values_are_reached_by_path() -> Result<()> {
+        let token = balance();
+
+        assert_eq!(token.get("0")?.as_b256()?, [7; 32]);
+        assert_eq!(token.get("1[0].1")?.as_u64()?, 5);
+        assert_eq!(token.index(1)?.index(1)?.as_variant()?, (0, &Token::Unit));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mismatches_are_reported() {
+        let token = balance();
+
+        let err = token.get("1[1].1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "codec: expected variant 1 of the enum, but it holds variant 0"
+        );
+
+        let err = token.get("owner").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("tokens don't carry field names, give the position of the field instead"));
+
+        let err = token.index(2).unwrap_err();
+        assert!(err.to_string().contains("index 2 is out of bounds"));
+
+        let err = Token::Bool(true).as_u64().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "codec: expected an unsigned integer of at most 64 bits, got `Bool(true)`"
+        );
+    }
+}
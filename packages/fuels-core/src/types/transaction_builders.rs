@@ -20,7 +20,9 @@ use fuel_types::{bytes::padded_len_usize, canonical::Serialize, Bytes32, ChainId
 use itertools::Itertools;
 
 use crate::{
-    constants::{BASE_ASSET_ID, SIGNATURE_WITNESS_SIZE, WITNESS_STATIC_SIZE, WORD_SIZE},
+    constants::{
+        BASE_ASSET_ID, SIGNATURE_WITNESS_SIZE, TRANSFER_MEMO_PREFIX, WITNESS_STATIC_SIZE, WORD_SIZE,
+    },
     offsets,
     traits::Signer,
     types::{
@@ -42,7 +44,13 @@ use crate::{
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait DryRunner: Send + Sync {
     async fn dry_run_and_get_used_gas(&self, tx: FuelTransaction, tolerance: f32) -> Result<u64>;
+    /// The minimum gas price accepted by the node.
     async fn min_gas_price(&self) -> Result<u64>;
+    /// The gas price given to transactions whose `TxPolicies` don't set one. Defaults to the
+    /// minimum gas price.
+    async fn gas_price(&self) -> Result<u64> {
+        self.min_gas_price().await
+    }
     fn consensus_parameters(&self) -> &ConsensusParameters;
 }
 
@@ -56,6 +64,10 @@ impl<T: DryRunner> DryRunner for &T {
         (*self).min_gas_price().await
     }
 
+    async fn gas_price(&self) -> Result<u64> {
+        (*self).gas_price().await
+    }
+
     fn consensus_parameters(&self) -> &ConsensusParameters {
         (*self).consensus_parameters()
     }
@@ -255,7 +267,7 @@ macro_rules! impl_tx_trait {
                     .collect();
             }
 
-            fn generate_fuel_policies(&self, network_min_gas_price: u64) -> Policies {
+            fn generate_fuel_policies(&self, default_gas_price: u64) -> Policies {
                 let mut policies = Policies::default();
                 policies.set(PolicyType::MaxFee, self.tx_policies.max_fee());
                 policies.set(PolicyType::Maturity, self.tx_policies.maturity());
@@ -268,7 +280,7 @@ macro_rules! impl_tx_trait {
 
                 policies.set(
                     PolicyType::GasPrice,
-                    self.tx_policies.gas_price().or(Some(network_min_gas_price)),
+                    self.tx_policies.gas_price().or(Some(default_gas_price)),
                 );
 
                 policies
@@ -437,7 +449,7 @@ impl ScriptTransactionBuilder {
         provider: &impl DryRunner,
     ) -> Result<Script> {
         let num_witnesses = self.num_witnesses()?;
-        let policies = self.generate_fuel_policies(provider.min_gas_price().await?);
+        let policies = self.generate_fuel_policies(provider.gas_price().await?);
 
         let has_no_code = self.script.is_empty();
         let dry_run_witnesses = self.create_dry_run_witnesses(num_witnesses);
@@ -512,6 +524,20 @@ impl ScriptTransactionBuilder {
             .with_tx_policies(tx_policies)
     }
 
+    /// Attaches `memo` to the transaction, e.g. a payment reference, by storing it in the script
+    /// data. It can be read back with [`ScriptTransaction::transfer_memo`]. An empty `memo`
+    /// attaches nothing. Meant for transfers, as it replaces the script and its data.
+    pub fn with_transfer_memo(self, memo: &[u8]) -> Self {
+        if memo.is_empty() {
+            return self;
+        }
+
+        let script_data = [TRANSFER_MEMO_PREFIX.as_slice(), memo].concat();
+
+        self.with_script([op::ret(RegId::ONE)].into_iter().collect())
+            .with_script_data(script_data)
+    }
+
     /// Craft a transaction used to transfer funds to a contract.
     pub fn prepare_contract_transfer(
         to: ContractId,
@@ -622,7 +648,7 @@ impl CreateTransactionBuilder {
             .resolve_fuel_tx(
                 base_offset,
                 &consensus_parameters.chain_id,
-                provider.min_gas_price().await?,
+                provider.gas_price().await?,
             )
             .await?;
 
@@ -636,10 +662,10 @@ impl CreateTransactionBuilder {
         self,
         mut base_offset: usize,
         chain_id: &ChainId,
-        network_min_gas_price: u64,
+        default_gas_price: u64,
     ) -> Result<Create> {
         let num_witnesses = self.num_witnesses()?;
-        let policies = self.generate_fuel_policies(network_min_gas_price);
+        let policies = self.generate_fuel_policies(default_gas_price);
 
         let storage_slots_offset = self.storage_slots.len() * StorageSlot::SLOT_SIZE;
         base_offset += storage_slots_offset + policies.size_dynamic();
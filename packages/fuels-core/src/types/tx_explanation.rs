@@ -0,0 +1,396 @@
+use std::fmt;
+
+use fuel_tx::{Input, Output};
+use fuel_types::{AssetId, ContractId};
+
+use crate::{
+    codec::{resolve_fn_selector, token_to_json, ABIDecoder, AbiRegistry, ParsedAbi},
+    constants::BASE_ASSET_ID,
+    types::{
+        bech32::{Bech32Address, Bech32ContractId},
+        errors::Result,
+        param_types::ParamType,
+        transaction::{extract_owner_or_recipient, Transaction},
+    },
+};
+
+/// A printable summary of a transaction, see `ScriptTransaction::explain` and
+/// `CreateTransaction::explain`. Useful when debugging failing transactions or asking users to
+/// confirm what they are about to sign.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxExplanation {
+    pub kind: &'static str,
+    pub gas_price: u64,
+    pub maturity: u32,
+    pub inputs: Vec<InputSummary>,
+    pub outputs: Vec<OutputSummary>,
+    /// The size of every witness, in bytes.
+    pub witnesses: Vec<usize>,
+    pub script: Option<ScriptSummary>,
+    /// The contract calls found in the script data, for contracts whose ABI is registered.
+    pub contract_calls: Vec<ContractCallSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSummary {
+    /// A coin or a message, spent by `owner`.
+    Resource {
+        owner: Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+        is_predicate: bool,
+    },
+    Contract {
+        contract_id: Bech32ContractId,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputSummary {
+    Coin {
+        to: Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+    },
+    Change {
+        to: Bech32Address,
+        asset_id: AssetId,
+    },
+    Variable,
+    Contract,
+    ContractCreated {
+        contract_id: Bech32ContractId,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptSummary {
+    pub gas_limit: u64,
+    pub script_len: usize,
+    pub script_data_len: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractCallSummary {
+    pub contract_id: Bech32ContractId,
+    pub function: String,
+    /// The decoded arguments as JSON, or why they couldn't be decoded.
+    pub args: std::result::Result<Vec<String>, String>,
+}
+
+impl TxExplanation {
+    pub(crate) fn new(
+        kind: &'static str,
+        tx: &impl Transaction,
+        script: Option<(ScriptSummary, &[u8])>,
+        abi_registry: &AbiRegistry,
+    ) -> Self {
+        let inputs = tx.inputs().iter().map(summarize_input).collect::<Vec<_>>();
+
+        let contract_calls = script
+            .as_ref()
+            .map(|(_, script_data)| {
+                let called_contracts = tx.inputs().iter().filter_map(called_contract_id);
+                find_contract_calls(called_contracts, script_data, abi_registry)
+            })
+            .unwrap_or_default();
+
+        Self {
+            kind,
+            gas_price: tx.gas_price(),
+            maturity: tx.maturity(),
+            inputs,
+            outputs: tx.outputs().iter().map(summarize_output).collect(),
+            witnesses: tx
+                .witnesses()
+                .iter()
+                .map(|witness| witness.as_vec().len())
+                .collect(),
+            script: script.map(|(summary, _)| summary),
+            contract_calls,
+        }
+    }
+}
+
+fn summarize_input(input: &Input) -> InputSummary {
+    match extract_owner_or_recipient(input) {
+        Some(owner) => InputSummary::Resource {
+            owner,
+            amount: input.amount().unwrap_or_default(),
+            asset_id: input
+                .asset_id(&BASE_ASSET_ID)
+                .copied()
+                .unwrap_or(BASE_ASSET_ID),
+            is_predicate: input.is_coin_predicate() || input.is_message_coin_predicate(),
+        },
+        None => InputSummary::Contract {
+            contract_id: called_contract_id(input)
+                .copied()
+                .unwrap_or_default()
+                .into(),
+        },
+    }
+}
+
+fn called_contract_id(input: &Input) -> Option<&ContractId> {
+    match input {
+        Input::Contract(contract) => Some(&contract.contract_id),
+        _ => None,
+    }
+}
+
+fn summarize_output(output: &Output) -> OutputSummary {
+    match output {
+        Output::Coin {
+            to,
+            amount,
+            asset_id,
+        } => OutputSummary::Coin {
+            to: (*to).into(),
+            amount: *amount,
+            asset_id: *asset_id,
+        },
+        Output::Change { to, asset_id, .. } => OutputSummary::Change {
+            to: (*to).into(),
+            asset_id: *asset_id,
+        },
+        Output::Variable { .. } => OutputSummary::Variable,
+        Output::Contract { .. } => OutputSummary::Contract,
+        Output::ContractCreated { contract_id, .. } => OutputSummary::ContractCreated {
+            contract_id: (*contract_id).into(),
+        },
+    }
+}
+
+/// Every call script segment holds the id of the called contract followed by the selector of
+/// the function, optionally the offset of the arguments and then the encoded arguments.
+fn find_contract_calls<'a>(
+    called_contracts: impl Iterator<Item = &'a ContractId>,
+    script_data: &[u8],
+    abi_registry: &AbiRegistry,
+) -> Vec<ContractCallSummary> {
+    let mut calls = vec![];
+    for contract_id in called_contracts {
+        let Some(abi) = abi_registry.get(contract_id) else {
+            continue;
+        };
+
+        let id_len = contract_id.as_ref().len();
+        let occurrences = script_data
+            .windows(id_len)
+            .enumerate()
+            .filter(|(_, window)| *window == contract_id.as_ref())
+            .map(|(position, _)| position + id_len);
+
+        for selector_start in occurrences {
+            if let Some(call) = explain_call(contract_id, abi, &script_data[selector_start..]) {
+                calls.push((selector_start, call));
+            }
+        }
+    }
+
+    calls.sort_by_key(|(position, _)| *position);
+    calls.into_iter().map(|(_, call)| call).collect()
+}
+
+fn explain_call(
+    contract_id: &ContractId,
+    abi: &ParsedAbi,
+    data: &[u8],
+) -> Option<ContractCallSummary> {
+    let selector = data.get(..8)?;
+
+    abi.abi.functions.iter().find_map(|function| {
+        let input_types = function
+            .inputs
+            .iter()
+            .map(|input| abi.resolve(input))
+            .collect::<Result<Vec<_>>>()
+            .ok()?;
+
+        if resolve_fn_selector(&function.name, &input_types) != selector {
+            return None;
+        }
+
+        // mirrors the check done when the call is built
+        let has_args_offset = input_types.len() > 1
+            || input_types.iter().any(|param_type| {
+                !matches!(
+                    param_type,
+                    ParamType::Unit
+                        | ParamType::Bool
+                        | ParamType::U8
+                        | ParamType::U16
+                        | ParamType::U32
+                        | ParamType::U64
+                )
+            });
+        let args_start = if has_args_offset { 16 } else { 8 };
+
+        let args = data
+            .get(args_start..)
+            .ok_or_else(|| "script data ends before the arguments".to_string())
+            .and_then(|args| {
+                ABIDecoder::default()
+                    .decode_multiple(&input_types, args)
+                    .map_err(|err| err.to_string())
+            })
+            .map(|tokens| {
+                tokens
+                    .iter()
+                    .map(|token| token_to_json(token).to_string())
+                    .collect()
+            });
+
+        Some(ContractCallSummary {
+            contract_id: (*contract_id).into(),
+            function: function.name.clone(),
+            args,
+        })
+    })
+}
+
+impl fmt::Display for TxExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} transaction (gas price: {}, maturity: {})",
+            self.kind, self.gas_price, self.maturity
+        )?;
+
+        writeln!(f, "inputs:")?;
+        for (index, input) in self.inputs.iter().enumerate() {
+            match input {
+                InputSummary::Resource {
+                    owner,
+                    amount,
+                    asset_id,
+                    is_predicate,
+                } => {
+                    let spender = if *is_predicate { "predicate" } else { "owner" };
+                    writeln!(
+                        f,
+                        "  [{index}] {amount} of asset {asset_id} ({spender}: {owner})"
+                    )?;
+                }
+                InputSummary::Contract { contract_id } => {
+                    writeln!(f, "  [{index}] contract {contract_id}")?
+                }
+            }
+        }
+
+        writeln!(f, "outputs:")?;
+        for (index, output) in self.outputs.iter().enumerate() {
+            match output {
+                OutputSummary::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                } => writeln!(f, "  [{index}] {amount} of asset {asset_id} to {to}")?,
+                OutputSummary::Change { to, asset_id } => {
+                    writeln!(f, "  [{index}] change of asset {asset_id} to {to}")?
+                }
+                OutputSummary::Variable => writeln!(f, "  [{index}] variable")?,
+                OutputSummary::Contract => writeln!(f, "  [{index}] contract")?,
+                OutputSummary::ContractCreated { contract_id } => {
+                    writeln!(f, "  [{index}] creates contract {contract_id}")?
+                }
+            }
+        }
+
+        let witness_sizes = self
+            .witnesses
+            .iter()
+            .map(|size| format!("{size} bytes"))
+            .collect::<Vec<_>>();
+        writeln!(f, "witnesses: [{}]", witness_sizes.join(", "))?;
+
+        if let Some(script) = &self.script {
+            writeln!(
+                f,
+                "script: {} bytes, script data: {} bytes, gas limit: {}",
+                script.script_len, script.script_data_len, script.gas_limit
+            )?;
+        }
+
+        for call in &self.contract_calls {
+            match &call.args {
+                Ok(args) => writeln!(
+                    f,
+                    "call {}::{}({})",
+                    call.contract_id,
+                    call.function,
+                    args.join(", ")
+                )?,
+                Err(reason) => writeln!(
+                    f,
+                    "call {}::{}(<undecodable: {reason}>)",
+                    call.contract_id, call.function
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::AbiCache;
+
+    const ABI: &str = r#"{
+        "types": [
+            {"typeId": 0, "type": "()", "components": null, "typeParameters": null},
+            {"typeId": 1, "type": "u64", "components": null, "typeParameters": null},
+            {"typeId": 2, "type": "b256", "components": null, "typeParameters": null}
+        ],
+        "functions": [{
+            "inputs": [
+                {"name": "amount", "type": 1, "typeArguments": null},
+                {"name": "recipient", "type": 2, "typeArguments": null}
+            ],
+            "name": "transfer",
+            "output": {"name": "", "type": 0, "typeArguments": null},
+            "attributes": null
+        }],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#;
+
+    #[test]
+    fn contract_calls_are_decoded_from_the_script_data() -> Result<()> {
+        let contract_id = ContractId::from([7; 32]);
+        let parsed_abi = AbiCache::new().get_or_insert_json(ABI)?;
+        let recipient = format!("0x{}", "01".repeat(32));
+        let selector = resolve_fn_selector("transfer", &[ParamType::U64, ParamType::B256]);
+
+        let script_data = [
+            [0; 8].to_vec(),      // amount
+            [0; 32].to_vec(),     // asset id
+            contract_id.to_vec(), // contract id
+            selector.to_vec(),    // selector
+            [0; 8].to_vec(),      // args offset
+            parsed_abi.encode("transfer", &["42", &recipient])?,
+        ]
+        .concat();
+
+        let registry = AbiRegistry::new().with_abi(contract_id, parsed_abi);
+        let calls = find_contract_calls([contract_id].iter(), &script_data, &registry);
+
+        assert_eq!(
+            calls,
+            vec![ContractCallSummary {
+                contract_id: contract_id.into(),
+                function: "transfer".to_string(),
+                args: Ok(vec!["42".to_string(), format!("\"{recipient}\"")]),
+            }]
+        );
+
+        let unknown_contract = ContractId::from([8; 32]);
+        assert!(find_contract_calls([unknown_contract].iter(), &script_data, &registry).is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use fuel_tx::ConsensusParameters;
+
+use crate::types::transaction::{ScriptTransaction, Transaction};
+
+/// Share of a chain limit above which [`TxSizeEstimate::warning`] is set.
+const NEAR_LIMIT: f64 = 0.9;
+
+/// The size of a script transaction and what it costs, see
+/// `ContractCallHandler::estimate_size`. Lets batchers check how many calls still fit into a
+/// multicall before sending it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxSizeEstimate {
+    /// The size of the serialized transaction, in bytes, as charged by the chain.
+    pub size: u64,
+    pub script_data_size: u64,
+    /// The gas charged for the size of the transaction.
+    pub bytes_gas: u64,
+    /// What `bytes_gas` costs at the gas price of the transaction.
+    pub bytes_fee: u64,
+    pub max_size: u64,
+    pub max_script_data_size: u64,
+    /// Set when the transaction takes up more than 90% of the size or script data limit of the
+    /// chain.
+    pub warning: Option<String>,
+}
+
+impl TxSizeEstimate {
+    pub fn new(tx: &ScriptTransaction, consensus_parameters: &ConsensusParameters) -> Self {
+        let fee_params = consensus_parameters.fee_params();
+
+        let size = tx.metered_bytes_size() as u64;
+        let script_data_size = tx.script_data().len() as u64;
+        let bytes_gas = size.saturating_mul(fee_params.gas_per_byte);
+        let bytes_fee = (u128::from(bytes_gas) * u128::from(tx.gas_price()))
+            .div_ceil(u128::from(fee_params.gas_price_factor.max(1)));
+
+        let max_size = consensus_parameters.tx_params().max_size;
+        let max_script_data_size = consensus_parameters.script_params().max_script_data_length;
+        let warning = near_limit("transaction size", size, max_size)
+            .or_else(|| near_limit("script data size", script_data_size, max_script_data_size));
+
+        Self {
+            size,
+            script_data_size,
+            bytes_gas,
+            bytes_fee: u64::try_from(bytes_fee).unwrap_or(u64::MAX),
+            max_size,
+            max_script_data_size,
+            warning,
+        }
+    }
+}
+
+fn near_limit(what: &str, value: u64, limit: u64) -> Option<String> {
+    (value as f64 > limit as f64 * NEAR_LIMIT)
+        .then(|| format!("{what} of {value} bytes is close to the limit of {limit} bytes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{FeeParameters, ScriptParameters, TxParameters};
+
+    use super::*;
+
+    #[test]
+    fn bytes_are_charged_and_limits_are_watched() {
+        let tx: ScriptTransaction = fuel_tx::TransactionBuilder::script(vec![], vec![0; 100])
+            .gas_price(3)
+            .finalize()
+            .into();
+        let size = tx.metered_bytes_size() as u64;
+
+        let mut consensus_parameters = ConsensusParameters {
+            fee_params: FeeParameters::default()
+                .with_gas_per_byte(2)
+                .with_gas_price_factor(4),
+            ..Default::default()
+        };
+
+        let estimate = TxSizeEstimate::new(&tx, &consensus_parameters);
+        assert_eq!(estimate.size, size);
+        assert_eq!(estimate.script_data_size, 100);
+        assert_eq!(estimate.bytes_gas, 2 * size);
+        assert_eq!(estimate.bytes_fee, (2 * size * 3).div_ceil(4));
+        assert_eq!(estimate.warning, None);
+
+        consensus_parameters.script_params =
+            ScriptParameters::default().with_max_script_data_length(105);
+        let warning = TxSizeEstimate::new(&tx, &consensus_parameters).warning;
+        assert_eq!(
+            warning.as_deref(),
+            Some("script data size of 100 bytes is close to the limit of 105 bytes")
+        );
+
+        consensus_parameters.tx_params = TxParameters::default().with_max_size(size);
+        let warning = TxSizeEstimate::new(&tx, &consensus_parameters).warning;
+        assert!(warning
+            .expect("should be close to the limit")
+            .starts_with("transaction size"));
+    }
+}
@@ -13,7 +13,10 @@ use crate::{
     types::errors::{transaction::Reason, Error, Result},
 };
 
-#[derive(Debug, Clone)]
+/// Serializes to JSON as an object with a `status` field, one of `success`, `submitted`,
+/// `squeezed_out` or `revert`, next to the fields of the variant.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
 pub enum TxStatus {
     Success {
         receipts: Vec<Receipt>,
@@ -0,0 +1,183 @@
+#![cfg(feature = "std")]
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use fuel_types::{AssetId, Bytes32, ChainId};
+
+use crate::{
+    codec::AbiRegistry,
+    types::{
+        bech32::Bech32Address,
+        transaction::{Transaction, TransactionType},
+        transaction_response::TransactionResponse,
+        tx_explanation::{ContractCallSummary, InputSummary, OutputSummary, TxExplanation},
+        tx_status::TxStatus,
+    },
+};
+
+/// What a transaction did from the point of view of an address, see
+/// `Provider::get_wallet_activity`. Wallet apps list these as the history of the address.
+#[derive(Debug, Clone)]
+pub struct WalletActivity {
+    pub tx_id: Bytes32,
+    pub kind: ActivityKind,
+    pub status: TxStatus,
+    pub block_id: Option<Bytes32>,
+    pub time: Option<DateTime<Utc>>,
+    /// Coins sent to others, by asset. Only set when the address paid for the transaction.
+    pub sent: BTreeMap<AssetId, u64>,
+    /// Coins received from others, by asset. Change isn't included, its amount is only known
+    /// once the transaction is executed.
+    pub received: BTreeMap<AssetId, u64>,
+    /// The contract calls made by the script, for contracts whose ABI was registered.
+    pub contract_calls: Vec<ContractCallSummary>,
+    pub transaction: TransactionType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    /// The address spent coins or messages without calling a contract.
+    Sent,
+    /// Others sent coins to the address.
+    Received,
+    /// The script called at least one contract.
+    ContractCall,
+    Deployment,
+    /// Mints, or transactions involving the address in no other way.
+    Other,
+}
+
+impl WalletActivity {
+    pub fn new(
+        response: TransactionResponse,
+        owner: &Bech32Address,
+        chain_id: ChainId,
+        abi_registry: &AbiRegistry,
+    ) -> Self {
+        let (tx_id, explanation) = match &response.transaction {
+            TransactionType::Script(tx) => (tx.id(chain_id), Some(tx.explain(abi_registry))),
+            TransactionType::Create(tx) => (tx.id(chain_id), Some(tx.explain(abi_registry))),
+            TransactionType::Mint(tx) => (tx.id(chain_id), None),
+        };
+
+        let mut activity = Self {
+            tx_id,
+            kind: ActivityKind::Other,
+            status: response.status,
+            block_id: response.block_id,
+            time: response.time,
+            sent: BTreeMap::new(),
+            received: BTreeMap::new(),
+            contract_calls: vec![],
+            transaction: response.transaction,
+        };
+        if let Some(explanation) = explanation {
+            activity.classify(explanation, owner);
+        }
+
+        activity
+    }
+
+    fn classify(&mut self, explanation: TxExplanation, owner: &Bech32Address) {
+        let spent_by_owner = explanation.inputs.iter().any(|input| {
+            matches!(input, InputSummary::Resource { owner: spender, .. } if spender == owner)
+        });
+        let calls_contracts = explanation
+            .inputs
+            .iter()
+            .any(|input| matches!(input, InputSummary::Contract { .. }));
+
+        for output in &explanation.outputs {
+            let OutputSummary::Coin {
+                to,
+                amount,
+                asset_id,
+            } = output
+            else {
+                continue;
+            };
+
+            let totals = match (spent_by_owner, to == owner) {
+                (true, false) => &mut self.sent,
+                (false, true) => &mut self.received,
+                _ => continue,
+            };
+            let total = totals.entry(*asset_id).or_default();
+            *total = total.saturating_add(*amount);
+        }
+
+        self.kind = if matches!(self.transaction, TransactionType::Create(_)) {
+            ActivityKind::Deployment
+        } else if calls_contracts {
+            ActivityKind::ContractCall
+        } else if spent_by_owner {
+            ActivityKind::Sent
+        } else if !self.received.is_empty() {
+            ActivityKind::Received
+        } else {
+            ActivityKind::Other
+        };
+        self.contract_calls = explanation.contract_calls;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        coin::Coin, transaction::ScriptTransaction, transaction_builders::create_coin_input,
+    };
+
+    fn transfer(from: &Bech32Address, to: &Bech32Address) -> TransactionResponse {
+        let coin = Coin {
+            amount: 100,
+            owner: from.clone(),
+            ..Default::default()
+        };
+        let tx: ScriptTransaction = fuel_tx::TransactionBuilder::script(vec![], vec![])
+            .add_input(create_coin_input(coin, 0))
+            .add_output(fuel_tx::Output::coin(to.into(), 30, AssetId::default()))
+            .add_output(fuel_tx::Output::change(from.into(), 0, AssetId::default()))
+            .finalize()
+            .into();
+
+        TransactionResponse {
+            transaction: TransactionType::Script(tx),
+            status: TxStatus::Success { receipts: vec![] },
+            block_id: None,
+            time: None,
+        }
+    }
+
+    #[test]
+    fn transfers_are_sent_by_one_side_and_received_by_the_other() {
+        let sender = Bech32Address::new("fuel", [1; 32]);
+        let recipient = Bech32Address::new("fuel", [2; 32]);
+        let bystander = Bech32Address::new("fuel", [3; 32]);
+        let activity = |owner: &Bech32Address| {
+            WalletActivity::new(
+                transfer(&sender, &recipient),
+                owner,
+                ChainId::default(),
+                &AbiRegistry::new(),
+            )
+        };
+
+        let sent = activity(&sender);
+        assert_eq!(sent.kind, ActivityKind::Sent);
+        assert_eq!(sent.sent, BTreeMap::from([(AssetId::default(), 30)]));
+        assert!(sent.received.is_empty());
+
+        let received = activity(&recipient);
+        assert_eq!(received.kind, ActivityKind::Received);
+        assert!(received.sent.is_empty());
+        assert_eq!(
+            received.received,
+            BTreeMap::from([(AssetId::default(), 30)])
+        );
+        assert_eq!(received.tx_id, sent.tx_id);
+
+        assert_eq!(activity(&bystander).kind, ActivityKind::Other);
+    }
+}
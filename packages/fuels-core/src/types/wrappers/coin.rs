@@ -26,6 +26,19 @@ pub struct Coin {
     pub status: CoinStatus,
 }
 
+impl Coin {
+    /// The height of the first block the coin can be spent in. Its `maturity` counts the blocks
+    /// from the one it was created in.
+    pub fn matures_at(&self) -> u32 {
+        self.block_created.saturating_add(self.maturity)
+    }
+
+    /// Whether a transaction spending the coin is accepted once the chain is at `block_height`.
+    pub fn is_mature(&self, block_height: u32) -> bool {
+        block_height >= self.matures_at()
+    }
+}
+
 impl From<ClientCoin> for Coin {
     fn from(coin: ClientCoin) -> Self {
         Self {
@@ -54,3 +67,27 @@ impl From<Coin> for CoinConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coins_mature_relative_to_their_block() {
+        let coin = Coin {
+            block_created: 10,
+            maturity: 5,
+            ..Default::default()
+        };
+
+        assert_eq!(coin.matures_at(), 15);
+        assert!(!coin.is_mature(14));
+        assert!(coin.is_mature(15));
+
+        let unlocked = Coin {
+            block_created: 10,
+            ..Default::default()
+        };
+        assert!(unlocked.is_mature(10));
+    }
+}
@@ -17,16 +17,19 @@ use fuel_tx::{
     Input, Mint, Output, Salt as FuelSalt, Script, StorageSlot, Transaction as FuelTransaction,
     TransactionFee, UniqueIdentifier, Witness,
 };
-use fuel_types::{bytes::padded_len_usize, AssetId, ChainId};
+use fuel_types::{bytes::padded_len_usize, Address, AssetId, ChainId};
 use fuel_vm::checked_transaction::EstimatePredicates;
 use itertools::Itertools;
 
 use crate::{
-    constants::BASE_ASSET_ID,
+    codec::AbiRegistry,
+    constants::{BASE_ASSET_ID, TRANSFER_MEMO_PREFIX},
     traits::Signer,
     types::{
         bech32::Bech32Address,
         errors::{error_transaction, Result},
+        signing_request::SigningRequest,
+        tx_explanation::{ScriptSummary, TxExplanation},
     },
     utils::{calculate_witnesses_size, sealed},
 };
@@ -80,7 +83,7 @@ impl MintTransaction {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 //ANCHOR: tx_policies_struct
 pub struct TxPolicies {
     gas_price: Option<u64>,
@@ -170,6 +173,40 @@ pub trait EstimablePredicates: sealed::Sealed {
     /// before sending the transaction to the node. The estimation will check
     /// all predicates and set the `predicate_gas_used` to the actual consumed gas.
     fn estimate_predicates(&mut self, consensus_parameters: &ConsensusParameters) -> Result<()>;
+
+    /// Sets the `predicate_gas_used` of every input to the one found in `estimated`, a copy of
+    /// this transaction whose predicates were estimated elsewhere, e.g. by the node.
+    fn apply_predicate_estimation(&mut self, estimated: &FuelTransaction) -> Result<()>;
+}
+
+fn copy_predicate_gas_used(inputs: &mut [Input], estimated: &FuelTransaction) -> Result<()> {
+    let estimated_inputs = match estimated {
+        FuelTransaction::Script(tx) => tx.inputs(),
+        FuelTransaction::Create(tx) => tx.inputs(),
+        FuelTransaction::Mint(_) => {
+            return Err(error_transaction!(
+                Other,
+                "mint transactions have no predicates to estimate"
+            ))
+        }
+    };
+
+    if estimated_inputs.len() != inputs.len() {
+        return Err(error_transaction!(
+            Other,
+            "estimated transaction has {} inputs, expected {}",
+            estimated_inputs.len(),
+            inputs.len()
+        ));
+    }
+
+    for (input, estimated_input) in inputs.iter_mut().zip(estimated_inputs) {
+        if let Some(predicate_gas_used) = estimated_input.predicate_gas_used() {
+            input.set_predicate_gas_used(predicate_gas_used);
+        }
+    }
+
+    Ok(())
 }
 
 pub trait GasValidation: sealed::Sealed {
@@ -227,6 +264,64 @@ pub trait Transaction:
         signer: &(impl Signer + Send + Sync),
         chain_id: ChainId,
     ) -> Result<Signature>;
+
+    /// The owners of the signed inputs and the witness indexes these inputs reference, ordered
+    /// by index. Each owner has to sign the final transaction and have the signature placed at
+    /// its indexes, see [`Transaction::sign_at_witness_index`].
+    fn witness_indexes(&self) -> Vec<(Bech32Address, u8)> {
+        self.inputs()
+            .iter()
+            .filter_map(signed_input_witness)
+            .map(|(owner, witness_index)| (Bech32Address::from(*owner), witness_index))
+            .unique()
+            .sorted_by_key(|(_, witness_index)| *witness_index)
+            .collect()
+    }
+
+    /// Like [`Transaction::sign_with`], but puts the signature at the witness indexes
+    /// referenced by the inputs of `signer` instead of appending it. The signers of a
+    /// transaction with inputs from several owners can then sign it in any order. Witnesses
+    /// for the signers that didn't sign yet are filled with placeholder signatures.
+    ///
+    /// Fails if `signer` owns none of the signed inputs.
+    async fn sign_at_witness_index(
+        &mut self,
+        signer: &(impl Signer + Send + Sync),
+        chain_id: ChainId,
+    ) -> Result<Signature>;
+
+    /// Checks that the signature of every signed input was made by its owner over the id of
+    /// this transaction on the chain `chain_id`. Transactions are signed for a single chain, so
+    /// this fails for a transaction signed for another one, e.g. testnet instead of mainnet.
+    fn verify_signatures(&self, chain_id: ChainId) -> Result<()> {
+        let message = Message::from_bytes(*self.id(chain_id));
+
+        self.inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| {
+                signed_input_witness(input)
+                    .map(|(owner, witness_index)| (index, owner, witness_index))
+            })
+            .try_for_each(|(index, owner, witness_index)| {
+                let signer = self
+                    .witnesses()
+                    .get(witness_index as usize)
+                    .and_then(|witness| Signature::try_from(witness.as_vec().as_slice()).ok())
+                    .and_then(|signature| signature.recover(&message).ok())
+                    .map(|public_key| Address::from(*public_key.hash()));
+
+                if signer.as_ref() != Some(owner) {
+                    return Err(error_transaction!(
+                        Validation,
+                        "the signature of input {index} is not valid on chain {chain_id}: the \
+                         transaction was signed for another chain or by another key"
+                    ));
+                }
+
+                Ok(())
+            })
+    }
 }
 
 impl From<TransactionType> for FuelTransaction {
@@ -249,6 +344,28 @@ fn extract_coin_type_id(input: &Input) -> Option<CoinTypeId> {
     None
 }
 
+/// The owner of a signed input and the index of the witness holding the owner's signature.
+fn signed_input_witness(input: &Input) -> Option<(&Address, u8)> {
+    match input {
+        Input::CoinSigned(CoinSigned {
+            owner,
+            witness_index,
+            ..
+        })
+        | Input::MessageCoinSigned(MessageCoinSigned {
+            recipient: owner,
+            witness_index,
+            ..
+        })
+        | Input::MessageDataSigned(MessageDataSigned {
+            recipient: owner,
+            witness_index,
+            ..
+        }) => Some((owner, *witness_index)),
+        _ => None,
+    }
+}
+
 pub fn extract_owner_or_recipient(input: &Input) -> Option<Bech32Address> {
     let addr = match input {
         Input::CoinSigned(CoinSigned { owner, .. })
@@ -430,6 +547,51 @@ macro_rules! impl_tx_wrapper {
 
                 Ok(signature)
             }
+
+            async fn sign_at_witness_index(
+                &mut self,
+                signer: &(impl Signer + Send + Sync),
+                chain_id: ChainId,
+            ) -> Result<Signature> {
+                let owner = signer.address();
+                let witness_indexes = self
+                    .witness_indexes()
+                    .into_iter()
+                    .filter(|(input_owner, _)| input_owner == owner)
+                    .map(|(_, witness_index)| witness_index as usize)
+                    .collect::<Vec<_>>();
+                let Some(&last_index) = witness_indexes.last() else {
+                    return Err(error_transaction!(
+                        Validation,
+                        "`{owner}` doesn't own any of the signed inputs of the transaction"
+                    ));
+                };
+
+                let message = Message::from_bytes(*self.id(chain_id));
+                let signature = signer.sign(message).await?;
+
+                let mut witnesses = self.tx.witnesses().clone();
+                if witnesses.len() <= last_index {
+                    let placeholder: Witness = Signature::default().as_ref().into();
+                    witnesses.resize(last_index + 1, placeholder);
+                }
+                for witness_index in witness_indexes {
+                    witnesses[witness_index] = signature.as_ref().into();
+                }
+
+                let new_witnesses_size =
+                    padded_len_usize(calculate_witnesses_size(&witnesses)) as u64;
+                if new_witnesses_size > self.tx.witness_limit() {
+                    return Err(error_transaction!(
+                        Validation,
+                        "Witness limit exceeded. Consider setting the limit manually with \
+                        a transaction builder. The new limit should be: `{new_witnesses_size}`"
+                    ));
+                }
+                *self.tx.witnesses_mut() = witnesses;
+
+                Ok(signature)
+            }
         }
     };
 }
@@ -443,6 +605,10 @@ impl EstimablePredicates for CreateTransaction {
 
         Ok(())
     }
+
+    fn apply_predicate_estimation(&mut self, estimated: &FuelTransaction) -> Result<()> {
+        copy_predicate_gas_used(self.tx.inputs_mut(), estimated)
+    }
 }
 
 impl CreateTransaction {
@@ -461,6 +627,21 @@ impl CreateTransaction {
     pub fn bytecode_length(&self) -> u64 {
         *self.tx.bytecode_length()
     }
+
+    /// A printable summary of the transaction, see [`TxExplanation`].
+    pub fn explain(&self, abi_registry: &AbiRegistry) -> TxExplanation {
+        TxExplanation::new("create", self, None, abi_registry)
+    }
+
+    /// What `signer` is asked to sign, see [`SigningRequest`].
+    pub fn signing_request(
+        &self,
+        signer: &Bech32Address,
+        chain_id: ChainId,
+        abi_registry: &AbiRegistry,
+    ) -> SigningRequest {
+        SigningRequest::new(self, self.explain(abi_registry), signer, chain_id)
+    }
 }
 
 impl GasValidation for CreateTransaction {
@@ -486,6 +667,10 @@ impl EstimablePredicates for ScriptTransaction {
 
         Ok(())
     }
+
+    fn apply_predicate_estimation(&mut self, estimated: &FuelTransaction) -> Result<()> {
+        copy_predicate_gas_used(self.tx.inputs_mut(), estimated)
+    }
 }
 
 impl GasValidation for ScriptTransaction {
@@ -519,6 +704,12 @@ impl ScriptTransaction {
         self.tx.script_data()
     }
 
+    /// The memo attached with `ScriptTransactionBuilder::with_transfer_memo`, if any.
+    pub fn transfer_memo(&self) -> Option<&[u8]> {
+        self.script_data()
+            .strip_prefix(TRANSFER_MEMO_PREFIX.as_slice())
+    }
+
     pub fn gas_limit(&self) -> u64 {
         *self.tx.script_gas_limit()
     }
@@ -527,6 +718,34 @@ impl ScriptTransaction {
         self.tx.set_script_gas_limit(gas_limit);
         self
     }
+
+    /// A printable summary of the transaction, see [`TxExplanation`]. The contract calls made
+    /// by the script are decoded for contracts whose ABI is in `abi_registry`.
+    pub fn explain(&self, abi_registry: &AbiRegistry) -> TxExplanation {
+        let script = ScriptSummary {
+            gas_limit: self.gas_limit(),
+            script_len: self.script().len(),
+            script_data_len: self.script_data().len(),
+        };
+
+        TxExplanation::new(
+            "script",
+            self,
+            Some((script, self.script_data())),
+            abi_registry,
+        )
+    }
+
+    /// What `signer` is asked to sign, including the decoded contract calls, see
+    /// [`SigningRequest`].
+    pub fn signing_request(
+        &self,
+        signer: &Bech32Address,
+        chain_id: ChainId,
+        abi_registry: &AbiRegistry,
+    ) -> SigningRequest {
+        SigningRequest::new(self, self.explain(abi_registry), signer, chain_id)
+    }
 }
 
 #[cfg(test)]
@@ -535,6 +754,7 @@ mod test {
     use fuel_tx::policies::Policies;
 
     use super::*;
+    use crate::types::{coin::Coin, transaction_builders::create_coin_input};
 
     #[test]
     fn append_witnesses_returns_error_when_limit_exceeded() {
@@ -560,4 +780,101 @@ mod test {
 
         assert_eq!(&err.to_string(), expected_err_str);
     }
+
+    #[test]
+    fn transfer_memo_is_read_from_script_data() {
+        let tx_with_script_data = |script_data: Vec<u8>| ScriptTransaction {
+            tx: FuelTransaction::script(
+                0,
+                vec![],
+                script_data,
+                Policies::default(),
+                vec![],
+                vec![],
+                vec![],
+            ),
+            is_using_predicates: false,
+        };
+
+        let tx = tx_with_script_data([TRANSFER_MEMO_PREFIX.as_slice(), b"deposit-42"].concat());
+        assert_eq!(tx.transfer_memo(), Some(b"deposit-42".as_slice()));
+
+        let tx = tx_with_script_data(vec![1, 2, 3]);
+        assert_eq!(tx.transfer_memo(), None);
+    }
+
+    struct MockSigner {
+        address: Bech32Address,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Signer for MockSigner {
+        async fn sign(&self, _message: Message) -> Result<Signature> {
+            Ok(Signature::from_bytes([self.address.hash()[0]; 64]))
+        }
+
+        fn address(&self) -> &Bech32Address {
+            &self.address
+        }
+    }
+
+    #[tokio::test]
+    async fn signatures_land_at_the_witness_indexes_of_their_signers() -> Result<()> {
+        let alice = MockSigner {
+            address: Bech32Address::new("fuel", [1; 32]),
+        };
+        let bob = MockSigner {
+            address: Bech32Address::new("fuel", [2; 32]),
+        };
+        let input = |owner: &Bech32Address, witness_index| {
+            let coin = Coin {
+                amount: 100,
+                owner: owner.clone(),
+                ..Default::default()
+            };
+            create_coin_input(coin, witness_index)
+        };
+        let mut tx = ScriptTransaction {
+            tx: FuelTransaction::script(
+                0,
+                vec![],
+                vec![],
+                Policies::default().with_witness_limit(1000),
+                vec![
+                    input(&bob.address, 1),
+                    input(&alice.address, 0),
+                    input(&bob.address, 1),
+                ],
+                vec![],
+                vec![],
+            ),
+            is_using_predicates: false,
+        };
+        assert_eq!(
+            tx.witness_indexes(),
+            vec![(alice.address.clone(), 0), (bob.address.clone(), 1)]
+        );
+
+        tx.sign_at_witness_index(&bob, ChainId::default()).await?;
+        assert_eq!(tx.witnesses().len(), 2);
+        assert_eq!(tx.witnesses()[1].as_vec(), &vec![2; 64]);
+
+        tx.sign_at_witness_index(&alice, ChainId::default()).await?;
+        assert_eq!(tx.witnesses().len(), 2);
+        assert_eq!(tx.witnesses()[0].as_vec(), &vec![1; 64]);
+
+        let stranger = MockSigner {
+            address: Bech32Address::new("fuel", [3; 32]),
+        };
+        let err = tx
+            .sign_at_witness_index(&stranger, ChainId::default())
+            .await
+            .expect_err("should own no inputs");
+        assert!(err
+            .to_string()
+            .ends_with("doesn't own any of the signed inputs of the transaction"));
+
+        Ok(())
+    }
 }
@@ -1,28 +1,18 @@
+pub mod bytecode;
 pub mod constants;
 pub mod offsets;
+pub mod sparse_merkle;
 
-use constants::{WITNESS_STATIC_SIZE, WORD_SIZE};
+use constants::WITNESS_STATIC_SIZE;
 use fuel_tx::Witness;
 
-use crate::{error, types::errors::Result};
+use crate::{codec::WordCodec, error, types::errors::Result};
 
 pub fn checked_round_up_to_word_alignment(bytes_len: usize) -> Result<usize> {
-    let lhs = bytes_len.checked_add(WORD_SIZE - 1).ok_or_else(|| {
+    WordCodec::padded_len(bytes_len).ok_or_else(|| {
         error!(
             Codec,
-            "addition overflow while rounding up {bytes_len} bytes to word alignment"
-        )
-    })?;
-    let rhs = lhs.checked_rem(WORD_SIZE).ok_or_else(|| {
-        error!(
-            Codec,
-            "remainder overflow while rounding up {bytes_len} bytes to word alignment"
-        )
-    })?;
-    lhs.checked_sub(rhs).ok_or_else(|| {
-        error!(
-            Codec,
-            "substraction overflow while rounding up {bytes_len} bytes to word alignment"
+            "overflow while rounding up {bytes_len} bytes to word alignment"
         )
     })
 }
@@ -0,0 +1,223 @@
+//! Hashes and patches of compiled bytecode, as computed by the node. Useful to deployment
+//! tooling and to scripts verifying that a deployed contract matches its sources.
+
+use fuel_crypto::Hasher;
+use fuel_tx::{ConsensusParameters, Contract, StorageSlot};
+use fuel_types::{Bytes32, ContractId, Salt};
+
+use crate::{constants::WORD_SIZE, error, types::errors::Result};
+
+/// Every instruction takes 4 bytes.
+const INSTRUCTION_SIZE: usize = 4;
+
+/// The merkle root of the contract's bytecode, as used in its id.
+pub fn code_root(binary: &[u8]) -> Bytes32 {
+    Contract::from(binary).root()
+}
+
+/// The merkle root of the contract's storage upon deployment, as used in its id.
+pub fn state_root(storage_slots: &[StorageSlot]) -> Bytes32 {
+    Contract::initial_state_root(storage_slots.iter())
+}
+
+/// The id the contract gets once deployed with `salt` and `storage_slots`.
+pub fn contract_id(binary: &[u8], salt: &Salt, storage_slots: &[StorageSlot]) -> ContractId {
+    Contract::from(binary).id(salt, &code_root(binary), &state_root(storage_slots))
+}
+
+/// The SHA-256 of the bytecode, e.g. to compare a build with a published artifact.
+pub fn bytecode_hash(binary: &[u8]) -> Bytes32 {
+    Hasher::hash(binary)
+}
+
+/// Returns a copy of `binary` with `values[i]` written at `offsets[i]`, e.g. to set the
+/// configurable constants of a program from the offsets found in its ABI. Fails if a value
+/// doesn't fit in the binary.
+pub fn patch_configurables(binary: &[u8], offsets: &[u64], values: &[Vec<u8>]) -> Result<Vec<u8>> {
+    if offsets.len() != values.len() {
+        return Err(error!(
+            Other,
+            "got {} offsets but {} values",
+            offsets.len(),
+            values.len()
+        ));
+    }
+
+    let mut patched = binary.to_vec();
+    for (offset, value) in offsets.iter().zip(values) {
+        let start = *offset as usize;
+        let destination = start
+            .checked_add(value.len())
+            .and_then(|end| patched.get_mut(start..end))
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "cannot write {} bytes at offset {offset} of a {} bytes binary",
+                    value.len(),
+                    binary.len()
+                )
+            })?;
+        destination.copy_from_slice(value);
+    }
+
+    Ok(patched)
+}
+
+/// The size of a compiled Sway program and of its sections, see [`analyze_bytecode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BytecodeReport {
+    pub size: usize,
+    /// The instructions, before the data section.
+    pub code_size: usize,
+    /// Constants and configurables, at the end of the binary.
+    pub data_size: usize,
+    pub num_instructions: usize,
+    /// A lower bound of the gas used to verify the program as a predicate: one unit per
+    /// instruction, as every instruction costs at least that much. It only holds if every
+    /// instruction runs, loops and expensive instructions such as hashes make the actual amount
+    /// higher. The VM's figure is set on the transaction spending the predicate by
+    /// [`EstimablePredicates::estimate_predicates`].
+    ///
+    /// [`EstimablePredicates::estimate_predicates`]: crate::types::transaction::EstimablePredicates::estimate_predicates
+    pub estimated_predicate_gas: u64,
+}
+
+impl BytecodeReport {
+    /// Checks the size and estimated gas against the predicate limits of the chain. Since the
+    /// gas is a lower bound, passing doesn't guarantee the predicate fits in the gas limit.
+    pub fn check_predicate_limits(&self, consensus_parameters: &ConsensusParameters) -> Result<()> {
+        let params = &consensus_parameters.predicate_params;
+        check_limit(
+            "predicate size",
+            self.size as u64,
+            params.max_predicate_length,
+        )?;
+        check_limit(
+            "estimated predicate gas",
+            self.estimated_predicate_gas,
+            params.max_gas_per_predicate,
+        )
+    }
+
+    pub fn check_script_limits(&self, consensus_parameters: &ConsensusParameters) -> Result<()> {
+        check_limit(
+            "script size",
+            self.size as u64,
+            consensus_parameters.script_params.max_script_length,
+        )
+    }
+
+    pub fn check_contract_limits(&self, consensus_parameters: &ConsensusParameters) -> Result<()> {
+        check_limit(
+            "contract size",
+            self.size as u64,
+            consensus_parameters.contract_params.contract_max_size,
+        )
+    }
+}
+
+fn check_limit(what: &str, value: u64, limit: u64) -> Result<()> {
+    if value > limit {
+        return Err(error!(
+            Other,
+            "{what} of {value} exceeds the limit of {limit} of the chain"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Measures a compiled Sway program without running it, so its size can be checked against
+/// the limits of the chain before deploying it or funding a predicate. The offset of the data
+/// section is read from the second word of the binary, where the compiler puts it.
+pub fn analyze_bytecode(binary: &[u8]) -> Result<BytecodeReport> {
+    let data_offset = binary
+        .get(WORD_SIZE..2 * WORD_SIZE)
+        .map(|word| u64::from_be_bytes(word.try_into().expect("a word is 8 bytes")) as usize)
+        .filter(|offset| (2 * WORD_SIZE..=binary.len()).contains(offset))
+        .ok_or_else(|| error!(Other, "not a Sway binary: no valid data section offset"))?;
+
+    let num_instructions = data_offset / INSTRUCTION_SIZE;
+
+    Ok(BytecodeReport {
+        size: binary.len(),
+        code_size: data_offset,
+        data_size: binary.len() - data_offset,
+        num_instructions,
+        estimated_predicate_gas: num_instructions as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configurables_are_patched_within_the_binary() -> Result<()> {
+        let binary = [0u8; 16];
+
+        let patched = patch_configurables(&binary, &[8], &[vec![1, 2]])?;
+        assert_eq!(patched, [[0; 8].as_slice(), &[1, 2], &[0; 6]].concat());
+
+        let err = patch_configurables(&binary, &[15], &[vec![1, 2]])
+            .expect_err("should not fit in the binary");
+        assert_eq!(
+            err.to_string(),
+            "cannot write 2 bytes at offset 15 of a 16 bytes binary"
+        );
+        assert!(patch_configurables(&binary, &[0, 8], &[vec![1]]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn contract_id_depends_on_code_salt_and_storage() {
+        let binary = [1u8; 16];
+        let slot = StorageSlot::new(Bytes32::from([1; 32]), Bytes32::from([2; 32]));
+        let id = contract_id(&binary, &Salt::default(), &[]);
+
+        assert_eq!(id, contract_id(&binary, &Salt::default(), &[]));
+        assert_ne!(id, contract_id(&binary, &Salt::from([1; 32]), &[]));
+        assert_ne!(id, contract_id(&binary, &Salt::default(), &[slot]));
+        assert_ne!(code_root(&binary), code_root(&[2u8; 16]));
+    }
+
+    #[test]
+    fn code_and_data_sections_are_measured() -> Result<()> {
+        // a jump, the data section offset and two more instructions, followed by a word of data
+        let binary = [
+            [0x90, 0, 0, 4, 0x47, 0, 0, 0].as_slice(),
+            &24u64.to_be_bytes(),
+            &[0x47, 0, 0, 0, 0x24, 0x40, 0, 0],
+            &[0; 8],
+        ]
+        .concat();
+
+        let report = analyze_bytecode(&binary)?;
+        assert_eq!(
+            report,
+            BytecodeReport {
+                size: 32,
+                code_size: 24,
+                data_size: 8,
+                num_instructions: 6,
+                estimated_predicate_gas: 6,
+            }
+        );
+
+        let mut consensus_parameters = ConsensusParameters::default();
+        report.check_predicate_limits(&consensus_parameters)?;
+        consensus_parameters.predicate_params.max_predicate_length = 16;
+        let err = report
+            .check_predicate_limits(&consensus_parameters)
+            .expect_err("should be too big");
+        assert_eq!(
+            err.to_string(),
+            "predicate size of 32 exceeds the limit of 16 of the chain"
+        );
+
+        assert!(analyze_bytecode(&[0; 8]).is_err());
+
+        Ok(())
+    }
+}
@@ -12,6 +12,9 @@ pub const BASE_ASSET_ID: AssetId = AssetId::BASE;
 
 pub const DEFAULT_GAS_ESTIMATION_TOLERANCE: f64 = 0.2;
 
+// Marks the script data of a transfer as carrying a memo
+pub const TRANSFER_MEMO_PREFIX: [u8; 8] = *b"fuelmemo";
+
 // The size of a signature inside a transaction `Witness`
 pub const WITNESS_STATIC_SIZE: usize = 8;
 const SIGNATURE_SIZE: usize = 64;
@@ -0,0 +1,239 @@
+//! The sparse merkle tree the node commits contract storage to. Computes the same roots as the
+//! node and proves that a slot holds a value, so storage reads can be verified off-chain
+//! against a state root.
+
+use std::collections::BTreeMap;
+
+use fuel_crypto::Hasher;
+use fuel_tx::StorageSlot;
+use fuel_types::Bytes32;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+/// Stands for empty subtrees.
+const PLACEHOLDER: Bytes32 = Bytes32::new([0; 32]);
+
+/// Sparse merkle tree over 256-bit paths, the path of a key being its SHA-256. A subtree
+/// holding a single leaf is represented by that leaf.
+///
+/// ```
+/// use fuel_types::Bytes32;
+/// use fuels_core::sparse_merkle::SparseMerkleTree;
+///
+/// let mut tree = SparseMerkleTree::new();
+/// tree.insert(&Bytes32::from([1; 32]), &[2; 32]);
+///
+/// let proof = tree.prove(&Bytes32::from([1; 32])).unwrap();
+/// assert!(proof.verify(&tree.root(), &Bytes32::from([1; 32]), &[2; 32]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseMerkleTree {
+    // leaf hashes by path
+    leaves: BTreeMap<Bytes32, Bytes32>,
+}
+
+impl SparseMerkleTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The tree of a contract's initial storage, its root is the contract's state root.
+    pub fn from_slots<'a>(storage_slots: impl IntoIterator<Item = &'a StorageSlot>) -> Self {
+        let mut tree = Self::new();
+        for slot in storage_slots {
+            tree.insert(slot.key(), slot.value().as_ref());
+        }
+
+        tree
+    }
+
+    /// Sets the value of `key`, replacing the previous one.
+    pub fn insert(&mut self, key: &Bytes32, value: &[u8]) {
+        let path = path_of(key);
+        self.leaves.insert(path, leaf_hash(&path, value));
+    }
+
+    pub fn remove(&mut self, key: &Bytes32) {
+        self.leaves.remove(&path_of(key));
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        let leaves = self.leaves.iter().collect::<Vec<_>>();
+
+        subtree_root(&leaves, 0)
+    }
+
+    /// Proves the current value of `key`. `None` if the tree doesn't hold `key`.
+    pub fn prove(&self, key: &Bytes32) -> Option<InclusionProof> {
+        let path = path_of(key);
+        if !self.leaves.contains_key(&path) {
+            return None;
+        }
+
+        let mut leaves = self.leaves.iter().collect::<Vec<_>>();
+        let mut side_nodes = vec![];
+        let mut depth = 0;
+        while leaves.len() > 1 {
+            let (left, right) = split_at_bit(&leaves, depth);
+            let (on_path, aside) = if bit_at(&path, depth) {
+                (right, left)
+            } else {
+                (left, right)
+            };
+
+            side_nodes.push(subtree_root(&aside, depth + 1));
+            leaves = on_path;
+            depth += 1;
+        }
+
+        Some(InclusionProof { side_nodes })
+    }
+}
+
+/// Proves that a key holds a value in the tree with a given root, see
+/// [`SparseMerkleTree::prove`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InclusionProof {
+    /// The roots of the subtrees next to the path of the key, from the root down.
+    pub side_nodes: Vec<Bytes32>,
+}
+
+impl InclusionProof {
+    pub fn verify(&self, root: &Bytes32, key: &Bytes32, value: &[u8]) -> bool {
+        let path = path_of(key);
+
+        let computed_root = self.side_nodes.iter().enumerate().rev().fold(
+            leaf_hash(&path, value),
+            |hash, (depth, side_node)| {
+                if bit_at(&path, depth) {
+                    node_hash(side_node, &hash)
+                } else {
+                    node_hash(&hash, side_node)
+                }
+            },
+        );
+
+        computed_root == *root
+    }
+}
+
+fn subtree_root(leaves: &[(&Bytes32, &Bytes32)], depth: usize) -> Bytes32 {
+    match leaves {
+        [] => PLACEHOLDER,
+        [(_, leaf_hash)] => **leaf_hash,
+        _ => {
+            let (left, right) = split_at_bit(leaves, depth);
+
+            node_hash(
+                &subtree_root(&left, depth + 1),
+                &subtree_root(&right, depth + 1),
+            )
+        }
+    }
+}
+
+/// Leaves as `(path, leaf hash)`, sorted by path.
+type Leaves<'a> = Vec<(&'a Bytes32, &'a Bytes32)>;
+
+fn split_at_bit<'a>(
+    leaves: &[(&'a Bytes32, &'a Bytes32)],
+    depth: usize,
+) -> (Leaves<'a>, Leaves<'a>) {
+    leaves
+        .iter()
+        .copied()
+        .partition(|(path, _)| !bit_at(path, depth))
+}
+
+/// Bits are read from the most significant one, `1` going right.
+fn bit_at(path: &Bytes32, index: usize) -> bool {
+    let byte = path[index / 8];
+
+    (byte >> (7 - index % 8)) & 1 == 1
+}
+
+fn path_of(key: &Bytes32) -> Bytes32 {
+    Hasher::hash(key)
+}
+
+fn leaf_hash(path: &Bytes32, value: &[u8]) -> Bytes32 {
+    Hasher::default()
+        .chain([LEAF_PREFIX])
+        .chain(path)
+        .chain(Hasher::hash(value))
+        .finalize()
+}
+
+fn node_hash(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+    Hasher::default()
+        .chain([NODE_PREFIX])
+        .chain(left)
+        .chain(right)
+        .finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::Contract;
+
+    use super::*;
+
+    fn slots(count: u8) -> Vec<StorageSlot> {
+        (0..count)
+            .map(|i| StorageSlot::new(Bytes32::from([i; 32]), Bytes32::from([i + 1; 32])))
+            .collect()
+    }
+
+    #[test]
+    fn roots_match_the_contract_state_roots() {
+        for count in [0, 1, 2, 3, 10, 64] {
+            let slots = slots(count);
+
+            assert_eq!(
+                SparseMerkleTree::from_slots(&slots).root(),
+                Contract::initial_state_root(slots.iter()),
+                "with {count} slots"
+            );
+        }
+    }
+
+    #[test]
+    fn proofs_verify_only_the_proven_value() {
+        let slots = slots(10);
+        let tree = SparseMerkleTree::from_slots(&slots);
+        let root = tree.root();
+
+        for slot in &slots {
+            let proof = tree.prove(slot.key()).expect("the key is in the tree");
+
+            assert!(proof.verify(&root, slot.key(), slot.value().as_ref()));
+            assert!(!proof.verify(&root, slot.key(), &[0; 32]));
+        }
+
+        assert_eq!(tree.prove(&Bytes32::from([42; 32])), None);
+    }
+
+    #[test]
+    fn inserting_replaces_and_removing_restores() {
+        let mut tree = SparseMerkleTree::from_slots(&slots(3));
+        let root = tree.root();
+        let key = Bytes32::from([7; 32]);
+
+        tree.insert(&key, &[1; 32]);
+        let with_key = tree.root();
+        tree.insert(&key, &[2; 32]);
+        assert_ne!(tree.root(), with_key);
+        assert_eq!(tree.len(), 4);
+
+        tree.remove(&key);
+        assert_eq!(tree.root(), root);
+    }
+}
@@ -18,6 +18,12 @@ impl From<MacroAbigenTarget> for AbigenTarget {
             name: macro_target.name,
             abi: macro_target.abi,
             program_type: macro_target.program_type,
+            convertible_with: macro_target.convertible_with,
+            include: macro_target.include,
+            exclude: macro_target.exclude,
+            interface: macro_target.interface,
+            mock: macro_target.mock,
+            impl_errors: macro_target.impl_errors,
         }
     }
 }
@@ -29,6 +35,12 @@ pub(crate) struct MacroAbigenTarget {
     pub(crate) name: String,
     pub(crate) abi: String,
     pub(crate) program_type: ProgramType,
+    pub(crate) convertible_with: Option<String>,
+    pub(crate) include: Vec<String>,
+    pub(crate) exclude: Vec<String>,
+    pub(crate) interface: Option<String>,
+    pub(crate) mock: bool,
+    pub(crate) impl_errors: bool,
 }
 
 pub(crate) struct MacroAbigenTargets {
@@ -51,15 +63,73 @@ impl MacroAbigenTarget {
         let program_type = command.name.try_into()?;
 
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "abi"])?;
+        name_values.validate_has_no_other_names(&[
+            "name",
+            "abi",
+            "convertible_with",
+            "include",
+            "exclude",
+            "interface",
+            "mock",
+            "impl_errors",
+        ])?;
 
         let name = name_values.get_as_lit_str("name")?.value();
         let abi = name_values.get_as_lit_str("abi")?.value();
+        let convertible_with = name_values
+            .try_get("convertible_with")
+            .is_some()
+            .then(|| name_values.get_as_lit_str("convertible_with"))
+            .transpose()?
+            .map(|lit_str| lit_str.value());
+        let interface = name_values
+            .try_get("interface")
+            .is_some()
+            .then(|| name_values.get_as_lit_str("interface"))
+            .transpose()?
+            .map(|lit_str| lit_str.value());
+        let mock = Self::parse_flag(&name_values, "mock")?;
+        let impl_errors = Self::parse_flag(&name_values, "impl_errors")?;
+        let include = Self::parse_patterns(&name_values, "include")?;
+        let exclude = Self::parse_patterns(&name_values, "exclude")?;
 
         Ok(Self {
             name,
             abi,
             program_type,
+            convertible_with,
+            include,
+            exclude,
+            interface,
+            mock,
+            impl_errors,
         })
     }
+
+    /// Flags are off unless given as e.g. `mock = true`.
+    fn parse_flag(name_values: &UniqueNameValues, name: &str) -> Result<bool> {
+        if name_values.try_get(name).is_none() {
+            return Ok(false);
+        }
+
+        Ok(name_values.get_as_lit_bool(name)?.value)
+    }
+
+    /// Patterns are given as a single comma-separated string, e.g. `include = "get_*, owner"`.
+    fn parse_patterns(name_values: &UniqueNameValues, name: &str) -> Result<Vec<String>> {
+        if name_values.try_get(name).is_none() {
+            return Ok(vec![]);
+        }
+
+        let patterns = name_values
+            .get_as_lit_str(name)?
+            .value()
+            .split(',')
+            .map(str::trim)
+            .filter(|pattern| !pattern.is_empty())
+            .map(ToString::to_string)
+            .collect();
+
+        Ok(patterns)
+    }
 }
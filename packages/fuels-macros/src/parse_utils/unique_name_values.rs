@@ -4,7 +4,7 @@ use fuels_code_gen::utils::ident;
 use itertools::Itertools;
 use proc_macro2::{Ident, Span, TokenStream};
 use syn::{
-    parse::Parser, punctuated::Punctuated, spanned::Spanned, Error, Expr, Lit, LitStr,
+    parse::Parser, punctuated::Punctuated, spanned::Spanned, Error, Expr, Lit, LitBool, LitStr,
     MetaNameValue,
 };
 
@@ -70,6 +70,21 @@ impl UniqueNameValues {
         }
     }
 
+    pub fn get_as_lit_bool(&self, name: &str) -> syn::Result<&LitBool> {
+        let value = self
+            .try_get(name)
+            .ok_or_else(|| Error::new(self.span, format!("missing attribute '{name}'")))?;
+
+        if let Lit::Bool(lit_bool) = value {
+            Ok(lit_bool)
+        } else {
+            Err(Error::new_spanned(
+                value.clone(),
+                format!("expected the attribute '{name}' to have a bool value"),
+            ))
+        }
+    }
+
     fn extract_name_values<T: Iterator<Item = MetaNameValue>>(
         name_value_metas: T,
     ) -> syn::Result<Vec<(Ident, Lit)>> {
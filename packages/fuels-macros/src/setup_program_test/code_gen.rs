@@ -62,6 +62,12 @@ fn generate_abigen_targets(project_lookup: &HashMap<String, Project>) -> Vec<Abi
             name: name.clone(),
             abi: project.abi_path(),
             program_type: project.program_type,
+            convertible_with: None,
+            include: vec![],
+            exclude: vec![],
+            interface: None,
+            mock: false,
+            impl_errors: false,
         })
         .collect()
 }
@@ -110,6 +116,7 @@ fn contract_deploying_code(
         .map(|command| {
             let contract_instance_name = ident(&command.name);
             let contract_struct_name = ident(&command.contract.value());
+            let contract_id_name = ident(&format!("{}Id", command.contract.value()));
             let wallet_name = ident(&command.wallet);
 
             let project = project_lookup
@@ -140,7 +147,7 @@ fn contract_deploying_code(
                     .await
                     .expect("Failed to deploy the contract");
 
-                    #contract_struct_name::new(contract_id, #wallet_name.clone())
+                    #contract_struct_name::new(#contract_id_name::new(contract_id), #wallet_name.clone())
                 };
             }
         })
@@ -0,0 +1,9 @@
+use fuels_macros::abigen;
+
+abigen!(Contract(
+    name = "MyContract",
+    abi = "some-abi.json",
+    convertible_with = 1
+));
+
+fn main() {}
@@ -0,0 +1,369 @@
+use std::{collections::BTreeMap, fmt};
+
+use fuel_tx::{Address, AssetId, ContractId, Receipt, ScriptExecutionResult};
+
+/// Where a balance changed by a call is held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BalanceHolder {
+    /// The coins the script has to spend. What the script doesn't spend goes back to the owners
+    /// of the transaction inputs as change.
+    Script,
+    Contract(ContractId),
+    /// The owner of a variable output.
+    Address(Address),
+}
+
+impl fmt::Display for BalanceHolder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BalanceHolder::Script => write!(f, "script"),
+            BalanceHolder::Contract(id) => write!(f, "contract {id}"),
+            BalanceHolder::Address(address) => write!(f, "address {address}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceChange {
+    pub holder: BalanceHolder,
+    pub asset_id: AssetId,
+    pub delta: i128,
+}
+
+/// What a call did, as far as its receipts tell: the contracts it called and the balances it
+/// moved, minted or burned. Computed from the receipts of a dry-run, it shows what a call
+/// would do before it is submitted, see [`FuelCallResponse::effects`].
+///
+/// The receipts don't record storage writes, so the contracts whose storage may have changed
+/// are the called ones. To see the slots themselves, compare snapshots of the storage with
+/// [`StorageDiff`](crate::storage_diff::StorageDiff).
+///
+/// [`FuelCallResponse::effects`]: crate::call_response::FuelCallResponse::effects
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallEffects {
+    /// In the order of their first call.
+    pub called_contracts: Vec<ContractId>,
+    /// The net change of every balance that changed, ordered by holder and asset.
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+impl CallEffects {
+    /// A reverted script has no effects, whatever its receipts say happened before the revert.
+    pub fn from_receipts(receipts: &[Receipt]) -> Self {
+        let ledger = Ledger::from_receipts(receipts);
+
+        let balance_changes = ledger
+            .deltas
+            .into_iter()
+            .filter(|(_, delta)| *delta != 0)
+            .map(|((holder, asset_id), delta)| BalanceChange {
+                holder,
+                asset_id,
+                delta,
+            })
+            .collect();
+
+        Self {
+            called_contracts: ledger.called_contracts,
+            balance_changes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.called_contracts.is_empty() && self.balance_changes.is_empty()
+    }
+}
+
+/// How one asset moved in a transaction: how much of it was minted or burned and what every
+/// party involved received or sent in total, see [`FuelCallResponse::asset_flows`].
+///
+/// Coins are never created or destroyed other than by minting and burning, so the flows of the
+/// parties add up to `net_minted`.
+///
+/// [`FuelCallResponse::asset_flows`]: crate::call_response::FuelCallResponse::asset_flows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetFlow {
+    pub asset_id: AssetId,
+    /// Minted minus burned.
+    pub net_minted: i128,
+    /// The net amount received, if positive, or sent, if negative, by every party whose flows
+    /// don't cancel out, ordered by party.
+    pub parties: Vec<(BalanceHolder, i128)>,
+}
+
+impl AssetFlow {
+    /// One flow per asset that was moved, minted or burned, ordered by asset. Coins forwarded
+    /// with contract calls count as transfers to the called contract. A reverted script moves
+    /// nothing.
+    pub fn from_receipts(receipts: &[Receipt]) -> Vec<Self> {
+        let ledger = Ledger::from_receipts(receipts);
+
+        let mut flows: BTreeMap<AssetId, AssetFlow> = BTreeMap::new();
+        // minting credits the contract, so every minted asset has deltas too. The deltas are
+        // ordered by holder, so are the parties of every flow.
+        for ((holder, asset_id), delta) in ledger.deltas {
+            let flow = flows.entry(asset_id).or_insert_with(|| AssetFlow {
+                asset_id,
+                net_minted: ledger.minted.get(&asset_id).copied().unwrap_or_default(),
+                parties: vec![],
+            });
+            if delta != 0 {
+                flow.parties.push((holder, delta));
+            }
+        }
+
+        flows
+            .into_values()
+            .filter(|flow| flow.net_minted != 0 || !flow.parties.is_empty())
+            .collect()
+    }
+}
+
+/// The net balance changes recorded by the receipts of a script.
+#[derive(Default)]
+struct Ledger {
+    /// In the order of their first call.
+    called_contracts: Vec<ContractId>,
+    deltas: BTreeMap<(BalanceHolder, AssetId), i128>,
+    minted: BTreeMap<AssetId, i128>,
+}
+
+impl Ledger {
+    fn from_receipts(receipts: &[Receipt]) -> Self {
+        let reverted = receipts.iter().any(|receipt| {
+            matches!(
+                receipt,
+                Receipt::ScriptResult { result, .. } if *result != ScriptExecutionResult::Success
+            )
+        });
+        let mut ledger = Self::default();
+        if reverted {
+            return ledger;
+        }
+
+        for receipt in receipts {
+            match receipt {
+                Receipt::Call {
+                    id,
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => {
+                    if !ledger.called_contracts.contains(to) {
+                        ledger.called_contracts.push(*to);
+                    }
+                    ledger.transfer(
+                        holder_of(id),
+                        BalanceHolder::Contract(*to),
+                        *asset_id,
+                        *amount,
+                    );
+                }
+                Receipt::Transfer {
+                    id,
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => ledger.transfer(
+                    holder_of(id),
+                    BalanceHolder::Contract(*to),
+                    *asset_id,
+                    *amount,
+                ),
+                Receipt::TransferOut {
+                    id,
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => ledger.transfer(
+                    holder_of(id),
+                    BalanceHolder::Address(*to),
+                    *asset_id,
+                    *amount,
+                ),
+                Receipt::Mint {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } => ledger.mint(*contract_id, contract_id.asset_id(sub_id), i128::from(*val)),
+                Receipt::Burn {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } => ledger.mint(
+                    *contract_id,
+                    contract_id.asset_id(sub_id),
+                    -i128::from(*val),
+                ),
+                _ => {}
+            }
+        }
+
+        ledger
+    }
+
+    fn add(&mut self, holder: BalanceHolder, asset_id: AssetId, delta: i128) {
+        *self.deltas.entry((holder, asset_id)).or_default() += delta;
+    }
+
+    fn transfer(&mut self, from: BalanceHolder, to: BalanceHolder, asset_id: AssetId, amount: u64) {
+        self.add(from, asset_id, -i128::from(amount));
+        self.add(to, asset_id, i128::from(amount));
+    }
+
+    /// Burning is minting a negative amount.
+    fn mint(&mut self, contract_id: ContractId, asset_id: AssetId, amount: i128) {
+        self.add(BalanceHolder::Contract(contract_id), asset_id, amount);
+        *self.minted.entry(asset_id).or_default() += amount;
+    }
+}
+
+/// The script runs under the zeroed contract id.
+fn holder_of(id: &ContractId) -> BalanceHolder {
+    if *id == ContractId::zeroed() {
+        BalanceHolder::Script
+    } else {
+        BalanceHolder::Contract(*id)
+    }
+}
+
+/// One line per called contract, then one per balance change, e.g.
+/// `contract 0x…: -100 of 0x…`.
+impl fmt::Display for CallEffects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for contract_id in &self.called_contracts {
+            writeln!(f, "calls contract {contract_id}")?;
+        }
+        for change in &self.balance_changes {
+            writeln!(
+                f,
+                "{}: {:+} of {}",
+                change.holder, change.delta, change.asset_id
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::Bytes32;
+
+    use super::*;
+
+    fn script_result(result: ScriptExecutionResult) -> Receipt {
+        Receipt::ScriptResult {
+            result,
+            gas_used: 0,
+        }
+    }
+
+    #[test]
+    fn balance_changes_are_netted_per_holder_and_asset() {
+        let contract = ContractId::from([1; 32]);
+        let recipient = Address::from([2; 32]);
+        let asset_id = AssetId::from([3; 32]);
+        let sub_id = Bytes32::zeroed();
+        let receipts = vec![
+            Receipt::call(ContractId::zeroed(), contract, 100, asset_id, 0, 0, 0, 0, 0),
+            Receipt::transfer_out(contract, recipient, 40, asset_id, 0, 0),
+            Receipt::mint(sub_id, contract, 5, 0, 0),
+            Receipt::burn(sub_id, contract, 5, 0, 0),
+            script_result(ScriptExecutionResult::Success),
+        ];
+
+        let effects = CallEffects::from_receipts(&receipts);
+
+        assert_eq!(effects.called_contracts, vec![contract]);
+        let mut expected = vec![
+            BalanceChange {
+                holder: BalanceHolder::Script,
+                asset_id,
+                delta: -100,
+            },
+            BalanceChange {
+                holder: BalanceHolder::Contract(contract),
+                asset_id,
+                delta: 60,
+            },
+            BalanceChange {
+                holder: BalanceHolder::Address(recipient),
+                asset_id,
+                delta: 40,
+            },
+        ];
+        expected.sort_by_key(|change| (change.holder, change.asset_id));
+        // minting and burning the same amount cancel out
+        assert_eq!(effects.balance_changes, expected);
+        assert!(effects
+            .to_string()
+            .contains(&format!("address {recipient}: +40 of {asset_id}")));
+
+        let mut reverted = receipts;
+        reverted.push(script_result(ScriptExecutionResult::Revert));
+        assert!(CallEffects::from_receipts(&reverted).is_empty());
+    }
+
+    #[test]
+    fn asset_flows_add_up_to_the_minted_amount() {
+        let contract = ContractId::from([1; 32]);
+        let recipient = Address::from([2; 32]);
+        let sub_id = Bytes32::zeroed();
+        let minted_asset = contract.asset_id(&sub_id);
+        let base_asset = AssetId::zeroed();
+        let receipts = vec![
+            Receipt::call(
+                ContractId::zeroed(),
+                contract,
+                10,
+                base_asset,
+                0,
+                0,
+                0,
+                0,
+                0,
+            ),
+            Receipt::mint(sub_id, contract, 100, 0, 0),
+            Receipt::transfer_out(contract, recipient, 70, minted_asset, 0, 0),
+            Receipt::burn(sub_id, contract, 20, 0, 0),
+            script_result(ScriptExecutionResult::Success),
+        ];
+
+        let flows = AssetFlow::from_receipts(&receipts);
+
+        let mut expected = vec![
+            AssetFlow {
+                asset_id: base_asset,
+                net_minted: 0,
+                parties: vec![
+                    (BalanceHolder::Script, -10),
+                    (BalanceHolder::Contract(contract), 10),
+                ],
+            },
+            AssetFlow {
+                asset_id: minted_asset,
+                net_minted: 80,
+                parties: vec![
+                    (BalanceHolder::Contract(contract), 10),
+                    (BalanceHolder::Address(recipient), 70),
+                ],
+            },
+        ];
+        expected.sort_by_key(|flow| flow.asset_id);
+        assert_eq!(flows, expected);
+        for flow in &flows {
+            let total: i128 = flow.parties.iter().map(|(_, delta)| delta).sum();
+            assert_eq!(total, flow.net_minted);
+        }
+
+        let mut reverted = receipts;
+        reverted.push(script_result(ScriptExecutionResult::Revert));
+        assert!(AssetFlow::from_receipts(&reverted).is_empty());
+    }
+}
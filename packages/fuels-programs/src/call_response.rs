@@ -2,10 +2,13 @@ use std::fmt::Debug;
 
 use fuel_tx::{Bytes32, Receipt};
 use fuels_core::{
-    codec::{LogDecoder, LogResult},
+    codec::{token_to_json, EmittedLog, LogDecoder, LogResult, MultiAbiLogDecoder},
     traits::{Parameterize, Tokenizable},
-    types::errors::Result,
+    types::{errors::Result, Token},
 };
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::call_effects::{AssetFlow, CallEffects};
 
 /// [`FuelCallResponse`] is a struct that is returned by a call to the contract or script. Its value
 /// field holds the decoded typed value returned by the contract's method. The other field holds all
@@ -18,8 +21,13 @@ pub struct FuelCallResponse<D> {
     pub value: D,
     pub receipts: Vec<Receipt>,
     pub gas_used: u64,
+    /// The gas limit of the transaction, when it was sent or simulated by a call handler.
+    pub gas_limit: Option<u64>,
     pub log_decoder: LogDecoder,
     pub tx_id: Option<Bytes32>,
+    /// The returned value broken into its named elements, set by script call handlers built
+    /// `with_flattened_output(true)`, see [`fuels_core::codec::flatten_output`].
+    pub flattened_value: Option<Vec<(String, Token)>>,
 }
 // ANCHOR_END: fuel_call_response
 
@@ -43,9 +51,11 @@ impl<D> FuelCallResponse<D> {
         Self {
             value,
             gas_used: Self::get_gas_used(&receipts),
+            gas_limit: None,
             receipts,
             log_decoder,
             tx_id,
+            flattened_value: None,
         }
     }
 
@@ -56,4 +66,119 @@ impl<D> FuelCallResponse<D> {
     pub fn decode_logs_with_type<T: Tokenizable + Parameterize + 'static>(&self) -> Result<Vec<T>> {
         self.log_decoder.decode_logs_with_type::<T>(&self.receipts)
     }
+
+    /// Decodes the logs of every contract the call went through with their own ABI, along with
+    /// who emitted each of them, see [`MultiAbiLogDecoder`].
+    pub fn decode_logs_with(&self, decoder: &MultiAbiLogDecoder) -> Vec<EmittedLog> {
+        decoder.decode_logs(&self.receipts)
+    }
+
+    /// The contracts called and the balances changed, see [`CallEffects`]. On the response of a
+    /// `simulate()`, what the call would do if it were submitted.
+    pub fn effects(&self) -> CallEffects {
+        CallEffects::from_receipts(&self.receipts)
+    }
+
+    /// The net flows of every asset moved, minted or burned by the call, per party, see
+    /// [`AssetFlow`].
+    pub fn asset_flows(&self) -> Vec<AssetFlow> {
+        AssetFlow::from_receipts(&self.receipts)
+    }
+}
+
+impl<V, E> FuelCallResponse<std::result::Result<V, E>> {
+    /// Surfaces the error returned by a contract function returning a `Result`, so that it can
+    /// be handled like any other Rust error:
+    ///
+    /// ```ignore
+    /// let response = contract_methods.withdraw(100).call().await?.into_result()?;
+    /// ```
+    pub fn into_result(self) -> std::result::Result<FuelCallResponse<V>, E> {
+        let value = self.value?;
+
+        Ok(FuelCallResponse {
+            value,
+            receipts: self.receipts,
+            gas_used: self.gas_used,
+            gas_limit: self.gas_limit,
+            log_decoder: self.log_decoder,
+            tx_id: self.tx_id,
+            flattened_value: self.flattened_value,
+        })
+    }
+}
+
+impl<D: Tokenizable + Clone> FuelCallResponse<D> {
+    /// Serializes the response to JSON, see the [`Serialize`] implementation for its shape.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// Serializes as `{"tx_id", "gas_used", "value", "receipts"}`. The value is converted as
+/// described in [`token_to_json`] and the receipts keep the shape given to them by `fuel-tx`.
+impl<D: Tokenizable + Clone> Serialize for FuelCallResponse<D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("FuelCallResponse", 4)?;
+        response.serialize_field("tx_id", &self.tx_id)?;
+        response.serialize_field("gas_used", &self.gas_used)?;
+        response.serialize_field("value", &token_to_json(&self.value.clone().into_token()))?;
+        response.serialize_field("receipts", &self.receipts)?;
+
+        response.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::ScriptExecutionResult;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn responses_are_serialized_to_json() -> Result<()> {
+        let receipts = vec![Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            gas_used: 42,
+        }];
+        let response = FuelCallResponse::new(
+            (7u64, u128::MAX),
+            receipts.clone(),
+            LogDecoder::default(),
+            None,
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&response.to_json()?)?;
+
+        let expected = json!({
+            "tx_id": null,
+            "gas_used": 42,
+            "value": [7, u128::MAX.to_string()],
+            "receipts": serde_json::to_value(receipts)?,
+        });
+        assert_eq!(json, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn returned_errors_are_surfaced() {
+        let response = |value: std::result::Result<u64, String>| {
+            let receipts = vec![Receipt::ScriptResult {
+                result: ScriptExecutionResult::Success,
+                gas_used: 42,
+            }];
+            FuelCallResponse::new(value, receipts, LogDecoder::default(), None)
+        };
+
+        let ok = response(Ok(7)).into_result().expect("should succeed");
+        assert_eq!(ok.value, 7);
+        assert_eq!(ok.gas_used, 42);
+
+        let err = response(Err("insufficient funds".to_string()))
+            .into_result()
+            .expect_err("should fail");
+        assert_eq!(err, "insufficient funds");
+    }
 }
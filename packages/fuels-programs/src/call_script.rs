@@ -0,0 +1,201 @@
+//! Layout of the script data used by the SDK's contract call scripts.
+//!
+//! Every contract call gets its own segment in the script data, laid out as follows:
+//! 1. Amount to be forwarded ([`AMOUNT_SIZE`])
+//! 2. Asset ID to be forwarded ([`ASSET_ID_SIZE`])
+//! 3. Gas to be forwarded ([`GAS_FORWARDED_SIZE`]) - Optional
+//! 4. Contract ID ([`CONTRACT_ID_SIZE`])
+//! 5. Function selector ([`SELECTOR_SIZE`])
+//! 6. Calldata offset ([`CUSTOM_INPUT_OFFSET_SIZE`]) - Optional
+//! 7. Encoded arguments (variable length)
+//!
+//! The first segment begins at the offset returned by
+//! [`call_script_data_offset`](fuels_core::offsets::call_script_data_offset).
+
+use std::{fmt::Debug, sync::Arc};
+
+use fuel_tx::{AssetId, ConsensusParameters, ContractId};
+use fuel_types::bytes::padded_len_usize;
+use fuels_core::{constants::WORD_SIZE, offsets::base_offset_script, types::errors::Result};
+
+use crate::contract::ContractCall;
+
+pub const AMOUNT_SIZE: usize = WORD_SIZE;
+pub const ASSET_ID_SIZE: usize = AssetId::LEN;
+pub const GAS_FORWARDED_SIZE: usize = WORD_SIZE;
+pub const CONTRACT_ID_SIZE: usize = ContractId::LEN;
+pub const SELECTOR_SIZE: usize = WORD_SIZE;
+pub const CUSTOM_INPUT_OFFSET_SIZE: usize = WORD_SIZE;
+
+/// Absolute offsets of the items making up the script data segment of a single contract call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallSegmentOffsets {
+    pub amount: usize,
+    pub asset_id: usize,
+    pub gas_forwarded: Option<usize>,
+    /// Where the contract ID is stored, immediately followed by the selector. This is the
+    /// offset the `CALL` instruction expects.
+    pub call_data: usize,
+    pub selector: usize,
+    pub custom_input_offset: Option<usize>,
+    pub encoded_args: usize,
+}
+
+impl CallSegmentOffsets {
+    /// Computes the offsets of a segment starting at `segment_offset`.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment_offset`:          Where the segment begins within the transaction.
+    /// * `has_gas_forwarded`:       Whether the call forwards a specific amount of gas.
+    /// * `has_custom_input_offset`: Whether the encoded arguments are preceded by their own
+    ///                              offset (needed for heap types and multiple arguments).
+    pub fn new(
+        segment_offset: usize,
+        has_gas_forwarded: bool,
+        has_custom_input_offset: bool,
+    ) -> Self {
+        let amount = segment_offset;
+        let asset_id = amount + AMOUNT_SIZE;
+        let gas_forwarded = has_gas_forwarded.then_some(asset_id + ASSET_ID_SIZE);
+        let call_data =
+            asset_id + ASSET_ID_SIZE + optional_size(has_gas_forwarded, GAS_FORWARDED_SIZE);
+        let selector = call_data + CONTRACT_ID_SIZE;
+        let custom_input_offset = has_custom_input_offset.then_some(selector + SELECTOR_SIZE);
+        let encoded_args = selector
+            + SELECTOR_SIZE
+            + optional_size(has_custom_input_offset, CUSTOM_INPUT_OFFSET_SIZE);
+
+        Self {
+            amount,
+            asset_id,
+            gas_forwarded,
+            call_data,
+            selector,
+            custom_input_offset,
+            encoded_args,
+        }
+    }
+
+    /// The length of the segment excluding the encoded arguments.
+    pub fn header_len(&self) -> usize {
+        self.encoded_args - self.amount
+    }
+}
+
+type ScriptDataBuilder = dyn Fn(&[ContractCall], usize) -> Result<Vec<u8>> + Send + Sync;
+
+/// Replaces the SDK's default call script with user provided bytecode. Inputs, outputs and
+/// receipt decoding are still handled by the SDK, so the script is expected to call the
+/// contracts and return their results the same way the default one does.
+#[derive(Clone)]
+pub struct CustomCallScript {
+    script: Vec<u8>,
+    script_data_builder: Arc<ScriptDataBuilder>,
+}
+
+impl CustomCallScript {
+    /// # Arguments
+    ///
+    /// * `script`:              The script bytecode.
+    /// * `script_data_builder`: Lays out the script data for the given calls. Receives the
+    ///                          offset at which the script data will begin in the transaction.
+    pub fn new(
+        script: Vec<u8>,
+        script_data_builder: impl Fn(&[ContractCall], usize) -> Result<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            script,
+            script_data_builder: Arc::new(script_data_builder),
+        }
+    }
+
+    /// Returns the script together with the script data built for `calls`.
+    pub fn build(
+        &self,
+        calls: &[ContractCall],
+        consensus_parameters: &ConsensusParameters,
+    ) -> Result<(Vec<u8>, Vec<u8>)> {
+        let data_offset =
+            base_offset_script(consensus_parameters) + padded_len_usize(self.script.len());
+        let script_data = (self.script_data_builder)(calls, data_offset)?;
+
+        Ok((self.script.clone(), script_data))
+    }
+}
+
+impl Debug for CustomCallScript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomCallScript")
+            .field("script", &self.script)
+            .finish_non_exhaustive()
+    }
+}
+
+fn optional_size(is_present: bool, size: usize) -> usize {
+    if is_present {
+        size
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_script_data_starts_after_the_padded_script() -> Result<()> {
+        let consensus_parameters = ConsensusParameters::default();
+        let custom_script = CustomCallScript::new(vec![1, 2, 3], |calls, data_offset| {
+            assert!(calls.is_empty());
+            Ok((data_offset as u64).to_be_bytes().to_vec())
+        });
+
+        let (script, script_data) = custom_script.build(&[], &consensus_parameters)?;
+
+        let expected_offset = base_offset_script(&consensus_parameters) + WORD_SIZE;
+        assert_eq!(script, vec![1, 2, 3]);
+        assert_eq!(script_data, (expected_offset as u64).to_be_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn offsets_of_minimal_segment() {
+        let offsets = CallSegmentOffsets::new(100, false, false);
+
+        assert_eq!(
+            offsets,
+            CallSegmentOffsets {
+                amount: 100,
+                asset_id: 108,
+                gas_forwarded: None,
+                call_data: 140,
+                selector: 172,
+                custom_input_offset: None,
+                encoded_args: 180,
+            }
+        );
+        assert_eq!(offsets.header_len(), 80);
+    }
+
+    #[test]
+    fn offsets_of_segment_with_optional_items() {
+        let offsets = CallSegmentOffsets::new(100, true, true);
+
+        assert_eq!(
+            offsets,
+            CallSegmentOffsets {
+                amount: 100,
+                asset_id: 108,
+                gas_forwarded: Some(140),
+                call_data: 148,
+                selector: 180,
+                custom_input_offset: Some(188),
+                encoded_args: 196,
+            }
+        );
+        assert_eq!(offsets.header_len(), 96);
+    }
+}
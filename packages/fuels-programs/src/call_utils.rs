@@ -1,4 +1,4 @@
-use std::{collections::HashSet, iter, vec};
+use std::{collections::HashSet, future::Future, iter, time::Duration, vec};
 
 use fuel_abi_types::error_codes::FAILED_TRANSFER_TO_ADDRESS_SIGNAL;
 use fuel_asm::{op, RegId};
@@ -18,11 +18,15 @@ use fuels_core::{
         transaction_builders::{
             BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
         },
+        tx_status::TxStatus,
     },
 };
 use itertools::{chain, Itertools};
 
-use crate::contract::ContractCall;
+use crate::{
+    call_script::{CallSegmentOffsets, CustomCallScript},
+    contract::ContractCall,
+};
 
 #[derive(Default)]
 /// Specifies offsets of [`Opcode::CALL`][`fuel_asm::Opcode::CALL`] parameters stored in the script
@@ -37,6 +41,104 @@ pub(crate) struct CallOpcodeParamsOffset {
 /// How many times to attempt to resolve missing tx dependencies.
 pub const DEFAULT_TX_DEP_ESTIMATION_ATTEMPTS: u64 = 10;
 
+/// Awaits `future`, giving up with [`Error::Timeout`] once `timeout` elapses. Giving up drops
+/// the future, aborting any request still in flight.
+pub(crate) async fn with_timeout<R>(
+    timeout: Option<Duration>,
+    future: impl Future<Output = Result<R>>,
+) -> Result<R> {
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, future)
+            .await
+            .map_err(|_| Error::Timeout(timeout))?,
+        None => future.await,
+    }
+}
+
+/// Raises the gas limit of a call whose dry-run runs out of gas and tries again, see
+/// `ContractCallHandler::with_gas_bump`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GasBump {
+    factor: f64,
+    max_gas_limit: u64,
+}
+
+impl GasBump {
+    /// Every retry multiplies the gas limit by `factor`, without going over `max_gas_limit`.
+    pub fn new(factor: f64, max_gas_limit: u64) -> Result<Self> {
+        if factor.is_nan() || factor <= 1.0 {
+            return Err(error!(
+                Other,
+                "gas bump factor must be greater than 1, got {factor}"
+            ));
+        }
+
+        Ok(Self {
+            factor,
+            max_gas_limit,
+        })
+    }
+
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    pub fn max_gas_limit(&self) -> u64 {
+        self.max_gas_limit
+    }
+
+    /// The gas limit to try after `gas_limit` ran out. `None` once the maximum was tried.
+    pub(crate) fn next(&self, gas_limit: u64) -> Option<u64> {
+        if gas_limit >= self.max_gas_limit {
+            return None;
+        }
+        let bumped = (gas_limit as f64 * self.factor).ceil() as u64;
+
+        Some(bumped.max(gas_limit + 1).min(self.max_gas_limit))
+    }
+}
+
+/// Builds the transaction of `calls`, raising its gas limit with `gas_bump` for as long as its
+/// dry-run runs out of gas. Other failures are left for the caller to surface.
+pub(crate) async fn build_tx_with_gas_bump<T: Account>(
+    calls: &[ContractCall],
+    tx_policies: TxPolicies,
+    account: &T,
+    custom_call_script: Option<&CustomCallScript>,
+    gas_bump: GasBump,
+) -> Result<ScriptTransaction> {
+    let provider = account.try_provider()?;
+    let mut tx =
+        build_tx_from_contract_calls(calls, tx_policies, account, custom_call_script).await?;
+
+    loop {
+        let tx_status = provider.checked_dry_run(tx.clone()).await?;
+        if !ran_out_of_gas(&tx_status) {
+            return Ok(tx);
+        }
+
+        let gas_limit = gas_bump.next(tx.gas_limit()).ok_or_else(|| {
+            error!(
+                Other,
+                "the call runs out of gas even with the maximum gas limit of {}",
+                gas_bump.max_gas_limit()
+            )
+        })?;
+        let tx_policies = tx_policies.with_script_gas_limit(gas_limit);
+        tx = build_tx_from_contract_calls(calls, tx_policies, account, custom_call_script).await?;
+    }
+}
+
+pub(crate) fn ran_out_of_gas(tx_status: &TxStatus) -> bool {
+    let TxStatus::Revert { receipts, .. } = tx_status else {
+        return false;
+    };
+
+    receipts.iter().any(|receipt| {
+        matches!(receipt, Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::OutOfGas)
+    })
+}
+
 pub(crate) mod sealed {
     pub trait Sealed {}
 }
@@ -104,18 +206,27 @@ pub trait TxDependencyExtension: Sized + sealed::Sealed {
 }
 
 /// Creates a [`ScriptTransactionBuilder`] from contract calls.
+/// If a `custom_call_script` is given it replaces the default call script.
 pub(crate) async fn transaction_builder_from_contract_calls(
     calls: &[ContractCall],
     tx_policies: TxPolicies,
     account: &impl Account,
+    custom_call_script: Option<&CustomCallScript>,
 ) -> Result<ScriptTransactionBuilder> {
-    let calls_instructions_len = compute_calls_instructions_len(calls)?;
     let consensus_parameters = account.try_provider()?.consensus_parameters();
-    let data_offset = call_script_data_offset(consensus_parameters, calls_instructions_len);
 
-    let (script_data, call_param_offsets) =
-        build_script_data_from_contract_calls(calls, data_offset)?;
-    let script = get_instructions(calls, call_param_offsets)?;
+    let (script, script_data) = match custom_call_script {
+        Some(custom_call_script) => custom_call_script.build(calls, consensus_parameters)?,
+        None => {
+            let calls_instructions_len = compute_calls_instructions_len(calls)?;
+            let data_offset = call_script_data_offset(consensus_parameters, calls_instructions_len);
+
+            let (script_data, call_param_offsets) =
+                build_script_data_from_contract_calls(calls, data_offset)?;
+
+            (get_instructions(calls, call_param_offsets)?, script_data)
+        }
+    };
 
     let required_asset_amounts = calculate_required_asset_amounts(calls);
 
@@ -145,8 +256,11 @@ pub(crate) async fn build_tx_from_contract_calls(
     calls: &[ContractCall],
     tx_policies: TxPolicies,
     account: &impl Account,
+    custom_call_script: Option<&CustomCallScript>,
 ) -> Result<ScriptTransaction> {
-    let mut tb = transaction_builder_from_contract_calls(calls, tx_policies, account).await?;
+    let mut tb =
+        transaction_builder_from_contract_calls(calls, tx_policies, account, custom_call_script)
+            .await?;
 
     let required_asset_amounts = calculate_required_asset_amounts(calls);
 
@@ -163,7 +277,7 @@ pub(crate) async fn build_tx_from_contract_calls(
 
 /// Compute the length of the calling scripts for the two types of contract calls: those that return
 /// a heap type, and those that don't.
-fn compute_calls_instructions_len(calls: &[ContractCall]) -> Result<usize> {
+pub(crate) fn compute_calls_instructions_len(calls: &[ContractCall]) -> Result<usize> {
     calls
         .iter()
         .map(|c| {
@@ -244,14 +358,8 @@ pub(crate) fn get_instructions(
         })
 }
 
-/// Returns script data, consisting of the following items in the given order:
-/// 1. Amount to be forwarded `(1 * `[`WORD_SIZE`]`)`
-/// 2. Asset ID to be forwarded ([`AssetId::LEN`])
-/// 3. Gas to be forwarded `(1 * `[`WORD_SIZE`]`)` - Optional
-/// 4. Contract ID ([`ContractId::LEN`]);
-/// 5. Function selector `(1 * `[`WORD_SIZE`]`)`
-/// 6. Calldata offset (optional) `(1 * `[`WORD_SIZE`]`)`
-/// 7. Encoded arguments (optional) (variable length)
+/// Returns script data, consisting of one segment per call laid out as described in
+/// [`call_script`](crate::call_script).
 pub(crate) fn build_script_data_from_contract_calls(
     calls: &[ContractCall],
     data_offset: usize,
@@ -264,54 +372,38 @@ pub(crate) fn build_script_data_from_contract_calls(
 
     for call in calls {
         let gas_forwarded = call.call_parameters.gas_forwarded();
+        let offsets = CallSegmentOffsets::new(
+            segment_offset,
+            gas_forwarded.is_some(),
+            call.compute_custom_input_offset,
+        );
 
         script_data.extend(call.call_parameters.amount().to_be_bytes());
         script_data.extend(call.call_parameters.asset_id().iter());
-
-        let gas_forwarded_size = gas_forwarded
-            .map(|gf| {
-                script_data.extend((gf as Word).to_be_bytes());
-
-                WORD_SIZE
-            })
-            .unwrap_or_default();
-
+        if let Some(gas_forwarded) = gas_forwarded {
+            script_data.extend((gas_forwarded as Word).to_be_bytes());
+        }
         script_data.extend(call.contract_id.hash().as_ref());
         script_data.extend(call.encoded_selector);
 
-        let call_param_offsets = CallOpcodeParamsOffset {
-            amount_offset: segment_offset,
-            asset_id_offset: segment_offset + WORD_SIZE,
-            gas_forwarded_offset: gas_forwarded.map(|_| segment_offset + WORD_SIZE + AssetId::LEN),
-            call_data_offset: segment_offset + WORD_SIZE + AssetId::LEN + gas_forwarded_size,
-        };
-        param_offsets.push(call_param_offsets);
+        param_offsets.push(CallOpcodeParamsOffset {
+            amount_offset: offsets.amount,
+            asset_id_offset: offsets.asset_id,
+            gas_forwarded_offset: offsets.gas_forwarded,
+            call_data_offset: offsets.call_data,
+        });
 
         // If the method call takes custom inputs or has more than
-        // one argument, we need to calculate the `call_data_offset`,
-        // which points to where the data for the custom types start in the
-        // transaction. If it doesn't take any custom inputs, this isn't necessary.
-        let encoded_args_start_offset = if call.compute_custom_input_offset {
-            // Custom inputs are stored after the previously added parameters,
-            // including custom_input_offset
-            let custom_input_offset = segment_offset
-                + WORD_SIZE // amount size
-                + AssetId::LEN
-                + gas_forwarded_size
-                + ContractId::LEN
-                + WORD_SIZE // encoded_selector size
-                + WORD_SIZE; // custom_input_offset size
-            script_data.extend((custom_input_offset as Word).to_be_bytes());
-
-            custom_input_offset
-        } else {
-            segment_offset
-        };
+        // one argument, we need to store the offset at which the
+        // encoded arguments start so that the contract can find them.
+        if offsets.custom_input_offset.is_some() {
+            script_data.extend((offsets.encoded_args as Word).to_be_bytes());
+        }
 
         let bytes = call
             .encoded_args
             .as_ref()
-            .map(|ub| ub.resolve(encoded_args_start_offset as Word))
+            .map(|ub| ub.resolve(offsets.encoded_args as Word))
             .map_err(|e| error!(Codec, "cannot encode contract call arguments: {e}"))?;
         script_data.extend(bytes);
 
@@ -384,38 +476,95 @@ pub(crate) fn get_single_call_instructions(
 fn extract_heap_data(param_type: &ParamType) -> Result<Vec<fuel_asm::Instruction>> {
     match param_type {
         ParamType::Enum { variants, .. } => {
-            let Some((discriminant, heap_type)) = variants.heap_type_variant() else {
+            let mut heap_type_variants = variants.heap_type_variants().peekable();
+            if heap_type_variants.peek().is_none() {
                 return Ok(vec![]);
-            };
+            }
 
             let param_type_width = param_type.compute_encoding_in_bytes()?;
-            let heap_type_width = heap_type.compute_encoding_in_bytes()?;
-            let ptr_offset = ((param_type_width - heap_type_width) / 8) as u16;
 
-            Ok([
-                vec![
-                    // All the registers 0x15-0x18 are free
-                    // Load the selected discriminant to a free register
+            // All the registers 0x15-0x18 are free
+            // The first word of the CALL return is the enum discriminant. It is safe to load
+            // because the offset is 0.
+            let mut instructions = vec![op::lw(0x18, RegId::RET, 0)];
+            for (discriminant, heap_type) in heap_type_variants {
+                let heap_type_width = heap_type.compute_encoding_in_bytes()?;
+                let ptr_offset = ((param_type_width - heap_type_width) / 8) as u16;
+                let extract_data = extract_data_receipt(ptr_offset, false, heap_type)?;
+
+                instructions.extend([
+                    // Load the discriminant of the heap type variant to a free register
                     op::movi(0x17, discriminant as u32),
-                    // the first word of the CALL return is the enum discriminant. It is safe to load
-                    // because the offset is 0.
-                    op::lw(0x18, RegId::RET, 0),
-                    // If the discriminant is not the one from the heap type, then jump ahead and
-                    // return an empty receipt. Otherwise return heap data with the right length.
-                    // Jump by (last argument + 1) instructions according to specs
-                    op::jnef(0x17, 0x18, RegId::ZERO, 3),
-                ],
-                // ================= EXECUTED IF THE DISCRIMINANT POINTS TO A HEAP TYPE
-                extract_data_receipt(ptr_offset, false, heap_type)?,
-                // ================= EXECUTED IF THE DISCRIMINANT DOESN'T POINT TO A HEAP TYPE
-                vec![op::retd(0x15, RegId::ZERO)],
-            ]
-            .concat())
+                    // If it is not the returned one, jump over the instructions returning its
+                    // data. Jump by (last argument + 1) instructions according to specs
+                    op::jnef(0x17, 0x18, RegId::ZERO, extract_data.len() as u8),
+                ]);
+                // ================= EXECUTED IF THE DISCRIMINANT POINTS TO THIS HEAP TYPE
+                // `retd` ends the script, so at most one of the variants returns its data
+                instructions.extend(extract_data);
+            }
+            // ================= EXECUTED IF THE DISCRIMINANT DOESN'T POINT TO A HEAP TYPE
+            instructions.push(op::retd(0x15, RegId::ZERO));
+
+            Ok(instructions)
         }
-        _ => extract_data_receipt(0, true, param_type),
+        _ => match param_type.nested_heap_element() {
+            Some(element_type) => extract_nested_heap_data(element_type),
+            None => extract_data_receipt(0, true, param_type),
+        },
     }
 }
 
+/// Returns a vector of heap types, e.g. `Vec<Vec<u64>>`, in a single receipt. `retd` ends the
+/// script, so the vector's buffer, i.e. the (ptr, cap, len) of every element, and the data of
+/// every element are first copied one after the other into a buffer allocated on the heap.
+fn extract_nested_heap_data(element_type: &ParamType) -> Result<Vec<fuel_asm::Instruction>> {
+    let element_width = element_type.compute_encoding_in_bytes()? as u16;
+    let inner_type_byte_size = element_type
+        .heap_inner_element_size(false)?
+        .ok_or_else(|| error!(Other, "`{element_type:?}` is not a heap type"))?
+        as u16;
+
+    Ok(vec![
+        // The vector's buffer and its number of elements
+        op::lw(0x15, RegId::RET, 0),
+        op::lw(0x16, RegId::RET, 2),
+        op::muli(0x17, 0x16, element_width),
+        // ================= SUM THE SIZE OF THE BUFFER AND OF THE DATA OF EVERY ELEMENT
+        op::move_(0x18, 0x17),
+        op::move_(0x19, 0x15),
+        op::movi(0x1A, 0),
+        // Skip the loop if the vector is empty.
+        // Jump by (last argument + 1) instructions according to specs
+        op::jnzf(0x16, RegId::ZERO, 1),
+        op::jmpf(RegId::ZERO, 6),
+        op::lw(0x1B, 0x19, 2),
+        op::muli(0x1B, 0x1B, inner_type_byte_size),
+        op::add(0x18, 0x18, 0x1B),
+        op::addi(0x19, 0x19, element_width),
+        op::addi(0x1A, 0x1A, 1),
+        op::jneb(0x1A, 0x16, RegId::ZERO, 4),
+        // ================= COPY THE BUFFER, THEN THE DATA OF EVERY ELEMENT
+        op::aloc(0x18),
+        op::move_(0x1B, RegId::HP),
+        op::mcp(0x1B, 0x15, 0x17),
+        op::add(0x1C, 0x1B, 0x17),
+        op::move_(0x19, 0x15),
+        op::movi(0x1A, 0),
+        op::jnzf(0x16, RegId::ZERO, 1),
+        op::jmpf(RegId::ZERO, 8),
+        op::lw(0x1D, 0x19, 0),
+        op::lw(0x1E, 0x19, 2),
+        op::muli(0x1E, 0x1E, inner_type_byte_size),
+        op::mcp(0x1C, 0x1D, 0x1E),
+        op::add(0x1C, 0x1C, 0x1E),
+        op::addi(0x19, 0x19, element_width),
+        op::addi(0x1A, 0x1A, 1),
+        op::jneb(0x1A, 0x16, RegId::ZERO, 6),
+        op::retd(0x1B, 0x18),
+    ])
+}
+
 fn extract_data_receipt(
     ptr_offset: u16,
     top_level_type: bool,
@@ -553,12 +702,14 @@ fn extract_unique_contract_ids(calls: &[ContractCall]) -> HashSet<ContractId> {
         .collect()
 }
 
+#[doc(hidden)]
 pub fn is_missing_output_variables(receipts: &[Receipt]) -> bool {
     receipts.iter().any(
         |r| matches!(r, Receipt::Revert { ra, .. } if *ra == FAILED_TRANSFER_TO_ADDRESS_SIGNAL),
     )
 }
 
+#[doc(hidden)]
 pub fn find_id_of_missing_contract(receipts: &[Receipt]) -> Option<Bech32ContractId> {
     receipts.iter().find_map(|receipt| match receipt {
         Receipt::Panic {
@@ -574,6 +725,7 @@ pub fn find_id_of_missing_contract(receipts: &[Receipt]) -> Option<Bech32Contrac
     })
 }
 
+#[doc(hidden)]
 pub fn new_variable_outputs(num: usize) -> Vec<Output> {
     vec![
         Output::Variable {
@@ -621,6 +773,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn gas_bumps_grow_up_to_the_maximum() -> Result<()> {
+        let gas_bump = GasBump::new(1.5, 1000)?;
+
+        assert_eq!(gas_bump.next(100), Some(150));
+        assert_eq!(gas_bump.next(0), Some(1));
+        assert_eq!(gas_bump.next(800), Some(1000));
+        assert_eq!(gas_bump.next(1000), None);
+
+        let err = GasBump::new(1.0, 1000).expect_err("should not grow");
+        assert_eq!(
+            err.to_string(),
+            "gas bump factor must be greater than 1, got 1"
+        );
+
+        Ok(())
+    }
+
     fn random_bech32_addr() -> Bech32Address {
         Bech32Address::new("fuel", rand::thread_rng().gen::<[u8; 32]>())
     }
@@ -629,6 +799,21 @@ mod test {
         Bech32ContractId::new("fuel", rand::thread_rng().gen::<[u8; 32]>())
     }
 
+    #[tokio::test]
+    async fn calls_are_abandoned_after_the_timeout() -> Result<()> {
+        let timeout = Duration::from_millis(10);
+
+        let err = with_timeout(Some(timeout), std::future::pending::<Result<()>>())
+            .await
+            .expect_err("should time out");
+        assert!(matches!(err, Error::Timeout(elapsed) if elapsed == timeout));
+
+        let value = with_timeout(Some(timeout), async { Ok(42) }).await?;
+        assert_eq!(value, 42);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_script_data() {
         // Arrange
@@ -956,8 +1141,13 @@ mod test {
         const GAS_OFFSET_INSTRUCTION_COUNT: usize = 2;
         // 4 instructions (lw, lw, muli, retd) added by extract_data_receipt
         const EXTRACT_DATA_RECEIPT_INSTRUCTION_COUNT: usize = 4;
-        // 4 instructions (movi, lw, jnef, retd) added by extract_heap_data
-        const EXTRACT_HEAP_DATA_INSTRUCTION_COUNT: usize = 4;
+        // 2 instructions (lw, retd) added by extract_heap_data
+        const EXTRACT_HEAP_DATA_INSTRUCTION_COUNT: usize = 2;
+        // 2 instructions (movi, jnef) added by extract_heap_data for every heap type variant
+        const HEAP_TYPE_VARIANT_INSTRUCTION_COUNT: usize = 2;
+        // 31 instructions added by extract_nested_heap_data: 3 to read the vector, 5 + 6 to sum
+        // the sizes, 8 + 8 to copy the data and the final retd
+        const EXTRACT_NESTED_HEAP_DATA_INSTRUCTION_COUNT: usize = 31;
 
         #[test]
         fn test_simple() {
@@ -996,6 +1186,25 @@ mod test {
             }
         }
 
+        #[test]
+        fn test_with_nested_heap_type() {
+            let output_params = vec![
+                ParamType::Vector(Box::new(ParamType::Vector(Box::new(ParamType::U64)))),
+                ParamType::Vector(Box::new(ParamType::String)),
+                ParamType::Vector(Box::new(ParamType::Bytes)),
+            ];
+            for output_param in output_params {
+                let mut call = ContractCall::new_with_random_id();
+                call.output_param = output_param;
+                let instructions_len = compute_calls_instructions_len(&[call]).unwrap();
+                assert_eq!(
+                    instructions_len,
+                    Instruction::SIZE
+                        * (BASE_INSTRUCTION_COUNT + EXTRACT_NESTED_HEAP_DATA_INSTRUCTION_COUNT)
+                );
+            }
+        }
+
         #[test]
         fn test_with_gas_offset_and_heap_type() {
             let mut call = ContractCall::new_with_random_id();
@@ -1030,12 +1239,37 @@ mod test {
                     instructions_len,
                     Instruction::SIZE
                         * (BASE_INSTRUCTION_COUNT
-                            + EXTRACT_DATA_RECEIPT_INSTRUCTION_COUNT
-                            + EXTRACT_HEAP_DATA_INSTRUCTION_COUNT)
+                            + EXTRACT_HEAP_DATA_INSTRUCTION_COUNT
+                            + HEAP_TYPE_VARIANT_INSTRUCTION_COUNT
+                            + EXTRACT_DATA_RECEIPT_INSTRUCTION_COUNT)
                 );
             }
         }
 
+        #[test]
+        fn test_with_enum_with_multiple_heap_variants() {
+            let mut call = ContractCall::new_with_random_id();
+            call.output_param = ParamType::Enum {
+                variants: EnumVariants::new(vec![
+                    ParamType::Vector(Box::new(ParamType::U8)),
+                    ParamType::U8,
+                    ParamType::String,
+                    ParamType::Bytes,
+                ])
+                .unwrap(),
+                generics: Vec::new(),
+            };
+            let instructions_len = compute_calls_instructions_len(&[call]).unwrap();
+            assert_eq!(
+                instructions_len,
+                Instruction::SIZE
+                    * (BASE_INSTRUCTION_COUNT
+                        + EXTRACT_HEAP_DATA_INSTRUCTION_COUNT
+                        + 3 * (HEAP_TYPE_VARIANT_INSTRUCTION_COUNT
+                            + EXTRACT_DATA_RECEIPT_INSTRUCTION_COUNT))
+            );
+        }
+
         #[test]
         fn test_with_enum_with_only_non_heap_variants() {
             let mut call = ContractCall::new_with_random_id();
@@ -5,36 +5,53 @@ use std::{
     fs, io,
     marker::PhantomData,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 use fuel_tx::{
-    AssetId, Bytes32, Contract as FuelContract, ContractId, Output, Receipt, Salt, StorageSlot,
+    Address, AssetId, Bytes32, Contract as FuelContract, ContractId, Output, Receipt, Salt,
+    StorageSlot,
+};
+use fuels_accounts::{
+    provider::TransactionCost, wallet::WalletUnlocked, Account, SubmittingAccount, ViewOnlyAccount,
 };
-use fuels_accounts::{provider::TransactionCost, Account};
 use fuels_core::{
+    bytecode,
     codec::{ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder},
     constants::{BASE_ASSET_ID, DEFAULT_CALL_PARAMS_AMOUNT},
     traits::{Parameterize, Tokenizable},
     types::{
         bech32::{Bech32Address, Bech32ContractId},
+        coin::Coin,
+        coin_type::CoinType,
         errors::{error, Error, Result},
+        input::Input,
         param_types::ParamType,
         transaction::{ScriptTransaction, Transaction, TxPolicies},
-        transaction_builders::{CreateTransactionBuilder, ScriptTransactionBuilder},
+        transaction_builders::{
+            CreateTransactionBuilder, ScriptTransactionBuilder, TransactionBuilder,
+        },
+        tx_size::TxSizeEstimate,
         tx_status::TxStatus,
         unresolved_bytes::UnresolvedBytes,
         Selector, Token,
     },
     Configurables,
 };
+use itertools::Itertools;
 
 use crate::{
     call_response::FuelCallResponse,
+    call_script::CustomCallScript,
     call_utils::{
-        build_tx_from_contract_calls, new_variable_outputs, sealed,
-        transaction_builder_from_contract_calls, TxDependencyExtension,
+        build_tx_from_contract_calls, build_tx_with_gas_bump, new_variable_outputs, sealed,
+        transaction_builder_from_contract_calls, with_timeout, GasBump, TxDependencyExtension,
     },
+    deployments::{DeployedContract, Deployments},
+    gas_profiler,
+    proxy::Proxy,
     receipt_parser::ReceiptParser,
+    simulation_cache::{CachedSimulation, SimulationCache, SimulationKey},
     submit_response::{SubmitResponse, SubmitResponseMultiple},
 };
 
@@ -270,11 +287,9 @@ impl Contract {
         salt: &Salt,
         storage_slots: &[StorageSlot],
     ) -> (ContractId, Bytes32, Bytes32) {
-        let fuel_contract = FuelContract::from(binary);
-        let code_root = fuel_contract.root();
-        let state_root = FuelContract::initial_state_root(storage_slots.iter());
-
-        let contract_id = fuel_contract.id(salt, &code_root, &state_root);
+        let code_root = bytecode::code_root(binary);
+        let state_root = bytecode::state_root(storage_slots);
+        let contract_id = FuelContract::from(binary).id(salt, &code_root, &state_root);
 
         (contract_id, code_root, state_root)
     }
@@ -327,6 +342,112 @@ impl Contract {
         Ok(self.contract_id.into())
     }
 
+    /// Like [`Contract::deploy`], paying the fee with `coin` alone. Deployments that must not
+    /// compete for the coins of `wallet` use this, see [`deploy_all`](crate::fixtures::deploy_all).
+    /// The coin is spent as a signed input, so this takes a wallet rather than any account.
+    pub(crate) async fn deploy_paying_with(
+        self,
+        wallet: &WalletUnlocked,
+        coin: Coin,
+    ) -> Result<Bech32ContractId> {
+        let mut tb = CreateTransactionBuilder::prepare_contract_deployment(
+            self.binary,
+            self.contract_id,
+            self.state_root,
+            self.salt,
+            self.storage_slots,
+            TxPolicies::default(),
+        );
+        tb.inputs_mut()
+            .push(Input::resource_signed(CoinType::Coin(coin)));
+        tb.outputs_mut()
+            .push(Output::change(wallet.address().into(), 0, BASE_ASSET_ID));
+
+        wallet.add_witnesses(&mut tb)?;
+
+        let provider = wallet.try_provider()?;
+
+        let tx = tb.build(provider).await?;
+
+        provider
+            .send_transaction_and_await_commit(tx)
+            .await?
+            .check(None)?;
+
+        Ok(self.contract_id.into())
+    }
+
+    /// Describes this contract for a [`Deployments`] manifest under `name`.
+    pub fn deployment(&self, name: impl Into<String>) -> DeployedContract {
+        DeployedContract {
+            name: name.into(),
+            contract_id: self.contract_id,
+            salt: self.salt,
+            code_root: self.code_root,
+            abi_path: None,
+        }
+    }
+
+    /// Deploys the contract and, once deployed, records it under `name` in `deployments`. Call
+    /// [`Deployments::save`] to persist the manifest.
+    pub async fn deploy_and_record(
+        self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+        name: impl Into<String>,
+        deployments: &mut Deployments,
+    ) -> Result<Bech32ContractId> {
+        let deployment = self.deployment(name);
+        let contract_id = self.deploy(account, tx_policies).await?;
+        deployments.insert(deployment);
+
+        Ok(contract_id)
+    }
+
+    /// Deploys the contract and then calls the initializer returned by `init` for the new
+    /// contract's id, e.g. a `constructor` method. The call is only made once the deployment is
+    /// committed. Returns the id of the contract along with the response of the initializer.
+    ///
+    /// ```ignore
+    /// let (contract_id, response) = Contract::load_from(path, LoadConfiguration::default())?
+    ///     .deploy_and_call(&wallet, TxPolicies::default(), |contract_id| {
+    ///         MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone())
+    ///             .methods()
+    ///             .initialize(owner)
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn deploy_and_call<T, D>(
+        self,
+        account: &impl Account,
+        tx_policies: TxPolicies,
+        init: impl FnOnce(&Bech32ContractId) -> ContractCallHandler<T, D>,
+    ) -> Result<(Bech32ContractId, FuelCallResponse<D>)>
+    where
+        T: SubmittingAccount,
+        D: Tokenizable + Parameterize + Debug,
+    {
+        let contract_id = self.deploy(account, tx_policies).await?;
+        let response = init(&contract_id).call().await?;
+
+        Ok((contract_id, response))
+    }
+
+    /// Deploys this contract, an SRC-14 proxy, and points it to `target`.
+    pub async fn deploy_as_proxy<T: Account>(
+        self,
+        target: impl Into<Bech32ContractId>,
+        account: T,
+        tx_policies: TxPolicies,
+    ) -> Result<Proxy<T>> {
+        let proxy_id = self.deploy(&account, tx_policies).await?;
+
+        let proxy = Proxy::new(proxy_id, account);
+        proxy.upgrade_to(target).await?;
+
+        Ok(proxy)
+    }
+
     pub fn load_from(binary_filepath: impl AsRef<Path>, config: LoadConfiguration) -> Result<Self> {
         let binary_filepath = binary_filepath.as_ref();
         validate_path_and_extension(binary_filepath, "bin")?;
@@ -345,6 +466,29 @@ impl Contract {
         Ok(Self::new(binary, config.salt, storage_slots))
     }
 
+    /// Like [`Contract::load_from`], for bytecode that never touched the filesystem, e.g.
+    /// generated or patched by a build pipeline. The configurables in `config` are applied to
+    /// `binary` just the same.
+    ///
+    /// Without a path there is no storage slots file to autoload, so autoloading must be
+    /// disabled in the [`StorageConfiguration`] and the slots given as overrides instead.
+    pub fn from_bytes(binary: impl Into<Vec<u8>>, config: LoadConfiguration) -> Result<Self> {
+        if config.storage.autoload_enabled() {
+            return Err(error!(
+                Other,
+                "cannot autoload the storage slots of a contract loaded from bytes. Disable \
+                 autoloading in `StorageConfiguration` and provide the slots as overrides"
+            ));
+        }
+
+        let mut binary = binary.into();
+        config.configurables.update_constants_in(&mut binary);
+
+        let storage_slots = config.storage.into_slots().collect();
+
+        Ok(Self::new(binary, config.salt, storage_slots))
+    }
+
     fn determine_storage_slots(
         storage_config: StorageConfiguration,
         binary_filepath: &Path,
@@ -449,6 +593,18 @@ impl ContractCall {
         }
     }
 
+    /// Decodes the value returned by this call from the `receipts` of its transaction, as
+    /// described by its `output_param`. Meant for calls whose output has no Rust type, e.g.
+    /// built from a JSON ABI at runtime.
+    pub fn decode_output(
+        &self,
+        receipts: &[Receipt],
+        decoder_config: DecoderConfig,
+    ) -> Result<Token> {
+        ReceiptParser::new(receipts, decoder_config)
+            .parse(Some(&self.contract_id), &self.output_param)
+    }
+
     pub fn append_variable_outputs(&mut self, num: u64) {
         self.variable_outputs
             .extend(new_variable_outputs(num as usize));
@@ -461,6 +617,107 @@ impl ContractCall {
     pub fn add_custom_asset(&mut self, asset_id: AssetId, amount: u64, to: Option<Bech32Address>) {
         *self.custom_assets.entry((asset_id, to)).or_default() += amount;
     }
+
+    /// Serializes the prepared call so that it can be stored and executed later, possibly by
+    /// another process. See [`ContractCall::from_bytes`].
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let encoded_args = self
+            .encoded_args
+            .as_ref()
+            .map_err(|e| error!(Codec, "cannot encode contract call arguments: {e}"))?;
+
+        let serialized = SerializedContractCall {
+            contract_id: *ContractId::from(&self.contract_id),
+            encoded_args: encoded_args.clone(),
+            encoded_selector: self.encoded_selector,
+            amount: self.call_parameters.amount,
+            asset_id: *self.call_parameters.asset_id,
+            gas_forwarded: self.call_parameters.gas_forwarded,
+            compute_custom_input_offset: self.compute_custom_input_offset,
+            variable_outputs: self.variable_outputs.len() as u64,
+            external_contracts: self
+                .external_contracts
+                .iter()
+                .map(|id| *ContractId::from(id))
+                .collect(),
+            output_param: self.output_param.clone(),
+            is_payable: self.is_payable,
+            custom_assets: self
+                .custom_assets
+                .iter()
+                .map(|((asset_id, to), amount)| {
+                    (
+                        **asset_id,
+                        to.as_ref().map(|to| *Address::from(to)),
+                        *amount,
+                    )
+                })
+                .collect(),
+        };
+
+        serde_json::to_vec(&serialized).map_err(|err| error!(Codec, "{err}"))
+    }
+
+    /// Restores a call serialized with [`ContractCall::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let serialized: SerializedContractCall =
+            serde_json::from_slice(bytes).map_err(|err| error!(Codec, "{err}"))?;
+
+        Ok(ContractCall {
+            contract_id: ContractId::from(serialized.contract_id).into(),
+            encoded_args: Ok(serialized.encoded_args),
+            encoded_selector: serialized.encoded_selector,
+            call_parameters: CallParameters {
+                amount: serialized.amount,
+                asset_id: AssetId::from(serialized.asset_id),
+                gas_forwarded: serialized.gas_forwarded,
+            },
+            compute_custom_input_offset: serialized.compute_custom_input_offset,
+            variable_outputs: new_variable_outputs(serialized.variable_outputs as usize),
+            external_contracts: serialized
+                .external_contracts
+                .into_iter()
+                .map(|id| ContractId::from(id).into())
+                .collect(),
+            output_param: serialized.output_param,
+            is_payable: serialized.is_payable,
+            custom_assets: serialized
+                .custom_assets
+                .into_iter()
+                .map(|(asset_id, to, amount)| {
+                    let to = to.map(|to| Address::from(to).into());
+                    ((AssetId::from(asset_id), to), amount)
+                })
+                .collect(),
+        })
+    }
+
+    /// How the call is named in a [`GasReport`](crate::gas_profiler::GasReport).
+    fn profile_label(&self) -> String {
+        format!(
+            "{}::{:#010x}",
+            self.contract_id,
+            u64::from_be_bytes(self.encoded_selector)
+        )
+    }
+}
+
+/// Wire format of [`ContractCall::to_bytes`]. Variable outputs carry no data, so only their
+/// count is kept.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedContractCall {
+    contract_id: [u8; 32],
+    encoded_args: UnresolvedBytes,
+    encoded_selector: Selector,
+    amount: u64,
+    asset_id: [u8; 32],
+    gas_forwarded: Option<u64>,
+    compute_custom_input_offset: bool,
+    variable_outputs: u64,
+    external_contracts: Vec<[u8; 32]>,
+    output_param: ParamType,
+    is_payable: bool,
+    custom_assets: Vec<([u8; 32], Option<[u8; 32]>, u64)>,
 }
 
 #[derive(Debug)]
@@ -470,6 +727,10 @@ pub struct ContractCallHandler<T: Account, D> {
     pub contract_call: ContractCall,
     pub tx_policies: TxPolicies,
     decoder_config: DecoderConfig,
+    custom_call_script: Option<CustomCallScript>,
+    timeout: Option<Duration>,
+    gas_bump: Option<GasBump>,
+    simulation_cache: Option<SimulationCache>,
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     pub account: T,
@@ -560,6 +821,59 @@ where
         self
     }
 
+    /// Replaces the default call script with a custom one. Inputs, outputs and the decoding
+    /// of the response are still handled by the SDK.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance.my_method(...).with_custom_call_script(custom_call_script).call()
+    /// ```
+    pub fn with_custom_call_script(mut self, custom_call_script: CustomCallScript) -> Self {
+        self.custom_call_script = Some(custom_call_script);
+        self
+    }
+
+    /// Sets a deadline for `call` and `simulate`. If it passes before the node responds, they
+    /// return [`Error::Timeout`] and the request in flight is aborted. A transaction that was
+    /// already sent by `call` may still be included in a block.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance.my_method(...).with_timeout(Duration::from_secs(5)).call()
+    /// ```
+    ///
+    /// [`Error::Timeout`]: fuels_core::types::errors::Error::Timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Dry-runs the transaction before `call`, `submit` and `simulate` and, as long as it runs
+    /// out of gas, raises its gas limit as set by `gas_bump` and tries again. The gas limit that was used
+    /// is reported in [`FuelCallResponse::gas_limit`].
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// let gas_bump = GasBump::new(1.5, 1_000_000)?;
+    /// my_contract_instance.my_method(...).with_gas_bump(gas_bump).call()
+    /// ```
+    pub fn with_gas_bump(mut self, gas_bump: GasBump) -> Self {
+        self.gas_bump = Some(gas_bump);
+        self
+    }
+
+    /// Makes `simulate` reuse the result of an identical simulation made at the latest block,
+    /// see [`SimulationCache`]. Contract instances set their cache, if any, on every call.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance.my_method(...).with_simulation_cache(cache).simulate()
+    /// ```
+    pub fn with_simulation_cache(mut self, cache: impl Into<Option<SimulationCache>>) -> Self {
+        self.simulation_cache = cache.into();
+        self
+    }
+
     /// Sets the call parameters for a given contract call.
     /// Note that this is a builder method, i.e. use it as a chain:
     ///
@@ -580,6 +894,7 @@ where
             std::slice::from_ref(&self.contract_call),
             self.tx_policies,
             &self.account,
+            self.custom_call_script.as_ref(),
         )
         .await
     }
@@ -590,45 +905,90 @@ where
             std::slice::from_ref(&self.contract_call),
             self.tx_policies,
             &self.account,
+            self.custom_call_script.as_ref(),
         )
         .await
     }
 
-    /// Call a contract's method on the node, in a state-modifying manner.
-    pub async fn call(mut self) -> Result<FuelCallResponse<D>> {
-        self.call_or_simulate(false).await
-    }
-
-    pub async fn submit(mut self) -> Result<SubmitResponse<T, D>> {
-        let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
-
-        let tx_id = provider.send_transaction(tx.clone()).await?;
-        self.cached_tx_id = Some(tx_id);
-
-        Ok(SubmitResponse::new(tx_id, self))
+    /// Same as `build_tx`, but with the gas limit raised as set by `with_gas_bump`, if any.
+    async fn build_bumped_tx(&self) -> Result<ScriptTransaction> {
+        match self.gas_bump {
+            Some(gas_bump) => {
+                build_tx_with_gas_bump(
+                    std::slice::from_ref(&self.contract_call),
+                    self.tx_policies,
+                    &self.account,
+                    self.custom_call_script.as_ref(),
+                    gas_bump,
+                )
+                .await
+            }
+            None => self.build_tx().await,
+        }
     }
 
     /// Call a contract's method on the node, in a simulated manner, meaning the state of the
     /// blockchain is *not* modified but simulated.
     pub async fn simulate(&mut self) -> Result<FuelCallResponse<D>> {
-        self.call_or_simulate(true).await
-    }
+        let cache = self.simulation_cache.clone();
+        let key = SimulationKey::for_call(&self.contract_call, self.tx_policies);
+        let Some((cache, key)) = cache.zip(key) else {
+            return self.call_or_simulate(true).await;
+        };
 
-    async fn call_or_simulate(&mut self, simulate: bool) -> Result<FuelCallResponse<D>> {
-        let tx = self.build_tx().await?;
         let provider = self.account.try_provider()?;
+        let block_height = provider.latest_block_height().await?;
+        if let Some(cached) = cache.get(block_height, &key) {
+            let mut response = self.get_response(cached.receipts)?;
+            response.gas_limit = cached.gas_limit;
 
-        self.cached_tx_id = Some(tx.id(provider.chain_id()));
+            return Ok(response);
+        }
 
-        let tx_status = if simulate {
-            provider.checked_dry_run(tx).await?
-        } else {
-            provider.send_transaction_and_await_commit(tx).await?
-        };
+        let response = self.call_or_simulate(true).await?;
+        cache.insert(
+            block_height,
+            key,
+            CachedSimulation {
+                receipts: response.receipts.clone(),
+                gas_limit: response.gas_limit,
+            },
+        );
+
+        Ok(response)
+    }
+
+    async fn call_or_simulate(&mut self, simulate: bool) -> Result<FuelCallResponse<D>> {
+        let timeout = self.timeout;
+        let (tx_status, gas_limit, tx_bytes) = with_timeout(timeout, async {
+            let tx = self.build_bumped_tx().await?;
+            let provider = self.account.try_provider()?;
+            let gas_limit = tx.gas_limit();
+            let tx_bytes = tx.metered_bytes_size();
+
+            self.cached_tx_id = Some(tx.id(provider.chain_id()));
+
+            let tx_status = if simulate {
+                provider.checked_dry_run(tx).await
+            } else {
+                provider.send_transaction_and_await_commit(tx).await
+            }?;
+
+            Ok((tx_status, gas_limit, tx_bytes))
+        })
+        .await?;
         let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
 
-        self.get_response(receipts)
+        let mut response = self.get_response(receipts)?;
+        response.gas_limit = Some(gas_limit);
+        gas_profiler::record(
+            || self.contract_call.profile_label(),
+            response.gas_used,
+            tx_bytes,
+            simulate,
+        );
+
+        Ok(response)
     }
 
     /// Get a contract's estimated cost
@@ -646,6 +1006,16 @@ where
         Ok(transaction_cost)
     }
 
+    /// The size of the call transaction and the part of its fee charged for it, with a warning
+    /// if it gets close to the limits of the chain. The call isn't submitted, but building the
+    /// transaction queries the node, e.g. for the inputs paying for it and its gas price.
+    pub async fn estimate_size(&self) -> Result<TxSizeEstimate> {
+        let tx = self.build_tx().await?;
+        let provider = self.account.try_provider()?;
+
+        Ok(TxSizeEstimate::new(&tx, provider.consensus_parameters()))
+    }
+
     /// Create a [`FuelCallResponse`] from call receipts
     pub fn get_response(&self, receipts: Vec<Receipt>) -> Result<FuelCallResponse<D>> {
         let token = ReceiptParser::new(&receipts, self.decoder_config).parse(
@@ -668,6 +1038,27 @@ where
     }
 }
 
+impl<T, D> ContractCallHandler<T, D>
+where
+    T: SubmittingAccount,
+    D: Tokenizable + Parameterize + Debug,
+{
+    /// Call a contract's method on the node, in a state-modifying manner.
+    pub async fn call(mut self) -> Result<FuelCallResponse<D>> {
+        self.call_or_simulate(false).await
+    }
+
+    pub async fn submit(mut self) -> Result<SubmitResponse<T, D>> {
+        let tx = self.build_bumped_tx().await?;
+        let provider = self.account.try_provider()?;
+
+        let tx_id = provider.send_transaction(tx.clone()).await?;
+        self.cached_tx_id = Some(tx_id);
+
+        Ok(SubmitResponse::new(tx_id, self))
+    }
+}
+
 impl<T: Account, D> sealed::Sealed for ContractCallHandler<T, D> {}
 
 #[async_trait::async_trait]
@@ -749,6 +1140,38 @@ pub fn method_hash<D: Tokenizable + Parameterize + Debug, T: Account>(
         datatype: PhantomData,
         log_decoder,
         decoder_config: Default::default(),
+        custom_call_script: None,
+        timeout: None,
+        gas_bump: None,
+        simulation_cache: None,
+    }
+}
+
+/// Selector and encoded arguments of a contract function call, produced without an account or a
+/// node. Used to build payloads for calls made by other contracts, scripts or proposals.
+#[derive(Debug, Clone)]
+pub struct EncodedFnCall {
+    pub selector: Selector,
+    pub encoded_args: UnresolvedBytes,
+}
+
+impl EncodedFnCall {
+    pub fn new(selector: Selector, args: &[Token], encoder_config: EncoderConfig) -> Result<Self> {
+        let encoded_args = ABIEncoder::new(encoder_config).encode(args)?;
+
+        Ok(Self {
+            selector,
+            encoded_args,
+        })
+    }
+
+    /// Returns the selector followed by the encoded arguments. Pointers to heap types inside the
+    /// arguments are resolved as if the arguments were placed at `args_offset`.
+    pub fn to_calldata(&self, args_offset: u64) -> Vec<u8> {
+        let mut calldata = self.selector.to_vec();
+        calldata.extend(self.encoded_args.resolve(args_offset));
+
+        calldata
     }
 }
 
@@ -787,6 +1210,9 @@ pub struct MultiContractCallHandler<T: Account> {
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     decoder_config: DecoderConfig,
+    custom_call_script: Option<CustomCallScript>,
+    timeout: Option<Duration>,
+    gas_bump: Option<GasBump>,
     pub account: T,
 }
 
@@ -799,6 +1225,9 @@ impl<T: Account> MultiContractCallHandler<T> {
             account,
             log_decoder: LogDecoder::new(Default::default()),
             decoder_config: DecoderConfig::default(),
+            custom_call_script: None,
+            timeout: None,
+            gas_bump: None,
         }
     }
 
@@ -808,6 +1237,31 @@ impl<T: Account> MultiContractCallHandler<T> {
         self
     }
 
+    /// Replaces the default call script with a custom one. Inputs, outputs and the decoding
+    /// of the response are still handled by the SDK.
+    pub fn with_custom_call_script(&mut self, custom_call_script: CustomCallScript) -> &mut Self {
+        self.custom_call_script = Some(custom_call_script);
+        self
+    }
+
+    /// Sets a deadline for `call` and `simulate`. If it passes before the node responds, they
+    /// return [`Error::Timeout`] and the request in flight is aborted. A transaction that was
+    /// already sent by `call` may still be included in a block.
+    ///
+    /// [`Error::Timeout`]: fuels_core::types::errors::Error::Timeout
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Dry-runs the transaction before `call`, `submit` and `simulate` and, as long as it runs
+    /// out of gas, raises its gas limit as set by `gas_bump` and tries again. The gas limit that was used
+    /// is reported in [`FuelCallResponse::gas_limit`].
+    pub fn with_gas_bump(&mut self, gas_bump: GasBump) -> &mut Self {
+        self.gas_bump = Some(gas_bump);
+        self
+    }
+
     /// Adds a contract call to be bundled in the transaction
     /// Note that this is a builder method
     pub fn add_call(
@@ -872,6 +1326,7 @@ impl<T: Account> MultiContractCallHandler<T> {
             &self.contract_calls,
             self.tx_policies,
             &self.account,
+            self.custom_call_script.as_ref(),
         )
         .await
     }
@@ -880,22 +1335,32 @@ impl<T: Account> MultiContractCallHandler<T> {
     pub async fn build_tx(&self) -> Result<ScriptTransaction> {
         self.validate_contract_calls()?;
 
-        build_tx_from_contract_calls(&self.contract_calls, self.tx_policies, &self.account).await
-    }
-
-    /// Call contract methods on the node, in a state-modifying manner.
-    pub async fn call<D: Tokenizable + Debug>(&mut self) -> Result<FuelCallResponse<D>> {
-        self.call_or_simulate(false).await
+        build_tx_from_contract_calls(
+            &self.contract_calls,
+            self.tx_policies,
+            &self.account,
+            self.custom_call_script.as_ref(),
+        )
+        .await
     }
 
-    pub async fn submit(mut self) -> Result<SubmitResponseMultiple<T>> {
-        let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
-
-        let tx_id = provider.send_transaction(tx).await?;
-        self.cached_tx_id = Some(tx_id);
-
-        Ok(SubmitResponseMultiple::new(tx_id, self))
+    /// Same as `build_tx`, but with the gas limit raised as set by `with_gas_bump`, if any.
+    async fn build_bumped_tx(&self) -> Result<ScriptTransaction> {
+        match self.gas_bump {
+            Some(gas_bump) => {
+                self.validate_contract_calls()?;
+
+                build_tx_with_gas_bump(
+                    &self.contract_calls,
+                    self.tx_policies,
+                    &self.account,
+                    self.custom_call_script.as_ref(),
+                    gas_bump,
+                )
+                .await
+            }
+            None => self.build_tx().await,
+        }
     }
 
     /// Call contract methods on the node, in a simulated manner, meaning the state of the
@@ -911,19 +1376,41 @@ impl<T: Account> MultiContractCallHandler<T> {
         &mut self,
         simulate: bool,
     ) -> Result<FuelCallResponse<D>> {
-        let tx = self.build_tx().await?;
-        let provider = self.account.try_provider()?;
-
-        self.cached_tx_id = Some(tx.id(provider.chain_id()));
-
-        let tx_status = if simulate {
-            provider.checked_dry_run(tx).await?
-        } else {
-            provider.send_transaction_and_await_commit(tx).await?
-        };
+        let timeout = self.timeout;
+        let (tx_status, gas_limit, tx_bytes) = with_timeout(timeout, async {
+            let tx = self.build_bumped_tx().await?;
+            let provider = self.account.try_provider()?;
+            let gas_limit = tx.gas_limit();
+            let tx_bytes = tx.metered_bytes_size();
+
+            self.cached_tx_id = Some(tx.id(provider.chain_id()));
+
+            let tx_status = if simulate {
+                provider.checked_dry_run(tx).await
+            } else {
+                provider.send_transaction_and_await_commit(tx).await
+            }?;
+
+            Ok((tx_status, gas_limit, tx_bytes))
+        })
+        .await?;
         let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
 
-        self.get_response(receipts)
+        let mut response = self.get_response(receipts)?;
+        response.gas_limit = Some(gas_limit);
+        gas_profiler::record(
+            || {
+                self.contract_calls
+                    .iter()
+                    .map(ContractCall::profile_label)
+                    .join(" + ")
+            },
+            response.gas_used,
+            tx_bytes,
+            simulate,
+        );
+
+        Ok(response)
     }
 
     /// Simulates a call without needing to resolve the generic for the return type
@@ -952,6 +1439,17 @@ impl<T: Account> MultiContractCallHandler<T> {
         Ok(transaction_cost)
     }
 
+    /// The size of the multicall transaction and the part of its fee charged for it, with a
+    /// warning if it gets close to the limits of the chain. Useful to check whether another call
+    /// still fits before adding it. The calls aren't submitted, but building the transaction
+    /// queries the node.
+    pub async fn estimate_size(&self) -> Result<TxSizeEstimate> {
+        let tx = self.build_tx().await?;
+        let provider = self.account.try_provider()?;
+
+        Ok(TxSizeEstimate::new(&tx, provider.consensus_parameters()))
+    }
+
     /// Create a [`FuelCallResponse`] from call receipts
     pub fn get_response<D: Tokenizable + Debug>(
         &self,
@@ -959,11 +1457,12 @@ impl<T: Account> MultiContractCallHandler<T> {
     ) -> Result<FuelCallResponse<D>> {
         let mut receipt_parser = ReceiptParser::new(&receipts, self.decoder_config);
 
-        let final_tokens = self
+        let outputs = self
             .contract_calls
             .iter()
-            .map(|call| receipt_parser.parse(Some(&call.contract_id), &call.output_param))
-            .collect::<Result<Vec<_>>>()?;
+            .map(|call| (Some(&call.contract_id), &call.output_param))
+            .collect::<Vec<_>>();
+        let final_tokens = receipt_parser.parse_all(&outputs)?;
 
         let tokens_as_tuple = Token::Tuple(final_tokens);
         let response = FuelCallResponse::<D>::new(
@@ -977,6 +1476,23 @@ impl<T: Account> MultiContractCallHandler<T> {
     }
 }
 
+impl<T: SubmittingAccount> MultiContractCallHandler<T> {
+    /// Call contract methods on the node, in a state-modifying manner.
+    pub async fn call<D: Tokenizable + Debug>(&mut self) -> Result<FuelCallResponse<D>> {
+        self.call_or_simulate(false).await
+    }
+
+    pub async fn submit(mut self) -> Result<SubmitResponseMultiple<T>> {
+        let tx = self.build_bumped_tx().await?;
+        let provider = self.account.try_provider()?;
+
+        let tx_id = provider.send_transaction(tx).await?;
+        self.cached_tx_id = Some(tx_id);
+
+        Ok(SubmitResponseMultiple::new(tx_id, self))
+    }
+}
+
 impl<T: Account> sealed::Sealed for MultiContractCallHandler<T> {}
 
 #[async_trait::async_trait]
@@ -1015,6 +1531,51 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn contract_call_survives_serialization() -> Result<()> {
+        // given
+        let mut call = ContractCall {
+            contract_id: ContractId::from([1; 32]).into(),
+            encoded_args: ABIEncoder::default().encode(&[Token::U64(42), Token::Bool(true)]),
+            encoded_selector: [0, 0, 0, 0, 1, 2, 3, 4],
+            call_parameters: CallParameters::new(10, AssetId::from([2; 32]), 1000),
+            compute_custom_input_offset: true,
+            variable_outputs: vec![],
+            external_contracts: vec![ContractId::from([3; 32]).into()],
+            output_param: ParamType::U64,
+            is_payable: true,
+            custom_assets: Default::default(),
+        };
+        call.append_variable_outputs(2);
+        call.add_custom_asset(
+            AssetId::from([4; 32]),
+            5,
+            Some(Address::from([5; 32]).into()),
+        );
+
+        // when
+        let restored = ContractCall::from_bytes(&call.to_bytes()?)?;
+
+        // then
+        assert_eq!(restored.contract_id, call.contract_id);
+        assert_eq!(
+            restored.encoded_args.as_ref().unwrap().resolve(0),
+            call.encoded_args.as_ref().unwrap().resolve(0)
+        );
+        assert_eq!(restored.encoded_selector, call.encoded_selector);
+        assert_eq!(restored.call_parameters.amount(), 10);
+        assert_eq!(restored.call_parameters.asset_id(), AssetId::from([2; 32]));
+        assert_eq!(restored.call_parameters.gas_forwarded(), Some(1000));
+        assert!(restored.compute_custom_input_offset);
+        assert_eq!(restored.variable_outputs, call.variable_outputs);
+        assert_eq!(restored.external_contracts, call.external_contracts);
+        assert_eq!(restored.output_param, ParamType::U64);
+        assert!(restored.is_payable);
+        assert_eq!(restored.custom_assets, call.custom_assets);
+
+        Ok(())
+    }
+
     #[test]
     fn merging_overrides_storage_slots() {
         // given
@@ -1079,6 +1640,47 @@ mod tests {
         assert_eq!(msg, format!("could not autoload storage slots from file: {storage_slots_path:?}. Either provide the file or disable autoloading in `StorageConfiguration`"));
     }
 
+    #[test]
+    fn loading_from_bytes_matches_loading_from_a_file() {
+        // given
+        let temp_dir = tempdir().unwrap();
+        let contract_bin = temp_dir.path().join("my_contract.bin");
+        let binary = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        std::fs::write(&contract_bin, &binary).unwrap();
+
+        let storage_config = StorageConfiguration::new(
+            false,
+            vec![StorageSlot::new([1; 32].into(), [2; 32].into())],
+        );
+        let load_config = LoadConfiguration::default()
+            .with_storage_configuration(storage_config)
+            .with_configurables(Configurables::new(vec![(4, vec![9, 9])]))
+            .with_salt([3; 32]);
+
+        // when
+        let from_file = Contract::load_from(&contract_bin, load_config.clone()).unwrap();
+        let from_bytes = Contract::from_bytes(binary, load_config).unwrap();
+
+        // then
+        assert_eq!(from_bytes.binary, vec![1, 2, 3, 4, 9, 9, 7, 8]);
+        assert_eq!(from_bytes.binary, from_file.binary);
+        assert_eq!(from_bytes.storage_slots, from_file.storage_slots);
+        assert_eq!(from_bytes.contract_id(), from_file.contract_id());
+    }
+
+    #[test]
+    fn loading_from_bytes_cannot_autoload_storage() {
+        let error = Contract::from_bytes(vec![], LoadConfiguration::default())
+            .expect_err("should have failed because there is no file to autoload from");
+
+        let Error::Other(msg) = error else {
+            panic!("expected an error of type `Other`");
+        };
+        assert!(
+            msg.starts_with("cannot autoload the storage slots of a contract loaded from bytes")
+        );
+    }
+
     fn save_slots(slots: &Vec<StorageSlot>, path: &Path) {
         std::fs::write(
             path,
@@ -0,0 +1,182 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use fuel_tx::{Bytes32, ContractId, Salt};
+use fuels_core::types::{
+    bech32::Bech32ContractId,
+    errors::{error, Result},
+};
+use serde::{Deserialize, Serialize};
+
+/// Directory, relative to the working directory, [`Deployments::load`] and
+/// [`Deployments::save`] use. Each network gets its own `<network>.json` file in it.
+pub const DEPLOYMENTS_DIR: &str = "deployments";
+
+/// A contract as it was deployed, see [`Contract::deployment`](crate::contract::Contract).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployedContract {
+    pub name: String,
+    pub contract_id: ContractId,
+    pub salt: Salt,
+    pub code_root: Bytes32,
+    pub abi_path: Option<PathBuf>,
+}
+
+impl DeployedContract {
+    pub fn with_abi_path(mut self, abi_path: impl Into<PathBuf>) -> Self {
+        self.abi_path = Some(abi_path.into());
+        self
+    }
+}
+
+/// The contracts deployed on a network, by name, so that applications can refer to contracts
+/// by name instead of hardcoding their ids.
+///
+/// ```no_run
+/// # use fuels_programs::deployments::Deployments;
+/// # fn example() -> fuels_core::types::errors::Result<()> {
+/// let deployments = Deployments::load("testnet")?;
+/// let token_id = deployments.contract_id("token")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deployments {
+    network: String,
+    contracts: BTreeMap<String, DeployedContract>,
+}
+
+impl Deployments {
+    pub fn new(network: impl Into<String>) -> Self {
+        Self {
+            network: network.into(),
+            contracts: Default::default(),
+        }
+    }
+
+    /// Loads the deployments of `network` from the [`DEPLOYMENTS_DIR`] directory.
+    pub fn load(network: &str) -> Result<Self> {
+        Self::load_from(Self::default_path(network))
+    }
+
+    /// Like [`Deployments::load`], but starts over with no deployments if none were saved for
+    /// `network` yet.
+    pub fn load_or_new(network: &str) -> Result<Self> {
+        let path = Self::default_path(network);
+        if !path.exists() {
+            return Ok(Self::new(network));
+        }
+
+        Self::load_from(path)
+    }
+
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("failed to read deployments from: {path:?}: {e}"),
+            )
+        })?;
+
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Saves the deployments to the [`DEPLOYMENTS_DIR`] directory, returning the path written to.
+    pub fn save(&self) -> Result<PathBuf> {
+        let path = Self::default_path(&self.network);
+        self.save_to(&path)?;
+
+        Ok(path)
+    }
+
+    pub fn save_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)?;
+
+        Ok(())
+    }
+
+    fn default_path(network: &str) -> PathBuf {
+        Path::new(DEPLOYMENTS_DIR).join(format!("{network}.json"))
+    }
+
+    pub fn network(&self) -> &str {
+        &self.network
+    }
+
+    /// Records `contract`, replacing any contract previously deployed under the same name.
+    pub fn insert(&mut self, contract: DeployedContract) -> &mut Self {
+        self.contracts.insert(contract.name.clone(), contract);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DeployedContract> {
+        self.contracts.get(name)
+    }
+
+    pub fn contract_id(&self, name: &str) -> Result<Bech32ContractId> {
+        self.get(name)
+            .map(|contract| contract.contract_id.into())
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "no contract named `{name}` was deployed on `{}`", self.network
+                )
+            })
+    }
+
+    pub fn contracts(&self) -> impl Iterator<Item = &DeployedContract> {
+        self.contracts.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn deployments_can_be_saved_and_loaded() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("manifests").join("testnet.json");
+
+        let token = DeployedContract {
+            name: "token".to_string(),
+            contract_id: ContractId::new([1; 32]),
+            salt: Salt::new([2; 32]),
+            code_root: Bytes32::new([3; 32]),
+            abi_path: None,
+        }
+        .with_abi_path("out/debug/token-abi.json");
+        let mut deployments = Deployments::new("testnet");
+        deployments.insert(token.clone());
+
+        deployments.save_to(&path)?;
+        let loaded = Deployments::load_from(&path)?;
+
+        assert_eq!(loaded, deployments);
+        assert_eq!(loaded.get("token"), Some(&token));
+        assert_eq!(
+            loaded.contract_id("token")?,
+            ContractId::new([1; 32]).into()
+        );
+
+        let err = loaded.contract_id("vault").expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "no contract named `vault` was deployed on `testnet`"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    collections::{HashMap, HashSet},
+    iter::{once, repeat, zip},
+    path::Path,
+};
+
+use fuel_tx::Output;
+use fuels_accounts::{wallet::WalletUnlocked, Account};
+use fuels_core::{
+    constants::BASE_ASSET_ID,
+    types::{
+        bech32::Bech32ContractId,
+        coin::Coin,
+        errors::{error, Error, Result},
+        transaction::{Transaction, TxPolicies},
+        transaction_builders::ScriptTransactionBuilder,
+    },
+};
+use futures::future::try_join_all;
+
+use crate::contract::{Contract, LoadConfiguration};
+
+/// Deploys the contracts integration tests work against, all at once, and returns their ids by
+/// name. Each contract is loaded from its binary like [`Contract::load_from`] does.
+///
+/// Deployments paid by the same wallet would compete for its coins, so its base asset is
+/// first split evenly into a coin per contract, in a single transaction. Each deployment then
+/// pays with its own coin and sends the change back to `wallet`.
+///
+/// ```ignore
+/// let ids = deploy_all(
+///     &[
+///         ("token", "out/token.bin", LoadConfiguration::default()),
+///         ("vault", "out/vault.bin", LoadConfiguration::default()),
+///     ],
+///     &wallet,
+/// )
+/// .await?;
+/// let vault = Vault::new(VaultId::new(ids["vault"].clone()), wallet);
+/// ```
+pub async fn deploy_all(
+    contracts: &[(&str, impl AsRef<Path>, LoadConfiguration)],
+    wallet: &WalletUnlocked,
+) -> Result<HashMap<String, Bech32ContractId>> {
+    let mut names = HashSet::new();
+    if let Some((name, ..)) = contracts.iter().find(|(name, ..)| !names.insert(*name)) {
+        return Err(error!(Other, "contract `{name}` is listed more than once"));
+    }
+
+    let loaded = contracts
+        .iter()
+        .map(|(name, path, config)| Ok((*name, Contract::load_from(path, config.clone())?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let coins = split_base_asset(wallet, loaded.len()).await?;
+
+    let deployments = zip(loaded, coins).map(|((name, contract), coin)| async move {
+        let contract_id = contract.deploy_paying_with(wallet, coin).await?;
+
+        Ok::<_, Error>((name.to_string(), contract_id))
+    });
+
+    Ok(try_join_all(deployments).await?.into_iter().collect())
+}
+
+/// Splits the base asset of `account` into `num_coins` coins of equal amount. A share the size
+/// of a coin is kept to pay for the split.
+async fn split_base_asset(account: &impl Account, num_coins: usize) -> Result<Vec<Coin>> {
+    if num_coins == 0 {
+        return Ok(vec![]);
+    }
+
+    let provider = account.try_provider()?;
+    let balance = account.get_asset_balance(&BASE_ASSET_ID).await?;
+    let coin_amount = balance / (num_coins as u64 + 1);
+    if coin_amount == 0 {
+        return Err(error!(
+            Other,
+            "a balance of {balance} is too low to pay for {num_coins} deployments"
+        ));
+    }
+    let total = coin_amount * num_coins as u64;
+
+    let owner = account.address();
+    let inputs = account
+        .get_asset_inputs_for_amount(BASE_ASSET_ID, total)
+        .await?;
+    let outputs = repeat(Output::coin(owner.into(), coin_amount, BASE_ASSET_ID))
+        .take(num_coins)
+        .chain(once(Output::change(owner.into(), 0, BASE_ASSET_ID)))
+        .collect();
+
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    account.add_witnesses(&mut tb)?;
+    account.adjust_for_fee(&mut tb, total).await?;
+
+    let tx = tb.build(provider).await?;
+    let tx_id = tx.id(provider.chain_id());
+    provider
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .check(None)?;
+
+    let coins = account
+        .get_coins(BASE_ASSET_ID)
+        .await?
+        .into_iter()
+        .filter(|coin| {
+            coin.utxo_id.tx_id() == &tx_id && (coin.utxo_id.output_index() as usize) < num_coins
+        })
+        .collect::<Vec<_>>();
+
+    if coins.len() != num_coins {
+        return Err(error!(
+            Other,
+            "expected {num_coins} coins from splitting the base asset, found {}",
+            coins.len()
+        ));
+    }
+
+    Ok(coins)
+}
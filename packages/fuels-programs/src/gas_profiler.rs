@@ -0,0 +1,290 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+use fuels_core::types::errors::{error, Result};
+use serde::{Deserialize, Serialize};
+
+/// Naming a file in this environment variable turns the profiler on for the whole process, see
+/// [`enable`]. Every call is appended to the file as a line of JSON, so the test binaries of a
+/// suite can all write to the same file. [`GasReport::load`] reads it back.
+pub const GAS_PROFILE_ENV: &str = "FUELS_GAS_PROFILE";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CALLS: Mutex<Vec<CallProfile>> = Mutex::new(Vec::new());
+
+/// What one call made through the SDK cost.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallProfile {
+    /// The name of the thread that made the call. The test harness names test threads after
+    /// their test.
+    pub test: Option<String>,
+    /// `<contract id>::<selector>` for a contract call, the calls joined by ` + ` for a
+    /// multicall and `script` for a script.
+    pub call: String,
+    pub gas_used: u64,
+    /// The metered size of the transaction.
+    pub tx_bytes: u64,
+    pub simulated: bool,
+}
+
+/// Starts recording the gas used and the transaction size of every contract call, multicall
+/// and script call made by this process, whether sent or simulated. Read them with [`report`].
+///
+/// The profiler is off by default, as it keeps every call in memory.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed) || profile_file().is_some()
+}
+
+/// The calls recorded so far by this process.
+pub fn report() -> GasReport {
+    GasReport {
+        calls: lock_calls().clone(),
+    }
+}
+
+/// How [`PrintReportOnDrop`] prints the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Table,
+    Json,
+}
+
+/// Turns the profiler on and returns a guard printing the [`report`] to stdout when dropped,
+/// e.g. at the end of a test:
+///
+/// ```ignore
+/// #[tokio::test]
+/// async fn deposits() -> Result<()> {
+///     let _gas = gas_profiler::print_report_on_drop(ReportFormat::Table);
+///     // calls made here end up in the printed report
+///     Ok(())
+/// }
+/// ```
+///
+/// Run the tests with `--nocapture` to see the report. The calls of every test running in the
+/// process are included, so run them with `--test-threads=1` to get one test's calls only.
+pub fn print_report_on_drop(format: ReportFormat) -> PrintReportOnDrop {
+    enable();
+
+    PrintReportOnDrop { format }
+}
+
+/// See [`print_report_on_drop`].
+#[derive(Debug)]
+#[must_use = "the report is printed when the guard is dropped"]
+pub struct PrintReportOnDrop {
+    format: ReportFormat,
+}
+
+impl Drop for PrintReportOnDrop {
+    fn drop(&mut self) {
+        let report = report();
+        match self.format {
+            ReportFormat::Table => println!("{report}"),
+            ReportFormat::Json => match report.to_json() {
+                Ok(json) => println!("{json}"),
+                Err(error) => tracing::warn!(%error, "could not print the gas report"),
+            },
+        }
+    }
+}
+
+pub(crate) fn record(
+    call: impl FnOnce() -> String,
+    gas_used: u64,
+    tx_bytes: usize,
+    simulated: bool,
+) {
+    if !is_enabled() {
+        return;
+    }
+
+    let profile = CallProfile {
+        test: std::thread::current().name().map(str::to_string),
+        call: call(),
+        gas_used,
+        tx_bytes: tx_bytes as u64,
+        simulated,
+    };
+
+    if let Some(path) = profile_file() {
+        // Statics aren't dropped at exit, so the calls are written as they happen instead of
+        // in a report at the end.
+        if let Err(error) = append(path, &profile) {
+            tracing::warn!(
+                path = ?path,
+                %error,
+                "could not record a call in the gas profile",
+            );
+        }
+    }
+    lock_calls().push(profile);
+}
+
+fn profile_file() -> Option<&'static PathBuf> {
+    static PROFILE_FILE: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+    PROFILE_FILE
+        .get_or_init(|| std::env::var_os(GAS_PROFILE_ENV).map(PathBuf::from))
+        .as_ref()
+}
+
+fn append(path: &Path, profile: &CallProfile) -> Result<()> {
+    let mut line = serde_json::to_string(profile)?;
+    line.push('\n');
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(line.as_bytes())?;
+
+    Ok(())
+}
+
+fn lock_calls() -> std::sync::MutexGuard<'static, Vec<CallProfile>> {
+    CALLS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The calls recorded by the profiler. Displayed, it is a table with a row per test and call,
+/// totalling their gas and bytes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasReport {
+    pub calls: Vec<CallProfile>,
+}
+
+impl GasReport {
+    /// Reads the calls written to the file named in [`GAS_PROFILE_ENV`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| error!(Other, "could not read the gas profile {path:?}: {e}"))?;
+
+        let calls = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Self { calls })
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    fn rows(&self) -> BTreeMap<(&str, &str), Totals> {
+        let mut rows = BTreeMap::<_, Totals>::new();
+        for profile in &self.calls {
+            let test = profile.test.as_deref().unwrap_or("<unnamed>");
+            let totals = rows.entry((test, profile.call.as_str())).or_default();
+            totals.calls += 1;
+            totals.gas_used = totals.gas_used.saturating_add(profile.gas_used);
+            totals.tx_bytes = totals.tx_bytes.saturating_add(profile.tx_bytes);
+        }
+
+        rows
+    }
+}
+
+#[derive(Debug, Default)]
+struct Totals {
+    calls: u64,
+    gas_used: u64,
+    tx_bytes: u64,
+}
+
+impl Display for GasReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows = self.rows();
+        let test_width = rows
+            .keys()
+            .map(|(test, _)| test.len())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        let call_width = rows
+            .keys()
+            .map(|(_, call)| call.len())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        writeln!(
+            f,
+            "{:test_width$}  {:call_width$}  {:>5}  {:>12}  {:>9}",
+            "test", "call", "calls", "gas used", "tx bytes"
+        )?;
+        for ((test, call), totals) in rows {
+            writeln!(
+                f,
+                "{test:test_width$}  {call:call_width$}  {:>5}  {:>12}  {:>9}",
+                totals.calls, totals.gas_used, totals.tx_bytes
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(test: &str, call: &str, gas_used: u64) -> CallProfile {
+        CallProfile {
+            test: Some(test.to_string()),
+            call: call.to_string(),
+            gas_used,
+            tx_bytes: 100,
+            simulated: false,
+        }
+    }
+
+    #[test]
+    fn calls_are_totalled_per_test_and_call() {
+        let report = GasReport {
+            calls: vec![
+                profile("deposits", "script", 10),
+                profile("deposits", "script", 15),
+                profile("withdrawals", "script", 7),
+            ],
+        };
+
+        let expected = "\
+test         call    calls      gas used   tx bytes
+deposits     script      2            25        200
+withdrawals  script      1             7        100
+";
+        assert_eq!(report.to_string(), expected);
+    }
+
+    #[test]
+    fn profiles_are_read_back_from_json_lines() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("gas.jsonl");
+        let calls = vec![profile("a", "script", 1), profile("b", "script", 2)];
+        for call in &calls {
+            append(&path, call)?;
+        }
+
+        assert_eq!(GasReport::load(&path)?, GasReport { calls });
+
+        Ok(())
+    }
+}
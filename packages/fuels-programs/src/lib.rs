@@ -1,6 +1,17 @@
+pub mod call_effects;
 pub mod call_response;
+pub mod call_script;
 pub mod call_utils;
 pub mod contract;
+pub mod deployments;
+pub mod fixtures;
+pub mod gas_profiler;
+pub mod proxy;
+pub mod read_aggregator;
+#[doc(hidden)]
 pub mod receipt_parser;
 pub mod script_calls;
+pub mod simulation_cache;
+pub mod src3;
+pub mod storage_diff;
 mod submit_response;
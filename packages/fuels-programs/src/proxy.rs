@@ -0,0 +1,84 @@
+use std::fmt::Debug;
+
+use fuel_tx::ContractId;
+use fuels_accounts::{Account, SubmittingAccount};
+use fuels_core::{
+    codec::{resolve_fn_selector, EncoderConfig, LogDecoder},
+    traits::{Parameterize, Tokenizable},
+    types::{bech32::Bech32ContractId, errors::Result, param_types::ParamType, Token},
+};
+
+use crate::{
+    call_response::FuelCallResponse,
+    contract::{method_hash, ContractCallHandler},
+};
+
+/// An SRC-14 proxy: a contract that forwards every call it doesn't recognize to its target
+/// contract. Pointing it to a new target upgrades the code while keeping the address and the
+/// storage.
+///
+/// To call the target's functions through the proxy, instantiate the target's bindings with the
+/// proxy's contract id and `with_proxy_target`.
+#[derive(Debug, Clone)]
+pub struct Proxy<T: Account> {
+    contract_id: Bech32ContractId,
+    account: T,
+}
+
+impl<T: Account> Proxy<T> {
+    pub fn new(contract_id: impl Into<Bech32ContractId>, account: T) -> Self {
+        Self {
+            contract_id: contract_id.into(),
+            account,
+        }
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    /// The contract calls are currently forwarded to, if any.
+    pub async fn target(&self) -> Result<Option<Bech32ContractId>> {
+        let response = self
+            .call_handler::<Option<ContractId>>("proxy_target", &[], &[])
+            .simulate()
+            .await?;
+
+        Ok(response.value.map(Into::into))
+    }
+
+    fn call_handler<D: Tokenizable + Parameterize + Debug>(
+        &self,
+        fn_name: &str,
+        param_types: &[ParamType],
+        args: &[Token],
+    ) -> ContractCallHandler<T, D> {
+        method_hash(
+            self.contract_id.clone(),
+            self.account.clone(),
+            resolve_fn_selector(fn_name, param_types),
+            args,
+            LogDecoder::new(Default::default()),
+            false,
+            EncoderConfig::default(),
+        )
+    }
+}
+
+impl<T: SubmittingAccount> Proxy<T> {
+    /// Makes the proxy forward calls to `new_target`.
+    pub async fn upgrade_to(
+        &self,
+        new_target: impl Into<Bech32ContractId>,
+    ) -> Result<FuelCallResponse<()>> {
+        let new_target = ContractId::from(new_target.into());
+
+        self.call_handler(
+            "set_proxy_target",
+            &[ContractId::param_type()],
+            &[new_target.into_token()],
+        )
+        .call()
+        .await
+    }
+}
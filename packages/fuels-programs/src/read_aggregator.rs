@@ -0,0 +1,496 @@
+use std::{collections::HashSet, fmt::Debug, iter::zip, marker::PhantomData, slice};
+
+use fuel_asm::Instruction;
+use fuel_tx::{ConsensusParameters, ContractId};
+use fuels_accounts::Account;
+use fuels_core::{
+    codec::{DecoderConfig, LogDecoder},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        errors::{error, Result},
+        transaction::TxPolicies,
+        Token,
+    },
+};
+use futures::future::try_join_all;
+
+use crate::{
+    call_utils::{
+        build_script_data_from_contract_calls, build_tx_from_contract_calls,
+        compute_calls_instructions_len, ran_out_of_gas,
+    },
+    contract::{ContractCall, ContractCallHandler},
+    receipt_parser::ReceiptParser,
+};
+
+/// Inputs and outputs left free in every request for the coins paying the fee and their change.
+const RESERVED_INPUTS_AND_OUTPUTS: usize = 8;
+
+/// Bundles many read-only calls, possibly to different contracts, into as few multicall
+/// simulations as the limits of the chain allow, runs those simulations concurrently and
+/// returns the decoded results in the order the calls were added. Meant for dashboards and
+/// indexers that need to read a lot of state at once.
+///
+/// ```ignore
+/// let mut aggregator = ReadAggregator::new(wallet.clone());
+/// let balance = aggregator.add(token.methods().balance_of(owner));
+/// let supply = aggregator.add(token.methods().total_supply());
+///
+/// let reads = aggregator.run().await?;
+/// let (balance, supply) = (reads.get(balance)?, reads.get(supply)?);
+/// ```
+///
+/// Calls are only simulated, whatever they do to the state of the contracts is discarded. A
+/// request that runs out of gas is split in two and both halves are retried.
+pub struct ReadAggregator<T: Account> {
+    account: T,
+    calls: Vec<ContractCall>,
+    log_decoder: LogDecoder,
+    decoder_config: DecoderConfig,
+    tx_policies: TxPolicies,
+    max_calls_per_request: Option<usize>,
+}
+
+impl<T: Account> ReadAggregator<T> {
+    pub fn new(account: T) -> Self {
+        Self {
+            account,
+            calls: vec![],
+            log_decoder: LogDecoder::new(Default::default()),
+            decoder_config: DecoderConfig::default(),
+            tx_policies: TxPolicies::default(),
+            max_calls_per_request: None,
+        }
+    }
+
+    /// Queues the call of `call_handler`. The returned handle gets its result out of
+    /// [`AggregatedReads`].
+    pub fn add<D>(&mut self, call_handler: ContractCallHandler<impl Account, D>) -> ReadHandle<D>
+    where
+        D: Tokenizable + Parameterize + Debug,
+    {
+        self.log_decoder.merge(call_handler.log_decoder);
+        self.calls.push(call_handler.contract_call);
+
+        ReadHandle {
+            index: self.calls.len() - 1,
+            _output: PhantomData,
+        }
+    }
+
+    /// Puts at most `max_calls_per_request` calls into a single simulation, on top of the
+    /// limits of the chain.
+    pub fn with_max_calls_per_request(mut self, max_calls_per_request: usize) -> Self {
+        self.max_calls_per_request = Some(max_calls_per_request);
+        self
+    }
+
+    pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
+        self.decoder_config = decoder_config;
+        self.log_decoder.set_decoder_config(decoder_config);
+        self
+    }
+
+    pub fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
+        self.tx_policies = tx_policies;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Simulates all the calls. Fails if any of them reverts.
+    pub async fn run(mut self) -> Result<AggregatedReads> {
+        let provider = self.account.try_provider()?;
+        let limits =
+            RequestLimits::new(provider.consensus_parameters(), self.max_calls_per_request);
+
+        let packing = pack(&self.calls_footprints()?, &limits)?;
+        let calls = std::mem::take(&mut self.calls);
+        let mut tokens = vec![None; calls.len()];
+        let mut pending = Batch::split_into(calls, packing);
+        let mut requests = 0;
+
+        while !pending.is_empty() {
+            let batches = std::mem::take(&mut pending);
+            requests += batches.len();
+
+            let outcomes = try_join_all(batches.iter().map(|batch| self.simulate(batch))).await?;
+            for (batch, outcome) in zip(batches, outcomes) {
+                match outcome {
+                    Some(batch_tokens) => zip(batch.indices, batch_tokens)
+                        .for_each(|(index, token)| tokens[index] = Some(token)),
+                    None => pending.extend(batch.halves()),
+                }
+            }
+        }
+
+        let tokens = tokens
+            .into_iter()
+            .map(|token| token.expect("every call was simulated"))
+            .collect();
+
+        Ok(AggregatedReads { tokens, requests })
+    }
+
+    fn calls_footprints(&self) -> Result<Vec<Footprint>> {
+        self.calls.iter().map(Footprint::new).collect()
+    }
+
+    /// The tokens of the calls of `batch`, or `None` if it ran out of gas and has more than one
+    /// call to split.
+    async fn simulate(&self, batch: &Batch) -> Result<Option<Vec<Token>>> {
+        let provider = self.account.try_provider()?;
+        let tx = build_tx_from_contract_calls(&batch.calls, self.tx_policies, &self.account, None)
+            .await?;
+
+        let tx_status = provider.checked_dry_run(tx).await?;
+        if batch.calls.len() > 1 && ran_out_of_gas(&tx_status) {
+            return Ok(None);
+        }
+        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
+
+        let outputs = batch
+            .calls
+            .iter()
+            .map(|call| (Some(&call.contract_id), &call.output_param))
+            .collect::<Vec<_>>();
+
+        ReceiptParser::new(&receipts, self.decoder_config)
+            .parse_all(&outputs)
+            .map(Some)
+    }
+}
+
+/// Refers to the result of a call queued with [`ReadAggregator::add`].
+#[derive(Debug)]
+pub struct ReadHandle<D> {
+    index: usize,
+    _output: PhantomData<fn() -> D>,
+}
+
+impl<D> Clone for ReadHandle<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for ReadHandle<D> {}
+
+impl<D> ReadHandle<D> {
+    /// The position of the call among the calls of the aggregator.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// The results of the calls of a [`ReadAggregator`].
+#[derive(Debug, Clone)]
+pub struct AggregatedReads {
+    tokens: Vec<Token>,
+    requests: usize,
+}
+
+impl AggregatedReads {
+    pub fn get<D: Tokenizable>(&self, handle: ReadHandle<D>) -> Result<D> {
+        let token = self.tokens.get(handle.index).ok_or_else(|| {
+            error!(
+                Other,
+                "no read at position {}: the handle belongs to another aggregator", handle.index
+            )
+        })?;
+
+        D::from_token(token.clone())
+    }
+
+    /// The results of all the calls, in the order they were added.
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    pub fn into_tokens(self) -> Vec<Token> {
+        self.tokens
+    }
+
+    /// How many simulations were sent to the node, retries included.
+    pub fn requests(&self) -> usize {
+        self.requests
+    }
+}
+
+/// What a request can hold, according to the consensus parameters of the chain.
+#[derive(Debug, Clone, Copy)]
+struct RequestLimits {
+    max_script_length: usize,
+    max_script_data_length: usize,
+    max_inputs: usize,
+    max_outputs: usize,
+    max_calls: usize,
+}
+
+impl RequestLimits {
+    fn new(consensus_parameters: &ConsensusParameters, max_calls: Option<usize>) -> Self {
+        let script_params = consensus_parameters.script_params();
+        let tx_params = consensus_parameters.tx_params();
+        let to_usize = |value: u64| usize::try_from(value).unwrap_or(usize::MAX);
+
+        Self {
+            max_script_length: to_usize(script_params.max_script_length),
+            max_script_data_length: to_usize(script_params.max_script_data_length),
+            max_inputs: to_usize(tx_params.max_inputs.into())
+                .saturating_sub(RESERVED_INPUTS_AND_OUTPUTS),
+            max_outputs: to_usize(tx_params.max_outputs.into())
+                .saturating_sub(RESERVED_INPUTS_AND_OUTPUTS),
+            max_calls: max_calls.unwrap_or(usize::MAX),
+        }
+    }
+}
+
+/// The share of a request a single call takes. The script and script data of a multicall are
+/// the concatenation of those of its calls.
+#[derive(Debug, Clone)]
+struct Footprint {
+    script_length: usize,
+    script_data_length: usize,
+    contracts: HashSet<ContractId>,
+    variable_outputs: usize,
+    returns_heap_type: bool,
+}
+
+impl Footprint {
+    fn new(call: &ContractCall) -> Result<Self> {
+        let calls = slice::from_ref(call);
+        let (script_data, _) = build_script_data_from_contract_calls(calls, 0)?;
+
+        Ok(Self {
+            script_length: compute_calls_instructions_len(calls)?,
+            script_data_length: script_data.len(),
+            contracts: call
+                .external_contracts
+                .iter()
+                .chain([&call.contract_id])
+                .map(Into::into)
+                .collect(),
+            variable_outputs: call.variable_outputs.len(),
+            returns_heap_type: call.output_param.is_extra_receipt_needed(true),
+        })
+    }
+}
+
+/// The calls packed into a request so far.
+#[derive(Debug, Default)]
+struct Packing {
+    indices: Vec<usize>,
+    script_length: usize,
+    script_data_length: usize,
+    contracts: HashSet<ContractId>,
+    variable_outputs: usize,
+    has_heap_type_call: bool,
+}
+
+impl Packing {
+    fn new() -> Self {
+        Self {
+            // the instruction returning from the script
+            script_length: Instruction::SIZE,
+            ..Default::default()
+        }
+    }
+
+    fn fits(&self, footprint: &Footprint, limits: &RequestLimits) -> bool {
+        let contracts = self.contracts.union(&footprint.contracts).count();
+
+        self.indices.len() < limits.max_calls
+            && self.script_length + footprint.script_length <= limits.max_script_length
+            && self.script_data_length + footprint.script_data_length
+                <= limits.max_script_data_length
+            && contracts <= limits.max_inputs
+            && contracts + self.variable_outputs + footprint.variable_outputs <= limits.max_outputs
+    }
+
+    fn push(&mut self, index: usize, footprint: &Footprint) {
+        self.indices.push(index);
+        self.script_length += footprint.script_length;
+        self.script_data_length += footprint.script_data_length;
+        self.contracts.extend(footprint.contracts.iter().copied());
+        self.variable_outputs += footprint.variable_outputs;
+        self.has_heap_type_call |= footprint.returns_heap_type;
+    }
+}
+
+/// Groups the calls into as few requests as possible, keeping them in order within each
+/// request. A multicall can only return one heap type, from its last call, so calls returning
+/// heap types close the requests they are added to.
+fn pack(footprints: &[Footprint], limits: &RequestLimits) -> Result<Vec<Vec<usize>>> {
+    for (index, footprint) in footprints.iter().enumerate() {
+        if !Packing::new().fits(footprint, limits) {
+            return Err(error!(
+                Other,
+                "the read at position {index} does not fit into a transaction on its own"
+            ));
+        }
+    }
+
+    let (heap_type_calls, other_calls): (Vec<_>, Vec<_>) = footprints
+        .iter()
+        .enumerate()
+        .partition(|(_, footprint)| footprint.returns_heap_type);
+
+    let mut packings = vec![Packing::new()];
+    for (index, footprint) in other_calls {
+        let current = packings.last_mut().expect("there is always a packing");
+        if !current.fits(footprint, limits) {
+            packings.push(Packing::new());
+        }
+        packings
+            .last_mut()
+            .expect("there is always a packing")
+            .push(index, footprint);
+    }
+
+    for (index, footprint) in heap_type_calls {
+        match packings
+            .iter_mut()
+            .find(|packing| !packing.has_heap_type_call && packing.fits(footprint, limits))
+        {
+            Some(packing) => packing.push(index, footprint),
+            None => {
+                let mut packing = Packing::new();
+                packing.push(index, footprint);
+                packings.push(packing);
+            }
+        }
+    }
+
+    Ok(packings
+        .into_iter()
+        .map(|packing| packing.indices)
+        .filter(|indices| !indices.is_empty())
+        .collect())
+}
+
+/// The calls of a request, along with their positions among all the calls.
+struct Batch {
+    indices: Vec<usize>,
+    calls: Vec<ContractCall>,
+}
+
+impl Batch {
+    fn split_into(calls: Vec<ContractCall>, packing: Vec<Vec<usize>>) -> Vec<Self> {
+        let mut calls = calls.into_iter().map(Some).collect::<Vec<_>>();
+
+        packing
+            .into_iter()
+            .map(|indices| {
+                let calls = indices
+                    .iter()
+                    .map(|&index| calls[index].take().expect("calls are packed once"))
+                    .collect();
+
+                Self { indices, calls }
+            })
+            .collect()
+    }
+
+    /// Splits the batch in two. The call returning a heap type, if any, stays last.
+    fn halves(mut self) -> [Self; 2] {
+        let middle = self.calls.len() / 2;
+        let second = Self {
+            indices: self.indices.split_off(middle),
+            calls: self.calls.split_off(middle),
+        };
+
+        [self, second]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::param_types::ParamType;
+
+    use super::*;
+
+    fn limits(max_calls: usize) -> RequestLimits {
+        RequestLimits {
+            max_script_length: usize::MAX,
+            max_script_data_length: usize::MAX,
+            max_inputs: usize::MAX,
+            max_outputs: usize::MAX,
+            max_calls,
+        }
+    }
+
+    fn footprints(output_params: &[ParamType]) -> Result<Vec<Footprint>> {
+        output_params
+            .iter()
+            .map(|output_param| {
+                let mut call = ContractCall::new_with_random_id();
+                call.output_param = output_param.clone();
+
+                Footprint::new(&call)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn calls_are_packed_in_order_up_to_the_limits() -> Result<()> {
+        let footprints = footprints(&[ParamType::U64; 5])?;
+
+        assert_eq!(
+            pack(&footprints, &limits(usize::MAX))?,
+            vec![vec![0, 1, 2, 3, 4]]
+        );
+        assert_eq!(
+            pack(&footprints, &limits(2))?,
+            vec![vec![0, 1], vec![2, 3], vec![4]]
+        );
+
+        let one_contract_per_request = RequestLimits {
+            max_inputs: 1,
+            ..limits(usize::MAX)
+        };
+        assert_eq!(
+            pack(&footprints[..2], &one_contract_per_request)?,
+            vec![vec![0], vec![1]]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn heap_type_calls_close_their_requests() -> Result<()> {
+        let footprints = footprints(&[
+            ParamType::Vector(Box::new(ParamType::U8)),
+            ParamType::U64,
+            ParamType::String,
+            ParamType::U64,
+        ])?;
+
+        assert_eq!(pack(&footprints, &limits(3))?, vec![vec![1, 3, 0], vec![2]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn calls_too_big_for_any_request_are_rejected() -> Result<()> {
+        let footprints = footprints(&[ParamType::U64])?;
+        let no_script_data = RequestLimits {
+            max_script_data_length: 0,
+            ..limits(usize::MAX)
+        };
+
+        let err = pack(&footprints, &no_script_data).expect_err("should not fit");
+
+        assert_eq!(
+            err.to_string(),
+            "the read at position 0 does not fit into a transaction on its own"
+        );
+
+        Ok(())
+    }
+}
@@ -9,6 +9,15 @@ use fuels_core::{
     },
 };
 use itertools::Itertools;
+#[cfg(feature = "parallel-decoding")]
+use rayon::prelude::*;
+
+/// The data of the receipts matched for a single output, not yet decoded.
+enum RawOutput {
+    Data(Vec<u8>),
+    /// One entry per element of a vector of heap types.
+    HeapElements(Vec<Vec<u8>>),
+}
 
 pub struct ReceiptParser {
     receipts: Vec<Receipt>,
@@ -38,18 +47,69 @@ impl ReceiptParser {
         contract_id: Option<&Bech32ContractId>,
         output_param: &ParamType,
     ) -> Result<Token> {
+        let output = self.extract_output(contract_id, output_param)?;
+
+        self.decode_output(output_param, &output)
+    }
+
+    /// Like calling [`ReceiptParser::parse`] for every `(contract_id, output_param)` pair, in
+    /// order. The receipts have to be matched sequentially, but with the `parallel-decoding`
+    /// feature the matched data is then decoded in parallel.
+    pub fn parse_all(
+        &mut self,
+        outputs: &[(Option<&Bech32ContractId>, &ParamType)],
+    ) -> Result<Vec<Token>> {
+        let extracted = outputs
+            .iter()
+            .map(|(contract_id, output_param)| {
+                Ok((
+                    *output_param,
+                    self.extract_output(*contract_id, output_param)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        #[cfg(feature = "parallel-decoding")]
+        let extracted = extracted.into_par_iter();
+        #[cfg(not(feature = "parallel-decoding"))]
+        let extracted = extracted.into_iter();
+
+        extracted
+            .map(|(output_param, output)| self.decode_output(output_param, &output))
+            .collect()
+    }
+
+    fn extract_output(
+        &mut self,
+        contract_id: Option<&Bech32ContractId>,
+        output_param: &ParamType,
+    ) -> Result<RawOutput> {
         let contract_id = contract_id
             .map(Into::into)
             // During a script execution, the script's contract id is the **null** contract id
             .unwrap_or_else(ContractId::zeroed);
 
+        if let Some(element_type) = output_param.nested_heap_element() {
+            return self
+                .extract_nested_heap_data(element_type, &contract_id)
+                .map(RawOutput::HeapElements)
+                .ok_or_else(|| Self::missing_receipts_error(output_param));
+        }
+
         output_param.validate_is_decodable(self.decoder.config.max_depth)?;
 
-        let data = self
-            .extract_raw_data(output_param, &contract_id)
-            .ok_or_else(|| Self::missing_receipts_error(output_param))?;
+        self.extract_raw_data(output_param, &contract_id)
+            .map(RawOutput::Data)
+            .ok_or_else(|| Self::missing_receipts_error(output_param))
+    }
 
-        self.decoder.decode(output_param, &data)
+    fn decode_output(&self, output_param: &ParamType, output: &RawOutput) -> Result<Token> {
+        match output {
+            RawOutput::Data(data) => self.decoder.decode(output_param, data),
+            RawOutput::HeapElements(elements) => {
+                self.decoder.decode_heap_elements(output_param, elements)
+            }
+        }
     }
 
     fn missing_receipts_error(output_param: &ParamType) -> Error {
@@ -65,7 +125,7 @@ impl ReceiptParser {
         contract_id: &ContractId,
     ) -> Option<Vec<u8>> {
         let extra_receipts_needed = output_param.is_extra_receipt_needed(true);
-        match output_param.get_return_location() {
+        match output_param.return_location() {
             ReturnLocation::ReturnData
                 if extra_receipts_needed && matches!(output_param, ParamType::Enum { .. }) =>
             {
@@ -151,6 +211,51 @@ impl ReceiptParser {
         None
     }
 
+    fn extract_nested_heap_data(
+        &mut self,
+        element_type: &ParamType,
+        contract_id: &ContractId,
+    ) -> Option<Vec<Vec<u8>>> {
+        // Same as for any other vector, the first ReturnData receipt holds the vec struct, i.e.
+        // (ptr, cap, len). The calling script then returns, in the second one, the vector's
+        // buffer, i.e. the (ptr, cap, len) of every element, followed by the data of every
+        // element (see `extract_nested_heap_data` in `call_utils`).
+        let (index, (vec_struct, data)) =
+            self.receipts.iter().tuple_windows().enumerate().find_map(
+                |(index, (current_receipt, next_receipt))| {
+                    match Self::extract_heap_data_from_receipts(
+                        current_receipt,
+                        next_receipt,
+                        contract_id,
+                    ) {
+                        (Some(vec_struct), Some(data)) => Some((index, (vec_struct, data))),
+                        _ => None,
+                    }
+                },
+            )?;
+
+        let num_of_elements = read_word(vec_struct, 2)? as usize;
+        let element_width = element_type.compute_encoding_in_bytes().ok()?;
+        let inner_type_byte_size = element_type.heap_inner_element_size(false).ok()??;
+
+        let buffer_len = num_of_elements.checked_mul(element_width)?;
+        let (buffer, mut elements_data) = (data.get(..buffer_len)?, data.get(buffer_len..)?);
+        let elements = buffer
+            .chunks(element_width)
+            .map(|element| {
+                let len = (read_word(element, 2)? as usize).checked_mul(inner_type_byte_size)?;
+                let (element_data, rest) = (elements_data.get(..len)?, elements_data.get(len..)?);
+                elements_data = rest;
+
+                Some(element_data.to_vec())
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        self.receipts.drain(index..=index + 1);
+
+        Some(elements)
+    }
+
     fn extract_heap_data_from_receipts<'a>(
         current_receipt: &'a Receipt,
         next_receipt: &'a Receipt,
@@ -181,6 +286,13 @@ impl ReceiptParser {
     }
 }
 
+/// The big-endian word at `index` of `bytes`.
+fn read_word(bytes: &[u8], index: usize) -> Option<u64> {
+    let word = bytes.get(index * 8..(index + 1) * 8)?;
+
+    Some(u64::from_be_bytes(word.try_into().ok()?))
+}
+
 #[cfg(test)]
 mod tests {
     use fuel_tx::ScriptExecutionResult;
@@ -351,4 +463,68 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn receipt_parser_extract_nested_heap_data() -> Result<()> {
+        let expected_receipts = get_relevant_receipts();
+        let contract_id = target_contract();
+
+        let mut receipts = expected_receipts.clone();
+        let words = |words: &[u64]| -> Vec<u8> {
+            words.iter().flat_map(|word| word.to_be_bytes()).collect()
+        };
+        // the vec struct: (ptr, cap, len)
+        receipts.push(get_return_data_receipt(
+            target_contract(),
+            &words(&[1000, 2, 2]),
+        ));
+        // the (ptr, cap, len) of both elements, then their data
+        let buffer: Vec<u8> = [
+            words(&[2000, 5, 5]),
+            words(&[3000, 0, 0]),
+            b"hello".to_vec(),
+        ]
+        .concat();
+        receipts.push(get_return_data_receipt(Default::default(), &buffer));
+        let mut parser = ReceiptParser::new(&receipts, Default::default());
+
+        let token = parser
+            .parse(Some(&contract_id.into()), &<Vec<String>>::param_type())
+            .expect("parsing should succeed");
+
+        assert_eq!(
+            <Vec<String>>::from_token(token)?,
+            vec!["hello".to_string(), "".to_string()]
+        );
+        assert_eq!(parser.receipts, expected_receipts);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receipt_parser_parse_all_keeps_the_order_of_outputs() -> Result<()> {
+        let expected_receipts = get_relevant_receipts();
+        let contract_id = target_contract();
+        let other_contract_id = ContractId::from([2u8; 32]);
+
+        let mut receipts = expected_receipts.clone();
+        receipts.push(get_return_receipt(other_contract_id, RECEIPT_VAL));
+        receipts.push(get_return_data_receipt(contract_id, RECEIPT_DATA));
+        let mut parser = ReceiptParser::new(&receipts, Default::default());
+
+        let array_type = <[u8; 3]>::param_type();
+        let u64_type = u64::param_type();
+        let tokens = parser.parse_all(&[
+            (Some(&contract_id.into()), &array_type),
+            (Some(&other_contract_id.into()), &u64_type),
+        ])?;
+
+        assert_eq!(
+            <([u8; 3], u64)>::from_token(Token::Tuple(tokens))?,
+            (*DECODED_DATA, RECEIPT_VAL)
+        );
+        assert_eq!(parser.receipts, expected_receipts);
+
+        Ok(())
+    }
 }
@@ -1,13 +1,13 @@
-use std::{collections::HashSet, fmt::Debug, marker::PhantomData};
+use std::{collections::HashSet, fmt::Debug, marker::PhantomData, time::Duration};
 
 use fuel_tx::{Bytes32, ContractId, Output, Receipt};
 use fuel_types::bytes::padded_len_usize;
 use fuels_accounts::{
     provider::{Provider, TransactionCost},
-    Account,
+    Account, SubmittingAccount,
 };
 use fuels_core::{
-    codec::{DecoderConfig, LogDecoder},
+    codec::{flatten_output, DecoderConfig, LogDecoder},
     error,
     offsets::base_offset_script,
     traits::{Parameterize, Tokenizable},
@@ -29,9 +29,10 @@ use crate::{
     call_response::FuelCallResponse,
     call_utils::{
         generate_contract_inputs, generate_contract_outputs, new_variable_outputs, sealed,
-        TxDependencyExtension,
+        with_timeout, TxDependencyExtension,
     },
     contract::SettableContract,
+    gas_profiler,
     receipt_parser::ReceiptParser,
     submit_response::SubmitResponse,
 };
@@ -84,6 +85,8 @@ pub struct ScriptCallHandler<T: Account, D> {
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     decoder_config: DecoderConfig,
+    timeout: Option<Duration>,
+    flatten_output: bool,
     pub account: T,
     pub provider: Provider,
     pub datatype: PhantomData<D>,
@@ -118,6 +121,8 @@ where
             datatype: PhantomData,
             log_decoder,
             decoder_config: DecoderConfig::default(),
+            timeout: None,
+            flatten_output: false,
         }
     }
 
@@ -133,12 +138,34 @@ where
         self
     }
 
+    /// Sets a deadline for `call` and `simulate`. If it passes before the node responds, they
+    /// return [`Error::Timeout`] and the request in flight is aborted. A transaction that was
+    /// already sent by `call` may still be included in a block.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// instance.main(...).with_timeout(Duration::from_secs(5)).call()
+    /// ```
+    ///
+    /// [`Error::Timeout`]: fuels_core::types::errors::Error::Timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
         self.decoder_config = decoder_config;
         self.log_decoder.set_decoder_config(decoder_config);
         self
     }
 
+    /// Also breaks the returned value into its elements, available as the `flattened_value` of
+    /// the response. Scripts returning a tuple get one value per element, named after its index.
+    pub fn with_flattened_output(mut self, flatten_output: bool) -> Self {
+        self.flatten_output = flatten_output;
+        self
+    }
+
     pub fn with_outputs(mut self, outputs: Vec<Output>) -> Self {
         self.script_call = self.script_call.with_outputs(outputs);
         self
@@ -230,31 +257,33 @@ where
     /// it will be a bool, works also for structs thanks to the `abigen!()`).
     /// The other field of [`FuelCallResponse`], `receipts`, contains the receipts of the transaction.
     async fn call_or_simulate(&mut self, simulate: bool) -> Result<FuelCallResponse<D>> {
-        let tx = self.build_tx().await?;
-
-        self.cached_tx_id = Some(tx.id(self.provider.chain_id()));
-
-        let tx_status = if simulate {
-            self.provider.checked_dry_run(tx).await?
-        } else {
-            self.provider.send_transaction_and_await_commit(tx).await?
-        };
+        let timeout = self.timeout;
+        let (tx_status, tx_bytes) = with_timeout(timeout, async {
+            let tx = self.build_tx().await?;
+            let tx_bytes = tx.metered_bytes_size();
+
+            self.cached_tx_id = Some(tx.id(self.provider.chain_id()));
+
+            let tx_status = if simulate {
+                self.provider.checked_dry_run(tx).await
+            } else {
+                self.provider.send_transaction_and_await_commit(tx).await
+            }?;
+
+            Ok((tx_status, tx_bytes))
+        })
+        .await?;
         let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
 
-        self.get_response(receipts)
-    }
-
-    /// Call a script on the node, in a state-modifying manner.
-    pub async fn call(mut self) -> Result<FuelCallResponse<D>> {
-        self.call_or_simulate(false).await
-    }
-
-    pub async fn submit(mut self) -> Result<SubmitResponse<T, D>> {
-        let tx = self.build_tx().await?;
-        let tx_id = self.provider.send_transaction(tx).await?;
-        self.cached_tx_id = Some(tx_id);
+        let response = self.get_response(receipts)?;
+        gas_profiler::record(
+            || "script".to_string(),
+            response.gas_used,
+            tx_bytes,
+            simulate,
+        );
 
-        Ok(SubmitResponse::new(tx_id, self))
+        Ok(response)
     }
 
     /// Call a script on the node, in a simulated manner, meaning the state of the
@@ -285,13 +314,17 @@ where
     pub fn get_response(&self, receipts: Vec<Receipt>) -> Result<FuelCallResponse<D>> {
         let token =
             ReceiptParser::new(&receipts, self.decoder_config).parse(None, &D::param_type())?;
+        let flattened_value = self.flatten_output.then(|| flatten_output(token.clone()));
 
-        Ok(FuelCallResponse::new(
+        let mut response = FuelCallResponse::new(
             D::from_token(token)?,
             receipts,
             self.log_decoder.clone(),
             self.cached_tx_id,
-        ))
+        );
+        response.flattened_value = flattened_value;
+
+        Ok(response)
     }
 
     /// Create a [`FuelCallResponse`] from `TxStatus`
@@ -302,6 +335,24 @@ where
     }
 }
 
+impl<T: SubmittingAccount, D> ScriptCallHandler<T, D>
+where
+    D: Parameterize + Tokenizable + Debug,
+{
+    /// Call a script on the node, in a state-modifying manner.
+    pub async fn call(mut self) -> Result<FuelCallResponse<D>> {
+        self.call_or_simulate(false).await
+    }
+
+    pub async fn submit(mut self) -> Result<SubmitResponse<T, D>> {
+        let tx = self.build_tx().await?;
+        let tx_id = self.provider.send_transaction(tx).await?;
+        self.cached_tx_id = Some(tx_id);
+
+        Ok(SubmitResponse::new(tx_id, self))
+    }
+}
+
 impl<T: Account, D> sealed::Sealed for ScriptCallHandler<T, D> {}
 
 #[async_trait::async_trait]
@@ -0,0 +1,217 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use fuel_tx::{AssetId, ContractId, Receipt};
+use fuels_core::types::{
+    bech32::Bech32ContractId, transaction::TxPolicies, unresolved_bytes::UnresolvedBytes, Selector,
+};
+
+use crate::contract::ContractCall;
+
+/// Keeps the receipts of `simulate`d contract calls for the block they were simulated at, so
+/// that applications polling the same views, e.g. dashboards, ask the node for the latest block
+/// height instead of dry-running every call again. The entries are dropped as soon as a new
+/// block is seen.
+///
+/// Only `simulate` goes through the cache, `call` and `submit` always reach the node. Clones
+/// share their entries.
+///
+/// ```ignore
+/// let contract = MyContract::new(MyContractId::new(contract_id), wallet)
+///     .with_simulation_cache(SimulationCache::new());
+/// // Dry-run once per block, no matter how often it is polled.
+/// let total = contract.methods().total_supply().simulate().await?.value;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SimulationCache {
+    state: Arc<Mutex<CacheState>>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    block_height: u32,
+    entries: HashMap<SimulationKey, CachedSimulation>,
+}
+
+impl CacheState {
+    fn advance_to(&mut self, block_height: u32) {
+        if block_height > self.block_height {
+            self.block_height = block_height;
+            self.entries.clear();
+        }
+    }
+}
+
+/// What a simulation depends on besides the state of the chain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct SimulationKey {
+    contract_id: ContractId,
+    selector: Selector,
+    encoded_args: UnresolvedBytes,
+    amount: u64,
+    asset_id: AssetId,
+    gas_forwarded: Option<u64>,
+    external_contracts: Vec<Bech32ContractId>,
+    tx_policies: TxPolicies,
+}
+
+impl SimulationKey {
+    /// `None` if the arguments of the call couldn't be encoded, as its simulation fails anyway.
+    pub(crate) fn for_call(call: &ContractCall, tx_policies: TxPolicies) -> Option<Self> {
+        Some(Self {
+            contract_id: (&call.contract_id).into(),
+            selector: call.encoded_selector,
+            encoded_args: call.encoded_args.as_ref().ok()?.clone(),
+            amount: call.call_parameters.amount(),
+            asset_id: call.call_parameters.asset_id(),
+            gas_forwarded: call.call_parameters.gas_forwarded(),
+            external_contracts: call.external_contracts.clone(),
+            tx_policies,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CachedSimulation {
+    pub receipts: Vec<Receipt>,
+    pub gas_limit: Option<u64>,
+}
+
+impl SimulationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of simulations kept for the latest block seen.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every entry, so that the next simulations reach the node even within the same
+    /// block.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    pub(crate) fn get(&self, block_height: u32, key: &SimulationKey) -> Option<CachedSimulation> {
+        let mut state = self.lock();
+        state.advance_to(block_height);
+
+        if state.block_height != block_height {
+            // The node answering this call lags behind one that answered an earlier call.
+            return None;
+        }
+
+        state.entries.get(key).cloned()
+    }
+
+    pub(crate) fn insert(
+        &self,
+        block_height: u32,
+        key: SimulationKey,
+        simulation: CachedSimulation,
+    ) {
+        let mut state = self.lock();
+        state.advance_to(block_height);
+
+        if state.block_height == block_height {
+            state.entries.insert(key, simulation);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(selector: u8) -> SimulationKey {
+        SimulationKey {
+            contract_id: ContractId::zeroed(),
+            selector: [selector; 8],
+            encoded_args: UnresolvedBytes::default(),
+            amount: 0,
+            asset_id: AssetId::zeroed(),
+            gas_forwarded: None,
+            external_contracts: vec![],
+            tx_policies: TxPolicies::default(),
+        }
+    }
+
+    fn simulation(gas_limit: u64) -> CachedSimulation {
+        CachedSimulation {
+            receipts: vec![],
+            gas_limit: Some(gas_limit),
+        }
+    }
+
+    #[test]
+    fn entries_are_dropped_on_a_new_block() {
+        let cache = SimulationCache::new();
+        cache.insert(5, key(1), simulation(10));
+        cache.insert(5, key(2), simulation(20));
+
+        assert_eq!(cache.get(5, &key(1)).unwrap().gas_limit, Some(10));
+        assert_eq!(cache.len(), 2);
+
+        assert!(cache.get(6, &key(1)).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn lagging_block_heights_neither_hit_nor_evict() {
+        let cache = SimulationCache::new();
+        cache.insert(5, key(1), simulation(10));
+
+        assert!(cache.get(4, &key(1)).is_none());
+        cache.insert(4, key(2), simulation(20));
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(5, &key(1)).is_some());
+    }
+
+    #[test]
+    fn calls_differing_in_gas_contracts_or_policies_dont_share_entries() {
+        let cache = SimulationCache::new();
+        cache.insert(5, key(1), simulation(10));
+
+        let with_gas_forwarded = SimulationKey {
+            gas_forwarded: Some(100),
+            ..key(1)
+        };
+        let with_external_contracts = SimulationKey {
+            external_contracts: vec![ContractId::zeroed().into()],
+            ..key(1)
+        };
+        let with_tx_policies = SimulationKey {
+            tx_policies: TxPolicies::default().with_script_gas_limit(1000),
+            ..key(1)
+        };
+
+        assert!(cache.get(5, &with_gas_forwarded).is_none());
+        assert!(cache.get(5, &with_external_contracts).is_none());
+        assert!(cache.get(5, &with_tx_policies).is_none());
+    }
+
+    #[test]
+    fn clones_share_entries() {
+        let cache = SimulationCache::new();
+        cache.clone().insert(5, key(1), simulation(10));
+
+        assert!(cache.get(5, &key(1)).is_some());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use fuels_accounts::Account;
+use fuels_core::types::{errors::Result, AssetId, Bits256, Identity};
+
+use crate::{
+    call_utils::TxDependencyExtension,
+    contract::{CallParameters, ContractCallHandler, SettableContract},
+};
+
+/// Native asset management for contracts implementing the SRC-3 mint and burn standard.
+///
+/// `abigen!` implements this trait for every contract whose ABI contains the SRC-3 `mint` and
+/// `burn` functions. Its methods are prefixed with `src3_` so that they don't clash with the
+/// methods generated for the contract's own functions.
+pub trait Src3<T: Account>: SettableContract {
+    /// Handler calling the contract's `mint` function as is.
+    fn mint_call(
+        &self,
+        recipient: Identity,
+        sub_id: Bits256,
+        amount: u64,
+    ) -> ContractCallHandler<T, ()>;
+
+    /// Handler calling the contract's `burn` function as is.
+    fn burn_call(&self, sub_id: Bits256, amount: u64) -> ContractCallHandler<T, ()>;
+
+    /// The id of the asset minted by this contract under `sub_id`.
+    fn asset_id(&self, sub_id: &Bits256) -> AssetId {
+        self.id().asset_id(sub_id)
+    }
+
+    /// Mints `amount` of the `sub_id` asset to `recipient`. When the recipient is an address,
+    /// the variable output needed to receive the coins is appended to the transaction.
+    fn src3_mint(
+        &self,
+        recipient: Identity,
+        sub_id: Bits256,
+        amount: u64,
+    ) -> ContractCallHandler<T, ()> {
+        let mints_to_address = matches!(recipient, Identity::Address(_));
+        let handler = self.mint_call(recipient, sub_id, amount);
+
+        if mints_to_address {
+            handler.append_variable_outputs(1)
+        } else {
+            handler
+        }
+    }
+
+    /// Burns `amount` of the `sub_id` asset, forwarding the coins to the contract.
+    fn src3_burn(&self, sub_id: Bits256, amount: u64) -> Result<ContractCallHandler<T, ()>> {
+        let call_parameters = CallParameters::default()
+            .with_amount(amount)
+            .with_asset_id(self.asset_id(&sub_id));
+
+        self.burn_call(sub_id, amount).call_params(call_parameters)
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use fuel_tx::{Bytes32, StorageSlot};
+
+/// A storage slot whose value differs between two snapshots of a contract's storage. A `None`
+/// value means the slot wasn't set in that snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotChange {
+    pub key: Bytes32,
+    pub before: Option<Bytes32>,
+    pub after: Option<Bytes32>,
+}
+
+/// The storage slots that were set, changed or cleared between two snapshots of a contract's
+/// storage, ordered by key.
+///
+/// The node doesn't expose the storage of contracts, let alone at past block heights, so the
+/// snapshots have to come from elsewhere, e.g. the storage slots JSON files written by `forc`
+/// or a node snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageDiff {
+    changes: Vec<SlotChange>,
+}
+
+impl StorageDiff {
+    pub fn between(before: &[StorageSlot], after: &[StorageSlot]) -> Self {
+        let mut slots: BTreeMap<Bytes32, (Option<Bytes32>, Option<Bytes32>)> = BTreeMap::new();
+        for slot in before {
+            slots.entry(*slot.key()).or_default().0 = Some(*slot.value());
+        }
+        for slot in after {
+            slots.entry(*slot.key()).or_default().1 = Some(*slot.value());
+        }
+
+        let changes = slots
+            .into_iter()
+            .filter(|(_, (before, after))| before != after)
+            .map(|(key, (before, after))| SlotChange { key, before, after })
+            .collect();
+
+        Self { changes }
+    }
+
+    pub fn changes(&self) -> &[SlotChange] {
+        &self.changes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_changed_slots_are_reported() {
+        let slot = |key: u8, value: u8| StorageSlot::new([key; 32].into(), [value; 32].into());
+        let before = [slot(1, 1), slot(2, 2), slot(3, 3)];
+        let after = [slot(4, 4), slot(3, 3), slot(2, 5)];
+
+        let diff = StorageDiff::between(&before, &after);
+
+        let expected = vec![
+            SlotChange {
+                key: [1; 32].into(),
+                before: Some([1; 32].into()),
+                after: None,
+            },
+            SlotChange {
+                key: [2; 32].into(),
+                before: Some([2; 32].into()),
+                after: Some([5; 32].into()),
+            },
+            SlotChange {
+                key: [4; 32].into(),
+                before: None,
+                after: Some([4; 32].into()),
+            },
+        ];
+        assert_eq!(diff.changes(), expected);
+        assert!(StorageDiff::between(&before, &before).is_empty());
+    }
+}
@@ -5,6 +5,7 @@ use fuels_accounts::wallet::WalletUnlocked;
 use fuels_core::types::errors::Result;
 
 use crate::{
+    address_book::AddressBook,
     node_types::{ChainConfig, Config},
     setup_custom_assets_coins, setup_test_provider,
     wallets_config::*,
@@ -85,6 +86,72 @@ pub async fn launch_custom_provider_and_get_wallets(
     Ok(wallets)
 }
 
+/// Test wallets that can be looked up by name.
+#[derive(Debug, Clone)]
+pub struct NamedWallets {
+    names: Vec<String>,
+    wallets: Vec<WalletUnlocked>,
+}
+
+impl NamedWallets {
+    /// # Panics
+    /// If no wallet was created under `name`.
+    pub fn named(&self, name: &str) -> &WalletUnlocked {
+        self.names
+            .iter()
+            .position(|known| known == name)
+            .map(|index| &self.wallets[index])
+            .unwrap_or_else(|| panic!("no wallet named `{name}`"))
+    }
+
+    pub fn address_book(&self) -> AddressBook {
+        let mut address_book = AddressBook::new();
+        for (name, wallet) in self.names.iter().zip(&self.wallets) {
+            address_book.insert(name.as_str(), wallet.address());
+        }
+
+        address_book
+    }
+
+    pub fn into_wallets(self) -> Vec<WalletUnlocked> {
+        self.wallets
+    }
+}
+
+/// Like [`launch_custom_provider_and_get_wallets`], creating one wallet for each of the `names`.
+/// The number of wallets set in `wallet_config` is ignored.
+///
+/// # Examples
+/// ```
+/// use fuels_test_helpers::{launch_custom_provider_and_get_named_wallets, WalletsConfig};
+///
+/// async fn named_wallets() -> Result<(), Box<dyn std::error::Error>> {
+///   let names = ["alice", "bob"];
+///   let wallets =
+///       launch_custom_provider_and_get_named_wallets(&names, WalletsConfig::default(), None, None)
+///           .await?;
+///   println!("alice is {}", wallets.named("alice").address());
+///   Ok(())
+/// }
+/// ```
+pub async fn launch_custom_provider_and_get_named_wallets(
+    names: &[&str],
+    wallet_config: WalletsConfig,
+    provider_config: Option<Config>,
+    chain_config: Option<ChainConfig>,
+) -> Result<NamedWallets> {
+    let wallet_config =
+        WalletsConfig::new_multiple_assets(names.len() as u64, wallet_config.assets().to_vec());
+    let wallets =
+        launch_custom_provider_and_get_wallets(wallet_config, provider_config, chain_config)
+            .await?;
+
+    Ok(NamedWallets {
+        names: names.iter().map(ToString::to_string).collect(),
+        wallets,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use fuel_core_chain_config::ChainConfig;
@@ -97,7 +164,10 @@ mod tests {
     };
     use rand::Fill;
 
-    use crate::{launch_custom_provider_and_get_wallets, AssetConfig, WalletsConfig};
+    use crate::{
+        launch_custom_provider_and_get_named_wallets, launch_custom_provider_and_get_wallets,
+        AssetConfig, WalletsConfig,
+    };
 
     #[tokio::test]
     async fn test_wallet_config() -> Result<()> {
@@ -177,6 +247,28 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn wallets_can_be_named() -> Result<()> {
+        let wallets = launch_custom_provider_and_get_named_wallets(
+            &["alice", "bob"],
+            WalletsConfig::default(),
+            None,
+            None,
+        )
+        .await?;
+
+        let alice = wallets.named("alice");
+        let bob = wallets.named("bob");
+        assert_ne!(alice.address(), bob.address());
+        assert!(alice.get_asset_balance(&BASE_ASSET_ID).await? > 0);
+
+        let address_book = wallets.address_book();
+        assert_eq!(address_book.get("bob"), Some(bob.address()));
+        assert_eq!(address_book.name_of(alice.address()), Some("alice"));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn generated_wallets_are_deterministic() -> Result<()> {
         let num_wallets = 32;
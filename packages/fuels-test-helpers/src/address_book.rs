@@ -0,0 +1,113 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Human readable names for addresses, for tests and examples involving several parties.
+///
+/// Address books are stored as TOML tables mapping names to bech32 addresses:
+///
+/// ```toml
+/// alice = "fuel1..."
+/// bob = "fuel1..."
+/// ```
+#[serde_as]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AddressBook {
+    #[serde_as(as = "BTreeMap<_, DisplayFromStr>")]
+    addresses: BTreeMap<String, Bech32Address>,
+}
+
+impl AddressBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Names `address`, returning the address previously known under `name`, if any.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        address: impl Into<Bech32Address>,
+    ) -> Option<Bech32Address> {
+        self.addresses.insert(name.into(), address.into())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Bech32Address> {
+        self.addresses.get(name)
+    }
+
+    pub fn name_of(&self, address: &Bech32Address) -> Option<&str> {
+        self.addresses
+            .iter()
+            .find_map(|(name, known)| (known == address).then_some(name.as_str()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Bech32Address)> {
+        self.addresses
+            .iter()
+            .map(|(name, address)| (name.as_str(), address))
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|e| error!(Other, "invalid address book: {e}"))
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).map_err(|e| error!(Other, "cannot serialize address book: {e}"))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_toml(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_toml()?)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const ALICE: &str = "fuel17x9kg3k7hqf42396vqenukm4yf59e5k0vj4yunr4mae9zjv9pdjszy098t";
+    const BOB: &str = "fuel1tsj7rax2xd7xvvutzl2xh4kwve07syj528plednfpttkcfn4vrrsn3gu76";
+
+    #[test]
+    fn round_trips_through_toml_files() -> Result<()> {
+        let mut book = AddressBook::new();
+        book.insert("alice", Bech32Address::from_str(ALICE)?);
+        book.insert("bob", Bech32Address::from_str(BOB)?);
+
+        assert_eq!(
+            book.to_toml()?,
+            format!("alice = \"{ALICE}\"\nbob = \"{BOB}\"\n")
+        );
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("addresses.toml");
+        book.save(&path)?;
+
+        let loaded = AddressBook::load(&path)?;
+        assert_eq!(loaded, book);
+        assert_eq!(loaded.name_of(&Bech32Address::from_str(BOB)?), Some("bob"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_addresses() {
+        let err = AddressBook::from_toml("alice = \"not an address\"")
+            .expect_err("should fail because of the address");
+
+        assert!(err.to_string().contains("invalid address book"));
+    }
+}
@@ -0,0 +1,124 @@
+//! Funding wallets from a testnet faucet, e.g. to run examples and e2e tests against a public
+//! testnet.
+
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+};
+use serde_json::{json, Value};
+
+/// Environment variable [`FaucetConfig::from_env`] reads the captcha token from.
+pub const FAUCET_CAPTCHA_ENV: &str = "FUEL_FAUCET_CAPTCHA";
+/// Environment variable [`FaucetConfig::from_env`] reads the bearer token from.
+pub const FAUCET_AUTH_TOKEN_ENV: &str = "FUEL_FAUCET_AUTH_TOKEN";
+
+/// Credentials passed through to the faucet. Public faucets are usually protected by a captcha,
+/// solved beforehand in a browser, or by an API token handed out to CI setups.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FaucetConfig {
+    captcha: Option<String>,
+    auth_token: Option<String>,
+}
+
+impl FaucetConfig {
+    /// Reads the credentials from the [`FAUCET_CAPTCHA_ENV`] and [`FAUCET_AUTH_TOKEN_ENV`]
+    /// environment variables, if set.
+    pub fn from_env() -> Self {
+        Self {
+            captcha: std::env::var(FAUCET_CAPTCHA_ENV).ok(),
+            auth_token: std::env::var(FAUCET_AUTH_TOKEN_ENV).ok(),
+        }
+    }
+
+    pub fn with_captcha(mut self, captcha: impl Into<String>) -> Self {
+        self.captcha = Some(captcha.into());
+        self
+    }
+
+    /// Sent as a bearer token in the `Authorization` header.
+    pub fn with_auth_token(mut self, auth_token: impl Into<String>) -> Self {
+        self.auth_token = Some(auth_token.into());
+        self
+    }
+}
+
+/// Asks the faucet at `faucet_url` to send coins to `address`, using the credentials found in
+/// the environment (see [`FaucetConfig::from_env`]). Returns the faucet's response.
+///
+/// ```ignore
+/// let network = Network::Testnet;
+/// request_funds(wallet.address(), network.faucet_url().unwrap()).await?;
+/// ```
+pub async fn request_funds(address: &Bech32Address, faucet_url: &str) -> Result<Value> {
+    request_funds_with_config(address, faucet_url, &FaucetConfig::from_env()).await
+}
+
+pub async fn request_funds_with_config(
+    address: &Bech32Address,
+    faucet_url: &str,
+    config: &FaucetConfig,
+) -> Result<Value> {
+    let url = dispense_url(faucet_url);
+    let body = json!({
+        "address": address.to_string(),
+        "captcha": config.captcha.clone().unwrap_or_default(),
+    });
+
+    let mut request = reqwest::Client::new().post(&url).json(&body);
+    if let Some(auth_token) = &config.auth_token {
+        request = request.bearer_auth(auth_token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| error!(Other, "faucet request to `{url}` failed: {e}"))?;
+    let status = response.status();
+    let response_body = response
+        .text()
+        .await
+        .map_err(|e| error!(Other, "could not read the faucet's response: {e}"))?;
+
+    if !status.is_success() {
+        let reason = serde_json::from_str::<Value>(&response_body)
+            .ok()
+            .and_then(|json| {
+                json.get("error")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            })
+            .unwrap_or(response_body);
+
+        return Err(error!(Other, "faucet responded with {status}: {reason}"));
+    }
+
+    Ok(serde_json::from_str(&response_body).unwrap_or(Value::String(response_body)))
+}
+
+fn dispense_url(faucet_url: &str) -> String {
+    let faucet_url = faucet_url.trim_end_matches('/');
+    let faucet_url = if faucet_url.contains("://") {
+        faucet_url.to_string()
+    } else {
+        format!("https://{faucet_url}")
+    };
+
+    format!("{faucet_url}/dispense")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispense_url_is_derived_from_the_faucet_url() {
+        assert_eq!(
+            dispense_url("https://faucet-beta-5.fuel.network/"),
+            "https://faucet-beta-5.fuel.network/dispense"
+        );
+        assert_eq!(
+            dispense_url("faucet-beta-5.fuel.network"),
+            "https://faucet-beta-5.fuel.network/dispense"
+        );
+    }
+}
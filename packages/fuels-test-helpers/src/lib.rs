@@ -3,6 +3,7 @@ extern crate core;
 
 #[cfg(feature = "fuels-accounts")]
 pub use accounts::*;
+pub use address_book::*;
 use fuel_core_chain_config::StateConfig;
 use fuel_tx::{Bytes32, UtxoId};
 use fuel_types::{AssetId, Nonce};
@@ -18,7 +19,11 @@ use fuels_core::{
 };
 pub use node_types::*;
 use rand::Fill;
+#[cfg(feature = "fuels-accounts")]
+pub use recording::*;
 use utils::{into_coin_configs, into_message_configs};
+#[cfg(feature = "fuels-accounts")]
+pub use wallet_pool::*;
 pub use wallets_config::*;
 mod node_types;
 
@@ -27,11 +32,17 @@ pub(crate) mod fuel_bin_service;
 
 #[cfg(feature = "fuels-accounts")]
 mod accounts;
+mod address_book;
+pub mod faucet;
+#[cfg(feature = "fuels-accounts")]
+mod recording;
 
 pub use service::*;
 mod service;
 
 mod utils;
+#[cfg(feature = "fuels-accounts")]
+mod wallet_pool;
 mod wallets_config;
 
 /// Create a vector of `num_asset`*`coins_per_asset` UTXOs and a vector of the unique corresponding
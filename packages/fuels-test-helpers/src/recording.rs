@@ -0,0 +1,399 @@
+//! VCR-style tests: record what a test exchanges with a node once, then replay it without one.
+//!
+//! A [`ProviderRecorder`] sits between a [`Provider`] and a node and writes down every request
+//! and response into a [`Cassette`]. A [`ProviderReplayer`] later serves the cassette back to a
+//! provider, so application logic built on top of the SDK can be tested deterministically and
+//! without launching a node:
+//!
+//! ```ignore
+//! // once, against a node
+//! let recorder = ProviderRecorder::start(node_url).await?;
+//! run_scenario(recorder.provider().await?).await?;
+//! recorder.cassette().save("tests/cassettes/scenario.json")?;
+//!
+//! // in the test
+//! let replayer = ProviderReplayer::start(Cassette::load("tests/cassettes/scenario.json")?).await?;
+//! run_scenario(replayer.provider().await?).await?;
+//! ```
+//!
+//! Requests are matched on their method, path and body, identical requests getting their
+//! responses in the order they were recorded. The scenario must therefore send the same
+//! requests on every run, e.g. by using wallets with fixed private keys.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+    net::SocketAddr,
+    path::Path,
+    sync::{Arc, Mutex as StdMutex},
+};
+
+use fuels_accounts::provider::Provider;
+use fuels_core::types::errors::{error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+/// A request sent to the node and the response it got.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    pub path: String,
+    pub request: String,
+    pub status: u16,
+    pub content_type: String,
+    pub response: String,
+}
+
+/// Everything recorded by a [`ProviderRecorder`], in the order it happened.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+
+        serde_json::from_str(&contents).map_err(|e| error!(Other, "invalid cassette: {e}"))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| error!(Other, "could not serialize the cassette: {e}"))?;
+
+        Ok(std::fs::write(path, contents)?)
+    }
+}
+
+/// Forwards the requests of providers connected to it to a node, recording them in a
+/// [`Cassette`]. Stops when dropped.
+#[derive(Debug)]
+pub struct ProviderRecorder {
+    addr: SocketAddr,
+    cassette: Arc<StdMutex<Cassette>>,
+    server: JoinHandle<()>,
+}
+
+impl ProviderRecorder {
+    /// Starts recording the traffic to the node at `node_url`, e.g. `127.0.0.1:4000`.
+    pub async fn start(node_url: impl AsRef<str>) -> Result<Self> {
+        let node_url = node_url.as_ref().trim_end_matches('/');
+        let node_url = if node_url.starts_with("http") {
+            node_url.to_string()
+        } else {
+            format!("http://{node_url}")
+        };
+
+        let cassette = Arc::new(StdMutex::new(Cassette::default()));
+        let (addr, server) = serve(Arc::new(Mode::Record {
+            node_url,
+            client: reqwest::Client::new(),
+            cassette: Arc::clone(&cassette),
+        }))
+        .await?;
+
+        Ok(Self {
+            addr,
+            cassette,
+            server,
+        })
+    }
+
+    /// The address providers should connect to in order to be recorded.
+    pub fn url(&self) -> String {
+        self.addr.to_string()
+    }
+
+    /// A provider connected through the recorder.
+    pub async fn provider(&self) -> Result<Provider> {
+        Provider::connect(self.url()).await
+    }
+
+    /// What was recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        lock(&self.cassette).clone()
+    }
+}
+
+impl Drop for ProviderRecorder {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Answers the requests of providers connected to it with the responses of a [`Cassette`], in
+/// place of a node. Requests that weren't recorded get an error response. Stops when dropped.
+#[derive(Debug)]
+pub struct ProviderReplayer {
+    addr: SocketAddr,
+    server: JoinHandle<()>,
+}
+
+impl ProviderReplayer {
+    pub async fn start(cassette: Cassette) -> Result<Self> {
+        let mut recorded: HashMap<RequestKey, VecDeque<Interaction>> = HashMap::new();
+        for interaction in cassette.interactions {
+            recorded
+                .entry(RequestKey::of(&interaction))
+                .or_default()
+                .push_back(interaction);
+        }
+
+        let (addr, server) = serve(Arc::new(Mode::Replay {
+            recorded: StdMutex::new(recorded),
+        }))
+        .await?;
+
+        Ok(Self { addr, server })
+    }
+
+    pub fn url(&self) -> String {
+        self.addr.to_string()
+    }
+
+    /// A provider served by the replayer.
+    pub async fn provider(&self) -> Result<Provider> {
+        Provider::connect(self.url()).await
+    }
+}
+
+impl Drop for ProviderReplayer {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct RequestKey {
+    method: String,
+    path: String,
+    request: String,
+}
+
+impl RequestKey {
+    fn of(interaction: &Interaction) -> Self {
+        Self {
+            method: interaction.method.clone(),
+            path: interaction.path.clone(),
+            request: interaction.request.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Mode {
+    Record {
+        node_url: String,
+        client: reqwest::Client,
+        cassette: Arc<StdMutex<Cassette>>,
+    },
+    Replay {
+        recorded: StdMutex<HashMap<RequestKey, VecDeque<Interaction>>>,
+    },
+}
+
+impl Mode {
+    async fn respond(&self, request: RequestKey) -> Interaction {
+        match self {
+            Mode::Record {
+                node_url,
+                client,
+                cassette,
+            } => {
+                let interaction = forward(client, node_url, &request)
+                    .await
+                    .unwrap_or_else(|e| error_interaction(&request, 502, &e.to_string()));
+                lock(cassette).interactions.push(interaction.clone());
+
+                interaction
+            }
+            Mode::Replay { recorded } => lock(recorded)
+                .get_mut(&request)
+                .and_then(VecDeque::pop_front)
+                .unwrap_or_else(|| {
+                    let reason = format!(
+                        "no recorded response left for {} {} with body: {}",
+                        request.method, request.path, request.request
+                    );
+                    error_interaction(&request, 500, &reason)
+                }),
+        }
+    }
+}
+
+async fn forward(
+    client: &reqwest::Client,
+    node_url: &str,
+    request: &RequestKey,
+) -> reqwest::Result<Interaction> {
+    let method =
+        reqwest::Method::from_bytes(request.method.as_bytes()).unwrap_or(reqwest::Method::POST);
+    let response = client
+        .request(method, format!("{node_url}{}", request.path))
+        .header("Content-Type", "application/json")
+        .body(request.request.clone())
+        .send()
+        .await?;
+
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("application/json")
+        .to_string();
+    // subscriptions are read until the node closes them
+    let body = response.text().await?;
+
+    Ok(Interaction {
+        method: request.method.clone(),
+        path: request.path.clone(),
+        request: request.request.clone(),
+        status,
+        content_type,
+        response: body,
+    })
+}
+
+fn error_interaction(request: &RequestKey, status: u16, reason: &str) -> Interaction {
+    Interaction {
+        method: request.method.clone(),
+        path: request.path.clone(),
+        request: request.request.clone(),
+        status,
+        content_type: "application/json".to_string(),
+        response: json!({ "errors": [{ "message": reason }] }).to_string(),
+    }
+}
+
+async fn serve(mode: Arc<Mode>) -> Result<(SocketAddr, JoinHandle<()>)> {
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+    let addr = listener.local_addr()?;
+
+    let server = tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let mode = Arc::clone(&mode);
+            tokio::spawn(async move {
+                // a failed connection only affects the request that was sent on it
+                let _ = handle_connection(stream, &mode).await;
+            });
+        }
+    });
+
+    Ok((addr, server))
+}
+
+/// Serves a single request per connection, which is all the node client needs.
+async fn handle_connection(mut stream: TcpStream, mode: &Mode) -> io::Result<()> {
+    let request = read_request(&mut stream).await?;
+    let interaction = mode.respond(request).await;
+
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        interaction.status,
+        reason_phrase(interaction.status),
+        interaction.content_type,
+        interaction.response.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(interaction.response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn read_request(stream: &mut TcpStream) -> io::Result<RequestKey> {
+    let mut buffer = vec![];
+    let mut chunk = [0u8; 4096];
+
+    let head_end = loop {
+        if let Some(position) = find_subslice(&buffer, b"\r\n\r\n") {
+            break position + 4;
+        }
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+
+    while buffer.len() < head_end + content_length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+    let body = &buffer[head_end..head_end + content_length];
+
+    Ok(RequestKey {
+        method,
+        path,
+        request: String::from_utf8_lossy(body).to_string(),
+    })
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        _ => "",
+    }
+}
+
+fn lock<T>(mutex: &StdMutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().expect("recorder lock should not be poisoned")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::setup_test_provider;
+
+    #[tokio::test]
+    async fn replayed_responses_match_the_recorded_ones() -> Result<()> {
+        let node_provider = setup_test_provider(vec![], vec![], None, None).await?;
+
+        let recorder = ProviderRecorder::start(node_provider.url()).await?;
+        let recorded_provider = recorder.provider().await?;
+        let recorded_info = recorded_provider.node_info().await?;
+        let cassette = recorder.cassette();
+        drop(recorder);
+
+        let path = tempfile::NamedTempFile::new()?.into_temp_path();
+        cassette.save(&path)?;
+
+        let replayer = ProviderReplayer::start(Cassette::load(&path)?).await?;
+        let replayed_provider = replayer.provider().await?;
+
+        let replayed_info = replayed_provider.node_info().await?;
+        assert_eq!(replayed_info.node_version, recorded_info.node_version);
+        assert_eq!(replayed_info.min_gas_price, recorded_info.min_gas_price);
+        assert_eq!(replayed_provider.chain_id(), recorded_provider.chain_id());
+        // every recorded response is served once
+        assert!(replayed_provider.node_info().await.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Deref,
+    sync::{Arc, Mutex as StdMutex},
+    time::{Duration, Instant},
+};
+
+use fuels_accounts::{wallet::WalletUnlocked, Account, ViewOnlyAccount};
+use fuels_core::{
+    constants::BASE_ASSET_ID,
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+        transaction::TxPolicies,
+        AssetId,
+    },
+};
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+/// How a [`WalletPool`] funds its wallets.
+#[derive(Debug, Clone)]
+pub struct WalletPoolConfig {
+    num_wallets: usize,
+    asset_id: AssetId,
+    top_up_amount: u64,
+    top_up_threshold: u64,
+}
+
+impl WalletPoolConfig {
+    /// `num_wallets` wallets, each funded with `top_up_amount` of the base asset whenever
+    /// its balance is below `top_up_threshold` upon being handed out.
+    pub fn new(num_wallets: usize, top_up_amount: u64, top_up_threshold: u64) -> Result<Self> {
+        if num_wallets == 0 {
+            return Err(error!(Other, "`num_wallets` must be greater than `0`"));
+        }
+
+        Ok(Self {
+            num_wallets,
+            asset_id: BASE_ASSET_ID,
+            top_up_amount,
+            top_up_threshold,
+        })
+    }
+
+    pub fn with_asset_id(mut self, asset_id: AssetId) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    pub fn num_wallets(&self) -> usize {
+        self.num_wallets
+    }
+
+    pub fn asset_id(&self) -> AssetId {
+        self.asset_id
+    }
+
+    pub fn top_up_amount(&self) -> u64 {
+        self.top_up_amount
+    }
+
+    pub fn top_up_threshold(&self) -> u64 {
+        self.top_up_threshold
+    }
+}
+
+/// What a wallet of a [`WalletPool`] went through.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WalletStats {
+    /// How many times the wallet was handed out.
+    pub uses: u64,
+    /// Failures reported with [`PooledWallet::record_failure`].
+    pub failures: u64,
+    /// How many times the bank topped the wallet up, not counting the initial funding.
+    pub top_ups: u64,
+    /// Total time the wallet was held by a task.
+    pub busy_time: Duration,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    bank: Mutex<WalletUnlocked>,
+    config: WalletPoolConfig,
+    idle: StdMutex<VecDeque<WalletUnlocked>>,
+    available: Arc<Semaphore>,
+    stats: StdMutex<HashMap<Bech32Address, WalletStats>>,
+}
+
+/// Hands out funded wallets to concurrent tasks, e.g. for load and soak tests. Each wallet is
+/// used by a single task at a time, which avoids tasks competing for the same coins. Wallets
+/// running low are topped up by the bank wallet before being handed out.
+///
+/// ```ignore
+/// let pool = WalletPool::new(bank, WalletPoolConfig::new(16, 1_000_000, 100_000)?).await?;
+///
+/// let tasks = (0..1000).map(|_| {
+///     let pool = pool.clone();
+///     tokio::spawn(async move {
+///         let wallet = pool.acquire().await?;
+///         // use `wallet` like a `WalletUnlocked`
+///         Ok::<_, Error>(())
+///     })
+/// });
+///
+/// futures::future::join_all(tasks).await;
+/// assert_eq!(pool.total_stats().uses, 1000);
+/// ```
+///
+/// Clones of the pool share the same wallets.
+#[derive(Debug, Clone)]
+pub struct WalletPool {
+    state: Arc<PoolState>,
+}
+
+impl WalletPool {
+    /// Creates `config.num_wallets()` random wallets, connected to the bank's provider, and
+    /// funds each with `config.top_up_amount()` from `bank`.
+    pub async fn new(bank: WalletUnlocked, config: WalletPoolConfig) -> Result<Self> {
+        let provider = bank.try_provider()?.clone();
+        let wallets = (0..config.num_wallets)
+            .map(|_| WalletUnlocked::new_random(Some(provider.clone())))
+            .collect::<Vec<_>>();
+
+        for wallet in &wallets {
+            bank.transfer(
+                wallet.address(),
+                config.top_up_amount,
+                config.asset_id,
+                TxPolicies::default(),
+            )
+            .await?;
+        }
+
+        Ok(Self::from_wallets(bank, wallets, config))
+    }
+
+    /// Pools already funded `wallets`. They are topped up from `bank` like the wallets of
+    /// [`WalletPool::new`].
+    pub fn from_wallets(
+        bank: WalletUnlocked,
+        wallets: Vec<WalletUnlocked>,
+        config: WalletPoolConfig,
+    ) -> Self {
+        let stats = wallets
+            .iter()
+            .map(|wallet| (wallet.address().clone(), WalletStats::default()))
+            .collect();
+
+        Self {
+            state: Arc::new(PoolState {
+                bank: Mutex::new(bank),
+                config,
+                available: Arc::new(Semaphore::new(wallets.len())),
+                idle: StdMutex::new(wallets.into()),
+                stats: StdMutex::new(stats),
+            }),
+        }
+    }
+
+    /// Waits for a wallet nobody is using, topping it up first if its balance fell below the
+    /// threshold. The wallet goes back to the pool once the returned guard is dropped.
+    pub async fn acquire(&self) -> Result<PooledWallet> {
+        let permit = Arc::clone(&self.state.available)
+            .acquire_owned()
+            .await
+            .map_err(|_| error!(Other, "the wallet pool was closed"))?;
+        let wallet = lock(&self.state.idle)
+            .pop_front()
+            .expect("a permit guarantees an idle wallet");
+
+        let pooled_wallet = PooledWallet {
+            wallet: Some(wallet),
+            pool: self.clone(),
+            acquired_at: Instant::now(),
+            _permit: permit,
+        };
+        self.top_up_if_needed(&pooled_wallet).await?;
+
+        Ok(pooled_wallet)
+    }
+
+    async fn top_up_if_needed(&self, wallet: &WalletUnlocked) -> Result<()> {
+        let config = &self.state.config;
+        let balance = wallet.get_asset_balance(&config.asset_id).await?;
+        if balance >= config.top_up_threshold {
+            return Ok(());
+        }
+
+        // a single task at a time spends the bank's coins
+        let bank = self.state.bank.lock().await;
+        bank.transfer(
+            wallet.address(),
+            config.top_up_amount,
+            config.asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+        drop(bank);
+
+        self.update_stats(wallet.address(), |stats| stats.top_ups += 1);
+
+        Ok(())
+    }
+
+    /// Statistics of every wallet in the pool, by address.
+    pub fn stats(&self) -> HashMap<Bech32Address, WalletStats> {
+        lock(&self.state.stats).clone()
+    }
+
+    /// Statistics summed over every wallet in the pool.
+    pub fn total_stats(&self) -> WalletStats {
+        lock(&self.state.stats)
+            .values()
+            .fold(WalletStats::default(), |total, stats| WalletStats {
+                uses: total.uses + stats.uses,
+                failures: total.failures + stats.failures,
+                top_ups: total.top_ups + stats.top_ups,
+                busy_time: total.busy_time + stats.busy_time,
+            })
+    }
+
+    fn update_stats(&self, address: &Bech32Address, update: impl FnOnce(&mut WalletStats)) {
+        update(lock(&self.state.stats).entry(address.clone()).or_default());
+    }
+}
+
+/// A wallet handed out by a [`WalletPool`], usable as a [`WalletUnlocked`]. Returns to the pool
+/// when dropped.
+#[derive(Debug)]
+pub struct PooledWallet {
+    wallet: Option<WalletUnlocked>,
+    pool: WalletPool,
+    acquired_at: Instant,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl PooledWallet {
+    /// Counts a failed operation towards the wallet's [`WalletStats`].
+    pub fn record_failure(&self) {
+        self.pool
+            .update_stats(self.address(), |stats| stats.failures += 1);
+    }
+}
+
+impl Deref for PooledWallet {
+    type Target = WalletUnlocked;
+
+    fn deref(&self) -> &Self::Target {
+        self.wallet
+            .as_ref()
+            .expect("the wallet is only taken when dropped")
+    }
+}
+
+impl Drop for PooledWallet {
+    fn drop(&mut self) {
+        let wallet = self
+            .wallet
+            .take()
+            .expect("the wallet is only taken when dropped");
+        let busy_time = self.acquired_at.elapsed();
+        self.pool.update_stats(wallet.address(), |stats| {
+            stats.uses += 1;
+            stats.busy_time += busy_time;
+        });
+
+        // the permit is released after the wallet is back in the pool
+        lock(&self.pool.state.idle).push_back(wallet);
+    }
+}
+
+fn lock<T>(mutex: &StdMutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .expect("wallet pool lock should not be poisoned")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{launch_custom_provider_and_get_wallets, WalletsConfig};
+
+    #[tokio::test]
+    async fn wallets_are_handed_out_once_at_a_time_and_topped_up() -> Result<()> {
+        let mut wallets = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new(Some(1), Some(10), Some(1_000_000)),
+            None,
+            None,
+        )
+        .await?;
+        let bank = wallets.pop().expect("should have a wallet");
+
+        let pool = WalletPool::new(bank, WalletPoolConfig::new(2, 1000, 500)?).await?;
+
+        let first = pool.acquire().await?;
+        let second = pool.acquire().await?;
+        assert_ne!(first.address(), second.address());
+
+        // spend enough for the next acquisition to top the wallet up
+        first
+            .transfer(second.address(), 600, BASE_ASSET_ID, TxPolicies::default())
+            .await?;
+        first.record_failure();
+        let first_address = first.address().clone();
+        drop(first);
+
+        let again = pool.acquire().await?;
+        assert_eq!(again.address(), &first_address);
+        assert_eq!(again.get_asset_balance(&BASE_ASSET_ID).await?, 1400);
+        drop(again);
+        drop(second);
+
+        let stats = pool.stats();
+        assert_eq!(stats[&first_address].uses, 2);
+        assert_eq!(stats[&first_address].failures, 1);
+        assert_eq!(stats[&first_address].top_ups, 1);
+        assert_eq!(pool.total_stats().uses, 3);
+
+        Ok(())
+    }
+}
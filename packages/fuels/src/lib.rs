@@ -38,7 +38,11 @@ pub mod programs {
 }
 
 pub mod core {
-    pub use fuels_core::{codec, constants, offsets, traits, Configurables};
+    #[cfg(feature = "fuzz")]
+    pub use fuels_core::fuzz;
+    pub use fuels_core::{
+        bytecode, codec, constants, offsets, sparse_merkle, traits, Configurables,
+    };
 }
 
 pub mod crypto {
@@ -58,8 +62,7 @@ pub mod test_helpers {
     pub use fuels_test_helpers::*;
 }
 
-/// Easy imports of frequently used
-#[doc(hidden)]
+/// Easy imports of frequently used types, traits and macros.
 pub mod prelude {
     //! The fuels-rs prelude
     //!
@@ -69,24 +72,61 @@ pub mod prelude {
     //! # #![allow(unused_imports)]
     //! use fuels::prelude::*;
     //! ```
+    //!
+    //! It covers what is needed to connect to a node, manage wallets, deploy and call contracts
+    //! and work with their types:
+    //!
+    //! ```no_run
+    //! use fuels::prelude::*;
+    //!
+    //! # async fn example() -> Result<()> {
+    //! let provider = Provider::connect(Network::Testnet).await?;
+    //! let wallet = WalletUnlocked::new_random(Some(provider));
+    //!
+    //! let contract_id: Bech32ContractId = Contract::load_from(
+    //!     "out/debug/contract.bin",
+    //!     LoadConfiguration::default(),
+    //! )?
+    //! .deploy(&wallet, TxPolicies::default())
+    //! .await?;
+    //!
+    //! let token = Token::U64(42);
+    //! assert_eq!(ParamType::U64, u64::param_type());
+    //! # Ok(())
+    //! # }
+    //! ```
+    //!
+    //! # Stability
+    //!
+    //! The prelude is curated: only items most users need end up here. Adding an item is a
+    //! minor change, since a glob import never conflicts with items defined or imported
+    //! explicitly by the importing module. Removing or renaming an item is a breaking change
+    //! and only happens in major releases.
     #[cfg(feature = "std")]
     pub use super::{
         accounts::{
+            networks::Network,
             predicate::Predicate,
             provider::*,
-            wallet::{generate_mnemonic_phrase, WalletUnlocked},
-            Account, ViewOnlyAccount,
+            read_only::ReadOnlyAccount,
+            wallet::{discover_accounts, generate_mnemonic_phrase, WalletUnlocked},
+            Account, SubmittingAccount, ViewOnlyAccount,
         },
         core::{
-            codec::{LogDecoder, LogId, LogResult},
-            traits::Signer,
+            codec::{
+                ABIDecoder, ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder, LogId, LogResult,
+            },
+            traits::{Parameterize, Signer, Tokenizable},
         },
         programs::{
+            call_response::FuelCallResponse,
             call_utils::TxDependencyExtension,
             contract::{
                 CallParameters, Contract, LoadConfiguration, MultiContractCallHandler,
                 SettableContract, StorageConfiguration,
             },
+            read_aggregator::ReadAggregator,
+            src3::Src3,
         },
         test_helpers::*,
         types::transaction_builders::*,
@@ -98,8 +138,10 @@ pub mod prelude {
         types::{
             bech32::{Bech32Address, Bech32ContractId},
             errors::{Error, Result},
+            param_types::ParamType,
             transaction::*,
-            Address, AssetId, Bytes, ContractId, RawSlice, Salt,
+            tx_status::TxStatus,
+            Address, AssetId, Bytes, ChainId, ContractId, RawSlice, Salt, Token,
         },
     };
 }
@@ -89,7 +89,7 @@ async fn compile_bindings_from_inline_contract() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let call_handler = contract_instance.methods().takes_ints_returns_bool(42_u32);
 
@@ -161,7 +161,7 @@ async fn compile_bindings_array_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let input = [1, 2, 3];
     let call_handler = contract_instance.methods().takes_array(input);
@@ -238,7 +238,7 @@ async fn compile_bindings_bool_array_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let input = [true, false, true];
     let call_handler = contract_instance.methods().takes_array(input);
@@ -300,7 +300,7 @@ async fn compile_bindings_string_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     // ANCHOR: contract_takes_string
     let call_handler = contract_instance.methods().takes_string(
@@ -370,7 +370,7 @@ async fn compile_bindings_b256_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let mut hasher = Sha256::new();
     hasher.update("test string".as_bytes());
@@ -439,7 +439,7 @@ async fn compile_bindings_evm_address_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let mut hasher = Sha256::new();
     hasher.update("test string".as_bytes());
@@ -552,7 +552,7 @@ async fn compile_bindings_struct_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let call_handler = contract_instance.methods().takes_struct(input);
 
@@ -655,7 +655,7 @@ async fn compile_bindings_nested_struct_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let call_handler = contract_instance
         .methods()
@@ -746,7 +746,7 @@ async fn compile_bindings_enum_input() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let call_handler = contract_instance.methods().takes_enum(variant);
 
@@ -919,3 +919,114 @@ async fn type_paths_respected() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn generated_api_trait_can_be_mocked() -> Result<()> {
+    abigen!(Contract(
+        name = "SimpleContract",
+        abi = "packages/fuels/tests/bindings/simple_contract/out/debug/simple_contract-abi.json",
+        mock = true
+    ));
+
+    // Business logic only depends on the trait, not on the concrete bindings
+    async fn is_valid(api: &dyn SimpleContractApi, arg: u32) -> Result<bool> {
+        api.takes_int_returns_bool(arg).await
+    }
+
+    let mock = SimpleContractMock::default().with_takes_int_returns_bool(|arg| Ok(arg == 32));
+
+    assert!(is_valid(&mock, 32).await?);
+    assert!(!is_valid(&mock, 42).await?);
+
+    let error = SimpleContractMock::default()
+        .takes_int_returns_bool(32)
+        .await
+        .expect_err("should fail since nothing was mocked");
+    assert!(error.to_string().contains("was not mocked"));
+
+    Ok(())
+}
+
+#[test]
+fn calldata_can_be_encoded_without_an_account() -> Result<()> {
+    abigen!(Contract(
+        name = "SimpleContract",
+        abi = "packages/fuels/tests/bindings/simple_contract/out/debug/simple_contract-abi.json"
+    ));
+
+    let calls = SimpleContractCalls::default();
+
+    let encoded = calls.takes_int_returns_bool(42)?.to_calldata(0);
+    assert_eq!("000000005f68ee3d000000000000002a", hex::encode(encoded));
+
+    let dynamically_encoded = calls
+        .encode_fn_call("takes_int_returns_bool", &[42u32.into_token()])?
+        .to_calldata(0);
+    assert_eq!(
+        "000000005f68ee3d000000000000002a",
+        hex::encode(dynamically_encoded)
+    );
+
+    let (_, selector) = SimpleContractCalls::selectors()
+        .into_iter()
+        .find(|(name, _)| *name == "takes_int_returns_bool")
+        .expect("selector should be listed");
+    assert_eq!("000000005f68ee3d", hex::encode(selector));
+
+    let error = calls
+        .encode_fn_call("not_a_function", &[])
+        .expect_err("should fail since the function is unknown");
+    assert!(error
+        .to_string()
+        .contains("no function named `not_a_function`"));
+
+    Ok(())
+}
+
+#[test]
+fn bindings_are_created_from_typed_contract_ids() {
+    abigen!(Contract(
+        name = "SimpleContract",
+        abi = "packages/fuels/tests/bindings/simple_contract/out/debug/simple_contract-abi.json"
+    ));
+    let wallet = WalletUnlocked::new_random(None);
+
+    let contract_id = SimpleContractId::new(null_contract_id());
+    let instance = SimpleContract::new(contract_id.clone(), wallet);
+
+    assert_eq!(instance.typed_contract_id(), contract_id);
+    assert_eq!(instance.contract_id(), contract_id.as_ref());
+    assert_eq!(contract_id.to_string(), null_contract_id().to_string());
+
+    // typed ids still convert into plain ones
+    assert_eq!(Bech32ContractId::from(contract_id), null_contract_id());
+}
+
+#[cfg(feature = "embed-abi")]
+#[test]
+fn bindings_embed_the_whole_abi() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "packages/fuels/tests/contracts/contract_test/out/debug/contract_test-abi.json",
+        include = "*_counter"
+    ));
+
+    let json_abi =
+        std::fs::read_to_string("tests/contracts/contract_test/out/debug/contract_test-abi.json")?;
+    let expected: serde_json::Value = serde_json::from_str(&json_abi)?;
+    let embedded: serde_json::Value = serde_json::from_str(MyContract::<WalletUnlocked>::abi())?;
+    assert_eq!(embedded["functions"], expected["functions"]);
+
+    // the functions filtered out of the bindings are kept in the embedded ABI
+    let program_abi = MyContract::<WalletUnlocked>::program_abi();
+    assert!(program_abi.function("initialize_counter").is_some());
+    assert!(program_abi.function("get_value").is_some());
+
+    // the parsed ABI is only built once
+    assert!(std::sync::Arc::ptr_eq(
+        &program_abi,
+        &MyContract::<WalletUnlocked>::program_abi()
+    ));
+
+    Ok(())
+}
@@ -17,7 +17,7 @@ async fn contract_uses_default_configurables() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance = MyContract::new(contract_id, wallet.clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id), wallet.clone());
 
     let response = contract_instance
         .methods()
@@ -105,7 +105,7 @@ async fn contract_configurables() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance = MyContract::new(contract_id, wallet.clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id), wallet.clone());
     // ANCHOR_END: contract_configurables
 
     let response = contract_instance
@@ -1,7 +1,8 @@
 use fuels::{
     core::codec::{calldata, fn_selector, DecoderConfig, EncoderConfig},
     prelude::*,
-    types::{errors::transaction::Reason, Bits256},
+    programs::call_utils::GasBump,
+    types::{errors::transaction::Reason, tx_status::TxStatus, Bits256, Identity},
 };
 
 #[tokio::test]
@@ -167,6 +168,33 @@ async fn test_multiple_read_calls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn read_only_instance_can_simulate() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "MultiReadContract",
+            project = "packages/fuels/tests/contracts/multiple_read_calls"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "MultiReadContract",
+            wallet = "wallet"
+        ),
+    );
+
+    contract_instance.methods().store(42).call().await?;
+
+    let provider = wallet.try_provider()?.clone();
+    let read_only_instance = contract_instance.with_provider(provider);
+
+    let stored = read_only_instance.methods().read().simulate().await?;
+
+    assert_eq!(stored.value, 42);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_call_beginner() -> Result<()> {
     setup_program_test!(
@@ -435,6 +463,45 @@ async fn test_auth_msg_sender_from_sdk() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn calls_can_be_simulated_as_any_address() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "AuthContract",
+            project = "packages/fuels/tests/contracts/auth_testing_contract"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "AuthContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let impersonated = WalletUnlocked::new_random(None);
+
+    // ANCHOR: simulate_as
+    let tx = contract_instance
+        .methods()
+        .check_msg_sender(impersonated.address())
+        .build_tx()
+        .await?;
+    let tx_status = provider.simulate_as(impersonated.address(), tx).await?;
+    // ANCHOR_END: simulate_as
+    assert!(matches!(tx_status, TxStatus::Success { .. }));
+
+    // the contract asserts that `msg_sender()` is the expected address
+    let tx = contract_instance
+        .methods()
+        .check_msg_sender(wallet.address())
+        .build_tx()
+        .await?;
+    let tx_status = provider.simulate_as(impersonated.address(), tx).await?;
+    assert!(matches!(tx_status, TxStatus::Revert { .. }));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_large_return_data() -> Result<()> {
     setup_program_test!(
@@ -648,6 +715,16 @@ async fn test_connect_wallet() -> Result<()> {
     let wallet_2_balance = wallet_2.get_asset_balance(&Default::default()).await?;
     assert_eq!(wallet_balance_second_call, wallet_balance);
     assert!(DEFAULT_COIN_AMOUNT > wallet_2_balance);
+
+    // the original instance is still connected to wallet
+    assert_eq!(contract_instance.account().address(), wallet.address());
+    assert_eq!(
+        contract_instance
+            .with_wallet(wallet_2.clone())
+            .account()
+            .address(),
+        wallet_2.address()
+    );
     Ok(())
 }
 
@@ -684,7 +761,7 @@ async fn test_output_variable_estimation() -> Result<()> {
     let (wallets, addresses, mint_asset_id, contract_id) =
         setup_output_variable_estimation_test().await?;
 
-    let contract_instance = MyContract::new(contract_id, wallets[0].clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id), wallets[0].clone());
     let contract_methods = contract_instance.methods();
     let amount = 1000;
 
@@ -742,7 +819,7 @@ async fn test_output_variable_estimation_default_attempts() -> Result<()> {
     let (wallets, addresses, mint_asset_id, contract_id) =
         setup_output_variable_estimation_test().await?;
 
-    let contract_instance = MyContract::new(contract_id, wallets[0].clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id), wallets[0].clone());
     let contract_methods = contract_instance.methods();
     let amount = 1000;
 
@@ -771,7 +848,8 @@ async fn test_output_variable_estimation_multicall() -> Result<()> {
     let (wallets, addresses, mint_asset_id, contract_id) =
         setup_output_variable_estimation_test().await?;
 
-    let contract_instance = MyContract::new(contract_id.clone(), wallets[0].clone());
+    let contract_instance =
+        MyContract::new(MyContractId::new(contract_id.clone()), wallets[0].clone());
     let contract_methods = contract_instance.methods();
     const NUM_OF_CALLS: u64 = 3;
     let amount = 1000;
@@ -1145,6 +1223,125 @@ async fn contract_load_error_messages() {
     }
 }
 
+#[tokio::test]
+async fn contracts_can_be_deployed_and_initialized() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+    );
+
+    // ANCHOR: deploy_and_call
+    let (contract_id, response) = Contract::load_from(
+        "tests/contracts/contract_test/out/debug/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy_and_call(&wallet, TxPolicies::default(), |contract_id| {
+        TestContract::new(TestContractId::new(contract_id.clone()), wallet.clone())
+            .methods()
+            .initialize_counter(42)
+    })
+    .await?;
+    // ANCHOR_END: deploy_and_call
+
+    assert_eq!(response.value, 42);
+
+    let counter = TestContract::new(TestContractId::new(contract_id), wallet.clone())
+        .methods()
+        .get_counter()
+        .call()
+        .await?
+        .value;
+    assert_eq!(counter, 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contracts_can_be_deployed_all_at_once() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+    );
+    let binary = "tests/contracts/contract_test/out/debug/contract_test.bin";
+
+    // ANCHOR: deploy_all
+    let contract_ids = fuels::programs::fixtures::deploy_all(
+        &[
+            ("first", binary, LoadConfiguration::default()),
+            (
+                "second",
+                binary,
+                LoadConfiguration::default().with_salt([1; 32]),
+            ),
+        ],
+        &wallet,
+    )
+    .await?;
+    // ANCHOR_END: deploy_all
+
+    assert_eq!(contract_ids.len(), 2);
+    assert_ne!(contract_ids["first"], contract_ids["second"]);
+    for contract_id in contract_ids.into_values() {
+        let response = TestContract::new(TestContractId::new(contract_id), wallet.clone())
+            .methods()
+            .initialize_counter(42)
+            .call()
+            .await?;
+        assert_eq!(response.value, 42);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn simulations_are_cached_until_a_new_block() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: simulation_cache
+    let cache = fuels::programs::simulation_cache::SimulationCache::new();
+    let cached_instance = contract_instance
+        .clone()
+        .with_simulation_cache(cache.clone());
+
+    let counter = cached_instance.methods().get_counter().simulate().await?;
+    // ANCHOR_END: simulation_cache
+    assert_eq!(counter.value, 0);
+
+    let counter = cached_instance.methods().get_counter().simulate().await?;
+    assert_eq!(counter.value, 0);
+    assert_eq!(cache.len(), 1);
+
+    // Calls are never cached and produce a block, invalidating the cache.
+    cached_instance
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+
+    let counter = cached_instance.methods().get_counter().simulate().await?;
+    assert_eq!(counter.value, 42);
+    assert_eq!(cache.len(), 1);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_payable_annotation() -> Result<()> {
     setup_program_test!(
@@ -1206,10 +1403,13 @@ async fn multi_call_from_calls_with_different_account_types() -> Result<()> {
     let wallet = WalletUnlocked::new_random(None);
     let predicate = Predicate::from_code(vec![]);
 
-    let contract_methods_wallet =
-        MyContract::new(Bech32ContractId::default(), wallet.clone()).methods();
+    let contract_methods_wallet = MyContract::new(
+        MyContractId::new(Bech32ContractId::default()),
+        wallet.clone(),
+    )
+    .methods();
     let contract_methods_predicate =
-        MyContract::new(Bech32ContractId::default(), predicate).methods();
+        MyContract::new(MyContractId::new(Bech32ContractId::default()), predicate).methods();
 
     let call_handler_1 = contract_methods_wallet.initialize_counter(42);
     let call_handler_2 = contract_methods_predicate.get_array([42; 2]);
@@ -1795,7 +1995,7 @@ async fn contract_encoder_config_is_applied() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let instance = TestContract::new(contract_id.clone(), wallet.clone());
+    let instance = TestContract::new(TestContractId::new(contract_id.clone()), wallet.clone());
 
     {
         let _encoding_ok = instance
@@ -1838,3 +2038,234 @@ async fn contract_encoder_config_is_applied() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn calls_through_upgradeable_proxy() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "MultiReadContract",
+            project = "packages/fuels/tests/contracts/multiple_read_calls"
+        )),
+    );
+
+    let mut implementations = vec![];
+    for salt in [[0; 32], [1; 32]] {
+        let contract_id = Contract::load_from(
+            "tests/contracts/multiple_read_calls/out/debug/multiple_read_calls.bin",
+            LoadConfiguration::default().with_salt(salt),
+        )?
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+        implementations.push(contract_id);
+    }
+    let [first_implementation, second_implementation]: [Bech32ContractId; 2] = implementations
+        .try_into()
+        .expect("two implementations were deployed");
+
+    let proxy = Contract::load_from(
+        "tests/contracts/proxy/out/debug/proxy.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy_as_proxy(
+        first_implementation.clone(),
+        wallet.clone(),
+        TxPolicies::default(),
+    )
+    .await?;
+    assert_eq!(proxy.target().await?, Some(first_implementation.clone()));
+
+    let instance = MultiReadContract::new(
+        MultiReadContractId::new(proxy.contract_id().clone()),
+        wallet.clone(),
+    )
+    .with_proxy_target(first_implementation);
+    instance.methods().store(42).call().await?;
+    assert_eq!(instance.methods().read().simulate().await?.value, 42);
+
+    proxy.upgrade_to(second_implementation.clone()).await?;
+    assert_eq!(proxy.target().await?, Some(second_implementation.clone()));
+
+    // the storage lives in the proxy, so it survives the upgrade
+    let instance = MultiReadContract::new(
+        MultiReadContractId::new(proxy.contract_id().clone()),
+        wallet,
+    )
+    .with_proxy_target(second_implementation);
+    assert_eq!(instance.methods().read().simulate().await?.value, 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn src3_contracts_can_mint_and_burn() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "AssetContract",
+            project = "packages/fuels/tests/contracts/src3_asset"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "AssetContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let sub_id = Bits256([1; 32]);
+    let asset_id = Src3::asset_id(&contract_instance, &sub_id);
+    let recipient = Identity::Address(wallet.address().into());
+
+    // the variable output needed by the recipient is added automatically
+    Src3::src3_mint(&contract_instance, recipient, sub_id, 100)
+        .call()
+        .await?;
+    assert_eq!(wallet.get_asset_balance(&asset_id).await?, 100);
+
+    Src3::src3_burn(&contract_instance, sub_id, 40)?
+        .call()
+        .await?;
+    assert_eq!(wallet.get_asset_balance(&asset_id).await?, 60);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_calls_can_time_out() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_methods = contract_instance.methods();
+
+    let timeout = std::time::Duration::from_nanos(1);
+    let err = contract_methods
+        .get_single(7)
+        .with_timeout(timeout)
+        .simulate()
+        .await
+        .expect_err("should time out");
+    assert!(matches!(err, Error::Timeout(elapsed) if elapsed == timeout));
+
+    let response = contract_methods
+        .get_single(7)
+        .with_timeout(std::time::Duration::from_secs(10))
+        .call()
+        .await?;
+    assert_eq!(response.value, 7);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gas_limit_is_bumped_until_the_call_has_enough() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let tx_policies = TxPolicies::default().with_script_gas_limit(100);
+
+    let err = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .with_tx_policies(tx_policies)
+        .simulate()
+        .await
+        .expect_err("should run out of gas");
+    assert!(err.to_string().contains("OutOfGas"));
+
+    // ANCHOR: gas_bump
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .with_tx_policies(tx_policies)
+        .with_gas_bump(GasBump::new(2.0, 1_000_000)?)
+        .call()
+        .await?;
+    assert_eq!(response.value, 42);
+    assert!(response.gas_limit.expect("is set by the call") > 100);
+    // ANCHOR_END: gas_bump
+
+    let err = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .with_tx_policies(tx_policies)
+        .with_gas_bump(GasBump::new(2.0, 200)?)
+        .simulate()
+        .await
+        .expect_err("should not be allowed enough gas");
+    assert_eq!(
+        err.to_string(),
+        "the call runs out of gas even with the maximum gas limit of 200"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contracts_can_be_called_through_a_shared_interface() -> Result<()> {
+    abigen!(
+        Contract(
+            name = "TestContract",
+            abi = "packages/fuels/tests/contracts/contract_test/out/debug/contract_test-abi.json",
+            interface = "Counter"
+        ),
+        Contract(
+            name = "ComplexTypesContract",
+            abi = "packages/fuels/tests/types/contracts/complex_types_contract/out/debug/complex_types_contract-abi.json",
+            interface = "Counter"
+        ),
+    );
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let deploy = |binary: &'static str| {
+        let wallet = wallet.clone();
+        async move {
+            Contract::load_from(binary, LoadConfiguration::default())?
+                .deploy(&wallet, TxPolicies::default())
+                .await
+        }
+    };
+    let test_contract_id =
+        deploy("tests/contracts/contract_test/out/debug/contract_test.bin").await?;
+    let complex_types_contract_id =
+        deploy("tests/types/contracts/complex_types_contract/out/debug/complex_types_contract.bin")
+            .await?;
+
+    // only `increment_counter` has the same types in both contracts
+    let counters: Vec<Box<dyn Counter>> = vec![
+        Box::new(TestContract::new(
+            TestContractId::new(test_contract_id),
+            wallet.clone(),
+        )),
+        Box::new(ComplexTypesContract::new(
+            ComplexTypesContractId::new(complex_types_contract_id),
+            wallet.clone(),
+        )),
+    ];
+
+    for counter in &counters {
+        counter.increment_counter(5).call().await?;
+        let response = counter.increment_counter(2).call().await?;
+
+        assert_eq!(response.value, 7);
+    }
+
+    Ok(())
+}
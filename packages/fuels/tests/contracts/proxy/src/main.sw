@@ -0,0 +1,32 @@
+contract;
+
+use std::execution::run_external;
+
+abi SRC14 {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId);
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId>;
+}
+
+storage {
+    proxy_target: Option<ContractId> = None,
+}
+
+impl SRC14 for Contract {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId) {
+        storage.proxy_target.write(Some(new_target));
+    }
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId> {
+        storage.proxy_target.read()
+    }
+}
+
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    run_external(storage.proxy_target.read().unwrap())
+}
@@ -87,7 +87,7 @@ async fn create_struct_from_decoded_tokens() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let call_handler = contract_instance.methods().takes_struct(struct_from_tokens);
 
@@ -200,7 +200,7 @@ async fn create_nested_struct_from_decoded_tokens() -> Result<()> {
 
     let wallet = launch_provider_and_get_wallet().await?;
 
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
 
     let call_handler = contract_instance
         .methods()
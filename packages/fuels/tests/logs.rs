@@ -1,5 +1,5 @@
 use fuels::{
-    core::codec::DecoderConfig,
+    core::codec::{assert_decoded_eq, DecoderConfig},
     prelude::*,
     types::{errors::transaction::Reason, Bits256, SizedAsciiString},
 };
@@ -116,6 +116,45 @@ async fn test_parse_logs_custom_types() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn logs_can_be_compared_to_snapshots() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "packages/fuels/tests/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let contract_methods = contract_instance.methods();
+    let response = contract_methods.produce_logs_custom_types().call().await?;
+
+    let logs = response.decode_logs_with_type::<(TestStruct, TestEnum)>()?;
+
+    assert_decoded_eq!(
+        logs,
+        r#"
+        Vec[
+            (
+                {
+                    true,
+                    0xef86afa9696cf0dc6385e2c407a6e159a1103cefb7e2ae0636fb33d3cb2a9e4a,
+                    64u64,
+                },
+                Enum(1: ()),
+            ),
+        ]
+        "#
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_parse_logs_generic_types() -> Result<()> {
     setup_program_test!(
@@ -385,7 +424,7 @@ async fn test_multi_call_contract_with_contract_logs() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.clone(), wallet.clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone());
 
     let call_handler_1 = contract_caller_instance
         .methods()
@@ -703,7 +742,7 @@ async fn test_contract_with_contract_logs() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.clone(), wallet.clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone());
 
     let expected_logs: Vec<String> = vec![
         format!("{:?}", 64),
@@ -963,7 +1002,7 @@ async fn test_contract_require_from_contract() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.clone(), wallet.clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone());
 
     let error = contract_caller_instance
         .methods()
@@ -1015,7 +1054,8 @@ async fn test_multi_call_contract_require_from_contract() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let lib_contract_instance = MyContract::new(contract_id.clone(), wallet.clone());
+    let lib_contract_instance =
+        MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone());
 
     let call_handler_1 = contract_instance.methods().produce_logs_values();
 
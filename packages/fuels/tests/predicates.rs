@@ -6,7 +6,14 @@ use fuels::{
         traits::Tokenizable,
     },
     prelude::*,
-    types::{coin::Coin, coin_type::CoinType, input::Input, message::Message, output::Output},
+    types::{
+        coin::Coin,
+        coin_type::CoinType,
+        input::Input,
+        message::Message,
+        output::Output,
+        transaction::{EstimablePredicates, ScriptTransaction, Transaction},
+    },
 };
 
 async fn assert_address_balance(
@@ -213,7 +220,8 @@ async fn pay_with_predicate() -> Result<()> {
     .deploy(&predicate, TxPolicies::default())
     .await?;
 
-    let contract_methods = MyContract::new(contract_id.clone(), predicate.clone()).methods();
+    let contract_methods =
+        MyContract::new(MyContractId::new(contract_id.clone()), predicate.clone()).methods();
     let tx_policies = TxPolicies::default()
         .with_gas_price(1)
         .with_script_gas_limit(1_000_000);
@@ -268,7 +276,8 @@ async fn pay_with_predicate_vector_data() -> Result<()> {
     .deploy(&predicate, TxPolicies::default())
     .await?;
 
-    let contract_methods = MyContract::new(contract_id.clone(), predicate.clone()).methods();
+    let contract_methods =
+        MyContract::new(MyContractId::new(contract_id.clone()), predicate.clone()).methods();
     let tx_policies = TxPolicies::default()
         .with_gas_price(1)
         .with_script_gas_limit(1_000_000);
@@ -506,7 +515,8 @@ async fn contract_tx_and_call_params_with_predicate() -> Result<()> {
     .deploy(&predicate, TxPolicies::default())
     .await?;
     println!("Contract deployed @ {contract_id}");
-    let contract_methods = MyContract::new(contract_id.clone(), predicate.clone()).methods();
+    let contract_methods =
+        MyContract::new(MyContractId::new(contract_id.clone()), predicate.clone()).methods();
 
     let tx_policies = TxPolicies::default().with_gas_price(1);
 
@@ -583,7 +593,8 @@ async fn diff_asset_predicate_payment() -> Result<()> {
     .deploy(&predicate, TxPolicies::default())
     .await?;
 
-    let contract_methods = MyContract::new(contract_id.clone(), predicate.clone()).methods();
+    let contract_methods =
+        MyContract::new(MyContractId::new(contract_id.clone()), predicate.clone()).methods();
 
     let call_params = CallParameters::default()
         .with_amount(1_000_000)
@@ -914,3 +925,308 @@ async fn predicate_encoder_config_is_applied() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn predicate_gas_can_be_estimated_by_the_node() -> Result<()> {
+    abigen!(Predicate(
+        name = "MyPredicate",
+        abi = "packages/fuels/tests/predicates/basic_predicate/out/debug/basic_predicate-abi.json"
+    ));
+
+    let predicate_data = MyPredicateEncoder::default().encode_data(4097, 4097)?;
+
+    let mut predicate: Predicate =
+        Predicate::load_from("tests/predicates/basic_predicate/out/debug/basic_predicate.bin")?
+            .with_data(predicate_data);
+
+    let num_coins = 4;
+    let num_messages = 0;
+    let amount = 16;
+    let (provider, _predicate_balance, receiver, _receiver_balance, asset_id) =
+        setup_predicate_test(predicate.address(), num_coins, num_messages, amount).await?;
+
+    predicate.set_provider(provider.clone());
+
+    let amount_to_send = 12;
+    let inputs = predicate
+        .get_asset_inputs_for_amount(asset_id, amount_to_send)
+        .await?;
+    let outputs =
+        predicate.get_asset_outputs_for_amount(receiver.address(), asset_id, amount_to_send);
+
+    let tx = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default())
+        .build(&provider)
+        .await?;
+
+    let estimated_tx = predicate.estimate_predicate_gas(tx.clone()).await?;
+
+    let mut locally_estimated_tx = tx;
+    locally_estimated_tx.estimate_predicates(provider.consensus_parameters())?;
+
+    let predicate_gas = |tx: &ScriptTransaction| {
+        tx.inputs()
+            .iter()
+            .filter_map(|input| input.predicate_gas_used())
+            .collect::<Vec<_>>()
+    };
+    let gas_used = predicate_gas(&estimated_tx);
+    assert!(!gas_used.is_empty());
+    assert!(gas_used.iter().all(|gas| *gas > 0));
+    assert_eq!(gas_used, predicate_gas(&locally_estimated_tx));
+
+    provider
+        .send_transaction_and_await_commit(estimated_tx)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn spend_from_multiple_predicates_and_a_wallet() -> Result<()> {
+    abigen!(
+        Predicate(
+            name = "BasicPredicate",
+            abi = "packages/fuels/tests/predicates/basic_predicate/out/debug/basic_predicate-abi.json"
+        ),
+        Predicate(
+            name = "VectorPredicate",
+            abi = "packages/fuels/tests/types/predicates/predicate_vector/out/debug/predicate_vector-abi.json"
+        )
+    );
+
+    let mut basic_predicate: Predicate =
+        Predicate::load_from("tests/predicates/basic_predicate/out/debug/basic_predicate.bin")?
+            .with_data(BasicPredicateEncoder::default().encode_data(4097, 4097)?);
+    let mut vector_predicate: Predicate = Predicate::load_from(
+        "tests/types/predicates/predicate_vector/out/debug/predicate_vector.bin",
+    )?
+    .with_data(VectorPredicateEncoder::default().encode_data(2, 40, vec![2, 4, 42])?);
+    let mut wallet = WalletUnlocked::new_random(None);
+    let receiver = WalletUnlocked::new_random(None);
+
+    let num_coins = 2;
+    let amount = 1000;
+    let asset_id = AssetId::default();
+    let coins = [
+        basic_predicate.address(),
+        vector_predicate.address(),
+        wallet.address(),
+    ]
+    .into_iter()
+    .flat_map(|owner| setup_single_asset_coins(owner, asset_id, num_coins, amount))
+    .collect();
+
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    basic_predicate.set_provider(provider.clone());
+    vector_predicate.set_provider(provider.clone());
+    wallet.set_provider(provider.clone());
+
+    let amount_to_send = 2500;
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        vec![],
+        vec![Output::coin(
+            receiver.address().into(),
+            amount_to_send,
+            asset_id,
+        )],
+        TxPolicies::default(),
+    );
+    basic_predicate
+        .add_spending_inputs(&mut tb, asset_id, amount)
+        .await?;
+    vector_predicate
+        .add_spending_inputs(&mut tb, asset_id, amount)
+        .await?;
+    wallet
+        .add_spending_inputs(&mut tb, asset_id, amount)
+        .await?;
+    wallet.adjust_for_fee(&mut tb, amount_to_send).await?;
+
+    // only one change output is allowed per asset, the first spender gets it
+    let change_outputs = tb
+        .outputs()
+        .iter()
+        .filter(|output| matches!(output, Output::Change { .. }))
+        .count();
+    assert_eq!(change_outputs, 1);
+
+    let tx = tb.build(&provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+
+    assert_address_balance(receiver.address(), &provider, asset_id, amount_to_send).await;
+
+    Ok(())
+}
+
+#[cfg(any(feature = "secp256r1", feature = "ed25519"))]
+async fn assert_signer_spends_predicate_coins(
+    mut predicate: Predicate,
+    signer: impl Signer + Send + Sync,
+) -> Result<()> {
+    let (provider, predicate_balance, receiver, receiver_balance, asset_id) =
+        setup_predicate_test(predicate.address(), 4, 0, 16).await?;
+    predicate.set_provider(provider.clone());
+
+    let amount_to_send = 12;
+    let inputs = predicate
+        .get_asset_inputs_for_amount(asset_id, amount_to_send)
+        .await?;
+    let outputs =
+        predicate.get_asset_outputs_for_amount(receiver.address(), asset_id, amount_to_send);
+
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    // No other witnesses, so the signature lands at index 0, as told to the predicate.
+    tb.add_signer(signer)?;
+    let tx = tb.build(&provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+
+    assert_address_balance(
+        predicate.address(),
+        &provider,
+        asset_id,
+        predicate_balance - amount_to_send,
+    )
+    .await;
+    assert_address_balance(
+        receiver.address(),
+        &provider,
+        asset_id,
+        receiver_balance + amount_to_send,
+    )
+    .await;
+
+    Ok(())
+}
+
+#[cfg(feature = "secp256r1")]
+#[tokio::test]
+async fn secp256r1_signer_spends_from_its_predicate() -> Result<()> {
+    use fuels::{accounts::signers::Secp256r1Signer, types::Bits256};
+
+    abigen!(Predicate(
+        name = "Secp256r1Predicate",
+        abi = "packages/fuels/tests/predicates/secp256r1_signature/out/debug/secp256r1_signature-abi.json"
+    ));
+
+    // ANCHOR: secp256r1_signer
+    let signer = Secp256r1Signer::random(&mut rand::thread_rng());
+
+    let configurables = Secp256r1PredicateConfigurables::default()
+        .with_PUBLIC_KEY_HASH(Bits256(*signer.public_key_hash()))?;
+    let predicate: Predicate = Predicate::load_from(
+        "tests/predicates/secp256r1_signature/out/debug/secp256r1_signature.bin",
+    )?
+    .with_configurables(configurables)
+    .with_data(Secp256r1PredicateEncoder::default().encode_data(0)?);
+
+    let signer = signer.with_address(predicate.address().clone());
+    // ANCHOR_END: secp256r1_signer
+
+    assert_signer_spends_predicate_coins(predicate, signer).await
+}
+
+#[cfg(feature = "ed25519")]
+#[tokio::test]
+async fn ed25519_signer_spends_from_its_predicate() -> Result<()> {
+    use fuels::{accounts::signers::Ed25519Signer, types::Bits256};
+
+    abigen!(Predicate(
+        name = "Ed25519Predicate",
+        abi = "packages/fuels/tests/predicates/ed25519_signature/out/debug/ed25519_signature-abi.json"
+    ));
+
+    // ANCHOR: ed25519_signer
+    let signer = Ed25519Signer::random(&mut rand::thread_rng());
+
+    let configurables =
+        Ed25519PredicateConfigurables::default().with_PUBLIC_KEY(Bits256(*signer.public_key()))?;
+    let predicate: Predicate =
+        Predicate::load_from("tests/predicates/ed25519_signature/out/debug/ed25519_signature.bin")?
+            .with_configurables(configurables)
+            .with_data(Ed25519PredicateEncoder::default().encode_data(0)?);
+
+    let signer = signer.with_address(predicate.address().clone());
+    // ANCHOR_END: ed25519_signer
+
+    assert_signer_spends_predicate_coins(predicate, signer).await
+}
+
+#[tokio::test]
+async fn abstract_account_transfers_and_calls_contracts() -> Result<()> {
+    use fuels::{accounts::abstract_account::AbstractAccount, types::Bits256};
+
+    abigen!(
+        Contract(
+            name = "MyContract",
+            abi = "packages/fuels/tests/contracts/contract_test/out/debug/contract_test-abi.json"
+        ),
+        Predicate(
+            name = "MultisigPredicate",
+            abi = "packages/fuels/tests/predicates/multisig/out/debug/multisig-abi.json"
+        )
+    );
+
+    let owner_1 = WalletUnlocked::new_random(None);
+    let owner_2 = WalletUnlocked::new_random(None);
+
+    // ANCHOR: abstract_account
+    let configurables = MultisigPredicateConfigurables::default()
+        .with_OWNER_1(Bits256(owner_1.address().hash().into()))?
+        .with_OWNER_2(Bits256(owner_2.address().hash().into()))?;
+    let predicate: Predicate =
+        Predicate::load_from("tests/predicates/multisig/out/debug/multisig.bin")?
+            .with_configurables(configurables);
+
+    // Transfers and calls carry no other witnesses, so the signatures start at index 0
+    let mut account = AbstractAccount::new(predicate)
+        .with_predicate_data(MultisigPredicateEncoder::default().encode_data(0)?)
+        .with_signer(owner_1)
+        .with_signer(owner_2);
+    // ANCHOR_END: abstract_account
+
+    let (provider, predicate_balance, receiver, receiver_balance, asset_id) =
+        setup_predicate_test(account.address(), 2, 0, 1000).await?;
+    account.set_provider(provider.clone());
+
+    let amount_to_send = 100;
+    account
+        .transfer(
+            receiver.address(),
+            amount_to_send,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    assert_address_balance(
+        receiver.address(),
+        &provider,
+        asset_id,
+        receiver_balance + amount_to_send,
+    )
+    .await;
+
+    // ANCHOR: abstract_account_deploy
+    // A deployment carries the bytecode as its first witness, the signatures start at index 1
+    let deployer = account
+        .clone()
+        .with_predicate_data(MultisigPredicateEncoder::default().encode_data(1)?);
+    let contract_id = Contract::load_from(
+        "tests/contracts/contract_test/out/debug/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&deployer, TxPolicies::default())
+    .await?;
+    // ANCHOR_END: abstract_account_deploy
+
+    let response = MyContract::new(MyContractId::new(contract_id), account.clone())
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+    assert_eq!(response.value, 42);
+
+    assert!(account.get_asset_balance(&asset_id).await? < predicate_balance);
+
+    Ok(())
+}
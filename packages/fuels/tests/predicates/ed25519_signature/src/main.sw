@@ -0,0 +1,16 @@
+predicate;
+
+use std::{b512::B512, constants::ZERO_B256, ecr::ed_verify, tx::{tx_id, tx_witness_data}};
+
+configurable {
+    PUBLIC_KEY: b256 = ZERO_B256,
+}
+
+fn main(witness_index: u64) -> bool {
+    let signature: B512 = tx_witness_data(witness_index);
+
+    match ed_verify(PUBLIC_KEY, signature, tx_id()) {
+        Result::Ok(valid) => valid,
+        Result::Err(_) => false,
+    }
+}
@@ -0,0 +1,22 @@
+predicate;
+
+use std::{b512::B512, constants::ZERO_B256, ecr::ec_recover_address, tx::{tx_id, tx_witness_data}};
+
+configurable {
+    OWNER_1: b256 = ZERO_B256,
+    OWNER_2: b256 = ZERO_B256,
+}
+
+fn signed_by(witness_index: u64, owner: b256) -> bool {
+    let signature: B512 = tx_witness_data(witness_index);
+
+    match ec_recover_address(signature, tx_id()) {
+        Result::Ok(address) => address.value == owner,
+        Result::Err(_) => false,
+    }
+}
+
+// Both owners sign, in order, starting at witness `first_witness_index`.
+fn main(first_witness_index: u64) -> bool {
+    signed_by(first_witness_index, OWNER_1) && signed_by(first_witness_index + 1, OWNER_2)
+}
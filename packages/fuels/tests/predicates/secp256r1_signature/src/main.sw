@@ -0,0 +1,17 @@
+predicate;
+
+use std::{b512::B512, constants::ZERO_B256, ecr::ec_recover_address_r1, tx::{tx_id, tx_witness_data}};
+
+configurable {
+    // SHA-256 of the uncompressed secp256r1 public key, `x` followed by `y`.
+    PUBLIC_KEY_HASH: b256 = ZERO_B256,
+}
+
+fn main(witness_index: u64) -> bool {
+    let signature: B512 = tx_witness_data(witness_index);
+
+    match ec_recover_address_r1(signature, tx_id()) {
+        Result::Ok(address) => address.value == PUBLIC_KEY_HASH,
+        Result::Err(_) => false,
+    }
+}
@@ -2,7 +2,7 @@ use std::{ops::Add, str::FromStr};
 
 use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
 use fuels::{
-    accounts::Account,
+    accounts::{network_registry::NetworkRegistry, Account},
     client::{PageDirection, PaginationRequest},
     crypto::SecretKey,
     prelude::*,
@@ -13,6 +13,7 @@ use fuels::{
         errors::transaction::Reason,
         message::Message,
         transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
+        tx_status::TxStatus,
         Bits256,
     },
 };
@@ -42,7 +43,8 @@ async fn test_provider_launch_and_connect() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance_connected = MyContract::new(contract_id.clone(), wallet.clone());
+    let contract_instance_connected =
+        MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone());
 
     let response = contract_instance_connected
         .methods()
@@ -52,7 +54,7 @@ async fn test_provider_launch_and_connect() -> Result<()> {
     assert_eq!(42, response.value);
 
     wallet.set_provider(provider);
-    let contract_instance_launched = MyContract::new(contract_id, wallet);
+    let contract_instance_launched = MyContract::new(MyContractId::new(contract_id), wallet);
 
     let response = contract_instance_launched
         .methods()
@@ -176,7 +178,7 @@ async fn test_input_message_pays_fee() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance = MyContract::new(contract_id, wallet.clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id), wallet.clone());
 
     let response = contract_instance
         .methods()
@@ -615,7 +617,8 @@ async fn testnet_hello_world() -> Result<()> {
     .deploy(&wallet, tx_policies)
     .await?;
 
-    let contract_methods = MyContract::new(contract_id, wallet.clone()).methods();
+    let contract_methods =
+        MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
 
     let response = contract_methods
         .initialize_counter(42)
@@ -669,6 +672,167 @@ async fn test_parse_block_time() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn immutable_queries_can_be_cached() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::BASE, 1, DEFAULT_COIN_AMOUNT);
+    let provider = setup_test_provider(coins, vec![], None, None)
+        .await?
+        .with_query_cache(QueryCacheConfig::new(10, 2)?);
+    wallet.set_provider(provider.clone());
+
+    let (tx_id, _) = wallet
+        .transfer(
+            WalletUnlocked::new_random(None).address(),
+            100,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .await?;
+    provider.produce_blocks(2, None).await?;
+
+    let tx_response = provider.get_transaction_by_id(&tx_id).await?.unwrap();
+    let block = provider
+        .block(&tx_response.block_id.unwrap())
+        .await?
+        .unwrap();
+
+    for _ in 0..2 {
+        let by_height = provider
+            .block_by_height(block.header.height)
+            .await?
+            .unwrap();
+        assert_eq!(by_height.id, block.id);
+
+        let cached_response = provider.get_transaction_by_id(&tx_id).await?.unwrap();
+        assert_eq!(cached_response.block_id, tx_response.block_id);
+    }
+    assert!(provider.block_by_height(100).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn raw_graphql_queries_are_deserialized() -> Result<()> {
+    #[derive(serde::Deserialize)]
+    struct ChainQuery {
+        chain: Chain,
+    }
+    #[derive(serde::Deserialize)]
+    struct Chain {
+        name: String,
+    }
+
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+
+    let response: ChainQuery = provider
+        .raw_graphql("query { chain { name } }", serde_json::json!({}))
+        .await?;
+    assert_eq!(response.chain.name, provider.chain_info().await?.name);
+
+    let contract_id = format!("{:#x}", ContractId::zeroed());
+    let response: serde_json::Value = provider
+        .raw_graphql(
+            "query($id: ContractId!) { contract(id: $id) { id } }",
+            serde_json::json!({ "id": contract_id }),
+        )
+        .await?;
+    assert!(response["contract"].is_null());
+
+    let err = provider
+        .raw_graphql::<serde_json::Value>("query { unknownField }", serde_json::json!({}))
+        .await
+        .expect_err("should fail because the field doesn't exist");
+    assert!(err.to_string().contains("graphql query failed"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_config_applies_to_every_request() -> Result<()> {
+    use fuels::accounts::provider::ProviderConfig;
+
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+
+    // ANCHOR: provider_config
+    let provider = provider.with_config(ProviderConfig {
+        request_timeout: Some(std::time::Duration::from_secs(10)),
+        poll_interval: std::time::Duration::from_millis(100),
+        max_response_size: Some(1024 * 1024),
+    });
+    // ANCHOR_END: provider_config
+    provider.chain_info().await?;
+
+    // ANCHOR: provider_config_override
+    let impatient = provider.clone().with_config(ProviderConfig {
+        request_timeout: Some(std::time::Duration::from_nanos(1)),
+        ..*provider.config()
+    });
+    // ANCHOR_END: provider_config_override
+    let err = impatient.chain_info().await.expect_err("should time out");
+    assert!(err.to_string().contains("the node did not respond within"));
+
+    let err = provider
+        .clone()
+        .with_config(ProviderConfig {
+            max_response_size: Some(10),
+            ..*provider.config()
+        })
+        .raw_graphql::<serde_json::Value>("query { chain { name } }", serde_json::json!({}))
+        .await
+        .expect_err("should be too big");
+    assert!(err
+        .to_string()
+        .contains("exceeds the maximum response size of 10 bytes"));
+
+    // the original provider is unaffected by the overrides
+    provider.chain_info().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transfers_can_await_confirmations() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::BASE, 1, DEFAULT_COIN_AMOUNT);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    let (tx_id, _) = wallet
+        .transfer(
+            WalletUnlocked::new_random(None).address(),
+            100,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let producer = async {
+        for _ in 0..3 {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            provider.produce_blocks(1, None).await?;
+        }
+        Ok::<_, Error>(())
+    };
+    let timeout = std::time::Duration::from_secs(10);
+    let (status, produced) =
+        tokio::join!(provider.await_confirmations(&tx_id, 3, timeout), producer);
+    produced?;
+
+    assert!(matches!(status?, TxStatus::Success { .. }));
+    assert!(provider.latest_block_height().await? >= 4);
+
+    // no blocks are produced anymore
+    let timeout = std::time::Duration::from_millis(500);
+    let err = provider
+        .await_confirmations(&tx_id, 10, timeout)
+        .await
+        .expect_err("should time out");
+    assert!(matches!(err, Error::Timeout(elapsed) if elapsed == timeout));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_get_spendable_with_exclusion() -> Result<()> {
     let coin_amount_1 = 1000;
@@ -987,3 +1151,126 @@ async fn test_build_with_provider() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn contracts_are_called_on_the_network_they_are_bound_to() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "packages/fuels/tests/contracts/contract_test/out/debug/contract_test-abi.json"
+    ));
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let other_provider = setup_test_provider(vec![], vec![], None, None).await?;
+
+    let contract_id = Contract::load_from(
+        "tests/contracts/contract_test/out/debug/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+
+    // ANCHOR: network_registry
+    let mut networks = NetworkRegistry::new()
+        .with_network("deployed", wallet.try_provider()?.clone())
+        .with_network("other", other_provider);
+    networks.bind(&contract_id, "deployed")?;
+    assert_eq!(networks.network_of(&contract_id), Some("deployed"));
+
+    // the wallet isn't connected to the network the contract was deployed to
+    let mut disconnected_wallet = wallet.clone();
+    disconnected_wallet.set_provider(networks.provider("other")?.clone());
+
+    let contract_instance = MyContract::new(
+        MyContractId::new(contract_id.clone()),
+        networks.wallet_for(&contract_id, &disconnected_wallet)?,
+    );
+    // ANCHOR_END: network_registry
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+    assert_eq!(response.value, 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_activity_is_classified_and_decoded() -> Result<()> {
+    use fuels::{
+        core::codec::{AbiCache, AbiRegistry},
+        types::wallet_activity::ActivityKind,
+    };
+
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "packages/fuels/tests/contracts/contract_test/out/debug/contract_test-abi.json"
+    ));
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let receiver = WalletUnlocked::new_random(Some(provider.clone()));
+
+    let contract_id = Contract::load_from(
+        "tests/contracts/contract_test/out/debug/contract_test.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+    MyContract::new(MyContractId::new(contract_id.clone()), wallet.clone())
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+    wallet
+        .transfer(
+            receiver.address(),
+            100,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    // ANCHOR: wallet_activity
+    let abi = AbiCache::new().get_or_insert_json(&std::fs::read_to_string(
+        "tests/contracts/contract_test/out/debug/contract_test-abi.json",
+    )?)?;
+    let registry = AbiRegistry::new().with_abi(&contract_id, abi);
+
+    let page = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Backward,
+    };
+    let history = provider
+        .get_wallet_activity(wallet.address(), page.clone(), &registry)
+        .await?
+        .results;
+    // ANCHOR_END: wallet_activity
+
+    let of_kind = |kind| {
+        history
+            .iter()
+            .find(|activity| activity.kind == kind)
+            .expect("should be in the history")
+    };
+    assert_eq!(history.len(), 3);
+    of_kind(ActivityKind::Deployment);
+
+    let call = &of_kind(ActivityKind::ContractCall).contract_calls[0];
+    assert_eq!(call.function, "initialize_counter");
+    assert_eq!(call.args, Ok(vec!["42".to_string()]));
+
+    let expected_transfer = std::collections::BTreeMap::from([(BASE_ASSET_ID, 100)]);
+    assert_eq!(of_kind(ActivityKind::Sent).sent, expected_transfer);
+
+    let received = provider
+        .get_wallet_activity(receiver.address(), page, &registry)
+        .await?
+        .results;
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].kind, ActivityKind::Received);
+    assert_eq!(received[0].received, expected_transfer);
+
+    Ok(())
+}
@@ -28,7 +28,7 @@ async fn test_storage_initialization() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_instance = MyContract::new(contract_id, wallet.clone());
+    let contract_instance = MyContract::new(MyContractId::new(contract_id), wallet.clone());
 
     let result = contract_instance
         .methods()
@@ -64,7 +64,8 @@ async fn test_init_storage_automatically() -> Result<()> {
         Bytes32::from_str("f383b0ce51358be57daa3b725fe44acdb2d880604e367199080b4379c41bb6ed")
             .unwrap();
 
-    let contract_methods = MyContract::new(contract_id, wallet.clone()).methods();
+    let contract_methods =
+        MyContract::new(MyContractId::new(contract_id), wallet.clone()).methods();
 
     let value = contract_methods
         .get_value_b256(Bits256(*key1))
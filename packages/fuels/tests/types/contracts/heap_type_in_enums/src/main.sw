@@ -8,6 +8,13 @@ pub enum TestError {
     Else: u64,
 }
 
+pub enum Payload {
+    Raw: Bytes,
+    Numbers: Vec<u64>,
+    Text: String,
+    Code: u64,
+}
+
 pub struct Bimbam {
     something: Bytes,
 }
@@ -23,6 +30,8 @@ abi MyContract {
     fn returns_str_option(return_some: bool) -> Option<str>;
     fn would_raise_a_memory_overflow() -> Result<Bytes, b256>;
     fn returns_a_heap_type_too_deep() -> Result<Bimbam, u64>;
+    fn returns_payload(variant: u64) -> Payload;
+    fn payload_len(payload: Payload) -> u64;
 }
 
 impl MyContract for Contract {
@@ -125,4 +134,33 @@ impl MyContract for Contract {
         b.push(2u8);
         Result::Ok(Bimbam { something: b })
     }
+
+    fn returns_payload(variant: u64) -> Payload {
+        match variant {
+            0 => {
+                let mut b = Bytes::new();
+                b.push(1u8);
+                b.push(2u8);
+                b.push(3u8);
+                Payload::Raw(b)
+            },
+            1 => {
+                let mut v = Vec::new();
+                v.push(4);
+                v.push(5);
+                Payload::Numbers(v)
+            },
+            2 => Payload::Text(String::from_ascii_str("six")),
+            _ => Payload::Code(7),
+        }
+    }
+
+    fn payload_len(payload: Payload) -> u64 {
+        match payload {
+            Payload::Raw(b) => b.len(),
+            Payload::Numbers(v) => v.len(),
+            Payload::Text(s) => s.as_bytes().len(),
+            Payload::Code(_) => 0,
+        }
+    }
 }
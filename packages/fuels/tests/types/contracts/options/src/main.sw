@@ -19,6 +19,7 @@ abi MyContract {
     fn get_some_enum() -> Option<TestEnum>;
     fn get_some_tuple() -> Option<(TestStruct, TestEnum)>;
     fn get_none() -> Option<Address>;
+    fn get_none_struct() -> Option<TestStruct>;
     fn input_primitive(s: Option<u64>) -> bool;
     fn input_struct(s: Option<TestStruct>) -> bool;
     fn input_enum(e: Option<TestEnum>) -> bool;
@@ -56,6 +57,10 @@ impl MyContract for Contract {
         Option::None
     }
 
+    fn get_none_struct() -> Option<TestStruct> {
+        Option::None
+    }
+
     fn input_primitive(input: Option<u64>) -> bool {
         if let Option::Some(u) = input {
             return u == 36;
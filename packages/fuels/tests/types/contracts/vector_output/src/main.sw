@@ -1,5 +1,8 @@
 contract;
 
+use std::bytes::Bytes;
+use std::string::String;
+
 struct Bimbam {
     bim: u64,
     bam: u32,
@@ -27,6 +30,9 @@ abi VectorsOutputContract {
     fn u32_in_vec(len: u32) -> Vec<u32>;
     fn u64_in_vec(len: u64) -> Vec<u64>;
     fn u8_in_vec(len: u8) -> Vec<u8>;
+    fn vec_in_vec() -> Vec<Vec<u64>>;
+    fn bytes_in_vec() -> Vec<Bytes>;
+    fn string_in_vec() -> Vec<String>;
 }
 
 impl VectorsOutputContract for Contract {
@@ -145,4 +151,40 @@ impl VectorsOutputContract for Contract {
         }
         vec
     }
+
+    fn vec_in_vec() -> Vec<Vec<u64>> {
+        let mut vec: Vec<Vec<u64>> = Vec::new();
+        let mut i: u64 = 0;
+        while i < 3 {
+            let mut inner: Vec<u64> = Vec::new();
+            let mut j: u64 = 0;
+            while j < i {
+                inner.push(j);
+                j += 1;
+            }
+            vec.push(inner);
+            i += 1;
+        }
+        vec
+    }
+
+    fn bytes_in_vec() -> Vec<Bytes> {
+        let mut first = Bytes::new();
+        first.push(1u8);
+        first.push(2u8);
+        let mut second = Bytes::new();
+        second.push(3u8);
+
+        let mut vec: Vec<Bytes> = Vec::new();
+        vec.push(first);
+        vec.push(second);
+        vec
+    }
+
+    fn string_in_vec() -> Vec<String> {
+        let mut vec: Vec<String> = Vec::new();
+        vec.push(String::from_ascii_str("hello"));
+        vec.push(String::from_ascii_str("world"));
+        vec
+    }
 }
@@ -80,7 +80,7 @@ async fn call_with_structs() -> Result<()> {
     .deploy(&wallet, TxPolicies::default())
     .await?;
 
-    let contract_methods = MyContract::new(contract_id, wallet).methods();
+    let contract_methods = MyContract::new(MyContractId::new(contract_id), wallet).methods();
 
     let response = contract_methods
         .initialize_counter(counter_config)
@@ -841,7 +841,7 @@ async fn strings_must_have_correct_length() {
     let wallet = launch_provider_and_get_wallet()
         .await
         .expect("Should have wallet");
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
     let _ = contract_instance
         .methods()
         .takes_string("fuell".try_into().unwrap());
@@ -891,7 +891,7 @@ async fn strings_must_have_all_ascii_chars() {
     ));
 
     let wallet = launch_provider_and_get_wallet().await.unwrap();
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
     let _ = contract_instance
         .methods()
         .takes_string("fueŁ".try_into().unwrap());
@@ -976,7 +976,7 @@ async fn strings_must_have_correct_length_custom_types() {
     ));
 
     let wallet = launch_provider_and_get_wallet().await.unwrap();
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
     let _ = contract_instance
         .methods()
         .takes_enum(MyEnum::Bar("fuell".try_into().unwrap()));
@@ -1071,7 +1071,7 @@ async fn strings_must_have_all_ascii_chars_custom_types() {
     let wallet = launch_provider_and_get_wallet()
         .await
         .expect("Should have wallet");
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(SimpleContractId::new(null_contract_id()), wallet);
     let _ = contract_instance.methods().takes_nested_struct(input);
 }
 
@@ -1124,6 +1124,11 @@ async fn test_rust_option_can_be_decoded() -> Result<()> {
 
     assert_eq!(response.value, expected_none);
 
+    // the padding of `None` must not be decoded as a zeroed struct
+    let response = contract_methods.get_none_struct().call().await?;
+
+    assert_eq!(response.value, None);
+
     Ok(())
 }
 
@@ -1233,6 +1238,47 @@ async fn test_rust_result_can_be_decoded() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn contract_errors_are_surfaced_as_rust_errors() -> Result<()> {
+    abigen!(Contract(
+        name = "TypesContract",
+        abi = "packages/fuels/tests/types/contracts/results/out/debug/results-abi.json",
+        impl_errors = true
+    ));
+
+    let wallet = launch_provider_and_get_wallet().await?;
+    let contract_id = Contract::load_from(
+        "tests/types/contracts/results/out/debug/results.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+    let contract_methods = TypesContract::new(TypesContractId::new(contract_id), wallet).methods();
+
+    let response = contract_methods
+        .get_ok_u64()
+        .call()
+        .await?
+        .into_result()
+        .expect("should succeed");
+    assert_eq!(response.value, 10);
+
+    // ANCHOR: contract_errors
+    let err: Box<dyn std::error::Error> = contract_methods
+        .get_error()
+        .call()
+        .await?
+        .into_result()
+        .expect_err("should fail")
+        .into();
+    // ANCHOR_END: contract_errors
+
+    let expected = TestError::NoAddress("error".try_into().unwrap());
+    assert_eq!(err.to_string(), format!("{expected:?}"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_rust_result_can_be_encoded() -> Result<()> {
     setup_program_test!(
@@ -1894,6 +1940,37 @@ async fn test_nested_vector_methods_fail() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_heap_types_in_vec_output() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "VectorOutputContract",
+            project = "packages/fuels/tests/types/contracts/vector_output"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "VectorOutputContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_methods = contract_instance.methods();
+
+    let response = contract_methods.vec_in_vec().call().await?;
+    assert_eq!(response.value, vec![vec![], vec![0], vec![0, 1]]);
+
+    let response = contract_methods.bytes_in_vec().call().await?;
+    assert_eq!(response.value, vec![Bytes(vec![1, 2]), Bytes(vec![3])]);
+
+    let response = contract_methods.string_in_vec().call().await?;
+    assert_eq!(
+        response.value,
+        vec!["hello".to_string(), "world".to_string()]
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_bytes_output() -> Result<()> {
     setup_program_test!(
@@ -2142,5 +2219,34 @@ async fn test_heap_type_in_enums() -> Result<()> {
         .expect_err("should fail because it has a deeply nested heap type");
     let expected = "codec: enums currently support only one level deep heap types".to_string();
     assert_eq!(resp.to_string(), expected);
+
+    // every heap type variant of an enum can be returned
+    let expected = [
+        Payload::Raw(Bytes(vec![1, 2, 3])),
+        Payload::Numbers(vec![4, 5]),
+        Payload::Text("six".to_string()),
+        Payload::Code(7),
+    ];
+    for (variant, expected) in expected.into_iter().enumerate() {
+        let resp = contract_methods
+            .returns_payload(variant as u64)
+            .call()
+            .await?;
+        assert_eq!(resp.value, expected);
+
+        // and passed as an argument
+        let resp = contract_methods
+            .payload_len(expected.clone())
+            .call()
+            .await?;
+        let expected_len = match expected {
+            Payload::Raw(bytes) => bytes.0.len(),
+            Payload::Numbers(numbers) => numbers.len(),
+            Payload::Text(text) => text.len(),
+            Payload::Code(_) => 0,
+        };
+        assert_eq!(resp.value, expected_len as u64);
+    }
+
     Ok(())
 }
@@ -0,0 +1,30 @@
+use fuels::prelude::*;
+
+abigen!(Contract(
+    name = "MyContract",
+    abi = r#"{
+        "types": [
+            {"typeId": 0, "type": "()", "components": null, "typeParameters": null},
+            {"typeId": 1, "type": "u64", "components": null, "typeParameters": null}
+        ],
+        "functions": [{
+            "inputs": [{"name": "value", "type": 1, "typeArguments": null}],
+            "name": "store",
+            "output": {"name": "", "type": 0, "typeArguments": null},
+            "attributes": null
+        }],
+        "loggedTypes": [],
+        "messagesTypes": [],
+        "configurables": []
+    }"#
+));
+
+async fn store(instance: MyContract<WalletUnlocked>, provider: Provider) -> Result<()> {
+    let read_only_instance = instance.with_provider(provider);
+
+    read_only_instance.methods().store(42).call().await?;
+
+    Ok(())
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 use std::iter::repeat;
 
 use fuel_tx::{input::coin::CoinSigned, Bytes32, Input, Output, TxPointer, UtxoId};
-use fuels::prelude::*;
+use fuels::{prelude::*, types::ChainId};
 
 #[tokio::test]
 async fn test_wallet_balance_api_multi_asset() -> Result<()> {
@@ -293,6 +293,40 @@ async fn send_transfer_transactions() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn transfers_can_carry_a_memo() -> Result<()> {
+    const AMOUNT: u64 = 5;
+    let (wallet_1, wallet_2) = setup_transfer_test(AMOUNT).await?;
+
+    let (tx_id, _receipts) = wallet_1
+        .transfer_with_memo(
+            wallet_2.address(),
+            2,
+            BASE_ASSET_ID,
+            b"deposit-42",
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let response = wallet_1
+        .try_provider()?
+        .get_transaction_by_id(&tx_id)
+        .await?
+        .expect("transaction should exist");
+    let tx: ScriptTransaction = match response.transaction {
+        TransactionType::Script(tx) => tx,
+        _ => panic!("Received unexpected tx type!"),
+    };
+
+    assert_eq!(tx.transfer_memo(), Some(b"deposit-42".as_slice()));
+    assert_eq!(
+        wallet_2.get_asset_balance(&BASE_ASSET_ID).await?,
+        AMOUNT + 2
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn transfer_coins_with_change() -> Result<()> {
     const AMOUNT: u64 = 5;
@@ -323,6 +357,130 @@ async fn transfer_coins_with_change() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn transferred_coins_can_be_spent_right_away() -> Result<()> {
+    use fuels::{
+        accounts::CreatedUtxo,
+        types::{coin::Coin, coin_type::CoinType, input::Input},
+    };
+
+    const AMOUNT: u64 = 5;
+    let (wallet_1, wallet_2) = setup_transfer_test(AMOUNT).await?;
+    let provider = wallet_1.try_provider()?;
+
+    let response = wallet_1
+        .transfer_with_utxos(
+            wallet_2.address(),
+            3,
+            BASE_ASSET_ID,
+            &[],
+            TxPolicies::default(),
+        )
+        .await?;
+    let sent = response
+        .coins_to(wallet_2.address())
+        .next()
+        .expect("should have created a coin");
+    let change = response
+        .change_of(wallet_1.address())
+        .next()
+        .expect("should have returned change");
+    assert_eq!(sent.amount, 3);
+    assert_eq!(
+        change.amount,
+        wallet_1.get_asset_balance(&BASE_ASSET_ID).await?
+    );
+
+    // the coins are spent without asking the node for them, the node rejects inputs whose
+    // amount doesn't match the coin
+    fn spend(
+        utxo: &CreatedUtxo,
+        from: &WalletUnlocked,
+        to: &WalletUnlocked,
+    ) -> Result<ScriptTransactionBuilder> {
+        let coin = Coin {
+            amount: utxo.amount,
+            utxo_id: utxo.utxo_id,
+            owner: utxo.owner.clone(),
+            asset_id: utxo.asset_id,
+            ..Default::default()
+        };
+        let inputs = vec![Input::resource_signed(CoinType::Coin(coin))];
+        let outputs = from.get_asset_outputs_for_amount(to.address(), BASE_ASSET_ID, 1);
+        let mut tb =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+        tb.add_signer(from.clone())?;
+
+        Ok(tb)
+    }
+
+    let tx = spend(sent, &wallet_2, &wallet_1)?.build(provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+    let tx = spend(change, &wallet_1, &wallet_2)?.build(provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+
+    assert_eq!(wallet_2.get_asset_balance(&BASE_ASSET_ID).await?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn immature_coins_are_locked_and_never_selected() -> Result<()> {
+    use fuels::types::coin::Coin;
+
+    let mut wallet_1 = WalletUnlocked::new_random(None);
+    let mut wallet_2 = WalletUnlocked::new_random(None);
+
+    let mut coins = setup_single_asset_coins(wallet_1.address(), BASE_ASSET_ID, 1, 100);
+    let locked_coin = Coin {
+        maturity: 5,
+        ..setup_single_asset_coins(wallet_1.address(), BASE_ASSET_ID, 1, 1000).remove(0)
+    };
+    coins.push(locked_coin);
+
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet_1.set_provider(provider.clone());
+    wallet_2.set_provider(provider.clone());
+
+    // ANCHOR: coin_balances
+    let balances = wallet_1.get_coin_balances().await?;
+    // ANCHOR_END: coin_balances
+    assert_eq!(balances.spendable[&BASE_ASSET_ID], 100);
+    assert_eq!(balances.locked[&BASE_ASSET_ID], 1000);
+
+    let response = wallet_1
+        .transfer(
+            wallet_2.address(),
+            500,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .await;
+    assert!(response.is_err());
+
+    wallet_1
+        .transfer(wallet_2.address(), 50, BASE_ASSET_ID, TxPolicies::default())
+        .await?;
+    assert_eq!(wallet_2.get_asset_balance(&BASE_ASSET_ID).await?, 50);
+
+    provider.produce_blocks(5, None).await?;
+
+    let balances = wallet_1.get_coin_balances().await?;
+    assert!(balances.locked.is_empty());
+    assert!(balances.spendable[&BASE_ASSET_ID] >= 1000);
+
+    wallet_1
+        .transfer(
+            wallet_2.address(),
+            500,
+            BASE_ASSET_ID,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_wallet_get_coins() -> Result<()> {
     const AMOUNT: u64 = 1000;
@@ -460,3 +618,79 @@ async fn test_transfer_with_multiple_signatures() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn transactions_signed_for_another_chain_are_rejected() -> Result<()> {
+    let wallet_config = base_asset_wallet_config(1);
+    let wallet = launch_custom_provider_and_get_wallets(wallet_config, None, None)
+        .await?
+        .pop()
+        .unwrap();
+    let provider = wallet.try_provider()?;
+
+    let amount = 10;
+    let build_transfer = || async {
+        let inputs = wallet
+            .get_asset_inputs_for_amount(BASE_ASSET_ID, amount)
+            .await?;
+        let outputs =
+            wallet.get_asset_outputs_for_amount(&Address::zeroed().into(), BASE_ASSET_ID, amount);
+
+        ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default())
+            .build_without_signatures(provider)
+            .await
+    };
+
+    let mut tx = build_transfer().await?;
+    let other_chain_id = ChainId::new(*provider.chain_id() + 1);
+    tx.sign_with(&wallet, other_chain_id).await?;
+
+    let err = provider
+        .send_transaction(tx)
+        .await
+        .expect_err("should fail because of the chain id");
+    assert!(err
+        .to_string()
+        .contains("the signature of input 0 is not valid on chain"));
+
+    let mut tx = build_transfer().await?;
+    wallet.sign_transaction(&mut tx).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn accounts_are_discovered_up_to_the_gap_limit() -> Result<()> {
+    let phrase = "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+    let account = |index: usize| {
+        let path = format!("m/44'/1179993420'/{index}'/0/0");
+        WalletUnlocked::new_from_mnemonic_phrase_with_path(phrase, None, &path)
+    };
+
+    // accounts 0 and 2 are funded, account 1 is not
+    let mut coins = setup_single_asset_coins(account(0)?.address(), BASE_ASSET_ID, 1, 100);
+    coins.extend(setup_single_asset_coins(
+        account(2)?.address(),
+        BASE_ASSET_ID,
+        1,
+        100,
+    ));
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    let discovered = discover_accounts(phrase, &provider, 1).await?;
+    assert_eq!(discovered.len(), 1);
+    assert_eq!(discovered[0].address(), account(0)?.address());
+
+    let discovered = discover_accounts(phrase, &provider, 2).await?;
+    let addresses = discovered
+        .iter()
+        .map(|wallet| wallet.address().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        addresses,
+        vec![account(0)?.address().clone(), account(2)?.address().clone()]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+contract;
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+abi FnSelectors {
+    fn vec_of_u64(arg: Vec<u64>);
+    fn array_of_generic_structs(arg: [Wrapper<u32>; 2]);
+    fn option_of_bytes_vec(arg: Option<Vec<u8>>);
+    fn vec_of_vecs(arg: Vec<Vec<u8>>);
+}
+
+impl FnSelectors for Contract {
+    fn vec_of_u64(arg: Vec<u64>) {}
+    fn array_of_generic_structs(arg: [Wrapper<u32>; 2]) {}
+    fn option_of_bytes_vec(arg: Option<Vec<u8>>) {}
+    fn vec_of_vecs(arg: Vec<Vec<u8>>) {}
+}